@@ -306,6 +306,10 @@ fn main() {
                 ao_intensity: 0.4,
                 atlas_max_size: 4096,
                 atlas_padding: 1,
+                atlas_non_square: false,
+                atlas_mip_levels: 0,
+                texture_array: false,
+                interpolated_frame_ticks: 0,
                 include_air: false,
                 tint_provider: schematic_mesher::TintProvider::new(),
                 enable_block_light: false,
@@ -313,6 +317,9 @@ fn main() {
                 sky_light_level: 15,
                 enable_particles: false,
                 pre_built_atlas: None,
+                data_version: None,
+                skip_rigged_entities: false,
+                billboard_camera: None,
             },
         ),
         (
@@ -325,6 +332,10 @@ fn main() {
                 ao_intensity: 0.4,
                 atlas_max_size: 4096,
                 atlas_padding: 1,
+                atlas_non_square: false,
+                atlas_mip_levels: 0,
+                texture_array: false,
+                interpolated_frame_ticks: 0,
                 include_air: false,
                 tint_provider: schematic_mesher::TintProvider::new(),
                 enable_block_light: false,
@@ -332,6 +343,9 @@ fn main() {
                 sky_light_level: 15,
                 enable_particles: false,
                 pre_built_atlas: None,
+                data_version: None,
+                skip_rigged_entities: false,
+                billboard_camera: None,
             },
         ),
     ];
@@ -514,18 +514,18 @@ fn build_scene(s: &mut Scene) {
     // Player with full diamond armor
     s.set_with(19, 1, 77, "entity:player", &[
         ("facing", "south"),
-        ("helmet", "diamond"),
-        ("chestplate", "diamond"),
-        ("leggings", "diamond"),
-        ("boots", "diamond"),
+        ("helmet", "minecraft:diamond_helmet"),
+        ("chestplate", r#"minecraft:diamond_chestplate[trim={"pattern":"coast","material":"gold"},enchantment_glint_override=true]"#),
+        ("leggings", "minecraft:diamond_leggings"),
+        ("boots", "minecraft:diamond_boots"),
     ]);
-    // Player with mixed armor + pose
+    // Player with mixed (and dyed leather) armor + pose
     s.set_with(22, 1, 77, "entity:player", &[
         ("facing", "south"),
-        ("helmet", "iron"),
-        ("chestplate", "diamond"),
-        ("leggings", "gold"),
-        ("boots", "chainmail"),
+        ("helmet", "minecraft:leather_helmet[dyed_color=3361970]"),
+        ("chestplate", "minecraft:iron_chestplate"),
+        ("leggings", "minecraft:golden_leggings"),
+        ("boots", "minecraft:chainmail_boots"),
         ("RightArmPose", "45,0,0"),
         ("LeftArmPose", "-45,0,0"),
     ]);
@@ -590,6 +590,10 @@ fn config() -> MesherConfig {
         greedy_meshing: false,
         atlas_max_size: 4096,
         atlas_padding: 1,
+        atlas_non_square: false,
+        atlas_mip_levels: 0,
+        texture_array: false,
+        interpolated_frame_ticks: 0,
         include_air: false,
         tint_provider: TintProvider::new(),
         ambient_occlusion: true,
@@ -599,6 +603,9 @@ fn config() -> MesherConfig {
         sky_light_level: 15,
         enable_particles: true,
         pre_built_atlas: None,
+        data_version: None,
+        skip_rigged_entities: false,
+        billboard_camera: None,
     }
 }
 
@@ -77,6 +77,10 @@ fn config() -> MesherConfig {
         greedy_meshing: false,
         atlas_max_size: 4096,
         atlas_padding: 1,
+        atlas_non_square: false,
+        atlas_mip_levels: 0,
+        texture_array: false,
+        interpolated_frame_ticks: 0,
         include_air: false,
         tint_provider: TintProvider::new(),
         ambient_occlusion: true,
@@ -85,6 +89,9 @@ fn config() -> MesherConfig {
         enable_sky_light: false,
         sky_light_level: 15,
         pre_built_atlas: None,
+        data_version: None,
+        skip_rigged_entities: false,
+        billboard_camera: None,
     }
 }
 
@@ -197,6 +197,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         greedy_meshing: false,
         atlas_max_size: 4096,
         atlas_padding: 1,
+        atlas_non_square: false,
+        atlas_mip_levels: 0,
+        texture_array: false,
+        interpolated_frame_ticks: 0,
         include_air: false,
         tint_provider: TintProvider::new(),
         ambient_occlusion: true,
@@ -206,6 +210,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         sky_light_level: 15,
         enable_particles: true,
         pre_built_atlas: None,
+        data_version: None,
+        skip_rigged_entities: false,
+        billboard_camera: None,
     };
 
     let mesher = Mesher::with_config(pack, config);
@@ -281,9 +288,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 cutout_mesh: schematic_mesher::mesher::geometry::Mesh::new(),
                 transparent_mesh: schematic_mesher::mesher::geometry::Mesh::new(),
                 atlas: schematic_mesher::atlas::TextureAtlas::empty(),
+                texture_array: None,
                 bounds: BoundingBox::new([-0.5, -0.5, -0.5], [0.5, 0.5, 0.5]),
                 greedy_materials: Vec::new(),
                 animated_textures: Vec::new(),
+                diagnostics: Default::default(),
             }
         }
     };
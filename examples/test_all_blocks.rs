@@ -92,6 +92,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         greedy_meshing: false,
         atlas_max_size: 256,
         atlas_padding: 1,
+        atlas_non_square: false,
+        atlas_mip_levels: 0,
+        texture_array: false,
+        interpolated_frame_ticks: 0,
         include_air: false,
         tint_provider: TintProvider::new(),
         ambient_occlusion: false, // Disable for individual tests
@@ -101,6 +105,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         sky_light_level: 15,
         enable_particles: false,
         pre_built_atlas: None,
+        data_version: None,
+        skip_rigged_entities: false,
+        billboard_camera: None,
     };
 
     let mesher = Mesher::with_config(pack, config);
@@ -222,6 +229,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         greedy_meshing: false,
         atlas_max_size: 4096,
         atlas_padding: 1,
+        atlas_non_square: false,
+        atlas_mip_levels: 0,
+        texture_array: false,
+        interpolated_frame_ticks: 0,
         include_air: false,
         tint_provider: TintProvider::new(),
         ambient_occlusion: true,
@@ -231,6 +242,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         sky_light_level: 15,
         enable_particles: false,
         pre_built_atlas: None,
+        data_version: None,
+        skip_rigged_entities: false,
+        billboard_camera: None,
     };
     let mesher2 = Mesher::with_config(pack2, config2);
 
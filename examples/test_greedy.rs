@@ -108,6 +108,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             greedy_meshing: false,
             atlas_max_size: 4096,
             atlas_padding: 1,
+            atlas_non_square: false,
+            atlas_mip_levels: 0,
+            texture_array: false,
+            interpolated_frame_ticks: 0,
             include_air: false,
             tint_provider: TintProvider::new(),
             ambient_occlusion: true,
@@ -117,6 +121,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             sky_light_level: 15,
             enable_particles: false,
             pre_built_atlas: None,
+            data_version: None,
+            skip_rigged_entities: false,
+            billboard_camera: None,
         };
         let mesher_off = Mesher::with_config(pack1, config_off);
         let output_off = mesher_off.mesh(&source)?;
@@ -141,6 +148,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             greedy_meshing: true,
             atlas_max_size: 4096,
             atlas_padding: 1,
+            atlas_non_square: false,
+            atlas_mip_levels: 0,
+            texture_array: false,
+            interpolated_frame_ticks: 0,
             include_air: false,
             tint_provider: TintProvider::new(),
             ambient_occlusion: true,
@@ -150,6 +161,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             sky_light_level: 15,
             enable_particles: false,
             pre_built_atlas: None,
+            data_version: None,
+            skip_rigged_entities: false,
+            billboard_camera: None,
         };
         let mesher_on = Mesher::with_config(pack2, config_on);
         let output_on = mesher_on.mesh(&source)?;
@@ -197,6 +211,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             greedy_meshing: false,
             atlas_max_size: 4096,
             atlas_padding: 1,
+            atlas_non_square: false,
+            atlas_mip_levels: 0,
+            texture_array: false,
+            interpolated_frame_ticks: 0,
             include_air: false,
             tint_provider: TintProvider::new(),
             ambient_occlusion: true,
@@ -206,6 +224,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             sky_light_level: 15,
             enable_particles: false,
             pre_built_atlas: None,
+            data_version: None,
+            skip_rigged_entities: false,
+            billboard_camera: None,
         };
         let mesher_off = Mesher::with_config(pack1, config_off);
         let output_off = mesher_off.mesh(&source)?;
@@ -229,6 +250,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             greedy_meshing: true,
             atlas_max_size: 4096,
             atlas_padding: 1,
+            atlas_non_square: false,
+            atlas_mip_levels: 0,
+            texture_array: false,
+            interpolated_frame_ticks: 0,
             include_air: false,
             tint_provider: TintProvider::new(),
             ambient_occlusion: true,
@@ -238,6 +263,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             sky_light_level: 15,
             enable_particles: false,
             pre_built_atlas: None,
+            data_version: None,
+            skip_rigged_entities: false,
+            billboard_camera: None,
         };
         let mesher_on = Mesher::with_config(pack2, config_on);
         let output_on = mesher_on.mesh(&source)?;
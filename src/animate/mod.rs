@@ -16,6 +16,10 @@
 //!   on/off with a STEP `scale` track;
 //! - a block pushed by a piston → one node with a LINEAR `translation` track.
 
+mod rig;
+
+pub use rig::build_rigged_glb;
+
 use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
@@ -353,8 +357,10 @@ pub fn build_animated_glb(
             let out = piece_mesher.mesh_blocks(static_blocks.iter().map(|(p, b)| (*p, b)), bounds)?;
             pieces.push(AnimatedPiece {
                 mesh: out.mesh(),
+                emissive_indices: Vec::new(),
                 scale_keys: None,
                 translation_keys: None,
+                skin: None,
             });
         }
     }
@@ -392,8 +398,10 @@ pub fn build_animated_glb(
             }
             pieces.push(AnimatedPiece {
                 mesh,
+                emissive_indices: Vec::new(),
                 scale_keys: Some(keys),
                 translation_keys: None,
+                skin: None,
             });
         }
     }
@@ -406,8 +414,10 @@ pub fn build_animated_glb(
         }
         pieces.push(AnimatedPiece {
             mesh,
+            emissive_indices: Vec::new(),
             scale_keys: m.scale_keys.clone(),
             translation_keys: m.translation_keys.clone(),
+            skin: None,
         });
     }
 
@@ -0,0 +1,236 @@
+//! Rigged entity export: mobs and block entities as glTF skins.
+//!
+//! Instead of baking entity geometry, every entity [`entity::rig`] can rig
+//! becomes a skinned piece — one joint per model part, pivoted at the part's
+//! pose — while everything else (terrain, item frames, players, the stand
+//! under a lectern book) is meshed into one static piece as usual.
+//!
+//! With clips enabled, each rig also carries the procedural
+//! [`entity::clips`] for its type (walk cycles, wing flaps, chest lids, bell
+//! swings) as named glTF animations.
+
+use crate::atlas::TextureAtlas;
+use crate::error::Result;
+use crate::export::gltf_animated::{export_animated_glb, AnimatedPiece, PieceSkin};
+use crate::mesher::element::MISSING_TEXTURE_KEY;
+use crate::mesher::entity::{self, clips::EntityClip, rig::EntityRig};
+use crate::mesher::geometry::Mesh;
+use crate::mesher::{Mesher, MesherConfig};
+use crate::resource_pack::ResourcePack;
+use crate::types::{BlockPosition, InputBlock};
+
+use super::bounds_of;
+
+/// Build a GLB where each rigged entity carries its part hierarchy as a glTF
+/// skin, ready to be posed in Blender or by a runtime animator.
+///
+/// `with_clips` adds the built-in animation clips of each entity type.
+pub fn build_rigged_glb(
+    pack: &ResourcePack,
+    blocks: &[(BlockPosition, InputBlock)],
+    with_clips: bool,
+) -> Result<Vec<u8>> {
+    let rigs = entity_rigs(pack, blocks, with_clips);
+    let bounds = bounds_of(blocks.iter().map(|(p, _)| *p));
+
+    let cfg = MesherConfig {
+        cull_hidden_faces: false,
+        cull_occluded_blocks: false,
+        greedy_meshing: false,
+        ..Default::default()
+    };
+
+    // One full pass builds the shared atlas with every entity texture
+    // (including composited villager skins) that the rigs sample.
+    let atlas = {
+        let mesher = Mesher::with_config(pack.clone(), cfg.clone());
+        let out = mesher.mesh_blocks(blocks.iter().map(|(p, b)| (*p, b)), bounds)?;
+        out.atlas
+    };
+
+    let mut pieces: Vec<AnimatedPiece> = Vec::new();
+
+    // Everything that isn't rigged, meshed together.
+    {
+        let static_cfg = MesherConfig {
+            pre_built_atlas: Some(atlas.clone()),
+            skip_rigged_entities: true,
+            ..cfg
+        };
+        let mesher = Mesher::with_config(pack.clone(), static_cfg);
+        let out = mesher.mesh_blocks(blocks.iter().map(|(p, b)| (*p, b)), bounds)?;
+        let mesh = out.mesh();
+        if !mesh.is_empty() {
+            pieces.push(AnimatedPiece {
+                mesh,
+                emissive_indices: Vec::new(),
+                scale_keys: None,
+                translation_keys: None,
+                skin: None,
+            });
+        }
+    }
+
+    for (rig, clips) in rigs {
+        pieces.push(rigged_piece(rig, clips, &atlas));
+    }
+
+    export_animated_glb(&atlas, &pieces)
+}
+
+/// Rig every entity in `blocks` that [`entity::rig`] covers, placed at its
+/// block position, with its clips when `with_clips` is set.
+fn entity_rigs(
+    pack: &ResourcePack,
+    blocks: &[(BlockPosition, InputBlock)],
+    with_clips: bool,
+) -> Vec<(EntityRig, Vec<EntityClip>)> {
+    blocks
+        .iter()
+        .filter_map(|(pos, block)| {
+            let (mut rig, clips) = match entity::detect_mob_with_pack(block, pack) {
+                Some(mob_type) => {
+                    let rig = entity::rig::build_mob_rig(pack, block, mob_type)?;
+                    let clips = if with_clips { entity::clips::mob_clips(&rig, mob_type) } else { Vec::new() };
+                    (rig, clips)
+                }
+                None => {
+                    let entity_type = entity::detect_block_entity(block)?;
+                    let rig = entity::rig::build_block_entity_rig(pack, block, &entity_type)?;
+                    let clips =
+                        if with_clips { entity::clips::block_entity_clips(&rig, &entity_type) } else { Vec::new() };
+                    (rig, clips)
+                }
+            };
+            // Entity geometry is block-local [0,1]; blocks are centered on their position.
+            // Clips only rotate joints, so they survive the move unchanged.
+            rig.translate([pos.x as f32 - 0.5, pos.y as f32 - 0.5, pos.z as f32 - 0.5]);
+            Some((rig, clips))
+        })
+        .collect()
+}
+
+/// Turn a rig into a skinned piece, moving its UVs into the atlas.
+fn rigged_piece(rig: EntityRig, clips: Vec<EntityClip>, atlas: &TextureAtlas) -> AnimatedPiece {
+    let EntityRig { joints, mut vertices, indices, face_textures, emissive, vertex_joints } = rig;
+
+    let missing = atlas.get_region(MISSING_TEXTURE_KEY);
+    for (face, ft) in face_textures.iter().enumerate() {
+        let Some(region) = atlas.get_region(&ft.texture).or(missing) else { continue };
+        for v in vertices.iter_mut().skip(face * 4).take(4) {
+            v.uv = region.transform_uv(v.uv[0], v.uv[1]);
+        }
+    }
+
+    // Glowing quads (4 vertices each) get their own translucent primitive.
+    let (emissive_indices, indices): (Vec<u32>, Vec<u32>) = if emissive.contains(&true) {
+        let glows = |tri: &[u32]| emissive.get(tri[0] as usize / 4).copied().unwrap_or(false);
+        let (glow, rest): (Vec<&[u32]>, Vec<&[u32]>) = indices.chunks(3).partition(|tri| glows(tri));
+        (glow.concat(), rest.concat())
+    } else {
+        (Vec::new(), indices)
+    };
+
+    AnimatedPiece {
+        mesh: Mesh { vertices, indices },
+        emissive_indices,
+        scale_keys: None,
+        translation_keys: None,
+        skin: Some(PieceSkin { joints, vertex_joints, clips }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The JSON chunk of a GLB.
+    fn glb_json(glb: &[u8]) -> serde_json::Value {
+        let len = u32::from_le_bytes([glb[12], glb[13], glb[14], glb[15]]) as usize;
+        serde_json::from_slice(&glb[20..20 + len]).unwrap()
+    }
+
+    #[test]
+    fn test_rigged_glb_skins_mobs() {
+        let pack = ResourcePack::new();
+        let blocks = vec![
+            (BlockPosition::new(0, 0, 0), InputBlock::new("entity:pig")),
+            (BlockPosition::new(2, 0, 0), InputBlock::new("minecraft:chest")),
+        ];
+        let json = glb_json(&build_rigged_glb(&pack, &blocks, false).unwrap());
+
+        let skins = json["skins"].as_array().unwrap();
+        assert_eq!(skins.len(), 2);
+        let nodes = json["nodes"].as_array().unwrap();
+        let names: Vec<&str> = nodes.iter().filter_map(|n| n["name"].as_str()).collect();
+        assert!(names.contains(&"pig"));
+        assert!(names.contains(&"head"));
+        assert!(names.contains(&"lid"));
+
+        // Each skinned mesh has per-vertex joints and weights.
+        let attributes = &json["meshes"][0]["primitives"][0]["attributes"];
+        assert!(attributes.get("JOINTS_0").is_some());
+        assert!(attributes.get("WEIGHTS_0").is_some());
+        let ibm = &json["accessors"][skins[0]["inverseBindMatrices"].as_u64().unwrap() as usize];
+        assert_eq!(ibm["type"], "MAT4");
+        assert_eq!(ibm["count"], skins[0]["joints"].as_array().unwrap().len());
+        assert!(json.get("animations").is_none());
+    }
+
+    #[test]
+    fn test_rigged_glint_is_translucent_and_fullbright() {
+        let pack = ResourcePack::new();
+        let zombie = InputBlock::new("entity:zombie")
+            .with_property("chestplate", "iron_chestplate[enchantment_glint_override=true]");
+        let rig = entity::rig::build_mob_rig(&pack, &zombie, entity::MobType::Zombie).unwrap();
+        let glint: Vec<usize> = (0..rig.face_textures.len())
+            .filter(|&f| rig.face_textures[f].texture == entity::armor::GLINT_TEXTURE)
+            .collect();
+        assert!(!glint.is_empty());
+        assert!(glint.iter().all(|&f| rig.face_textures[f].is_transparent && rig.emissive[f]));
+        assert!(!rig.emissive[0]);
+
+        let json = glb_json(&build_rigged_glb(&pack, &[(BlockPosition::new(0, 0, 0), zombie)], false).unwrap());
+        let material = &json["materials"][1];
+        assert_eq!(material["alphaMode"], "BLEND");
+        assert_eq!(material["emissiveFactor"], serde_json::json!([1.0, 1.0, 1.0]));
+        let primitives = json["meshes"][0]["primitives"].as_array().unwrap();
+        let materials: Vec<u64> = primitives.iter().map(|p| p["material"].as_u64().unwrap()).collect();
+        assert_eq!(materials, [0, 1]);
+    }
+
+    #[test]
+    fn test_rigged_glb_clips() {
+        let pack = ResourcePack::new();
+        let blocks = vec![
+            (BlockPosition::new(0, 0, 0), InputBlock::new("entity:pig")),
+            (BlockPosition::new(2, 0, 0), InputBlock::new("entity:chicken")),
+            (BlockPosition::new(4, 0, 0), InputBlock::new("minecraft:chest")),
+            (BlockPosition::new(6, 0, 0), InputBlock::new("entity:bat")),
+        ];
+        let json = glb_json(&build_rigged_glb(&pack, &blocks, true).unwrap());
+
+        let animations = json["animations"].as_array().unwrap();
+        let names: Vec<&str> = animations.iter().filter_map(|a| a["name"].as_str()).collect();
+        // The flaps have different periods, so each mob gets its own.
+        assert_eq!(names, ["walk", "idle", "chicken_flap", "open_close", "bat_flap"]);
+
+        // Both walking mobs share one "walk" animation, rotating leg joints.
+        let nodes = json["nodes"].as_array().unwrap();
+        let walk = &animations[0];
+        let targets: Vec<&str> = walk["channels"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| {
+                assert_eq!(c["target"]["path"], "rotation");
+                nodes[c["target"]["node"].as_u64().unwrap() as usize]["name"].as_str().unwrap()
+            })
+            .collect();
+        assert_eq!(targets.iter().filter(|n| **n == "right_hind_leg").count(), 1);
+        assert_eq!(targets.iter().filter(|n| **n == "right_leg").count(), 1);
+        let sampler = &walk["samplers"][0];
+        assert_eq!(sampler["interpolation"], "LINEAR");
+        assert_eq!(json["accessors"][sampler["output"].as_u64().unwrap() as usize]["type"], "VEC4");
+    }
+}
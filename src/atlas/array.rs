@@ -0,0 +1,173 @@
+//! 2D texture array output, an alternative to the atlas.
+//!
+//! Every texture gets its own layer, so there is no bleeding between
+//! neighbors and UVs can tile past `[0, 1]` with a REPEAT sampler. A GPU array
+//! needs one size for all its layers, so textures are grouped by size into one
+//! [`TextureArrayGroup`] each; nothing is resampled. Layer indices count across
+//! the groups in order, so a per-vertex layer alone identifies its group.
+
+use super::builder::encode_png;
+use crate::error::{MesherError, Result};
+use crate::resource_pack::TextureData;
+use std::collections::{BTreeMap, HashMap};
+
+/// A built set of texture arrays, one group per texture size.
+#[derive(Debug, Clone)]
+pub struct TextureArray {
+    /// One array per distinct texture size, ordered by (width, height).
+    pub groups: Vec<TextureArrayGroup>,
+    index: HashMap<String, u32>,
+}
+
+/// Layers that share one size — one 2D array texture on the GPU.
+#[derive(Debug, Clone)]
+pub struct TextureArrayGroup {
+    /// Width of every layer in pixels.
+    pub width: u32,
+    /// Height of every layer in pixels.
+    pub height: u32,
+    /// Global layer index of this group's first layer.
+    pub first_layer: u32,
+    /// Texture path of each layer, in layer order.
+    pub layers: Vec<String>,
+    /// RGBA pixel data of all layers back to back, `width * height * 4`
+    /// bytes per layer — the layout GPU APIs expect for a layered upload.
+    pub pixels: Vec<u8>,
+}
+
+impl TextureArrayGroup {
+    /// RGBA pixel data of one layer, indexed within the group.
+    pub fn layer_pixels(&self, local_layer: usize) -> &[u8] {
+        let len = (self.width * self.height * 4) as usize;
+        &self.pixels[local_layer * len..(local_layer + 1) * len]
+    }
+
+    fn contains(&self, layer: u32) -> bool {
+        layer >= self.first_layer && layer < self.first_layer + self.layers.len() as u32
+    }
+}
+
+impl TextureArray {
+    /// Global layer index of a texture.
+    pub fn layer_index(&self, texture_path: &str) -> Option<u32> {
+        self.index.get(texture_path).copied()
+    }
+
+    /// Number of layers across all groups.
+    pub fn layer_count(&self) -> usize {
+        self.index.len()
+    }
+
+    /// The group holding a global layer, and the layer's index within it.
+    pub fn group_of(&self, layer: u32) -> Option<(&TextureArrayGroup, usize)> {
+        self.groups
+            .iter()
+            .find(|g| g.contains(layer))
+            .map(|g| (g, (layer - g.first_layer) as usize))
+    }
+
+    /// RGBA pixel data of one layer. Panics if `layer >= layer_count()`.
+    pub fn layer_pixels(&self, layer: usize) -> &[u8] {
+        let (group, local) = self.group_of(layer as u32).expect("layer out of range");
+        group.layer_pixels(local)
+    }
+
+    /// Export one layer as PNG bytes, at its group's size. Fails if
+    /// `layer >= layer_count()`.
+    pub fn layer_to_png(&self, layer: usize) -> Result<Vec<u8>> {
+        let (group, local) = u32::try_from(layer)
+            .ok()
+            .and_then(|layer| self.group_of(layer))
+            .ok_or_else(|| {
+                MesherError::AtlasBuild(format!(
+                    "texture array layer {} out of range ({} layers)",
+                    layer,
+                    self.layer_count()
+                ))
+            })?;
+        encode_png(group.layer_pixels(local), group.width, group.height)
+    }
+}
+
+/// Builder for [`TextureArray`]s.
+#[derive(Default)]
+pub struct TextureArrayBuilder {
+    textures: HashMap<String, TextureData>,
+}
+
+impl TextureArrayBuilder {
+    /// Create a new texture array builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a texture as a layer.
+    pub fn add_texture(&mut self, path: String, texture: TextureData) {
+        self.textures.insert(path, texture);
+    }
+
+    /// Build the arrays. Groups are ordered by size and layers by texture
+    /// path within a group, so the same textures always get the same indices.
+    pub fn build(self) -> TextureArray {
+        let mut by_size: BTreeMap<(u32, u32), Vec<(String, TextureData)>> = BTreeMap::new();
+        for (path, texture) in self.textures {
+            by_size.entry((texture.width, texture.height)).or_default().push((path, texture));
+        }
+
+        let mut groups = Vec::with_capacity(by_size.len());
+        let mut index = HashMap::new();
+        for ((width, height), mut textures) in by_size {
+            textures.sort_by(|a, b| a.0.cmp(&b.0));
+            let first_layer = index.len() as u32;
+            let mut pixels = Vec::with_capacity(textures.len() * (width * height * 4) as usize);
+            let mut layers = Vec::with_capacity(textures.len());
+            for (path, texture) in textures {
+                pixels.extend_from_slice(&texture.pixels);
+                index.insert(path.clone(), first_layer + layers.len() as u32);
+                layers.push(path);
+            }
+            groups.push(TextureArrayGroup {
+                width,
+                height,
+                first_layer,
+                layers,
+                pixels,
+            });
+        }
+
+        TextureArray { groups, index }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layers_are_grouped_by_size() {
+        let mut builder = TextureArrayBuilder::new();
+        builder.add_texture("b".to_string(), TextureData::new(16, 16, [9, 9, 9, 255].repeat(256)));
+        builder.add_texture("a".to_string(), TextureData::new(16, 16, [1, 2, 3, 255].repeat(256)));
+        builder.add_texture("sheet".to_string(), TextureData::new(64, 32, [7, 7, 7, 255].repeat(64 * 32)));
+
+        let array = builder.build();
+        assert_eq!(array.layer_count(), 3);
+        assert_eq!(array.groups.len(), 2);
+
+        let small = &array.groups[0];
+        assert_eq!((small.width, small.height, small.first_layer), (16, 16, 0));
+        assert_eq!(small.pixels.len(), 2 * 16 * 16 * 4);
+        assert_eq!(array.layer_index("a"), Some(0));
+        assert_eq!(array.layer_index("b"), Some(1));
+        assert!(array.layer_pixels(0).chunks(4).all(|px| px == [1, 2, 3, 255]));
+
+        // The non-square sheet keeps its own size instead of stretching the
+        // block layers.
+        let sheet = array.layer_index("sheet").unwrap();
+        let (group, local) = array.group_of(sheet).unwrap();
+        assert_eq!((group.width, group.height, local), (64, 32, 0));
+        assert_eq!(array.layer_pixels(sheet as usize).len(), 64 * 32 * 4);
+        assert!(array.layer_to_png(sheet as usize).is_ok());
+        assert!(array.layer_to_png(3).is_err());
+    }
+}
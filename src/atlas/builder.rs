@@ -1,5 +1,15 @@
-//! Texture atlas builder using simple row packing.
-
+//! Texture atlas builder using skyline packing.
+//!
+//! Pages are power-of-two squares, or optionally 2:1 rectangles when that is
+//! the smaller fit. Textures that don't fit into one `max_size` square spill
+//! onto further pages of the same size. Each [`AtlasRegion`] records its
+//! page, and [`AtlasRegion::transform_uv`] offsets U by the page index
+//! (UDIM-style), so geometry UVs identify their page and exporters can split
+//! primitives by it.
+
+use super::mip;
+use super::packer::Skyline;
+use super::pbr;
 use crate::error::{MesherError, Result};
 use crate::resource_pack::TextureData;
 use image::ImageEncoder;
@@ -16,6 +26,8 @@ pub struct AtlasRegion {
     pub u_max: f32,
     /// V coordinate of the bottom edge (0-1).
     pub v_max: f32,
+    /// Atlas page the region lives on (0 for single-page atlases).
+    pub page: u32,
 }
 
 impl AtlasRegion {
@@ -30,25 +42,51 @@ impl AtlasRegion {
     }
 
     /// Transform a local UV coordinate (0-1) to atlas coordinate.
+    /// U is offset by the page index, so page `n` occupies `u` in `[n, n + 1)`.
     pub fn transform_uv(&self, u: f32, v: f32) -> [f32; 2] {
         [
-            self.u_min + u * self.width(),
+            self.page as f32 + self.u_min + u * self.width(),
             self.v_min + v * self.height(),
         ]
     }
 }
 
+/// Pixel data of one atlas page beyond the first.
+#[derive(Debug, Clone)]
+pub struct AtlasPage {
+    /// RGBA pixel data, `width * height` of the owning atlas.
+    pub pixels: Vec<u8>,
+    /// LabPBR normal data for this page, laid out like `pixels`.
+    pub normal_pixels: Option<Vec<u8>>,
+    /// LabPBR specular data for this page, laid out like `pixels`.
+    pub specular_pixels: Option<Vec<u8>>,
+    /// Mip levels 1.. of `pixels`, see [`TextureAtlas::mip_chain`].
+    pub mip_chain: Vec<Vec<u8>>,
+}
+
 /// A built texture atlas.
 #[derive(Debug, Clone)]
 pub struct TextureAtlas {
-    /// Width of the atlas in pixels.
+    /// Width of each atlas page in pixels.
     pub width: u32,
-    /// Height of the atlas in pixels.
+    /// Height of each atlas page in pixels.
     pub height: u32,
-    /// RGBA pixel data.
+    /// RGBA pixel data of the first page.
     pub pixels: Vec<u8>,
     /// Mapping from texture path to atlas region.
     pub regions: HashMap<String, AtlasRegion>,
+    /// LabPBR normal atlas (RGBA, same layout as `pixels`), present when any
+    /// packed texture has a `_n` companion.
+    pub normal_pixels: Option<Vec<u8>>,
+    /// LabPBR specular atlas (RGBA, same layout as `pixels`), present when any
+    /// packed texture has a `_s` companion.
+    pub specular_pixels: Option<Vec<u8>>,
+    /// Pages after the first, when the textures overflowed `max_size`.
+    pub extra_pages: Vec<AtlasPage>,
+    /// Precomputed mip levels 1.. of the first page's color pixels, each half
+    /// the size of the previous one. Empty unless the atlas was built with
+    /// [`AtlasBuilder::mip_levels`].
+    pub mip_chain: Vec<Vec<u8>>,
 }
 
 impl TextureAtlas {
@@ -69,33 +107,133 @@ impl TextureAtlas {
             height: 16,
             pixels: vec![255; 16 * 16 * 4], // White
             regions: HashMap::new(),
+            normal_pixels: None,
+            specular_pixels: None,
+            extra_pages: Vec::new(),
+            mip_chain: Vec::new(),
         }
     }
 
-    /// Export the atlas as PNG bytes.
+    /// Number of pages (at least 1).
+    pub fn page_count(&self) -> usize {
+        1 + self.extra_pages.len()
+    }
+
+    /// Color, normal and specular pixels of a page.
+    fn page_layers(&self, page: usize) -> (&[u8], Option<&[u8]>, Option<&[u8]>) {
+        if page == 0 {
+            return (&self.pixels, self.normal_pixels.as_deref(), self.specular_pixels.as_deref());
+        }
+        let p = &self.extra_pages[page - 1];
+        (&p.pixels, p.normal_pixels.as_deref(), p.specular_pixels.as_deref())
+    }
+
+    /// RGBA pixel data of a page. Panics if `page >= page_count()`.
+    pub fn page_pixels(&self, page: usize) -> &[u8] {
+        self.page_layers(page).0
+    }
+
+    /// Number of mip levels including the base level (1 without a chain).
+    pub fn mip_level_count(&self) -> usize {
+        1 + self.mip_chain.len()
+    }
+
+    /// Width, height and RGBA pixels of a page's mip `level` (0 = base),
+    /// or `None` if the level wasn't generated.
+    pub fn mip_level(&self, page: usize, level: usize) -> Option<(u32, u32, &[u8])> {
+        let pixels = match (page, level) {
+            (_, 0) => self.page_layers(page).0,
+            (0, level) => self.mip_chain.get(level - 1)?,
+            (page, level) => self.extra_pages.get(page - 1)?.mip_chain.get(level - 1)?,
+        };
+        let width = (self.width >> level).max(1);
+        let height = (self.height >> level).max(1);
+        Some((width, height, pixels))
+    }
+
+    /// Fraction of the atlas's page area covered by texture regions (0-1),
+    /// excluding padding. Higher means less wasted texture payload.
+    pub fn packing_efficiency(&self) -> f32 {
+        let used: f32 = self.regions.values().map(|r| r.width() * r.height()).sum();
+        used / self.page_count() as f32
+    }
+
+    /// Whether the atlas carries normal or specular data.
+    pub fn has_pbr(&self) -> bool {
+        self.normal_pixels.is_some() || self.specular_pixels.is_some()
+    }
+
+    /// Export the first page as PNG bytes.
     pub fn to_png(&self) -> Result<Vec<u8>> {
-        let mut bytes = Vec::new();
-        let cursor = std::io::Cursor::new(&mut bytes);
-        let encoder = image::codecs::png::PngEncoder::new(cursor);
+        self.page_to_png(0)
+    }
+
+    /// Export a page as PNG bytes.
+    pub fn page_to_png(&self, page: usize) -> Result<Vec<u8>> {
+        encode_png(self.page_pixels(page), self.width, self.height)
+    }
+
+    /// Export the normal atlas as a glTF-convention tangent-space normal map
+    /// (XYZ in RGB). If only specular data exists the map is flat; `None`
+    /// when the atlas has no PBR data.
+    pub fn normal_map_png(&self) -> Result<Option<Vec<u8>>> {
+        self.page_normal_map_png(0)
+    }
+
+    /// [`normal_map_png`](Self::normal_map_png) for a specific page.
+    pub fn page_normal_map_png(&self, page: usize) -> Result<Option<Vec<u8>>> {
+        if !self.has_pbr() {
+            return Ok(None);
+        }
+        let pixels = match self.page_layers(page).1 {
+            Some(normal) => pbr::labpbr_normal_to_gltf(normal),
+            None => pbr::NEUTRAL_NORMAL.repeat((self.width * self.height) as usize),
+        };
+        encode_png(&pixels, self.width, self.height).map(Some)
+    }
 
-        encoder
-            .write_image(
-                &self.pixels,
-                self.width,
-                self.height,
-                image::ExtendedColorType::Rgba8,
-            )
-            .map_err(|e| MesherError::AtlasBuild(format!("Failed to encode PNG: {}", e)))?;
+    /// Export the specular atlas as a glTF metallic-roughness texture
+    /// (roughness in G, metalness in B). `None` when the atlas has no PBR data.
+    pub fn metallic_roughness_png(&self) -> Result<Option<Vec<u8>>> {
+        self.page_metallic_roughness_png(0)
+    }
 
-        Ok(bytes)
+    /// [`metallic_roughness_png`](Self::metallic_roughness_png) for a specific page.
+    pub fn page_metallic_roughness_png(&self, page: usize) -> Result<Option<Vec<u8>>> {
+        if !self.has_pbr() {
+            return Ok(None);
+        }
+        let pixels = match self.page_layers(page).2 {
+            Some(specular) => pbr::labpbr_specular_to_metallic_roughness(specular),
+            None => pbr::labpbr_specular_to_metallic_roughness(
+                &pbr::NEUTRAL_SPECULAR.repeat((self.width * self.height) as usize),
+            ),
+        };
+        encode_png(&pixels, self.width, self.height).map(Some)
     }
 }
 
+pub(super) fn encode_png(pixels: &[u8], width: u32, height: u32) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let cursor = std::io::Cursor::new(&mut bytes);
+    let encoder = image::codecs::png::PngEncoder::new(cursor);
+
+    encoder
+        .write_image(pixels, width, height, image::ExtendedColorType::Rgba8)
+        .map_err(|e| MesherError::AtlasBuild(format!("Failed to encode PNG: {}", e)))?;
+
+    Ok(bytes)
+}
+
 /// Builder for creating texture atlases.
 pub struct AtlasBuilder {
     max_size: u32,
     padding: u32,
     textures: HashMap<String, TextureData>,
+    normal_maps: HashMap<String, TextureData>,
+    specular_maps: HashMap<String, TextureData>,
+    non_square: bool,
+    mip_levels: u32,
 }
 
 impl AtlasBuilder {
@@ -105,22 +243,58 @@ impl AtlasBuilder {
             max_size,
             padding,
             textures: HashMap::new(),
+            normal_maps: HashMap::new(),
+            specular_maps: HashMap::new(),
+            non_square: false,
+            mip_levels: 0,
         }
     }
 
+    /// Allow 2:1 (wider than tall) pages when they fit the textures in less
+    /// area than the next square size.
+    pub fn non_square(mut self, enabled: bool) -> Self {
+        self.non_square = enabled;
+        self
+    }
+
+    /// Make the atlas safe to sample with `levels` mip levels: regions start
+    /// and end on `2^levels` pixel boundaries, the gutter is rounded up to a
+    /// multiple of that (at least one, even with padding 0) and filled by
+    /// extruding edge pixels like the padding, and the atlas carries a
+    /// precomputed [`mip_chain`](TextureAtlas::mip_chain) that preserves each
+    /// texture's alpha-test coverage. 0 disables this.
+    pub fn mip_levels(mut self, levels: u32) -> Self {
+        self.mip_levels = levels.min(MAX_MIP_LEVELS);
+        self
+    }
+
     /// Add a texture to the atlas.
     pub fn add_texture(&mut self, path: String, texture: TextureData) {
         self.textures.insert(path, texture);
     }
 
-    /// Build the texture atlas using simple row packing.
+    /// Add the LabPBR normal map (`_n`) for a texture added with
+    /// [`add_texture`](Self::add_texture). It is placed in the normal atlas at
+    /// the same region as the color texture.
+    pub fn add_normal_map(&mut self, path: String, texture: TextureData) {
+        self.normal_maps.insert(path, texture);
+    }
+
+    /// Add the LabPBR specular map (`_s`) for a texture added with
+    /// [`add_texture`](Self::add_texture).
+    pub fn add_specular_map(&mut self, path: String, texture: TextureData) {
+        self.specular_maps.insert(path, texture);
+    }
+
+    /// Build the texture atlas using skyline packing.
     pub fn build(self) -> Result<TextureAtlas> {
         if self.textures.is_empty() {
             return Ok(TextureAtlas::empty());
         }
 
-        let padding = self.padding;
         let max_size = self.max_size;
+        let layout = CellLayout::new(self.padding, self.mip_levels);
+        let padding = layout.gutter;
 
         // Sort textures by height (tallest first) for better packing, with the
         // path as a tiebreaker so packing is FULLY deterministic. Without the
@@ -129,113 +303,266 @@ impl AtlasBuilder {
         let mut textures: Vec<_> = self.textures.into_iter().collect();
         textures.sort_by(|a, b| b.1.height.cmp(&a.1.height).then_with(|| a.0.cmp(&b.0)));
 
-        // Calculate required atlas size
-        let total_area: u32 = textures
+        // Calculate required atlas area
+        let total_area: u64 = textures
             .iter()
-            .map(|(_, t)| (t.width + padding * 2) * (t.height + padding * 2))
+            .map(|(_, t)| {
+                let (w, h) = layout.cell_size(t);
+                w as u64 * h as u64
+            })
             .sum();
 
-        // Start with minimum size that could fit all textures
-        let min_size = (total_area as f64).sqrt().ceil() as u32;
-        let mut atlas_size = 64u32;
-        while atlas_size < min_size && atlas_size < max_size {
-            atlas_size *= 2;
+        // Try page sizes in increasing area, skipping those too small to hold
+        // every texture; if none fits, spill onto max_size pages.
+        let single_page = page_sizes(max_size, self.non_square)
+            .into_iter()
+            .filter(|&(w, h)| w as u64 * h as u64 >= total_area)
+            .find_map(|(w, h)| {
+                let positions = pack_page(&textures, w, h, &layout);
+                positions
+                    .into_iter()
+                    .map(|p| p.map(|(x, y)| (0, x, y)))
+                    .collect::<Option<Vec<_>>>()
+                    .map(|positions| (w, h, positions))
+            });
+        let (width, height, positions) = match single_page {
+            Some(packed) => packed,
+            None => (max_size, max_size, pack_pages(&textures, max_size, &layout)?),
+        };
+        let page_count = positions.iter().map(|&(page, _, _)| page as usize + 1).max().unwrap_or(1);
+        let page_len = (width * height * 4) as usize;
+
+        let mut pages = vec![vec![0u8; page_len]; page_count];
+        let mut regions = HashMap::new();
+        for ((path, texture), &(page, x, y)) in textures.iter().zip(&positions) {
+            blit_padded(&mut pages[page as usize], width, texture, x, y, &layout);
+
+            let (x, y) = (x + padding, y + padding);
+            regions.insert(
+                path.clone(),
+                AtlasRegion {
+                    u_min: x as f32 / width as f32,
+                    v_min: y as f32 / height as f32,
+                    u_max: (x + texture.width) as f32 / width as f32,
+                    v_max: (y + texture.height) as f32 / height as f32,
+                    page,
+                },
+            );
         }
 
-        // Try to pack at increasing sizes
-        loop {
-            if atlas_size > max_size {
-                return Err(MesherError::AtlasBuild(format!(
-                    "Failed to pack {} textures into {}x{} atlas",
-                    textures.len(),
-                    max_size,
-                    max_size
-                )));
+        // The PBR atlases share the color atlas's layout. Textures without a
+        // companion get the LabPBR neutral value over their whole cell.
+        let build_companion = |maps: &HashMap<String, TextureData>, neutral: [u8; 4]| {
+            if maps.is_empty() {
+                return vec![None; page_count];
             }
-
-            if let Some((pixels, regions)) = try_pack(&textures, atlas_size, padding) {
-                return Ok(TextureAtlas {
-                    width: atlas_size,
-                    height: atlas_size,
-                    pixels,
-                    regions,
+            let mut companion = vec![vec![0u8; page_len]; page_count];
+            for ((path, texture), &(page, x, y)) in textures.iter().zip(&positions) {
+                let map = match maps.get(path) {
+                    Some(map) if map.width == texture.width && map.height == texture.height => {
+                        map.clone()
+                    }
+                    Some(map) => resize_nearest(map, texture.width, texture.height),
+                    None => TextureData::new(
+                        texture.width,
+                        texture.height,
+                        neutral.repeat((texture.width * texture.height) as usize),
+                    ),
+                };
+                blit_padded(&mut companion[page as usize], width, &map, x, y, &layout);
+            }
+            companion.into_iter().map(Some).collect()
+        };
+        let normal = build_companion(&self.normal_maps, pbr::NEUTRAL_NORMAL);
+        let specular = build_companion(&self.specular_maps, pbr::NEUTRAL_SPECULAR);
+
+        // Mip chains need the textures on each page, in base-level pixels.
+        let levels = self.mip_levels.min(width.min(height).ilog2());
+        let mut mip_chains = vec![Vec::new(); page_count];
+        if levels > 0 {
+            let mut rects = vec![Vec::new(); page_count];
+            for ((_, texture), &(page, x, y)) in textures.iter().zip(&positions) {
+                let (cell_w, cell_h) = layout.cell_size(texture);
+                rects[page as usize].push(mip::MipRect {
+                    texture: (x + padding, y + padding, texture.width, texture.height),
+                    cell: (x, y, cell_w, cell_h),
                 });
             }
-
-            atlas_size *= 2;
+            for ((chain, pixels), rects) in mip_chains.iter_mut().zip(&pages).zip(&rects) {
+                *chain = mip::build_chain(pixels, width, height, levels, rects);
+            }
         }
+
+        let mut pages = pages.into_iter().zip(normal).zip(specular).zip(mip_chains).map(
+            |(((pixels, normal_pixels), specular_pixels), mip_chain)| AtlasPage {
+                pixels,
+                normal_pixels,
+                specular_pixels,
+                mip_chain,
+            },
+        );
+        let first = pages.next().expect("at least one page");
+        Ok(TextureAtlas {
+            width,
+            height,
+            pixels: first.pixels,
+            regions,
+            normal_pixels: first.normal_pixels,
+            specular_pixels: first.specular_pixels,
+            extra_pages: pages.collect(),
+            mip_chain: first.mip_chain,
+        })
     }
 }
 
-/// Try to pack textures into an atlas of the given size.
-fn try_pack(
-    textures: &[(String, TextureData)],
-    atlas_size: u32,
-    padding: u32,
-) -> Option<(Vec<u8>, HashMap<String, AtlasRegion>)> {
-    let mut pixels = vec![0u8; (atlas_size * atlas_size * 4) as usize];
-    let mut regions = HashMap::new();
-
-    // Simple row-based packing
-    let mut current_x = 0u32;
-    let mut current_y = 0u32;
-    let mut row_height = 0u32;
-
-    for (path, texture) in textures {
-        let tex_width = texture.width + padding * 2;
-        let tex_height = texture.height + padding * 2;
-
-        // Check if we need to start a new row
-        if current_x + tex_width > atlas_size {
-            current_x = 0;
-            current_y += row_height;
-            row_height = 0;
+/// Candidate page sizes in increasing area: power-of-two squares from 64 up
+/// to `max_size`, with 2:1 rectangles in between when `non_square` is set.
+fn page_sizes(max_size: u32, non_square: bool) -> Vec<(u32, u32)> {
+    let (mut w, mut h) = (64.min(max_size), 64.min(max_size));
+    let mut sizes = vec![(w, h)];
+    while (w, h) != (max_size, max_size) {
+        if non_square && w == h {
+            w = (w * 2).min(max_size);
+        } else if non_square {
+            h = (h * 2).min(max_size);
+        } else {
+            w = (w * 2).min(max_size);
+            h = (h * 2).min(max_size);
         }
+        sizes.push((w, h));
+    }
+    sizes
+}
 
-        // Check if we've run out of space
-        if current_y + tex_height > atlas_size {
-            return None;
-        }
+/// Largest supported mip alignment (`2^6` = 64, the smallest page size).
+const MAX_MIP_LEVELS: u32 = 6;
 
-        // Place the texture
-        let x = current_x + padding;
-        let y = current_y + padding;
-
-        // Copy texture pixels to atlas with edge-clamped padding.
-        // Padding pixels get the nearest edge pixel color to prevent
-        // bilinear filtering from bleeding black at texel boundaries.
-        for py in 0..tex_height {
-            for px in 0..tex_width {
-                let sx = (px as i32 - padding as i32).clamp(0, texture.width as i32 - 1) as u32;
-                let sy = (py as i32 - padding as i32).clamp(0, texture.height as i32 - 1) as u32;
-
-                let src_idx = ((sy * texture.width + sx) * 4) as usize;
-                let dst_x = current_x + px;
-                let dst_y = current_y + py;
-                let dst_idx = ((dst_y * atlas_size + dst_x) * 4) as usize;
-
-                if src_idx + 4 <= texture.pixels.len() && dst_idx + 4 <= pixels.len() {
-                    pixels[dst_idx..dst_idx + 4]
-                        .copy_from_slice(&texture.pixels[src_idx..src_idx + 4]);
-                }
-            }
+/// How textures are laid out in their cells: `gutter` pixels on every side,
+/// with cell positions and sizes aligned to `align` pixels.
+struct CellLayout {
+    gutter: u32,
+    align: u32,
+}
+
+impl CellLayout {
+    fn new(padding: u32, mip_levels: u32) -> Self {
+        let align = 1 << mip_levels;
+        // Mipmapped sampling reaches past the region even without padding.
+        let padding = if mip_levels > 0 { padding.max(1) } else { padding };
+        Self {
+            gutter: padding.div_ceil(align) * align,
+            align,
         }
+    }
 
-        // Record the region
-        let region = AtlasRegion {
-            u_min: x as f32 / atlas_size as f32,
-            v_min: y as f32 / atlas_size as f32,
-            u_max: (x + texture.width) as f32 / atlas_size as f32,
-            v_max: (y + texture.height) as f32 / atlas_size as f32,
+    /// Cell size of a texture, in pixels.
+    fn cell_size(&self, texture: &TextureData) -> (u32, u32) {
+        (
+            texture.width.div_ceil(self.align) * self.align + self.gutter * 2,
+            texture.height.div_ceil(self.align) * self.align + self.gutter * 2,
+        )
+    }
+}
+
+/// Skyline-pack textures into one `width × height` page. Returns the top-left
+/// corner of each texture's padded cell, in input order, or `None` for
+/// textures that didn't fit. Packing runs in units of the layout's alignment.
+fn pack_page<'a>(
+    textures: impl IntoIterator<Item = &'a (String, TextureData)>,
+    width: u32,
+    height: u32,
+    layout: &CellLayout,
+) -> Vec<Option<(u32, u32)>> {
+    let align = layout.align;
+    let mut skyline = Skyline::new(width / align, height / align);
+    textures
+        .into_iter()
+        .map(|(_, t)| {
+            let (w, h) = layout.cell_size(t);
+            skyline
+                .insert(w / align, h / align)
+                .map(|(x, y)| (x * align, y * align))
+        })
+        .collect()
+}
+
+/// Pack textures onto as many `page_size` square pages as needed. Returns
+/// `(page, x, y)` of each texture's padded cell, in input order.
+fn pack_pages(
+    textures: &[(String, TextureData)],
+    page_size: u32,
+    layout: &CellLayout,
+) -> Result<Vec<(u32, u32, u32)>> {
+    let mut positions: Vec<Option<(u32, u32, u32)>> = vec![None; textures.len()];
+    let mut page = 0;
+    loop {
+        let pending: Vec<usize> = (0..textures.len()).filter(|&i| positions[i].is_none()).collect();
+        let Some(&first) = pending.first() else {
+            break;
         };
-        regions.insert(path.clone(), region);
+        let placed = pack_page(pending.iter().map(|&i| &textures[i]), page_size, page_size, layout);
+        if placed.iter().all(Option::is_none) {
+            let (path, texture) = &textures[first];
+            return Err(MesherError::AtlasBuild(format!(
+                "Texture {} ({}x{}) does not fit into a {}x{} atlas page",
+                path, texture.width, texture.height, page_size, page_size
+            )));
+        }
+        for (&i, pos) in pending.iter().zip(placed) {
+            positions[i] = pos.map(|(x, y)| (page, x, y));
+        }
+        page += 1;
+    }
+    Ok(positions.into_iter().flatten().collect())
+}
 
-        // Update position
-        current_x += tex_width;
-        row_height = row_height.max(tex_height);
+/// Copy texture pixels into a padded cell at `(cell_x, cell_y)`.
+/// Padding pixels get the nearest edge pixel color to prevent
+/// bilinear filtering from bleeding black at texel boundaries.
+fn blit_padded(
+    pixels: &mut [u8],
+    atlas_width: u32,
+    texture: &TextureData,
+    cell_x: u32,
+    cell_y: u32,
+    layout: &CellLayout,
+) {
+    let padding = layout.gutter;
+    let (tex_width, tex_height) = layout.cell_size(texture);
+    for py in 0..tex_height {
+        for px in 0..tex_width {
+            let sx = (px as i32 - padding as i32).clamp(0, texture.width as i32 - 1) as u32;
+            let sy = (py as i32 - padding as i32).clamp(0, texture.height as i32 - 1) as u32;
+
+            let src_idx = ((sy * texture.width + sx) * 4) as usize;
+            let dst_x = cell_x + px;
+            let dst_y = cell_y + py;
+            let dst_idx = ((dst_y * atlas_width + dst_x) * 4) as usize;
+
+            if src_idx + 4 <= texture.pixels.len() && dst_idx + 4 <= pixels.len() {
+                pixels[dst_idx..dst_idx + 4]
+                    .copy_from_slice(&texture.pixels[src_idx..src_idx + 4]);
+            }
+        }
     }
+}
 
-    Some((pixels, regions))
+/// Nearest-neighbour resize, for PBR companions authored at a different
+/// resolution than their color texture.
+fn resize_nearest(texture: &TextureData, width: u32, height: u32) -> TextureData {
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        let sy = y * texture.height / height;
+        for x in 0..width {
+            let sx = x * texture.width / width;
+            let idx = ((sy * texture.width + sx) * 4) as usize;
+            match texture.pixels.get(idx..idx + 4) {
+                Some(px) => pixels.extend_from_slice(px),
+                None => pixels.extend_from_slice(&[0, 0, 0, 0]),
+            }
+        }
+    }
+    TextureData::new(width, height, pixels)
 }
 
 #[cfg(test)]
@@ -299,6 +626,137 @@ mod tests {
         assert!(atlas.contains("blue"));
     }
 
+    #[test]
+    fn test_pbr_atlases_share_regions() {
+        let mut builder = AtlasBuilder::new(256, 1);
+        builder.add_texture("plain".to_string(), create_test_texture(16, 16, [255, 0, 0, 255]));
+        builder.add_texture("pbr".to_string(), create_test_texture(16, 16, [0, 255, 0, 255]));
+        // Companion at half resolution gets scaled up to the color texture's size.
+        builder.add_normal_map("pbr".to_string(), create_test_texture(8, 8, [200, 60, 255, 255]));
+
+        let atlas = builder.build().unwrap();
+        assert!(atlas.has_pbr());
+        assert!(atlas.specular_pixels.is_none());
+        let normal = atlas.normal_pixels.as_ref().unwrap();
+        assert_eq!(normal.len(), atlas.pixels.len());
+
+        let pixel_at = |pixels: &[u8], path: &str| {
+            let region = atlas.get_region(path).unwrap();
+            let x = (region.u_min * atlas.width as f32) as usize + 15;
+            let y = (region.v_min * atlas.height as f32) as usize + 15;
+            let idx = (y * atlas.width as usize + x) * 4;
+            [pixels[idx], pixels[idx + 1], pixels[idx + 2], pixels[idx + 3]]
+        };
+        assert_eq!(pixel_at(normal, "pbr"), [200, 60, 255, 255]);
+        assert_eq!(pixel_at(normal, "plain"), pbr::NEUTRAL_NORMAL);
+        assert!(atlas.normal_map_png().unwrap().is_some());
+        assert!(atlas.metallic_roughness_png().unwrap().is_some());
+    }
+
+    #[test]
+    fn test_overflow_spills_onto_pages() {
+        let mut builder = AtlasBuilder::new(64, 0);
+        for i in 0..5 {
+            builder.add_texture(format!("tex{}", i), create_test_texture(32, 32, [i * 40, 0, 0, 255]));
+        }
+
+        let atlas = builder.build().unwrap();
+        assert_eq!((atlas.width, atlas.height), (64, 64));
+        assert_eq!(atlas.page_count(), 2);
+        assert_eq!(atlas.get_region("tex3").unwrap().page, 0);
+        let spilled = atlas.get_region("tex4").unwrap();
+        assert_eq!(spilled.page, 1);
+        assert_eq!(spilled.transform_uv(0.0, 0.0), [1.0, 0.0]);
+        assert_eq!(atlas.page_pixels(1)[0], 160);
+        assert!(atlas.page_to_png(1).is_ok());
+
+        let mut builder = AtlasBuilder::new(64, 0);
+        builder.add_texture("huge".to_string(), create_test_texture(128, 16, [0; 4]));
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn test_mixed_sizes_pack_tightly() {
+        // Row packing put these on a 256x256 page: the 64x64 sheet and the
+        // strip set the first row's height and the tiles ran out of width.
+        let mut builder = AtlasBuilder::new(1024, 0);
+        builder.add_texture("strip".to_string(), create_test_texture(16, 128, [0; 4]));
+        builder.add_texture("sheet".to_string(), create_test_texture(64, 64, [0; 4]));
+        for i in 0..40 {
+            builder.add_texture(format!("tile{:02}", i), create_test_texture(16, 16, [0; 4]));
+        }
+
+        let atlas = builder.build().unwrap();
+        assert_eq!((atlas.width, atlas.height), (128, 128));
+        assert!(atlas.packing_efficiency() > 0.8, "{}", atlas.packing_efficiency());
+
+        let mut builder = AtlasBuilder::new(1024, 0).non_square(true);
+        for i in 0..24 {
+            builder.add_texture(format!("tile{:02}", i), create_test_texture(16, 16, [0; 4]));
+        }
+        let atlas = builder.build().unwrap();
+        assert_eq!((atlas.width, atlas.height), (128, 64));
+        assert!((atlas.packing_efficiency() - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_mip_aligned_atlas() {
+        let mut builder = AtlasBuilder::new(256, 1).mip_levels(2);
+        builder.add_texture("odd".to_string(), create_test_texture(6, 10, [255, 0, 0, 255]));
+        let mut leaves = create_test_texture(16, 16, [0, 160, 0, 255]);
+        for (i, px) in leaves.pixels.chunks_mut(4).enumerate() {
+            if i % 2 == 1 || (i / 16) % 2 == 1 {
+                px.copy_from_slice(&[0, 0, 0, 0]);
+            }
+        }
+        builder.add_texture("leaves".to_string(), leaves);
+
+        let atlas = builder.build().unwrap();
+        for region in atlas.regions.values() {
+            let x = region.u_min * atlas.width as f32;
+            let y = region.v_min * atlas.height as f32;
+            assert_eq!((x % 4.0, y % 4.0), (0.0, 0.0));
+        }
+        // The 6x10 texture's cell is rounded up to 8x12 and filled by
+        // extruding its edge, not left transparent.
+        let region = atlas.get_region("odd").unwrap();
+        let x = (region.u_max * atlas.width as f32) as usize + 1;
+        let y = (region.v_max * atlas.height as f32) as usize + 1;
+        let idx = (y * atlas.width as usize + x) * 4;
+        assert_eq!(&atlas.pixels[idx..idx + 4], &[255, 0, 0, 255]);
+
+        assert_eq!(atlas.mip_level_count(), 3);
+        let (w, h, level2) = atlas.mip_level(0, 2).unwrap();
+        assert_eq!((w, h), (atlas.width / 4, atlas.height / 4));
+        assert_eq!(level2.len(), (w * h * 4) as usize);
+        // Three in four leaf texels are cut out; box filtering alone would
+        // average every texel to alpha 64 and the leaves would vanish.
+        let leaves = atlas.get_region("leaves").unwrap();
+        let x = (leaves.u_min * w as f32) as usize;
+        let y = (leaves.v_min * h as f32) as usize;
+        let idx = (y * w as usize + x) * 4;
+        assert_eq!(level2[idx + 1], 160);
+        assert!(level2[idx + 3] >= 128);
+        assert!(atlas.mip_level(0, 3).is_none());
+    }
+
+    #[test]
+    fn test_mip_levels_keep_a_gutter_without_padding() {
+        let mut builder = AtlasBuilder::new(256, 0).mip_levels(2);
+        builder.add_texture("red".to_string(), create_test_texture(16, 16, [255, 0, 0, 255]));
+        builder.add_texture("blue".to_string(), create_test_texture(16, 16, [0, 0, 255, 255]));
+
+        let atlas = builder.build().unwrap();
+        // A full aligned gutter of extruded edge around each region.
+        for (path, color) in [("red", [255, 0, 0, 255]), ("blue", [0, 0, 255, 255])] {
+            let region = atlas.get_region(path).unwrap();
+            let x = (region.u_max * atlas.width as f32) as usize + 3;
+            let y = (region.v_min * atlas.height as f32) as usize;
+            let idx = (y * atlas.width as usize + x) * 4;
+            assert_eq!(atlas.pixels[idx..idx + 4], color, "{}", path);
+        }
+    }
+
     #[test]
     fn test_atlas_region_transform() {
         let region = AtlasRegion {
@@ -306,6 +764,7 @@ mod tests {
             v_min: 0.5,
             u_max: 0.5,
             v_max: 0.75,
+            page: 0,
         };
 
         let [u, v] = region.transform_uv(0.0, 0.0);
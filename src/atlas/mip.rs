@@ -0,0 +1,161 @@
+//! Precomputed mip chains for mip-aligned atlases.
+//!
+//! Each level is a 2×2 box filter of the previous one. Color is weighted by
+//! alpha so transparent texels (often black) don't darken cutout edges, and
+//! alpha is rescaled per region so the fraction of texels passing the
+//! alpha-test cutoff matches the base level — without this, leaves and
+//! grass thin out and go hollow at distance.
+
+/// Alpha-test cutoff used for coverage (0.5, as in the cutout shaders).
+const ALPHA_CUTOFF: f32 = 0.5;
+
+/// A packed texture on a page, in base-level pixels.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MipRect {
+    /// Texture area: `(x, y, width, height)`.
+    pub texture: (u32, u32, u32, u32),
+    /// Texture area plus its gutter.
+    pub cell: (u32, u32, u32, u32),
+}
+
+/// Build levels `1..=levels` of an RGBA page. Level `n` is
+/// `max(1, width >> n) × max(1, height >> n)`.
+pub(crate) fn build_chain(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    levels: u32,
+    rects: &[MipRect],
+) -> Vec<Vec<u8>> {
+    // Base-level coverage of each texture that has partial transparency.
+    let targets: Vec<Option<f32>> = rects
+        .iter()
+        .map(|r| {
+            let opaque = rect_pixels(r.texture, width).all(|i| pixels[i + 3] == 255);
+            (!opaque).then(|| coverage(pixels, width, r.texture, 1.0))
+        })
+        .collect();
+
+    let mut chain: Vec<Vec<u8>> = Vec::with_capacity(levels as usize);
+    let (mut w, mut h) = (width, height);
+    for level in 1..=levels {
+        let src = chain.last().map_or(pixels, Vec::as_slice);
+        let (mut dst, nw, nh) = downsample(src, w, h);
+        for (rect, target) in rects.iter().zip(&targets) {
+            let Some(target) = *target else { continue };
+            let texture = scale_rect(rect.texture, level);
+            let scale = coverage_scale(&dst, nw, texture, target);
+            for i in rect_pixels(scale_rect(rect.cell, level), nw) {
+                if i + 3 < dst.len() {
+                    dst[i + 3] = (dst[i + 3] as f32 * scale).round().min(255.0) as u8;
+                }
+            }
+        }
+        chain.push(dst);
+        (w, h) = (nw, nh);
+    }
+    chain
+}
+
+/// Halve an RGBA image with a 2×2 box filter, alpha-weighting color.
+fn downsample(src: &[u8], width: u32, height: u32) -> (Vec<u8>, u32, u32) {
+    let (nw, nh) = ((width / 2).max(1), (height / 2).max(1));
+    let mut dst = vec![0u8; (nw * nh * 4) as usize];
+    for y in 0..nh {
+        for x in 0..nw {
+            let mut rgb = [0u32; 3];
+            let mut plain = [0u32; 3];
+            let mut alpha = 0u32;
+            for (sx, sy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                let sx = (x * 2 + sx).min(width - 1);
+                let sy = (y * 2 + sy).min(height - 1);
+                let i = ((sy * width + sx) * 4) as usize;
+                let a = src[i + 3] as u32;
+                for c in 0..3 {
+                    rgb[c] += src[i + c] as u32 * a;
+                    plain[c] += src[i + c] as u32;
+                }
+                alpha += a;
+            }
+            let o = ((y * nw + x) * 4) as usize;
+            for c in 0..3 {
+                dst[o + c] = (rgb[c] + alpha / 2)
+                    .checked_div(alpha)
+                    .unwrap_or((plain[c] + 2) / 4) as u8;
+            }
+            dst[o + 3] = ((alpha + 2) / 4) as u8;
+        }
+    }
+    (dst, nw, nh)
+}
+
+/// Find the alpha multiplier that brings a rect's coverage closest to
+/// `target` (binary search; coverage only grows with the multiplier).
+fn coverage_scale(pixels: &[u8], width: u32, rect: (u32, u32, u32, u32), target: f32) -> f32 {
+    let (mut lo, mut hi) = (0.0f32, 16.0f32);
+    for _ in 0..16 {
+        let mid = (lo + hi) / 2.0;
+        if coverage(pixels, width, rect, mid) < target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    hi
+}
+
+/// Fraction of a rect's texels whose scaled alpha passes the cutoff.
+fn coverage(pixels: &[u8], width: u32, rect: (u32, u32, u32, u32), scale: f32) -> f32 {
+    let (mut passed, mut total) = (0u32, 0u32);
+    for i in rect_pixels(rect, width) {
+        if let Some(&a) = pixels.get(i + 3) {
+            total += 1;
+            if a as f32 / 255.0 * scale >= ALPHA_CUTOFF {
+                passed += 1;
+            }
+        }
+    }
+    if total == 0 {
+        1.0
+    } else {
+        passed as f32 / total as f32
+    }
+}
+
+/// A base-level rect at mip `level`, kept at least one texel in size.
+fn scale_rect((x, y, w, h): (u32, u32, u32, u32), level: u32) -> (u32, u32, u32, u32) {
+    (x >> level, y >> level, (w >> level).max(1), (h >> level).max(1))
+}
+
+/// Byte offsets of every pixel in a rect.
+fn rect_pixels((x, y, w, h): (u32, u32, u32, u32), width: u32) -> impl Iterator<Item = usize> {
+    (y..y + h).flat_map(move |py| (x..x + w).map(move |px| ((py * width + px) * 4) as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preserves_alpha_test_coverage() {
+        // A 4x4 texture where a sparse checkerboard of texels is opaque: a
+        // plain box filter averages every 2x2 block to alpha 0.25 and the
+        // texture vanishes at level 1.
+        let mut pixels = vec![0u8; 4 * 4 * 4];
+        for i in [0, 2, 8, 10] {
+            pixels[i * 4..i * 4 + 4].copy_from_slice(&[0, 200, 0, 255]);
+        }
+        let rect = MipRect { texture: (0, 0, 4, 4), cell: (0, 0, 4, 4) };
+
+        let chain = build_chain(&pixels, 4, 4, 2, &[rect]);
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0].len(), 2 * 2 * 4);
+        assert_eq!(chain[1].len(), 4);
+        // Color ignores the transparent black texels.
+        assert_eq!(&chain[0][0..3], &[0, 200, 0]);
+        // Base coverage is 25%, so the level-1 texels must pass the cutoff
+        // instead of all sitting at alpha 64.
+        assert!(coverage(&chain[0], 2, (0, 0, 2, 2), 1.0) >= 0.25);
+        assert!(coverage(&chain[1], 1, (0, 0, 1, 1), 1.0) >= 0.25);
+    }
+}
@@ -1,8 +1,15 @@
 //! Texture atlas building.
 //!
 //! This module combines multiple textures into a single atlas
-//! and remaps UV coordinates accordingly.
+//! and remaps UV coordinates accordingly. LabPBR normal and specular
+//! companions are packed into parallel atlases with identical regions.
+//! [`TextureArray`] is the alternative one-layer-per-texture output.
 
+mod array;
 mod builder;
+mod mip;
+mod packer;
+pub mod pbr;
 
-pub use builder::{AtlasBuilder, TextureAtlas, AtlasRegion};
+pub use array::{TextureArray, TextureArrayBuilder, TextureArrayGroup};
+pub use builder::{AtlasBuilder, AtlasPage, TextureAtlas, AtlasRegion};
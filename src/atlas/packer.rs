@@ -0,0 +1,119 @@
+//! Skyline bin packing.
+//!
+//! The skyline is the upper contour of everything placed so far, stored as
+//! horizontal segments sorted by x. Each rectangle goes where its top edge
+//! ends up lowest (ties: leftmost), which keeps mixed sizes — 16×16 tiles,
+//! tall animation strips, 64×64 entity sheets — tightly interleaved instead
+//! of wasting the rest of a row next to the tallest texture.
+
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    x: u32,
+    y: u32,
+    width: u32,
+}
+
+/// A bottom-left skyline packer for one `width × height` bin.
+#[derive(Debug)]
+pub(crate) struct Skyline {
+    width: u32,
+    height: u32,
+    segments: Vec<Segment>,
+}
+
+impl Skyline {
+    pub(crate) fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            segments: vec![Segment { x: 0, y: 0, width }],
+        }
+    }
+
+    /// Place a `w × h` rectangle and return its top-left corner, or `None`
+    /// if it doesn't fit anywhere.
+    pub(crate) fn insert(&mut self, w: u32, h: u32) -> Option<(u32, u32)> {
+        if w == 0 || h == 0 || w > self.width || h > self.height {
+            return None;
+        }
+
+        // (top edge, x, segment index) of the best position so far
+        let mut best: Option<(u32, u32, usize)> = None;
+        for i in 0..self.segments.len() {
+            let x = self.segments[i].x;
+            if x + w > self.width {
+                break;
+            }
+            let y = self.resting_height(i, w);
+            if y + h > self.height {
+                continue;
+            }
+            if best.is_none_or(|(top, bx, _)| (y + h, x) < (top, bx)) {
+                best = Some((y + h, x, i));
+            }
+        }
+
+        let (top, x, index) = best?;
+        self.segments.insert(index, Segment { x, y: top, width: w });
+
+        // Trim the segments now covered by the new one.
+        let end = x + w;
+        let i = index + 1;
+        while i < self.segments.len() && self.segments[i].x < end {
+            let seg_end = self.segments[i].x + self.segments[i].width;
+            if seg_end <= end {
+                self.segments.remove(i);
+            } else {
+                self.segments[i].width = seg_end - end;
+                self.segments[i].x = end;
+                break;
+            }
+        }
+
+        // Merge neighbours at the same height.
+        let mut i = 0;
+        while i + 1 < self.segments.len() {
+            if self.segments[i].y == self.segments[i + 1].y {
+                self.segments[i].width += self.segments[i + 1].width;
+                self.segments.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+
+        Some((x, top - h))
+    }
+
+    /// Lowest y at which a rectangle of width `w` starting at segment `i`
+    /// clears every segment below it.
+    fn resting_height(&self, i: usize, w: u32) -> u32 {
+        let mut y = 0;
+        let mut remaining = w as i64;
+        for seg in &self.segments[i..] {
+            if remaining <= 0 {
+                break;
+            }
+            y = y.max(seg.y);
+            remaining -= seg.width as i64;
+        }
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_beside_tall_rectangles() {
+        let mut skyline = Skyline::new(64, 64);
+        // A tall strip, then tiles that fill the space next to it.
+        assert_eq!(skyline.insert(16, 64), Some((0, 0)));
+        let mut placed = 0;
+        while skyline.insert(16, 16).is_some() {
+            placed += 1;
+        }
+        assert_eq!(placed, 12);
+        assert_eq!(skyline.insert(1, 1), None);
+    }
+}
@@ -0,0 +1,71 @@
+//! LabPBR companion map conversion.
+//!
+//! LabPBR packs ship `<texture>_n.png` and `<texture>_s.png` next to each color
+//! texture. The atlas keeps them in their raw LabPBR encoding; exporters convert
+//! to glTF conventions with the functions here.
+//!
+//! - `_n`: R/G = tangent-space normal X/Y (OpenGL, +Y up), B = ambient
+//!   occlusion, A = height. Z is reconstructed from X and Y.
+//! - `_s`: R = perceptual smoothness, G = F0 (0–229) or a hardcoded metal
+//!   (230–255), B = porosity / subsurface, A = emission.
+
+/// Normal-map value for textures without a `_n` companion: flat, no AO, full height.
+pub const NEUTRAL_NORMAL: [u8; 4] = [128, 128, 255, 255];
+
+/// Specular value for textures without a `_s` companion: fully rough dielectric,
+/// no emission.
+pub const NEUTRAL_SPECULAR: [u8; 4] = [0, 0, 0, 255];
+
+/// Specular G values at or above this are LabPBR metals.
+const METAL_THRESHOLD: u8 = 230;
+
+/// Convert LabPBR `_n` pixels to a glTF normal map (XYZ in RGB, A = 255).
+pub fn labpbr_normal_to_gltf(pixels: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(pixels.len());
+    for px in pixels.chunks_exact(4) {
+        let x = px[0] as f32 / 255.0 * 2.0 - 1.0;
+        let y = px[1] as f32 / 255.0 * 2.0 - 1.0;
+        let z = (1.0 - x * x - y * y).max(0.0).sqrt();
+        let b = ((z * 0.5 + 0.5) * 255.0).round() as u8;
+        out.extend_from_slice(&[px[0], px[1], b, 255]);
+    }
+    out
+}
+
+/// Convert LabPBR `_s` pixels to a glTF metallic-roughness texture
+/// (G = perceptual roughness, B = metalness; R and A unused and set to 255).
+pub fn labpbr_specular_to_metallic_roughness(pixels: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(pixels.len());
+    for px in pixels.chunks_exact(4) {
+        let roughness = 255 - px[0];
+        let metalness = if px[1] >= METAL_THRESHOLD { 255 } else { 0 };
+        out.extend_from_slice(&[255, roughness, metalness, 255]);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_labpbr_channels() {
+        let normal = labpbr_normal_to_gltf(&NEUTRAL_NORMAL);
+        assert_eq!(&normal[..2], &[128, 128]);
+        assert_eq!(normal[2], 255);
+
+        // Tilted fully along +X: no Z left.
+        let tilted = labpbr_normal_to_gltf(&[255, 128, 40, 17]);
+        assert_eq!(tilted[2], 128);
+        assert_eq!(tilted[3], 255);
+
+        let mr = labpbr_specular_to_metallic_roughness(&[
+            0, 0, 0, 255, // neutral: rough dielectric
+            255, 10, 0, 255, // polished dielectric
+            200, 230, 0, 255, // metal
+        ]);
+        assert_eq!(&mr[0..4], &[255, 255, 0, 255]);
+        assert_eq!(&mr[4..8], &[255, 0, 0, 255]);
+        assert_eq!(&mr[8..12], &[255, 55, 255, 255]);
+    }
+}
@@ -4,7 +4,8 @@
 
 use clap::{Parser, Subcommand, ValueEnum};
 use schematic_mesher::{
-    export_glb, load_resource_pack, Mesher, MesherConfig, ObjExport,
+    export_glb, load_resource_pack, load_resource_packs, validate_pack, BlockRegistry, Mesher,
+    MesherConfig, ObjExport, PaintingRegistry, SkinLibrary,
 };
 use std::collections::HashMap;
 use std::fs;
@@ -77,9 +78,29 @@ enum Commands {
         #[arg(long, default_value = "4096")]
         atlas_size: u32,
 
+        /// Allow 2:1 atlas pages when smaller than the next square size
+        #[arg(long)]
+        atlas_non_square: bool,
+
+        /// Align the atlas for this many mip levels and build a mip chain
+        #[arg(long, default_value = "0")]
+        atlas_mip_levels: u32,
+
         /// Biome for tinting (e.g., "plains", "swamp", "jungle")
         #[arg(long)]
         biome: Option<String>,
+
+        /// Vanilla `blocks.json` report used to complete partial block states
+        #[arg(long)]
+        block_registry: Option<PathBuf>,
+
+        /// Painting variants: a registry JSON or a data pack directory
+        #[arg(long)]
+        painting_registry: Option<PathBuf>,
+
+        /// Directory of `<hash>.png` player skins and capes for profile `textures`
+        #[arg(long)]
+        skins: Option<PathBuf>,
     },
 
     /// Show information about a resource pack
@@ -88,6 +109,21 @@ enum Commands {
         #[arg(short, long)]
         resource_pack: PathBuf,
     },
+
+    /// Check a resource pack for missing models, textures and broken references
+    Validate {
+        /// Path(s) to resource packs, lowest priority first (ZIP or directory)
+        #[arg(short, long, required = true)]
+        resource_pack: Vec<PathBuf>,
+
+        /// Print the report as JSON instead of text
+        #[arg(long)]
+        json: bool,
+
+        /// Treat warnings as errors for the exit code
+        #[arg(long)]
+        strict: bool,
+    },
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
@@ -128,7 +164,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             no_ao,
             ao_intensity,
             atlas_size,
+            atlas_non_square,
+            atlas_mip_levels,
             biome,
+            block_registry,
+            painting_registry,
+            skins,
         } => {
             mesh_from_json(
                 &input,
@@ -139,12 +180,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 !no_ao,
                 ao_intensity,
                 atlas_size,
+                atlas_non_square,
+                atlas_mip_levels,
                 biome,
+                block_registry.as_ref(),
+                painting_registry.as_ref(),
+                skins.as_ref(),
             )?;
         }
         Commands::Info { resource_pack } => {
             show_pack_info(&resource_pack)?;
         }
+        Commands::Validate {
+            resource_pack,
+            json,
+            strict,
+        } => {
+            let ok = validate_packs(&resource_pack, json, strict)?;
+            if !ok {
+                std::process::exit(1);
+            }
+        }
     }
 
     Ok(())
@@ -202,12 +258,38 @@ fn mesh_from_json(
     ambient_occlusion: bool,
     ao_intensity: f32,
     atlas_size: u32,
+    atlas_non_square: bool,
+    atlas_mip_levels: u32,
     biome: Option<String>,
+    block_registry: Option<&PathBuf>,
+    painting_registry: Option<&PathBuf>,
+    skins: Option<&PathBuf>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("Loading resource pack from {:?}...", resource_pack_path);
-    let pack = load_resource_pack(resource_pack_path)?;
+    let mut pack = load_resource_pack(resource_pack_path)?;
     println!("  Found {} blockstates", pack.blockstate_count());
 
+    if let Some(registry_path) = block_registry {
+        println!("Loading block registry from {:?}...", registry_path);
+        let registry = BlockRegistry::load(registry_path)?;
+        println!("  Found {} blocks", registry.len());
+        pack.set_block_registry(registry);
+    }
+
+    if let Some(registry_path) = painting_registry {
+        println!("Loading painting variants from {:?}...", registry_path);
+        let registry = PaintingRegistry::load(registry_path)?;
+        println!("  Found {} variants", registry.len());
+        pack.set_painting_registry(registry);
+    }
+
+    if let Some(skins_path) = skins {
+        println!("Loading player skins from {:?}...", skins_path);
+        let library = SkinLibrary::load(skins_path)?;
+        println!("  Found {} textures", library.len());
+        pack.set_skin_library(library);
+    }
+
     println!("Loading block data from {:?}...", input_path);
     let json_content = fs::read_to_string(input_path)?;
     let block_data: BlockDataInput = serde_json::from_str(&json_content)?;
@@ -222,6 +304,9 @@ fn mesh_from_json(
     config.ambient_occlusion = ambient_occlusion;
     config.ao_intensity = ao_intensity;
     config.atlas_max_size = atlas_size;
+    config.atlas_non_square = atlas_non_square;
+    config.atlas_mip_levels = atlas_mip_levels;
+    config.data_version = block_data.data_version;
 
     if let Some(biome_name) = &biome {
         config = config.with_biome(biome_name);
@@ -249,11 +334,14 @@ fn mesh_from_json(
         output.total_triangles()
     );
     println!(
-        "  Atlas: {}x{} with {} regions",
+        "  Atlas: {}x{} with {} regions on {} page(s), {:.1}% packed",
         output.atlas.width,
         output.atlas.height,
-        output.atlas.regions.len()
+        output.atlas.regions.len(),
+        output.atlas.page_count(),
+        output.atlas.packing_efficiency() * 100.0
     );
+    print_diagnostics(&output.diagnostics);
 
     export_output(&output, output_path, format, "mesh")?;
 
@@ -272,6 +360,62 @@ fn show_pack_info(resource_pack_path: &PathBuf) -> Result<(), Box<dyn std::error
     Ok(())
 }
 
+fn validate_packs(
+    resource_pack_paths: &[PathBuf],
+    json: bool,
+    strict: bool,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    if !json {
+        println!("Loading resource pack(s) from {:?}...", resource_pack_paths);
+    }
+    let pack = load_resource_packs(resource_pack_paths)?;
+    let report = validate_pack(&pack);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("\nValidation Report:");
+        println!("  Blockstates checked: {}", report.blockstates_checked);
+        println!("  Models checked: {}", report.models_checked);
+        println!("  Textures checked: {}", report.textures_checked);
+        for issue in &report.issues {
+            let severity = match issue.severity {
+                schematic_mesher::resource_pack::Severity::Error => "error",
+                schematic_mesher::resource_pack::Severity::Warning => "warning",
+            };
+            match &issue.referenced_by {
+                Some(by) => println!(
+                    "  {}: {} ({}, referenced by {})",
+                    severity, issue.message, issue.resource, by
+                ),
+                None => println!("  {}: {} ({})", severity, issue.message, issue.resource),
+            }
+        }
+        println!(
+            "\n{} error(s), {} warning(s)",
+            report.error_count(),
+            report.warning_count()
+        );
+    }
+
+    Ok(report.is_ok() && !(strict && report.warning_count() > 0))
+}
+
+fn print_diagnostics(diagnostics: &schematic_mesher::MeshDiagnostics) {
+    for d in &diagnostics.unknown_blocks {
+        println!("  Warning: skipped {} x {} ({})", d.count, d.state, d.reason);
+    }
+    for d in &diagnostics.unsupported_entities {
+        println!("  Warning: unsupported entity {} x {}", d.count, d.state);
+    }
+    for d in &diagnostics.missing_textures {
+        println!("  Warning: missing texture {} ({} faces)", d.texture, d.face_count);
+    }
+    for d in &diagnostics.fallbacks {
+        println!("  Note: {:?} for {} ({} times)", d.kind, d.subject, d.count);
+    }
+}
+
 fn export_output(
     output: &schematic_mesher::MesherOutput,
     path: &PathBuf,
@@ -304,8 +448,13 @@ fn export_output(
             fs::write(&mtl_path, &obj_export.mtl)?;
             fs::write(&png_path, &obj_export.texture_png)?;
 
-            // Write greedy material texture files
+            // Write extra atlas pages and greedy material texture files
             let parent = obj_path.parent().unwrap_or(std::path::Path::new("."));
+            for tex in &obj_export.atlas_pages {
+                let tex_path = parent.join(&tex.filename);
+                fs::write(&tex_path, &tex.png_data)?;
+                println!("  Atlas page: {:?}", tex_path);
+            }
             for tex in &obj_export.greedy_textures {
                 let tex_path = parent.join(&tex.filename);
                 fs::write(&tex_path, &tex.png_data)?;
@@ -324,6 +473,9 @@ fn export_output(
 // JSON input format
 #[derive(serde::Deserialize)]
 struct BlockDataInput {
+    /// DataVersion the blocks were saved with; older states are upgraded.
+    #[serde(default, rename = "DataVersion", alias = "data_version")]
+    data_version: Option<u32>,
     blocks: Vec<BlockEntry>,
 }
 
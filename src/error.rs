@@ -48,6 +48,10 @@ pub enum MesherError {
     #[error("Model inheritance too deep (possible circular reference): {0}")]
     ModelInheritanceTooDeep(String),
 
+    /// Entity model file could not be converted.
+    #[error("Entity model error: {0}")]
+    EntityModel(String),
+
     /// Failed to build texture atlas.
     #[error("Atlas building error: {0}")]
     AtlasBuild(String),
@@ -5,14 +5,17 @@
 //! - Normals: f32 → i8 (normalized, axis-aligned → exact)
 //! - UVs: kept as f32 (greedy UVs can exceed 0-1 range)
 //! - Colors: f32 → u8 (normalized, 256 levels sufficient)
+//! - Tangents: f32 → i8 (normalized; only written when the atlas has LabPBR maps)
 //! - Indices: u32 → u16 when vertex_count < 65536
 
 use crate::error::{MesherError, Result};
 use crate::mesher::geometry::Mesh;
-use crate::mesher::MesherOutput;
+use crate::mesh_output::MeshLayer;
+use crate::mesher::{AnimatedTextureExport, MesherOutput};
 use gltf_json as json;
 use json::validation::Checked::Valid;
 use json::validation::USize64;
+use std::borrow::Cow;
 use std::mem;
 
 fn quantize_position(pos: [f32; 3], center: &[f32; 3], half_ext: &[f32; 3]) -> [i16; 3] {
@@ -32,6 +35,11 @@ fn quantize_normal(n: [f32; 3]) -> [i8; 3] {
     ]
 }
 
+fn quantize_tangent(t: [f32; 4]) -> [i8; 4] {
+    let [x, y, z] = quantize_normal([t[0], t[1], t[2]]);
+    [x, y, z, if t[3] < 0.0 { -127 } else { 127 }]
+}
+
 fn quantize_color(c: [f32; 4]) -> [u8; 4] {
     [
         (c[0] * 255.0).round().clamp(0.0, 255.0) as u8,
@@ -51,7 +59,15 @@ fn align_buffer(buffer: &mut Vec<u8>, alignment: usize) {
 /// Uses KHR_mesh_quantization for ~56% vertex data reduction.
 /// Separates opaque and transparent geometry into different primitives for correct rendering.
 /// Greedy-merged materials get their own textures with REPEAT wrapping for proper tiling.
+/// When the atlas carries LabPBR maps, atlas materials also get a `normalTexture` and a
+/// `metallicRoughnessTexture`, and atlas primitives get per-vertex tangents.
+/// Multi-page atlases get one image and one material set per page.
+/// Emissive faces get copies of the atlas materials that emit their base color.
+/// Faces using an animated texture get their own material sampling the sprite sheet;
+/// a `KHR_animation_pointer` STEP track drives its `KHR_texture_transform` offset through
+/// the mcmeta frame order and frame times. Greedy-merged animated faces stay on frame 0.
 pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
+    super::reject_texture_array(output)?;
     // Main layers are SoA (MeshLayer) and the writer reads SoA directly — no AoS
     // conversion. Greedy materials are still AoS Mesh (converted at their call).
     let opaque_mesh = &output.opaque_mesh;
@@ -66,8 +82,15 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
         return Err(MesherError::Export("Cannot export empty mesh".to_string()));
     }
 
-    // Get texture PNG data
-    let texture_png = atlas.to_png()?;
+    // Get texture PNG data: color, then the optional PBR maps, per atlas page
+    let mut page_pngs = Vec::with_capacity(atlas.page_count());
+    for page in 0..atlas.page_count() {
+        let mut pngs = vec![atlas.page_to_png(page)?];
+        pngs.extend(atlas.page_normal_map_png(page)?);
+        pngs.extend(atlas.page_metallic_roughness_png(page)?);
+        page_pngs.push(pngs);
+    }
+    let with_tangents = atlas.has_pbr();
 
     // Calculate combined bounding box for position quantization
     let (bounds_min, bounds_max) = calculate_bounds_all(output);
@@ -96,6 +119,8 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
         uv_bytes: usize,
         color_offset: usize,
         color_bytes: usize,
+        tangent_offset: usize,
+        tangent_bytes: usize,
         idx_offset: usize,
         idx_bytes: usize,
         vertex_count: usize,
@@ -110,6 +135,7 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
         layer: &crate::mesh_output::MeshLayer,
         center: &[f32; 3],
         half_ext: &[f32; 3],
+        with_tangents: bool,
     ) -> Option<MeshOffsets> {
         if layer.is_empty() {
             return None;
@@ -159,6 +185,15 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
             buffer.extend_from_slice(&q);
         }
 
+        // Tangents: i8 × 4 (1 byte each = 4 bytes/vertex), normal-mapped layers only
+        let tangent_offset = buffer.len();
+        if with_tangents {
+            for tangent in layer.compute_tangents() {
+                let q = quantize_tangent(tangent);
+                buffer.extend_from_slice(bytemuck_cast_slice(&q));
+            }
+        }
+
         // Indices: u16 or u32
         if use_u16_indices {
             // Align to 2 bytes (u16 alignment)
@@ -185,7 +220,9 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
             uv_offset,
             uv_bytes: color_offset - uv_offset,
             color_offset,
-            color_bytes: idx_offset - color_offset,
+            color_bytes: tangent_offset - color_offset,
+            tangent_offset,
+            tangent_bytes: idx_offset - tangent_offset,
             idx_offset,
             idx_bytes: end - idx_offset,
             vertex_count,
@@ -196,9 +233,36 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
         })
     }
 
-    let opaque_offsets = write_mesh(&mut buffer_data, opaque_mesh, &center, &half_ext);
-    let cutout_offsets = write_mesh(&mut buffer_data, cutout_mesh, &center, &half_ext);
-    let transparent_offsets = write_mesh(&mut buffer_data, transparent_mesh, &center, &half_ext);
+    // Atlas layers, split per page when the atlas spilled. Each page gets its
+    // own opaque/cutout/transparent material triple: material = page * 3 + kind.
+    // Emissive faces are split off again and get an emissive copy of it.
+    // Faces on animated regions are pulled out first: (animated texture, kind, offsets).
+    let mut atlas_offsets: Vec<(u32, bool, MeshOffsets)> = Vec::new();
+    let mut animated_offsets: Vec<(usize, usize, MeshOffsets)> = Vec::new();
+    for (kind, layer) in [opaque_mesh, cutout_mesh, transparent_mesh].into_iter().enumerate() {
+        let (layer, animated) = split_animated(layer, atlas, &output.animated_textures);
+        for (anim_idx, anim_layer) in animated {
+            if let Some(offsets) = write_mesh(&mut buffer_data, &anim_layer, &center, &half_ext, false) {
+                animated_offsets.push((anim_idx, kind, offsets));
+            }
+        }
+        let pages: Vec<(u32, Cow<MeshLayer>)> = if atlas.page_count() > 1 {
+            layer.split_atlas_pages().into_iter().map(|(p, l)| (p, Cow::Owned(l))).collect()
+        } else {
+            vec![(0, layer)]
+        };
+        for (page, layer) in pages {
+            let parts = match layer.split_emissive() {
+                Some((rest, emissive)) => vec![(false, Cow::Owned(rest)), (true, Cow::Owned(emissive))],
+                None => vec![(false, layer)],
+            };
+            for (emissive, layer) in parts {
+                if let Some(offsets) = write_mesh(&mut buffer_data, &layer, &center, &half_ext, with_tangents) {
+                    atlas_offsets.push((page * 3 + kind as u32, emissive, offsets));
+                }
+            }
+        }
+    }
 
     // Write greedy material mesh data
     let mut greedy_mesh_offsets: Vec<(Option<MeshOffsets>, Option<MeshOffsets>)> = Vec::new();
@@ -209,20 +273,29 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
             &crate::mesh_output::mesh_to_layer(&gm.opaque_mesh),
             &center,
             &half_ext,
+            false,
         );
         let transparent = write_mesh(
             &mut buffer_data,
             &crate::mesh_output::mesh_to_layer(&gm.transparent_mesh),
             &center,
             &half_ext,
+            false,
         );
         greedy_mesh_offsets.push((opaque, transparent));
     }
 
-    // Append atlas texture PNG (aligned to 4 bytes)
-    align_buffer(&mut buffer_data, 4);
-    let atlas_texture_offset = buffer_data.len();
-    buffer_data.extend_from_slice(&texture_png);
+    // Append atlas page PNGs (aligned to 4 bytes)
+    let mut page_texture_offsets: Vec<Vec<(usize, usize)>> = Vec::new();
+    for pngs in &page_pngs {
+        let mut offsets = Vec::new();
+        for png in pngs {
+            align_buffer(&mut buffer_data, 4);
+            offsets.push((buffer_data.len(), png.len()));
+            buffer_data.extend_from_slice(png);
+        }
+        page_texture_offsets.push(offsets);
+    }
 
     // Append greedy texture PNGs (aligned to 4 bytes)
     let mut greedy_texture_offsets: Vec<(usize, usize)> = Vec::new();
@@ -246,6 +319,24 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
         anim_texture_offsets.push((offset, at.sprite_sheet_png.len()));
     }
 
+    // Append keyframes of animated textures that are drawn: times (f32 seconds),
+    // then `KHR_texture_transform` offsets (vec2 f32). A closing key at the full
+    // duration repeats the first offset so looping players see the last frame's time.
+    let mut keyframe_offsets: Vec<Option<(usize, usize, f32)>> = Vec::new();
+    for (i, at) in output.animated_textures.iter().enumerate() {
+        if !animated_offsets.iter().any(|(a, _, _)| *a == i) {
+            keyframe_offsets.push(None);
+            continue;
+        }
+        let (times, offsets) = animation_keyframes(at);
+        align_buffer(&mut buffer_data, 4);
+        let times_offset = buffer_data.len();
+        buffer_data.extend_from_slice(bytemuck_cast_slice(&times));
+        let offsets_offset = buffer_data.len();
+        buffer_data.extend_from_slice(bytemuck_cast_slice(&offsets));
+        keyframe_offsets.push(Some((times_offset, offsets_offset, *times.last().unwrap_or(&0.0))));
+    }
+
     let total_buffer_size = buffer_data.len();
 
     // Build glTF arrays
@@ -353,52 +444,80 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
             None,
         ));
 
-        primitives.push(create_primitive(accessor_start, accessor_start + 4, material_idx));
-    }
-
-    // Material 0: Atlas opaque
-    materials.push(create_material_with_texture(json::material::AlphaMode::Opaque, 0));
-    // Material 1: Atlas cutout (alpha-tested, writes depth — fire, flowers, leaves)
-    materials.push(create_material_with_alpha_cutoff(0, 0.5));
-    // Material 2: Atlas transparent (alpha-blended — water, ice, stained glass)
-    materials.push(create_material_with_texture(json::material::AlphaMode::Blend, 0));
+        // Tangent accessor: i8, normalized (after indices so the base layout is unchanged)
+        let tangent_accessor = if offsets.tangent_bytes > 0 {
+            buffer_views.push(create_buffer_view(offsets.tangent_offset, offsets.tangent_bytes, Some(json::buffer::Target::ArrayBuffer)));
+            let tangent_view = *buffer_view_idx; *buffer_view_idx += 1;
+            accessors.push(create_accessor(
+                tangent_view,
+                offsets.vertex_count,
+                json::accessor::Type::Vec4,
+                json::accessor::ComponentType::I8,
+                true,
+                None,
+                None,
+            ));
+            Some(accessor_start + 5)
+        } else {
+            None
+        };
 
-    // Atlas texture image and glTF texture
-    buffer_views.push(json::buffer::View {
-        buffer: json::Index::new(0),
-        byte_length: USize64(texture_png.len() as u64),
-        byte_offset: Some(USize64(atlas_texture_offset as u64)),
-        byte_stride: None,
-        extensions: Default::default(),
-        extras: Default::default(),
-        target: None,
-    });
-    let atlas_image_view = buffer_view_idx;
-    buffer_view_idx += 1;
+        primitives.push(create_primitive(accessor_start, accessor_start + 4, tangent_accessor, material_idx));
+    }
 
-    images.push(json::Image {
-        buffer_view: Some(json::Index::new(atlas_image_view)),
-        mime_type: Some(json::image::MimeType("image/png".to_string())),
-        uri: None,
-        extensions: Default::default(),
-        extras: Default::default(),
-    });
-    textures.push(json::Texture {
-        sampler: Some(json::Index::new(0)),
-        source: json::Index::new(0),
-        extensions: Default::default(),
-        extras: Default::default(),
-    });
+    // Per atlas page: images + textures (color, then normal and metallic-roughness
+    // when present) and materials page*3 + {0: opaque, 1: cutout, 2: transparent}.
+    for offsets in &page_texture_offsets {
+        let first_texture = textures.len() as u32;
+        for &(offset, len) in offsets {
+            buffer_views.push(create_buffer_view(offset, len, None));
+            let img_view = buffer_view_idx;
+            buffer_view_idx += 1;
+
+            let image_idx = images.len() as u32;
+            images.push(json::Image {
+                buffer_view: Some(json::Index::new(img_view)),
+                mime_type: Some(json::image::MimeType("image/png".to_string())),
+                uri: None,
+                extensions: Default::default(),
+                extras: Default::default(),
+            });
+            textures.push(json::Texture {
+                sampler: Some(json::Index::new(0)),
+                source: json::Index::new(image_idx),
+                extensions: Default::default(),
+                extras: Default::default(),
+            });
+        }
 
-    // Add atlas-based primitives
-    if let Some(ref offsets) = opaque_offsets {
-        add_mesh_primitive(offsets, 0, &mut buffer_views, &mut accessors, &mut primitives, &mut buffer_view_idx);
-    }
-    if let Some(ref offsets) = cutout_offsets {
-        add_mesh_primitive(offsets, 1, &mut buffer_views, &mut accessors, &mut primitives, &mut buffer_view_idx);
+        let mut page_materials = [
+            // Atlas opaque
+            create_material_with_texture(json::material::AlphaMode::Opaque, first_texture),
+            // Atlas cutout (alpha-tested, writes depth — fire, flowers, leaves)
+            create_material_with_alpha_cutoff(first_texture, 0.5),
+            // Atlas transparent (alpha-blended — water, ice, stained glass)
+            create_material_with_texture(json::material::AlphaMode::Blend, first_texture),
+        ];
+        if offsets.len() == 3 {
+            for material in &mut page_materials {
+                apply_pbr_textures(material, first_texture + 1, first_texture + 2);
+            }
+        }
+        materials.extend(page_materials);
     }
-    if let Some(ref offsets) = transparent_offsets {
-        add_mesh_primitive(offsets, 2, &mut buffer_views, &mut accessors, &mut primitives, &mut buffer_view_idx);
+
+    // Add atlas-based primitives, creating emissive materials on first use
+    let mut emissive_materials: std::collections::HashMap<u32, u32> = Default::default();
+    for (material_idx, emissive, offsets) in &atlas_offsets {
+        let material_idx = if *emissive {
+            *emissive_materials.entry(*material_idx).or_insert_with(|| {
+                materials.push(emissive_material(&materials[*material_idx as usize]));
+                materials.len() as u32 - 1
+            })
+        } else {
+            *material_idx
+        };
+        add_mesh_primitive(offsets, material_idx, &mut buffer_views, &mut accessors, &mut primitives, &mut buffer_view_idx);
     }
 
     // Add greedy material images, textures, materials, and primitives
@@ -488,6 +607,91 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
         anim_texture_indices.push(image_idx);
     }
 
+    // Materials and primitives for animated faces, plus one animation per
+    // animated texture: a STEP sampler shared by one channel per material.
+    let mut animations: Vec<serde_json::Value> = Vec::new();
+    let mut animated_materials: Vec<(u32, f32)> = Vec::new();
+    for (i, at) in output.animated_textures.iter().enumerate() {
+        let Some((times_offset, offsets_offset, duration)) = keyframe_offsets[i] else {
+            continue;
+        };
+        let texture_idx = textures.len() as u32;
+        textures.push(json::Texture {
+            sampler: Some(json::Index::new(0)),
+            source: json::Index::new(anim_texture_indices[i]),
+            extensions: Default::default(),
+            extras: Default::default(),
+        });
+
+        let mut channels = Vec::new();
+        for (_, kind, offsets) in animated_offsets.iter().filter(|(a, _, _)| *a == i) {
+            let material_idx = materials.len() as u32;
+            materials.push(match kind {
+                0 => create_material_with_texture(json::material::AlphaMode::Opaque, texture_idx),
+                1 => create_material_with_alpha_cutoff(texture_idx, 0.5),
+                _ => create_material_with_texture(json::material::AlphaMode::Blend, texture_idx),
+            });
+            add_mesh_primitive(offsets, material_idx, &mut buffer_views, &mut accessors, &mut primitives, &mut buffer_view_idx);
+
+            let first_offset = at.frame_sequence().first().map_or(0.0, |&(frame, _)| {
+                frame as f32 / at.frame_count.max(1) as f32
+            });
+            animated_materials.push((material_idx, first_offset));
+            channels.push(serde_json::json!({
+                "sampler": 0,
+                "target": {
+                    "path": "pointer",
+                    "extensions": {
+                        "KHR_animation_pointer": {
+                            "pointer": format!(
+                                "/materials/{}/pbrMetallicRoughness/baseColorTexture/extensions/KHR_texture_transform/offset",
+                                material_idx
+                            ),
+                        },
+                    },
+                },
+            }));
+        }
+
+        let key_count = at.frame_sequence().len() + 1;
+        buffer_views.push(create_buffer_view(times_offset, key_count * 4, None));
+        let times_view = buffer_view_idx;
+        buffer_view_idx += 1;
+        buffer_views.push(create_buffer_view(offsets_offset, key_count * 8, None));
+        let offsets_view = buffer_view_idx;
+        buffer_view_idx += 1;
+
+        let times_accessor = accessors.len();
+        accessors.push(create_accessor(
+            times_view,
+            key_count,
+            json::accessor::Type::Scalar,
+            json::accessor::ComponentType::F32,
+            false,
+            Some(json::Value::from(vec![0.0f32])),
+            Some(json::Value::from(vec![duration])),
+        ));
+        accessors.push(create_accessor(
+            offsets_view,
+            key_count,
+            json::accessor::Type::Vec2,
+            json::accessor::ComponentType::F32,
+            false,
+            None,
+            None,
+        ));
+
+        animations.push(serde_json::json!({
+            "name": at.texture_path,
+            "samplers": [{
+                "input": times_accessor,
+                "output": times_accessor + 1,
+                "interpolation": "STEP",
+            }],
+            "channels": channels,
+        }));
+    }
+
     // Build scene extras with animation metadata
     let scene_extras: json::Extras = if !output.animated_textures.is_empty() {
         let anim_entries: Vec<serde_json::Value> = output.animated_textures.iter().enumerate().map(|(i, at)| {
@@ -500,6 +704,7 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
             entry.insert("frameHeight".into(), serde_json::Value::from(at.frame_height as u64));
             entry.insert("atlasX".into(), serde_json::Value::from(at.atlas_x as u64));
             entry.insert("atlasY".into(), serde_json::Value::from(at.atlas_y as u64));
+            entry.insert("atlasPage".into(), serde_json::Value::from(at.atlas_page as u64));
             if let Some(ref frames) = at.frames {
                 let frame_values: Vec<serde_json::Value> = frames.iter().map(|&f| serde_json::Value::from(f as u64)).collect();
                 entry.insert("frames".into(), serde_json::Value::from(frame_values));
@@ -569,9 +774,17 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
         ..Default::default()
     };
 
-    // Serialize JSON
-    let json_string = json::serialize::to_string(&root)
-        .map_err(|e| MesherError::Export(format!("Failed to serialize glTF JSON: {}", e)))?;
+    // Serialize JSON. gltf-json has no types for KHR_texture_transform or
+    // KHR_animation_pointer, so animated exports are patched as a JSON value.
+    let json_string = if animations.is_empty() {
+        json::serialize::to_string(&root)
+    } else {
+        json::serialize::to_value(&root).and_then(|mut value| {
+            add_texture_animations(&mut value, &animated_materials, animations);
+            json::serialize::to_string(&value)
+        })
+    }
+    .map_err(|e| MesherError::Export(format!("Failed to serialize glTF JSON: {}", e)))?;
     let json_bytes = json_string.as_bytes();
 
     // Pad JSON to 4-byte alignment
@@ -609,6 +822,102 @@ pub fn export_glb(output: &MesherOutput) -> Result<Vec<u8>> {
     Ok(glb)
 }
 
+/// Move triangles whose UV centroid lies in an animated texture's atlas region
+/// (its first frame) into one layer per animated texture. Their UVs are remapped
+/// onto frame 0 of the vertically stacked sprite sheet, `v` in `[0, 1/frame_count]`,
+/// so a texture transform offset of `frame / frame_count` selects a frame.
+/// Returns the remaining triangles and `(animated texture index, layer)` pairs.
+fn split_animated<'a>(
+    layer: &'a MeshLayer,
+    atlas: &crate::atlas::TextureAtlas,
+    animated: &[AnimatedTextureExport],
+) -> (Cow<'a, MeshLayer>, Vec<(usize, MeshLayer)>) {
+    if animated.is_empty() || layer.is_empty() {
+        return (Cow::Borrowed(layer), Vec::new());
+    }
+    let (width, height) = (atlas.width as f32, atlas.height as f32);
+    // Animated texture whose first frame contains a UV.
+    let locate = |[u, v]: [f32; 2]| -> Option<usize> {
+        let page = u.floor().max(0.0);
+        let (x, y) = ((u - page) * width, v * height);
+        animated.iter().position(|at| {
+            at.atlas_page == page as u32
+                && (at.atlas_x as f32..(at.atlas_x + at.frame_width) as f32).contains(&x)
+                && (at.atlas_y as f32..(at.atlas_y + at.frame_height) as f32).contains(&y)
+        })
+    };
+
+    let new_target = || (MeshLayer::new(), vec![u32::MAX; layer.positions.len()]);
+    let mut rest = new_target();
+    let mut split: std::collections::BTreeMap<usize, (MeshLayer, Vec<u32>)> = Default::default();
+    for tri in layer.indices.chunks_exact(3) {
+        let centroid = tri.iter().fold([0.0f32; 2], |acc, &i| {
+            let [u, v] = layer.uvs[i as usize];
+            [acc[0] + u / 3.0, acc[1] + v / 3.0]
+        });
+        let (target, anim) = match locate(centroid) {
+            Some(anim_idx) => (split.entry(anim_idx).or_insert_with(new_target), animated.get(anim_idx)),
+            None => (&mut rest, None),
+        };
+        for &i in tri {
+            let i = i as usize;
+            if target.1[i] == u32::MAX {
+                let uv = match anim {
+                    Some(at) => {
+                        let [u, v] = layer.uvs[i];
+                        let page = at.atlas_page as f32;
+                        let local_u = ((u - page) * width - at.atlas_x as f32) / at.frame_width.max(1) as f32;
+                        let local_v = (v * height - at.atlas_y as f32) / at.frame_height.max(1) as f32;
+                        [local_u, local_v / at.frame_count.max(1) as f32]
+                    }
+                    None => layer.uvs[i],
+                };
+                target.1[i] = target.0.push_vertex(layer.positions[i], layer.normals[i], uv, layer.colors[i]);
+                target.0.set_last_emissive(layer.is_emissive(i));
+            }
+            target.0.indices.push(target.1[i]);
+        }
+    }
+
+    if split.is_empty() {
+        return (Cow::Borrowed(layer), Vec::new());
+    }
+    (Cow::Owned(rest.0), split.into_iter().map(|(i, (layer, _))| (i, layer)).collect())
+}
+
+/// STEP keyframes for an animated texture: start times in seconds (20 ticks per
+/// second) and sprite sheet offsets, plus a closing key at the full duration.
+fn animation_keyframes(at: &AnimatedTextureExport) -> (Vec<f32>, Vec<[f32; 2]>) {
+    let sequence = at.frame_sequence();
+    let frame_count = at.frame_count.max(1) as f32;
+    let mut times = Vec::with_capacity(sequence.len() + 1);
+    let mut offsets = Vec::with_capacity(sequence.len() + 1);
+    let mut ticks = 0u32;
+    for &(frame, duration) in &sequence {
+        times.push(ticks as f32 / 20.0);
+        offsets.push([0.0, frame as f32 / frame_count]);
+        ticks += duration.max(1);
+    }
+    times.push(ticks as f32 / 20.0);
+    offsets.push(offsets.first().copied().unwrap_or([0.0, 0.0]));
+    (times, offsets)
+}
+
+/// Add the texture transforms of animated materials and the animations driving
+/// them to a serialized glTF root.
+fn add_texture_animations(root: &mut serde_json::Value, materials: &[(u32, f32)], animations: Vec<serde_json::Value>) {
+    for &(material, offset) in materials {
+        root["materials"][material as usize]["pbrMetallicRoughness"]["baseColorTexture"]["extensions"] = serde_json::json!({
+            "KHR_texture_transform": { "offset": [0.0, offset] },
+        });
+    }
+    root["animations"] = serde_json::Value::from(animations);
+    if let Some(used) = root["extensionsUsed"].as_array_mut() {
+        used.push("KHR_texture_transform".into());
+        used.push("KHR_animation_pointer".into());
+    }
+}
+
 /// Calculate bounding box from all meshes in the output.
 fn calculate_bounds_all(output: &MesherOutput) -> ([f32; 3], [f32; 3]) {
     let mut min = [f32::MAX; 3];
@@ -691,6 +1000,7 @@ fn create_accessor(
 fn create_primitive(
     positions_accessor: u32,
     indices_accessor: u32,
+    tangents_accessor: Option<u32>,
     material: u32,
 ) -> json::mesh::Primitive {
     let mut attributes = std::collections::BTreeMap::new();
@@ -710,6 +1020,9 @@ fn create_primitive(
         Valid(json::mesh::Semantic::Colors(0)),
         json::Index::new(positions_accessor + 3),
     );
+    if let Some(tangents) = tangents_accessor {
+        attributes.insert(Valid(json::mesh::Semantic::Tangents), json::Index::new(tangents));
+    }
 
     json::mesh::Primitive {
         attributes,
@@ -722,6 +1035,36 @@ fn create_primitive(
     }
 }
 
+/// Attach the atlas normal and metallic-roughness textures to a material.
+/// The factors become 1 so the texture values are used as-is.
+fn apply_pbr_textures(material: &mut json::Material, normal_idx: u32, metallic_roughness_idx: u32) {
+    material.normal_texture = Some(json::material::NormalTexture {
+        index: json::Index::new(normal_idx),
+        scale: 1.0,
+        tex_coord: 0,
+        extensions: Default::default(),
+        extras: Default::default(),
+    });
+    let pbr = &mut material.pbr_metallic_roughness;
+    pbr.metallic_roughness_texture = Some(json::texture::Info {
+        index: json::Index::new(metallic_roughness_idx),
+        tex_coord: 0,
+        extensions: Default::default(),
+        extras: Default::default(),
+    });
+    pbr.metallic_factor = json::material::StrengthFactor(1.0);
+    pbr.roughness_factor = json::material::StrengthFactor(1.0);
+}
+
+/// Copy of a material whose base color texture also drives emission, so
+/// fullbright faces glow under scene lighting.
+fn emissive_material(material: &json::Material) -> json::Material {
+    let mut material = material.clone();
+    material.emissive_texture = material.pbr_metallic_roughness.base_color_texture.clone();
+    material.emissive_factor = json::material::EmissiveFactor([1.0, 1.0, 1.0]);
+    material
+}
+
 /// Create a material with MASK alpha mode and a specific cutoff for binary-alpha textures.
 fn create_material_with_alpha_cutoff(texture_idx: u32, cutoff: f32) -> json::Material {
     json::Material {
@@ -812,9 +1155,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
 
         let glb = export_glb(&output).unwrap();
@@ -831,9 +1176,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
 
         let result = export_glb(&output);
@@ -854,9 +1201,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: mesh,
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
 
         let glb = export_glb(&output).unwrap();
@@ -909,9 +1258,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
 
         let glb = export_glb(&output).unwrap();
@@ -939,9 +1290,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [100.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
 
         let glb = export_glb(&output).unwrap();
@@ -950,4 +1303,158 @@ mod tests {
         // Total GLB includes JSON + textures, so just verify it's reasonably sized
         assert!(glb.len() < 14400, "Quantized GLB ({}) should be smaller than old vertex data alone (14400)", glb.len());
     }
+
+    #[test]
+    fn test_pbr_atlas_adds_normal_and_metallic_roughness() {
+        use crate::mesh_output::MeshLayer;
+
+        let mut layer = MeshLayer::new();
+        let up = [0.0, 1.0, 0.0];
+        layer.push_vertex([0.0, 0.0, 0.0], up, [0.0, 0.0], [1.0; 4]);
+        layer.push_vertex([1.0, 0.0, 0.0], up, [1.0, 0.0], [1.0; 4]);
+        layer.push_vertex([0.0, 0.0, 1.0], up, [0.0, 1.0], [1.0; 4]);
+        layer.indices = vec![0, 1, 2];
+
+        let atlas = TextureAtlas {
+            normal_pixels: Some(crate::atlas::pbr::NEUTRAL_NORMAL.repeat(16 * 16)),
+            ..TextureAtlas::empty()
+        };
+        let output = MesherOutput {
+            opaque_mesh: layer,
+            cutout_mesh: MeshLayer::new(),
+            transparent_mesh: MeshLayer::new(),
+            atlas,
+            texture_array: None,
+            bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
+            greedy_materials: Vec::new(),
+            animated_textures: Vec::new(),
+            diagnostics: Default::default(),
+        };
+
+        let glb = export_glb(&output).unwrap();
+        let json_chunk_len = u32::from_le_bytes([glb[12], glb[13], glb[14], glb[15]]) as usize;
+        let root: serde_json::Value = serde_json::from_slice(&glb[20..20 + json_chunk_len]).unwrap();
+        assert_eq!(root["images"].as_array().unwrap().len(), 3);
+        let material = &root["materials"][0];
+        assert_eq!(material["normalTexture"]["index"], 1);
+        assert_eq!(material["pbrMetallicRoughness"]["metallicRoughnessTexture"]["index"], 2);
+        assert!(root["meshes"][0]["primitives"][0]["attributes"]["TANGENT"].is_number());
+    }
+
+    #[test]
+    fn test_multi_page_atlas_gets_material_per_page() {
+        use crate::atlas::AtlasPage;
+        use crate::mesh_output::MeshLayer;
+
+        let mut layer = MeshLayer::new();
+        let up = [0.0, 1.0, 0.0];
+        for (page, x) in [(0.0, 0.0), (1.0, 2.0)] {
+            let base = layer.vertex_count() as u32;
+            layer.push_vertex([x, 0.0, 0.0], up, [page, 0.0], [1.0; 4]);
+            layer.push_vertex([x + 1.0, 0.0, 0.0], up, [page + 0.5, 0.0], [1.0; 4]);
+            layer.push_vertex([x, 0.0, 1.0], up, [page, 0.5], [1.0; 4]);
+            layer.indices.extend([base, base + 1, base + 2]);
+        }
+
+        let mut atlas = TextureAtlas::empty();
+        atlas.extra_pages.push(AtlasPage {
+            pixels: atlas.pixels.clone(),
+            normal_pixels: None,
+            specular_pixels: None,
+            mip_chain: Vec::new(),
+        });
+        let output = MesherOutput {
+            opaque_mesh: layer,
+            cutout_mesh: MeshLayer::new(),
+            transparent_mesh: MeshLayer::new(),
+            atlas,
+            texture_array: None,
+            bounds: BoundingBox::new([0.0, 0.0, 0.0], [3.0, 0.0, 1.0]),
+            greedy_materials: Vec::new(),
+            animated_textures: Vec::new(),
+            diagnostics: Default::default(),
+        };
+
+        let glb = export_glb(&output).unwrap();
+        let json_chunk_len = u32::from_le_bytes([glb[12], glb[13], glb[14], glb[15]]) as usize;
+        let root: serde_json::Value = serde_json::from_slice(&glb[20..20 + json_chunk_len]).unwrap();
+        assert_eq!(root["images"].as_array().unwrap().len(), 2);
+        assert_eq!(root["materials"].as_array().unwrap().len(), 6);
+        assert_eq!(root["materials"][3]["pbrMetallicRoughness"]["baseColorTexture"]["index"], 1);
+        let primitives = root["meshes"][0]["primitives"].as_array().unwrap();
+        let materials: Vec<_> = primitives.iter().map(|p| p["material"].as_u64().unwrap()).collect();
+        assert_eq!(materials, vec![0, 3]);
+    }
+
+    #[test]
+    fn test_animated_texture_gets_stepped_texture_transform() {
+        use crate::mesh_output::MeshLayer;
+        use crate::mesher::AnimatedTextureExport;
+
+        // Two triangles: one on the animated region at (0, 0) of a 16x16 atlas
+        // page, one on the static texture next to it.
+        let mut layer = MeshLayer::new();
+        let up = [0.0, 1.0, 0.0];
+        for (x, u) in [(0.0, 0.0), (2.0, 0.5)] {
+            let base = layer.vertex_count() as u32;
+            layer.push_vertex([x, 0.0, 0.0], up, [u, 0.0], [1.0; 4]);
+            layer.push_vertex([x + 1.0, 0.0, 0.0], up, [u + 0.5, 0.0], [1.0; 4]);
+            layer.push_vertex([x, 0.0, 1.0], up, [u, 0.5], [1.0; 4]);
+            layer.indices.extend([base, base + 1, base + 2]);
+        }
+
+        let output = MesherOutput {
+            opaque_mesh: layer,
+            cutout_mesh: MeshLayer::new(),
+            transparent_mesh: MeshLayer::new(),
+            atlas: TextureAtlas::empty(),
+            texture_array: None,
+            bounds: BoundingBox::new([0.0, 0.0, 0.0], [3.0, 0.0, 1.0]),
+            greedy_materials: Vec::new(),
+            animated_textures: vec![AnimatedTextureExport {
+                texture_path: "block/magma".to_string(),
+                sprite_sheet_png: TextureAtlas::empty().to_png().unwrap(),
+                frame_count: 4,
+                frametime: 2,
+                interpolate: false,
+                frames: Some(vec![2, 0, 3]),
+                frame_times: Some(vec![2, 6, 2]),
+                frame_width: 8,
+                frame_height: 8,
+                atlas_x: 0,
+                atlas_y: 0,
+                atlas_page: 0,
+            }],
+            diagnostics: Default::default(),
+        };
+
+        let glb = export_glb(&output).unwrap();
+        let json_chunk_len = u32::from_le_bytes([glb[12], glb[13], glb[14], glb[15]]) as usize;
+        let root: serde_json::Value = serde_json::from_slice(&glb[20..20 + json_chunk_len]).unwrap();
+
+        let primitives = root["meshes"][0]["primitives"].as_array().unwrap();
+        assert_eq!(primitives.len(), 2);
+        let material = &root["materials"][3];
+        assert_eq!(material["pbrMetallicRoughness"]["baseColorTexture"]["index"], 1);
+        let transform = &material["pbrMetallicRoughness"]["baseColorTexture"]["extensions"]["KHR_texture_transform"];
+        assert_eq!(transform["offset"], serde_json::json!([0.0, 0.5]));
+
+        let animation = &root["animations"][0];
+        assert_eq!(animation["name"], "block/magma");
+        assert_eq!(animation["samplers"][0]["interpolation"], "STEP");
+        assert_eq!(
+            animation["channels"][0]["target"]["extensions"]["KHR_animation_pointer"]["pointer"],
+            "/materials/3/pbrMetallicRoughness/baseColorTexture/extensions/KHR_texture_transform/offset"
+        );
+        let times = &root["accessors"][animation["samplers"][0]["input"].as_u64().unwrap() as usize];
+        assert_eq!(times["count"], 4);
+        assert_eq!(times["max"], serde_json::json!([0.5]));
+        let used: Vec<_> = root["extensionsUsed"].as_array().unwrap().iter().filter_map(|e| e.as_str()).collect();
+        assert!(used.contains(&"KHR_animation_pointer"));
+        assert!(used.contains(&"KHR_texture_transform"));
+
+        let (times, offsets) = animation_keyframes(&output.animated_textures[0]);
+        assert_eq!(times, vec![0.0, 0.1, 0.4, 0.5]);
+        assert_eq!(offsets, vec![[0.0, 0.5], [0.0, 0.0], [0.0, 0.75], [0.0, 0.5]]);
+    }
 }
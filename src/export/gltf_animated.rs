@@ -12,32 +12,62 @@
 //! - Every piece's geometry is flattened into a single mesh and drawn with one
 //!   shared `MASK` (alpha-tested) material. Opaque and cutout textures both
 //!   render correctly under alpha-test; truly transparent blocks (water/glass)
-//!   would be slightly off, which is fine for redstone replays.
+//!   would be slightly off, which is fine for redstone replays. A piece's
+//!   `emissive_indices` (armor glint on rigs) are a second primitive with a
+//!   translucent, fullbright material.
 //! - A `scale` track with STEP interpolation toggles a node between [0,0,0]
 //!   (invisible) and [1,1,1] (visible) — STEP means the collapsed-to-origin
 //!   intermediate is never seen, so it reads as a clean on/off.
 //! - A `translation` track with LINEAR interpolation slides a node — it is an
 //!   offset delta layered on the mesh's baked model-local position.
+//! - A piece with a [`PieceSkin`] (an entity rig) becomes an armature: the
+//!   piece node carries the tracks and parents both the joint hierarchy and a
+//!   skinned mesh node. Each vertex follows one joint with full weight, and
+//!   joints are named after the model parts so they can be posed in Blender.
+//! - A skin's [`EntityClip`]s become LINEAR `rotation` tracks on its joint
+//!   nodes, one named glTF animation per clip name shared by all pieces, next
+//!   to the (unnamed) replay animation.
 
 use crate::atlas::TextureAtlas;
 use crate::error::{MesherError, Result};
+use crate::mesher::entity::clips::EntityClip;
+use crate::mesher::entity::rig::EntityJoint;
 use crate::mesher::geometry::Mesh;
 use gltf_json as json;
 use json::validation::Checked::Valid;
 use json::validation::USize64;
 
+/// Material index of the translucent, fullbright glint material.
+const GLINT_MATERIAL: u32 = 1;
+
 /// One animated node in the scene: a baked mesh plus optional keyframe tracks.
 /// The mesh is in model-local coordinates (world − region origin). A piece with
 /// no tracks is static.
 pub struct AnimatedPiece {
     /// Merged geometry for this node (opaque + cutout + transparent flattened).
     pub mesh: Mesh,
+    /// Triangles over `mesh.vertices`, kept out of `mesh.indices`, drawn
+    /// translucent and fullbright (enchantment glint). Usually empty.
+    pub emissive_indices: Vec<u32>,
     /// Optional scale track — STEP interpolation. Use [0,0,0]↔[1,1,1] to toggle
     /// visibility (a block-state variant blinking on/off).
     pub scale_keys: Option<Vec<(f32, [f32; 3])>>,
     /// Optional translation track — LINEAR interpolation. An offset delta
     /// layered on top of the mesh's baked model-local position.
     pub translation_keys: Option<Vec<(f32, [f32; 3])>>,
+    /// Optional skeleton — the mesh is then skinned in its bind pose.
+    pub skin: Option<PieceSkin>,
+}
+
+/// Skeleton of a skinned piece, e.g. from an
+/// [`EntityRig`](crate::mesher::entity::rig::EntityRig).
+pub struct PieceSkin {
+    /// Joints, parents before children. Root joints have no parent.
+    pub joints: Vec<EntityJoint>,
+    /// Joint index per mesh vertex.
+    pub vertex_joints: Vec<u16>,
+    /// Rotation clips over these joints.
+    pub clips: Vec<EntityClip>,
 }
 
 /// Cast a slice of `Copy` values to bytes (little-endian on all our targets).
@@ -88,12 +118,19 @@ fn accessor(
 }
 
 /// Assemble a multi-node animated GLB from pre-meshed pieces sharing one atlas.
+/// The atlas must fit on a single page.
 pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Result<Vec<u8>> {
     if pieces.iter().all(|p| p.mesh.is_empty()) {
         return Err(MesherError::Export(
             "Cannot export empty animated mesh".to_string(),
         ));
     }
+    if atlas.page_count() > 1 {
+        return Err(MesherError::Export(format!(
+            "Animated export needs a single-page atlas, got {} pages (raise atlas_max_size)",
+            atlas.page_count()
+        )));
+    }
 
     let texture_png = atlas.to_png()?;
 
@@ -104,6 +141,9 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
     let mut nodes: Vec<json::Node> = Vec::new();
     let mut anim_channels: Vec<json::animation::Channel> = Vec::new();
     let mut anim_samplers: Vec<json::animation::Sampler> = Vec::new();
+    // (piece node, mesh) of each skinned piece; their joints are appended
+    // after all piece nodes so node index == piece index still holds.
+    let mut skinned: Vec<(usize, u32, &PieceSkin)> = Vec::new();
 
     // --- per-piece geometry → mesh + node ---
     for (piece_idx, piece) in pieces.iter().enumerate() {
@@ -173,25 +213,75 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
         accessors.push(accessor(bv + 3, vcount, json::accessor::Type::Vec4, json::accessor::ComponentType::F32, None, None));
         accessors.push(accessor(bv + 4, mesh.indices.len(), json::accessor::Type::Scalar, json::accessor::ComponentType::U32, None, None));
 
+        let glint_acc = (!piece.emissive_indices.is_empty()).then(|| {
+            let off = buf.len();
+            buf.extend_from_slice(cast_bytes(&piece.emissive_indices));
+            let view = buffer_views.len() as u32;
+            buffer_views.push(buffer_view(off, buf.len() - off, Some(json::buffer::Target::ElementArrayBuffer)));
+            accessors.push(accessor(view, piece.emissive_indices.len(), json::accessor::Type::Scalar, json::accessor::ComponentType::U32, None, None));
+            accessors.len() as u32 - 1
+        });
+
         let mut attributes = std::collections::BTreeMap::new();
         attributes.insert(Valid(json::mesh::Semantic::Positions), json::Index::new(av));
         attributes.insert(Valid(json::mesh::Semantic::Normals), json::Index::new(av + 1));
         attributes.insert(Valid(json::mesh::Semantic::TexCoords(0)), json::Index::new(av + 2));
         attributes.insert(Valid(json::mesh::Semantic::Colors(0)), json::Index::new(av + 3));
 
+        if let Some(skin) = &piece.skin {
+            if skin.vertex_joints.len() != vcount {
+                return Err(MesherError::Export(format!(
+                    "Skinned piece has {} vertices but {} vertex joints",
+                    vcount,
+                    skin.vertex_joints.len()
+                )));
+            }
+            // joints (u16 vec4) + weights (f32 vec4): one joint, full weight
+            align(&mut buf, 4);
+            let joints_off = buf.len();
+            for &j in &skin.vertex_joints {
+                buf.extend_from_slice(cast_bytes(&[j, 0, 0, 0]));
+            }
+            let weights_off = buf.len();
+            for _ in 0..vcount {
+                buf.extend_from_slice(cast_bytes(&[1.0f32, 0.0, 0.0, 0.0]));
+            }
+            let end = buf.len();
+
+            let jv = buffer_views.len() as u32;
+            buffer_views.push(buffer_view(joints_off, weights_off - joints_off, Some(json::buffer::Target::ArrayBuffer)));
+            buffer_views.push(buffer_view(weights_off, end - weights_off, Some(json::buffer::Target::ArrayBuffer)));
+            let ja = accessors.len() as u32;
+            accessors.push(accessor(jv, vcount, json::accessor::Type::Vec4, json::accessor::ComponentType::U16, None, None));
+            accessors.push(accessor(jv + 1, vcount, json::accessor::Type::Vec4, json::accessor::ComponentType::F32, None, None));
+            attributes.insert(Valid(json::mesh::Semantic::Joints(0)), json::Index::new(ja));
+            attributes.insert(Valid(json::mesh::Semantic::Weights(0)), json::Index::new(ja + 1));
+        }
+
         let mesh_idx = meshes.len() as u32;
+        if let Some(skin) = &piece.skin {
+            skinned.push((piece_idx, mesh_idx, skin));
+        }
+        let primitive = |indices: u32, material: u32| json::mesh::Primitive {
+            attributes: attributes.clone(),
+            extensions: Default::default(),
+            extras: Default::default(),
+            indices: Some(json::Index::new(indices)),
+            material: Some(json::Index::new(material)),
+            mode: Valid(json::mesh::Mode::Triangles),
+            targets: None,
+        };
+        let mut primitives = Vec::new();
+        if !mesh.indices.is_empty() {
+            primitives.push(primitive(av + 4, 0));
+        }
+        if let Some(glint_acc) = glint_acc {
+            primitives.push(primitive(glint_acc, GLINT_MATERIAL));
+        }
         meshes.push(json::Mesh {
             extensions: Default::default(),
             extras: Default::default(),
-            primitives: vec![json::mesh::Primitive {
-                attributes,
-                extensions: Default::default(),
-                extras: Default::default(),
-                indices: Some(json::Index::new(av + 4)),
-                material: Some(json::Index::new(0)),
-                mode: Valid(json::mesh::Mode::Triangles),
-                targets: None,
-            }],
+            primitives,
             weights: None,
         });
 
@@ -201,7 +291,9 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
             extensions: Default::default(),
             extras: Default::default(),
             matrix: None,
-            mesh: Some(json::Index::new(mesh_idx)),
+            // A skinned mesh ignores its node's transform, so it hangs off
+            // the armature instead (see below).
+            mesh: piece.skin.is_none().then(|| json::Index::new(mesh_idx)),
             rotation: None,
             // A scale-toggled node starts hidden iff its first key is [0,0,0];
             // bake that as the node's resting scale so frame 0 looks right even
@@ -216,7 +308,7 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
         let node_idx = piece_idx as u32;
 
         if let Some(keys) = &piece.scale_keys {
-            push_vec3_channel(
+            push_channel(
                 &mut buf,
                 &mut buffer_views,
                 &mut accessors,
@@ -229,7 +321,7 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
             );
         }
         if let Some(keys) = &piece.translation_keys {
-            push_vec3_channel(
+            push_channel(
                 &mut buf,
                 &mut buffer_views,
                 &mut accessors,
@@ -243,6 +335,95 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
         }
     }
 
+    // --- skins: armature children, joint nodes and inverse bind matrices ---
+    let mut skins: Vec<json::Skin> = Vec::new();
+    let mut node_names: Vec<(usize, String)> = Vec::new();
+    // (name, channels, samplers) per distinct clip name
+    let mut clip_animations: Vec<(String, Vec<json::animation::Channel>, Vec<json::animation::Sampler>)> =
+        Vec::new();
+    for (piece_node, mesh_idx, skin) in skinned {
+        let skin_idx = skins.len() as u32;
+        let mesh_node = nodes.len() as u32;
+        nodes.push(json::Node {
+            mesh: Some(json::Index::new(mesh_idx)),
+            skin: Some(json::Index::new(skin_idx)),
+            ..empty_node()
+        });
+
+        let joint_base = nodes.len() as u32;
+        let mut world: Vec<glam::Mat4> = Vec::with_capacity(skin.joints.len());
+        let mut armature_children = vec![json::Index::new(mesh_node)];
+        for (i, joint) in skin.joints.iter().enumerate() {
+            let parent_world = match joint.parent {
+                Some(p) => world[p],
+                None => {
+                    armature_children.push(json::Index::new(joint_base + i as u32));
+                    glam::Mat4::IDENTITY
+                }
+            };
+            world.push(parent_world * joint.local_matrix());
+            nodes.push(json::Node {
+                rotation: Some(json::scene::UnitQuaternion(joint.rotation)),
+                scale: Some(joint.scale),
+                translation: Some(joint.translation),
+                ..empty_node()
+            });
+            node_names.push((nodes.len() - 1, joint.name.clone()));
+        }
+        for (i, joint) in skin.joints.iter().enumerate() {
+            if let Some(p) = joint.parent {
+                nodes[joint_base as usize + p]
+                    .children
+                    .get_or_insert_with(Vec::new)
+                    .push(json::Index::new(joint_base + i as u32));
+            }
+        }
+        nodes[piece_node].children = Some(armature_children);
+
+        // inverse bind matrices (f32 mat4, column-major)
+        align(&mut buf, 4);
+        let ibm_off = buf.len();
+        for m in &world {
+            buf.extend_from_slice(cast_bytes(&m.inverse().to_cols_array()));
+        }
+        let ibm_view = buffer_views.len() as u32;
+        buffer_views.push(buffer_view(ibm_off, buf.len() - ibm_off, None));
+        let ibm_acc = accessors.len() as u32;
+        accessors.push(accessor(ibm_view, world.len(), json::accessor::Type::Mat4, json::accessor::ComponentType::F32, None, None));
+
+        skins.push(json::Skin {
+            extensions: Default::default(),
+            extras: Default::default(),
+            inverse_bind_matrices: Some(json::Index::new(ibm_acc)),
+            joints: (0..skin.joints.len() as u32).map(|i| json::Index::new(joint_base + i)).collect(),
+            skeleton: Some(json::Index::new(joint_base)),
+        });
+
+        for clip in &skin.clips {
+            let anim = match clip_animations.iter().position(|(name, _, _)| *name == clip.name) {
+                Some(i) => i,
+                None => {
+                    clip_animations.push((clip.name.clone(), Vec::new(), Vec::new()));
+                    clip_animations.len() - 1
+                }
+            };
+            let (_, channels, samplers) = &mut clip_animations[anim];
+            for (joint, keys) in &clip.rotation_tracks {
+                push_channel(
+                    &mut buf,
+                    &mut buffer_views,
+                    &mut accessors,
+                    samplers,
+                    channels,
+                    joint_base + *joint as u32,
+                    keys,
+                    json::animation::Property::Rotation,
+                    json::animation::Interpolation::Linear,
+                );
+            }
+        }
+    }
+
     // --- atlas image ---
     align(&mut buf, 4);
     let tex_off = buf.len();
@@ -266,7 +447,7 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
         extras: Default::default(),
     }];
     // One shared alpha-tested material (handles opaque + cutout textures).
-    let materials = vec![json::Material {
+    let mut materials = vec![json::Material {
         pbr_metallic_roughness: json::material::PbrMetallicRoughness {
             base_color_texture: Some(json::texture::Info {
                 index: json::Index::new(0),
@@ -291,20 +472,38 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
         extensions: Default::default(),
         extras: Default::default(),
     }];
+    // Glint: the same texture blended over the armor, glowing.
+    if pieces.iter().any(|p| !p.emissive_indices.is_empty()) {
+        let mut glint = materials[0].clone();
+        glint.alpha_mode = Valid(json::material::AlphaMode::Blend);
+        glint.alpha_cutoff = None;
+        glint.emissive_texture = glint.pbr_metallic_roughness.base_color_texture.clone();
+        glint.emissive_factor = json::material::EmissiveFactor([1.0, 1.0, 1.0]);
+        materials.push(glint);
+    }
 
     let scene_node_indices: Vec<json::Index<json::Node>> =
-        (0..nodes.len() as u32).map(json::Index::new).collect();
+        (0..pieces.len() as u32).map(json::Index::new).collect();
 
-    let animations = if anim_channels.is_empty() {
-        Vec::new()
-    } else {
-        vec![json::Animation {
+    let mut animations = Vec::new();
+    if !anim_channels.is_empty() {
+        animations.push(json::Animation {
             extensions: Default::default(),
             extras: Default::default(),
             channels: anim_channels,
             samplers: anim_samplers,
-        }]
-    };
+        });
+    }
+    let mut animation_names: Vec<(usize, String)> = Vec::new();
+    for (name, channels, samplers) in clip_animations {
+        animation_names.push((animations.len(), name));
+        animations.push(json::Animation {
+            extensions: Default::default(),
+            extras: Default::default(),
+            channels,
+            samplers,
+        });
+    }
 
     let root = json::Root {
         accessors,
@@ -329,6 +528,7 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
         materials,
         meshes,
         nodes,
+        skins,
         scenes: vec![json::Scene {
             extensions: Default::default(),
             extras: Default::default(),
@@ -338,8 +538,22 @@ pub fn export_animated_glb(atlas: &TextureAtlas, pieces: &[AnimatedPiece]) -> Re
         ..Default::default()
     };
 
-    let json_string = json::serialize::to_string(&root)
-        .map_err(|e| MesherError::Export(format!("glTF JSON serialize failed: {}", e)))?;
+    // gltf-json only serializes object names with its `names` feature, so
+    // joint and clip names are patched in as a JSON value.
+    let json_string = if node_names.is_empty() && animation_names.is_empty() {
+        json::serialize::to_string(&root)
+    } else {
+        json::serialize::to_value(&root).and_then(|mut value| {
+            for (node, name) in node_names {
+                value["nodes"][node]["name"] = json::Value::from(name);
+            }
+            for (animation, name) in animation_names {
+                value["animations"][animation]["name"] = json::Value::from(name);
+            }
+            json::serialize::to_string(&value)
+        })
+    }
+    .map_err(|e| MesherError::Export(format!("glTF JSON serialize failed: {}", e)))?;
     let json_bytes = json_string.as_bytes();
     let json_pad = (4 - (json_bytes.len() % 4)) % 4;
     let buf_pad = (4 - (buf.len() % 4)) % 4;
@@ -376,16 +590,16 @@ fn empty_node() -> json::Node {
     }
 }
 
-/// Append a keyframe channel (times + vec3 values) for one node property.
+/// Append a keyframe channel (times + vec3 or vec4 values) for one node property.
 #[allow(clippy::too_many_arguments)]
-fn push_vec3_channel(
+fn push_channel<const N: usize>(
     buf: &mut Vec<u8>,
     buffer_views: &mut Vec<json::buffer::View>,
     accessors: &mut Vec<json::Accessor>,
     samplers: &mut Vec<json::animation::Sampler>,
     channels: &mut Vec<json::animation::Channel>,
     node: u32,
-    keys: &[(f32, [f32; 3])],
+    keys: &[(f32, [f32; N])],
     property: json::animation::Property,
     interpolation: json::animation::Interpolation,
 ) {
@@ -402,7 +616,7 @@ fn push_vec3_channel(
         tmax = tmax.max(*t);
         buf.extend_from_slice(&t.to_le_bytes());
     }
-    // output: f32 vec3 values
+    // output: f32 vec3/vec4 values
     align(buf, 4);
     let v_off = buf.len();
     for (_, v) in keys {
@@ -428,7 +642,7 @@ fn push_vec3_channel(
     accessors.push(accessor(
         v_view,
         keys.len(),
-        json::accessor::Type::Vec3,
+        if N == 4 { json::accessor::Type::Vec4 } else { json::accessor::Type::Vec3 },
         json::accessor::ComponentType::F32,
         None,
         None,
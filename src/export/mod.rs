@@ -35,13 +35,25 @@ pub mod raw;
 pub mod usd;
 
 pub use gltf::export_glb;
-pub use gltf_animated::{export_animated_glb, AnimatedPiece};
+pub use gltf_animated::{export_animated_glb, AnimatedPiece, PieceSkin};
 pub use obj::{export_obj, ObjExport};
 pub use raw::{export_raw, RawMeshData};
 pub use usd::{export_usda, export_usdz, UsdaExport, UsdTexture};
 
 use crate::mesh_output::MeshOutput;
 
+/// The file formats reference atlas images; texture-array output has none,
+/// so it can only be consumed through the layers and [`export_raw`].
+pub(crate) fn reject_texture_array(output: &crate::mesher::MesherOutput) -> crate::Result<()> {
+    if output.texture_array.is_some() {
+        return Err(crate::MesherError::Export(
+            "Texture-array output can't be exported to a file format; mesh with an atlas instead"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
 /// Trait for exporting a [`MeshOutput`] to a specific format.
 ///
 /// Implementations receive a reference to the canonical mesh output and produce
@@ -92,9 +104,11 @@ impl MeshExporter for GlbExporter {
                 cutout_mesh: crate::mesh_output::MeshLayer::new(),
                 transparent_mesh: crate::mesh_output::MeshLayer::new(),
                 atlas: mesh.atlas.clone(),
+                texture_array: mesh.texture_array.clone(),
                 bounds: mesh.bounds,
                 greedy_materials: Vec::new(),
                 animated_textures: mesh.animated_textures.clone(),
+                diagnostics: mesh.diagnostics.clone(),
             };
             gltf::export_glb(&internal)
         } else {
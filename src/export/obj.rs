@@ -10,13 +10,31 @@ use std::fmt::Write;
 /// Export a mesh to OBJ format.
 /// Returns (obj_content, mtl_content) as strings.
 /// Greedy materials get separate MTL entries referencing individual texture files.
+/// Multi-page atlases get one material per page (`{name}_atlas_page<N>.png`).
+/// Emissive faces use an `_emissive` copy of their page material with `map_Ke`.
 pub fn export_obj(output: &MesherOutput, name: &str) -> Result<(String, String)> {
+    super::reject_texture_array(output)?;
     // Combine atlas-based meshes. Layers are SoA (MeshLayer); the OBJ writer
     // below works on AoS Mesh, so convert at the boundary.
     use crate::mesh_output::layer_to_internal_mesh;
-    let mut atlas_mesh = layer_to_internal_mesh(&output.opaque_mesh);
-    atlas_mesh.merge(&layer_to_internal_mesh(&output.cutout_mesh));
-    atlas_mesh.merge(&layer_to_internal_mesh(&output.transparent_mesh));
+    let mut atlas_layer = output.opaque_mesh.clone();
+    atlas_layer.merge(&output.cutout_mesh);
+    atlas_layer.merge(&output.transparent_mesh);
+    let atlas_pages = if output.atlas.page_count() > 1 {
+        atlas_layer.split_atlas_pages()
+    } else {
+        vec![(0, atlas_layer)]
+    };
+    let mut atlas_meshes: Vec<(u32, bool, crate::mesher::geometry::Mesh)> = Vec::new();
+    for (page, layer) in &atlas_pages {
+        match layer.split_emissive() {
+            Some((rest, emissive)) => {
+                atlas_meshes.push((*page, false, layer_to_internal_mesh(&rest)));
+                atlas_meshes.push((*page, true, layer_to_internal_mesh(&emissive)));
+            }
+            None => atlas_meshes.push((*page, false, layer_to_internal_mesh(layer))),
+        }
+    }
 
     let total_verts = output.total_vertices();
     let total_tris = output.total_triangles();
@@ -40,9 +58,9 @@ pub fn export_obj(output: &MesherOutput, name: &str) -> Result<(String, String)>
     writeln!(obj, "o {}", name).unwrap();
     writeln!(obj).unwrap();
 
-    // Collect all meshes: atlas mesh first, then greedy materials
+    // Collect all meshes: atlas page meshes first, then greedy materials
     let mut all_meshes: Vec<&crate::mesher::geometry::Mesh> = Vec::new();
-    all_meshes.push(&atlas_mesh);
+    all_meshes.extend(atlas_meshes.iter().map(|(_, _, mesh)| mesh));
     for gm in &output.greedy_materials {
         if !gm.opaque_mesh.is_empty() {
             all_meshes.push(&gm.opaque_mesh);
@@ -93,20 +111,23 @@ pub fn export_obj(output: &MesherOutput, name: &str) -> Result<(String, String)>
     let mut vertex_offset: usize = 0;
 
     // Atlas material faces
-    writeln!(obj, "usemtl {}_material", name).unwrap();
-    writeln!(obj).unwrap();
-    for i in (0..atlas_mesh.indices.len()).step_by(3) {
-        let i0 = atlas_mesh.indices[i] as usize + vertex_offset + 1;
-        let i1 = atlas_mesh.indices[i + 1] as usize + vertex_offset + 1;
-        let i2 = atlas_mesh.indices[i + 2] as usize + vertex_offset + 1;
-        writeln!(
-            obj,
-            "f {}/{}/{} {}/{}/{} {}/{}/{}",
-            i0, i0, i0, i1, i1, i1, i2, i2, i2
-        )
-        .unwrap();
+    for (page, emissive, atlas_mesh) in &atlas_meshes {
+        let kind = if *emissive { "_emissive" } else { "" };
+        writeln!(obj, "usemtl {}{}{}_material", name, page_suffix(*page), kind).unwrap();
+        writeln!(obj).unwrap();
+        for i in (0..atlas_mesh.indices.len()).step_by(3) {
+            let i0 = atlas_mesh.indices[i] as usize + vertex_offset + 1;
+            let i1 = atlas_mesh.indices[i + 1] as usize + vertex_offset + 1;
+            let i2 = atlas_mesh.indices[i + 2] as usize + vertex_offset + 1;
+            writeln!(
+                obj,
+                "f {}/{}/{} {}/{}/{} {}/{}/{}",
+                i0, i0, i0, i1, i1, i1, i2, i2, i2
+            )
+            .unwrap();
+        }
+        vertex_offset += atlas_mesh.vertex_count();
     }
-    vertex_offset += atlas_mesh.vertex_count();
 
     // Greedy material faces
     for (gi, gm) in output.greedy_materials.iter().enumerate() {
@@ -135,15 +156,34 @@ pub fn export_obj(output: &MesherOutput, name: &str) -> Result<(String, String)>
     writeln!(mtl, "# Schematic Mesher Material").unwrap();
     writeln!(mtl).unwrap();
 
-    // Atlas material
-    writeln!(mtl, "newmtl {}_material", name).unwrap();
-    writeln!(mtl, "Ka 1.0 1.0 1.0").unwrap();
-    writeln!(mtl, "Kd 1.0 1.0 1.0").unwrap();
-    writeln!(mtl, "Ks 0.0 0.0 0.0").unwrap();
-    writeln!(mtl, "Ns 10.0").unwrap();
-    writeln!(mtl, "d 1.0").unwrap();
-    writeln!(mtl, "illum 1").unwrap();
-    writeln!(mtl, "map_Kd {}_atlas.png", name).unwrap();
+    // Atlas materials, one per page
+    for page in 0..output.atlas.page_count() as u32 {
+        if page > 0 {
+            writeln!(mtl).unwrap();
+        }
+        let suffix = page_suffix(page);
+        writeln!(mtl, "newmtl {}{}_material", name, suffix).unwrap();
+        writeln!(mtl, "Ka 1.0 1.0 1.0").unwrap();
+        writeln!(mtl, "Kd 1.0 1.0 1.0").unwrap();
+        writeln!(mtl, "Ks 0.0 0.0 0.0").unwrap();
+        writeln!(mtl, "Ns 10.0").unwrap();
+        writeln!(mtl, "d 1.0").unwrap();
+        writeln!(mtl, "illum 1").unwrap();
+        writeln!(mtl, "map_Kd {}_atlas{}.png", name, suffix).unwrap();
+        if atlas_meshes.iter().any(|(p, emissive, _)| *p == page && *emissive) {
+            writeln!(mtl).unwrap();
+            writeln!(mtl, "newmtl {}{}_emissive_material", name, suffix).unwrap();
+            writeln!(mtl, "Ka 1.0 1.0 1.0").unwrap();
+            writeln!(mtl, "Kd 1.0 1.0 1.0").unwrap();
+            writeln!(mtl, "Ks 0.0 0.0 0.0").unwrap();
+            writeln!(mtl, "Ke 1.0 1.0 1.0").unwrap();
+            writeln!(mtl, "Ns 10.0").unwrap();
+            writeln!(mtl, "d 1.0").unwrap();
+            writeln!(mtl, "illum 1").unwrap();
+            writeln!(mtl, "map_Kd {}_atlas{}.png", name, suffix).unwrap();
+            writeln!(mtl, "map_Ke {}_atlas{}.png", name, suffix).unwrap();
+        }
+    }
 
     // Greedy materials
     for (gi, gm) in output.greedy_materials.iter().enumerate() {
@@ -163,6 +203,15 @@ pub fn export_obj(output: &MesherOutput, name: &str) -> Result<(String, String)>
     Ok((obj, mtl))
 }
 
+/// Name suffix for atlas page `page`; empty for the first page.
+fn page_suffix(page: u32) -> String {
+    if page == 0 {
+        String::new()
+    } else {
+        format!("_page{}", page)
+    }
+}
+
 /// A named texture file for OBJ export.
 pub struct ObjTexture {
     /// Filename for this texture (e.g., "block_stone.png").
@@ -176,6 +225,8 @@ pub struct ObjExport {
     pub obj: String,
     pub mtl: String,
     pub texture_png: Vec<u8>,
+    /// Further atlas pages (`{name}_atlas_page<N>.png`), when the atlas spilled.
+    pub atlas_pages: Vec<ObjTexture>,
     /// Additional texture files for greedy materials.
     pub greedy_textures: Vec<ObjTexture>,
 }
@@ -184,6 +235,14 @@ impl ObjExport {
     pub fn from_output(output: &MesherOutput, name: &str) -> Result<Self> {
         let (obj, mtl) = export_obj(output, name)?;
         let texture_png = output.atlas.to_png()?;
+        let atlas_pages = (1..output.atlas.page_count())
+            .map(|page| {
+                Ok(ObjTexture {
+                    filename: format!("{}_atlas{}.png", name, page_suffix(page as u32)),
+                    png_data: output.atlas.page_to_png(page)?,
+                })
+            })
+            .collect::<Result<_>>()?;
         let greedy_textures = output.greedy_materials.iter().map(|gm| {
             let filename = format!("{}.png", gm.texture_path.replace('/', "_"));
             ObjTexture {
@@ -195,6 +254,7 @@ impl ObjExport {
             obj,
             mtl,
             texture_png,
+            atlas_pages,
             greedy_textures,
         })
     }
@@ -222,9 +282,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
 
         let (obj, mtl) = export_obj(&output, "test").unwrap();
@@ -9,18 +9,26 @@ pub struct RawMeshData {
     pub positions: Vec<[f32; 3]>,
     /// Vertex normals (3 floats per vertex).
     pub normals: Vec<[f32; 3]>,
-    /// Texture coordinates (2 floats per vertex).
+    /// Texture coordinates (2 floats per vertex). On a multi-page atlas, U
+    /// is offset by the page index (page `n` spans `[n, n + 1)`).
     pub uvs: Vec<[f32; 2]>,
     /// Vertex colors (4 floats per vertex, RGBA).
     pub colors: Vec<[f32; 4]>,
     /// Triangle indices (3 per triangle).
     pub indices: Vec<u32>,
-    /// Texture atlas RGBA data.
+    /// Texture atlas RGBA data (first page; further pages are in
+    /// `MesherOutput::atlas.extra_pages`).
     pub texture_rgba: Vec<u8>,
     /// Texture atlas width.
     pub texture_width: u32,
     /// Texture atlas height.
     pub texture_height: u32,
+    /// Texture layer per vertex, in texture-array mode (the array is
+    /// `MesherOutput::texture_array`); empty otherwise.
+    pub texture_layers: Vec<u32>,
+    /// Emissive flag per vertex (see `MeshLayer::emissive`); empty when no
+    /// vertex is emissive.
+    pub emissive: Vec<bool>,
 }
 
 /// Export mesh as raw data.
@@ -30,6 +38,21 @@ pub fn export_raw(output: &MesherOutput) -> RawMeshData {
     // Combine opaque and transparent meshes
     let mesh = output.mesh();
     let atlas = &output.atlas;
+    let texture_layers = [&output.opaque_mesh, &output.cutout_mesh, &output.transparent_mesh]
+        .iter()
+        .flat_map(|layer| layer.texture_layers.iter().copied())
+        .collect();
+    let layers = [&output.opaque_mesh, &output.cutout_mesh, &output.transparent_mesh];
+    let emissive = if layers.iter().any(|layer| !layer.emissive.is_empty()) {
+        let mut emissive: Vec<bool> = layers
+            .iter()
+            .flat_map(|layer| (0..layer.vertex_count()).map(|i| layer.is_emissive(i)))
+            .collect();
+        emissive.resize(mesh.vertices.len(), false);
+        emissive
+    } else {
+        Vec::new()
+    };
 
     RawMeshData {
         positions: mesh.vertices.iter().map(|v| v.position).collect(),
@@ -40,6 +63,8 @@ pub fn export_raw(output: &MesherOutput) -> RawMeshData {
         texture_rgba: atlas.pixels.clone(),
         texture_width: atlas.width,
         texture_height: atlas.height,
+        texture_layers,
+        emissive,
     }
 }
 
@@ -95,9 +120,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
 
         let raw = export_raw(&output);
@@ -6,6 +6,7 @@
 use crate::error::{MesherError, Result};
 use crate::mesher::geometry::Mesh;
 use crate::mesher::MesherOutput;
+use std::borrow::Cow;
 use std::fmt::Write;
 use std::io::Write as IoWrite;
 
@@ -21,14 +22,36 @@ pub struct UsdTexture {
 pub struct UsdaExport {
     /// The USDA text content.
     pub usda: String,
-    /// Atlas texture PNG data.
+    /// Atlas texture PNG data (first page).
     pub atlas_png: Vec<u8>,
+    /// Further atlas pages (`textures/atlas_page<N>.png`), when the atlas spilled.
+    pub atlas_pages: Vec<UsdTexture>,
     /// Greedy material textures.
     pub greedy_textures: Vec<UsdTexture>,
+    /// Atlas normal and metallic-roughness maps (per page), when the pack has LabPBR data.
+    pub pbr_textures: Vec<UsdTexture>,
 }
 
-/// Export mesh data as USDA text plus texture files.
+/// Filenames of the atlas PBR maps, wired into the atlas materials.
+struct PbrMaps {
+    normal: String,
+    metallic_roughness: String,
+}
+
+/// Name suffix for atlas page `page`: empty for the first page so
+/// single-page exports keep their original names.
+fn page_suffix(page: usize) -> String {
+    if page == 0 {
+        String::new()
+    } else {
+        format!("_page{}", page)
+    }
+}
+
+/// Export mesh data as USDA text plus texture files. Emissive faces are bound
+/// to material copies whose `emissiveColor` reads the atlas.
 pub fn export_usda(output: &MesherOutput) -> Result<UsdaExport> {
+    super::reject_texture_array(output)?;
     let has_greedy = output
         .greedy_materials
         .iter()
@@ -37,7 +60,41 @@ pub fn export_usda(output: &MesherOutput) -> Result<UsdaExport> {
         return Err(MesherError::Export("Cannot export empty mesh".to_string()));
     }
 
-    let atlas_png = output.atlas.to_png()?;
+    let atlas = &output.atlas;
+    let atlas_png = atlas.to_png()?;
+    let mut atlas_pages = Vec::new();
+    let mut pbr_textures = Vec::new();
+    let mut page_pbr_maps = Vec::new();
+    for page in 0..atlas.page_count() {
+        let suffix = page_suffix(page);
+        if page > 0 {
+            atlas_pages.push(UsdTexture {
+                filename: format!("textures/atlas{}.png", suffix),
+                png_data: atlas.page_to_png(page)?,
+            });
+        }
+        let maps = if let (Some(normal), Some(metallic_roughness)) = (
+            atlas.page_normal_map_png(page)?,
+            atlas.page_metallic_roughness_png(page)?,
+        ) {
+            let maps = PbrMaps {
+                normal: format!("textures/atlas{}_normal.png", suffix),
+                metallic_roughness: format!("textures/atlas{}_metallic_roughness.png", suffix),
+            };
+            pbr_textures.push(UsdTexture {
+                filename: maps.normal.clone(),
+                png_data: normal,
+            });
+            pbr_textures.push(UsdTexture {
+                filename: maps.metallic_roughness.clone(),
+                png_data: metallic_roughness,
+            });
+            Some(maps)
+        } else {
+            None
+        };
+        page_pbr_maps.push(maps);
+    }
 
     // Pre-size the output buffer: ~200 bytes per vertex (multiple arrays) + ~10 per index
     let vert_count = output.total_vertices();
@@ -56,15 +113,29 @@ pub fn export_usda(output: &MesherOutput) -> Result<UsdaExport> {
     writeln!(usda, "def Xform \"Root\"").unwrap();
     writeln!(usda, "{{").unwrap();
 
-    // Atlas materials
-    write_material(&mut usda, "atlas_opaque", "textures/atlas.png", "clamp", 1.0);
-    write_material(
-        &mut usda,
-        "atlas_transparent",
-        "textures/atlas.png",
-        "clamp",
-        0.0,
-    );
+    // Atlas materials, one opaque/transparent pair per page
+    for (page, pbr_maps) in page_pbr_maps.iter().enumerate() {
+        let suffix = page_suffix(page);
+        let texture = format!("textures/atlas{}.png", suffix);
+        write_material(
+            &mut usda,
+            &format!("atlas{}_opaque", suffix),
+            &texture,
+            "clamp",
+            1.0,
+            pbr_maps.as_ref(),
+            false,
+        );
+        write_material(
+            &mut usda,
+            &format!("atlas{}_transparent", suffix),
+            &texture,
+            "clamp",
+            0.0,
+            pbr_maps.as_ref(),
+            false,
+        );
+    }
 
     // Greedy materials
     let mut greedy_textures = Vec::new();
@@ -75,7 +146,7 @@ pub fn export_usda(output: &MesherOutput) -> Result<UsdaExport> {
         let tex_filename = format!("textures/greedy_{}.png", i);
         let mat_name_opaque = format!("greedy_{}_opaque", i);
         let mat_name_transparent = format!("greedy_{}_transparent", i);
-        write_material(&mut usda, &mat_name_opaque, &tex_filename, "repeat", 1.0);
+        write_material(&mut usda, &mat_name_opaque, &tex_filename, "repeat", 1.0, None, false);
         if !gm.transparent_mesh.is_empty() {
             write_material(
                 &mut usda,
@@ -83,6 +154,8 @@ pub fn export_usda(output: &MesherOutput) -> Result<UsdaExport> {
                 &tex_filename,
                 "repeat",
                 0.0,
+                None,
+                false,
             );
         }
         greedy_textures.push(UsdTexture {
@@ -94,18 +167,48 @@ pub fn export_usda(output: &MesherOutput) -> Result<UsdaExport> {
     // Atlas-based meshes. Output layers are SoA (MeshLayer); the writer works on
     // AoS Mesh, so convert at the boundary.
     use crate::mesh_output::layer_to_internal_mesh;
-    if !output.opaque_mesh.is_empty() {
-        write_mesh_prim(&mut usda, "opaque", &layer_to_internal_mesh(&output.opaque_mesh), "atlas_opaque");
-    }
-    if !output.cutout_mesh.is_empty() {
-        write_mesh_prim(&mut usda, "cutout", &layer_to_internal_mesh(&output.cutout_mesh), "atlas_opaque");
+    let atlas_layers = [
+        ("opaque", &output.opaque_mesh, "opaque"),
+        ("cutout", &output.cutout_mesh, "opaque"),
+        ("transparent", &output.transparent_mesh, "transparent"),
+    ];
+    // Emissive faces go into `*_emissive` prims bound to emissive material copies.
+    let mut emissive_materials: Vec<(String, usize, &str)> = Vec::new();
+    for (prim, layer, material) in atlas_layers {
+        if layer.is_empty() {
+            continue;
+        }
+        let pages = if atlas.page_count() == 1 {
+            vec![(0, Cow::Borrowed(layer))]
+        } else {
+            layer.split_atlas_pages().into_iter().map(|(p, l)| (p, Cow::Owned(l))).collect()
+        };
+        for (page, page_layer) in pages {
+            let suffix = page_suffix(page as usize);
+            let material_name = format!("atlas{}_{}", suffix, material);
+            let Some((rest, emissive)) = page_layer.split_emissive() else {
+                write_mesh_prim(&mut usda, &format!("{}{}", prim, suffix), &layer_to_internal_mesh(&page_layer), &material_name);
+                continue;
+            };
+            if !rest.is_empty() {
+                write_mesh_prim(&mut usda, &format!("{}{}", prim, suffix), &layer_to_internal_mesh(&rest), &material_name);
+            }
+            let emissive_name = format!("{}_emissive", material_name);
+            write_mesh_prim(&mut usda, &format!("{}{}_emissive", prim, suffix), &layer_to_internal_mesh(&emissive), &emissive_name);
+            if !emissive_materials.iter().any(|(name, _, _)| *name == emissive_name) {
+                emissive_materials.push((emissive_name, page as usize, material));
+            }
+        }
     }
-    if !output.transparent_mesh.is_empty() {
-        write_mesh_prim(
+    for (name, page, material) in &emissive_materials {
+        write_material(
             &mut usda,
-            "transparent",
-            &layer_to_internal_mesh(&output.transparent_mesh),
-            "atlas_transparent",
+            name,
+            &format!("textures/atlas{}.png", page_suffix(*page)),
+            "clamp",
+            if *material == "opaque" { 1.0 } else { 0.0 },
+            page_pbr_maps[*page].as_ref(),
+            true,
         );
     }
 
@@ -135,7 +238,9 @@ pub fn export_usda(output: &MesherOutput) -> Result<UsdaExport> {
     Ok(UsdaExport {
         usda,
         atlas_png,
+        atlas_pages,
         greedy_textures,
+        pbr_textures,
     })
 }
 
@@ -164,8 +269,9 @@ pub fn export_usdz(output: &MesherOutput) -> Result<Vec<u8>> {
         zip.write_all(&export.atlas_png)
             .map_err(|e| MesherError::Export(format!("USDZ write error: {}", e)))?;
 
-        // Greedy textures
-        for tex in &export.greedy_textures {
+        // Extra atlas pages, greedy and PBR textures
+        let textures = export.atlas_pages.iter().chain(&export.greedy_textures).chain(&export.pbr_textures);
+        for tex in textures {
             zip.start_file(&tex.filename, options)
                 .map_err(|e| MesherError::Export(format!("USDZ write error: {}", e)))?;
             zip.write_all(&tex.png_data)
@@ -180,7 +286,15 @@ pub fn export_usdz(output: &MesherOutput) -> Result<Vec<u8>> {
 }
 
 /// Write a UsdPreviewSurface material definition.
-fn write_material(usda: &mut String, name: &str, texture_path: &str, wrap: &str, opacity: f32) {
+fn write_material(
+    usda: &mut String,
+    name: &str,
+    texture_path: &str,
+    wrap: &str,
+    opacity: f32,
+    pbr: Option<&PbrMaps>,
+    emissive: bool,
+) {
     writeln!(usda, "    def Material \"{}\"", name).unwrap();
     writeln!(usda, "    {{").unwrap();
     writeln!(
@@ -204,8 +318,38 @@ fn write_material(usda: &mut String, name: &str, texture_path: &str, wrap: &str,
         name
     )
     .unwrap();
-    writeln!(usda, "            float inputs:metallic = 0").unwrap();
-    writeln!(usda, "            float inputs:roughness = 1").unwrap();
+    if pbr.is_some() {
+        // glTF packing: roughness in G, metalness in B
+        writeln!(
+            usda,
+            "            float inputs:metallic.connect = </Root/{}/metallicRoughness.outputs:b>",
+            name
+        )
+        .unwrap();
+        writeln!(
+            usda,
+            "            float inputs:roughness.connect = </Root/{}/metallicRoughness.outputs:g>",
+            name
+        )
+        .unwrap();
+        writeln!(
+            usda,
+            "            normal3f inputs:normal.connect = </Root/{}/normal.outputs:rgb>",
+            name
+        )
+        .unwrap();
+    } else {
+        writeln!(usda, "            float inputs:metallic = 0").unwrap();
+        writeln!(usda, "            float inputs:roughness = 1").unwrap();
+    }
+    if emissive {
+        writeln!(
+            usda,
+            "            color3f inputs:emissiveColor.connect = </Root/{}/diffuse.outputs:rgb>",
+            name
+        )
+        .unwrap();
+    }
     if opacity < 1.0 {
         writeln!(
             usda,
@@ -257,6 +401,19 @@ fn write_material(usda: &mut String, name: &str, texture_path: &str, wrap: &str,
     }
     writeln!(usda, "        }}").unwrap();
 
+    if let Some(pbr) = pbr {
+        write_data_texture(usda, name, "normal", &pbr.normal, wrap, &["float3 outputs:rgb"], true);
+        write_data_texture(
+            usda,
+            name,
+            "metallicRoughness",
+            &pbr.metallic_roughness,
+            wrap,
+            &["float outputs:g", "float outputs:b"],
+            false,
+        );
+    }
+
     // Primvar reader
     writeln!(usda, "        def Shader \"st\"").unwrap();
     writeln!(usda, "        {{").unwrap();
@@ -276,6 +433,40 @@ fn write_material(usda: &mut String, name: &str, texture_path: &str, wrap: &str,
     writeln!(usda, "    }}\n").unwrap();
 }
 
+/// Write a non-color UsdUVTexture (raw color space). Normal maps are remapped
+/// from [0, 1] to [-1, 1] via scale/bias as UsdPreviewSurface expects.
+fn write_data_texture(
+    usda: &mut String,
+    material: &str,
+    shader: &str,
+    texture_path: &str,
+    wrap: &str,
+    outputs: &[&str],
+    is_normal: bool,
+) {
+    writeln!(usda, "        def Shader \"{}\"", shader).unwrap();
+    writeln!(usda, "        {{").unwrap();
+    writeln!(usda, "            uniform token info:id = \"UsdUVTexture\"").unwrap();
+    writeln!(usda, "            asset inputs:file = @{}@", texture_path).unwrap();
+    writeln!(usda, "            token inputs:sourceColorSpace = \"raw\"").unwrap();
+    writeln!(
+        usda,
+        "            float2 inputs:st.connect = </Root/{}/st.outputs:result>",
+        material
+    )
+    .unwrap();
+    writeln!(usda, "            token inputs:wrapS = \"{}\"", wrap).unwrap();
+    writeln!(usda, "            token inputs:wrapT = \"{}\"", wrap).unwrap();
+    if is_normal {
+        writeln!(usda, "            float4 inputs:scale = (2, 2, 2, 1)").unwrap();
+        writeln!(usda, "            float4 inputs:bias = (-1, -1, -1, 0)").unwrap();
+    }
+    for output in outputs {
+        writeln!(usda, "            {}", output).unwrap();
+    }
+    writeln!(usda, "        }}").unwrap();
+}
+
 /// Write a comma-separated array inline, streaming values directly to the buffer.
 fn write_array_inline<T, F>(usda: &mut String, items: &[T], mut fmt: F)
 where
@@ -391,9 +582,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         }
     }
 
@@ -433,9 +626,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
         assert!(export_usda(&output).is_err());
     }
@@ -452,9 +647,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: mesh,
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
         let export = export_usda(&output).unwrap();
         assert!(export.usda.contains("def Mesh \"transparent\""));
@@ -483,9 +680,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
         let export = export_usda(&output).unwrap();
         assert!(export.usda.contains("primvars:displayColor"));
@@ -534,4 +733,16 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_export_usd_pbr_maps() {
+        let mut output = make_triangle_output();
+        output.atlas.specular_pixels = Some(crate::atlas::pbr::NEUTRAL_SPECULAR.repeat(16 * 16));
+        let export = export_usda(&output).unwrap();
+        assert_eq!(export.pbr_textures.len(), 2);
+        assert!(export.usda.contains("@textures/atlas_normal.png@"));
+        assert!(export.usda.contains("inputs:normal.connect"));
+        assert!(export.usda.contains("metallicRoughness.outputs:g"));
+        assert!(export.usda.contains("token inputs:sourceColorSpace = \"raw\""));
+    }
 }
@@ -162,14 +162,17 @@ pub mod animate;
 pub use error::{MesherError, Result};
 pub use types::{Direction, Axis, BlockPosition, BoundingBox, InputBlock, BlockSource};
 pub use resource_pack::{ResourcePack, BlockModel, ModelElement, BlockstateDefinition};
-pub use atlas::{AtlasBuilder, AtlasRegion, TextureAtlas};
+pub use resource_pack::{validate_pack, ValidationIssue, ValidationReport};
+pub use resource_pack::{BlockRegistry, PaintingRegistry, SkinLibrary};
+pub use atlas::{AtlasBuilder, AtlasPage, AtlasRegion, TextureArray, TextureArrayGroup, TextureAtlas};
 
 // --- Animation (scenario replay) ---
-pub use animate::{build_animated_glb, Timeline, TimelineEvent, PistonAction};
+pub use animate::{build_animated_glb, build_rigged_glb, Timeline, TimelineEvent, PistonAction};
 
 // --- Mesher ---
 pub use mesher::{Mesher, MesherConfig, MesherOutput, Mesh, Vertex, TintColors, TintProvider};
 pub use mesher::ChunkIter;
+pub use mesher::MeshDiagnostics;
 
 // --- Canonical output types ---
 pub use mesh_output::{MeshOutput, MeshLayer};
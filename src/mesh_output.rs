@@ -17,6 +17,11 @@
 //! | UV        | `[f32; 2]` | 8 bytes  | [`uvs_bytes()`](MeshLayer::uvs_bytes) |
 //! | Color     | `[f32; 4]` | 16 bytes | [`colors_bytes()`](MeshLayer::colors_bytes) |
 //! | Index     | `u32`      | 4 bytes  | [`indices_bytes()`](MeshLayer::indices_bytes) |
+//! | Texture layer | `u32`  | 4 bytes  | [`texture_layers_bytes()`](MeshLayer::texture_layers_bytes) |
+//!
+//! Texture layers are only present in texture-array mode
+//! ([`MesherConfig::texture_array`](crate::MesherConfig::texture_array)); the
+//! array itself is [`MeshOutput::texture_array`].
 //!
 //! The `_bytes()` methods return `&[u8]` slices over the existing memory — zero allocation,
 //! zero copy — suitable for direct upload to GPU vertex/index buffers.
@@ -33,10 +38,10 @@
 //! back (e.g., for use with standalone export functions), use [`to_glb()`](MeshOutput::to_glb),
 //! [`to_usdz()`](MeshOutput::to_usdz), or [`to_obj()`](MeshOutput::to_obj).
 
-use crate::atlas::TextureAtlas;
+use crate::atlas::{TextureArray, TextureAtlas};
 use crate::error::Result;
 use crate::export::obj::ObjExport;
-use crate::mesher::AnimatedTextureExport;
+use crate::mesher::{AnimatedTextureExport, MeshDiagnostics};
 use crate::types::BoundingBox;
 use std::mem;
 
@@ -50,12 +55,20 @@ pub struct MeshLayer {
     pub positions: Vec<[f32; 3]>,
     /// Vertex normals (unit length).
     pub normals: Vec<[f32; 3]>,
-    /// Texture coordinates into the atlas.
+    /// Texture coordinates into the atlas, or into the vertex's texture
+    /// layer in texture-array mode (where they may tile past 1).
     pub uvs: Vec<[f32; 2]>,
     /// Vertex tint colors (biome coloring, AO, lighting baked in). RGBA, premultiplied.
     pub colors: Vec<[f32; 4]>,
     /// Triangle indices (three per triangle).
     pub indices: Vec<u32>,
+    /// Per-vertex layer into [`MeshOutput::texture_array`]. Empty unless
+    /// meshed in texture-array mode.
+    pub texture_layers: Vec<u32>,
+    /// Per-vertex emissive flag: faces that glow (emissive `_e` overlays and
+    /// elements with `light_emission`) and are meshed fullbright. Empty when
+    /// no vertex in the layer is emissive.
+    pub emissive: Vec<bool>,
 }
 
 impl MeshLayer {
@@ -104,6 +117,73 @@ impl MeshLayer {
         cast_slice(&self.indices)
     }
 
+    /// Raw bytes of the texture layers array. Zero-allocation view.
+    pub fn texture_layers_bytes(&self) -> &[u8] {
+        cast_slice(&self.texture_layers)
+    }
+
+    /// Whether a vertex is emissive.
+    pub fn is_emissive(&self, vertex: usize) -> bool {
+        self.emissive.get(vertex).copied().unwrap_or(false)
+    }
+
+    /// Set the emissive flag of the most recently pushed vertex, keeping
+    /// [`emissive`](Self::emissive) empty until some vertex emits.
+    pub(crate) fn set_last_emissive(&mut self, emissive: bool) {
+        if emissive || !self.emissive.is_empty() {
+            self.emissive.resize(self.positions.len() - 1, false);
+            self.emissive.push(emissive);
+        }
+    }
+
+    /// Per-vertex tangents (`xyz` + handedness `w`) for normal mapping,
+    /// accumulated from each triangle's UV gradients and orthogonalized
+    /// against the vertex normal.
+    ///
+    /// Atlas V grows down the image while normal maps encode +Y as up, so the
+    /// bitangent `cross(normal, tangent) * w` points toward decreasing V — the
+    /// convention glTF's `TANGENT` attribute expects.
+    pub fn compute_tangents(&self) -> Vec<[f32; 4]> {
+        let count = self.positions.len();
+        let mut tangents = vec![glam::Vec3::ZERO; count];
+        let mut bitangents = vec![glam::Vec3::ZERO; count];
+
+        for tri in self.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+            if a >= count || b >= count || c >= count {
+                continue;
+            }
+            let p0 = glam::Vec3::from(self.positions[a]);
+            let e1 = glam::Vec3::from(self.positions[b]) - p0;
+            let e2 = glam::Vec3::from(self.positions[c]) - p0;
+            // Flip V so the bitangent follows image-up.
+            let (du1, dv1) = (self.uvs[b][0] - self.uvs[a][0], self.uvs[a][1] - self.uvs[b][1]);
+            let (du2, dv2) = (self.uvs[c][0] - self.uvs[a][0], self.uvs[a][1] - self.uvs[c][1]);
+            let det = du1 * dv2 - du2 * dv1;
+            if det.abs() < 1e-12 {
+                continue;
+            }
+            let r = 1.0 / det;
+            let t = (e1 * dv2 - e2 * dv1) * r;
+            let bt = (e2 * du1 - e1 * du2) * r;
+            for i in [a, b, c] {
+                tangents[i] += t;
+                bitangents[i] += bt;
+            }
+        }
+
+        (0..count)
+            .map(|i| {
+                let n = glam::Vec3::from(self.normals[i]);
+                let t = tangents[i] - n * n.dot(tangents[i]);
+                // Degenerate UVs: any vector perpendicular to the normal will do.
+                let t = t.try_normalize().unwrap_or_else(|| n.any_orthonormal_vector());
+                let w = if n.cross(t).dot(bitangents[i]) < 0.0 { -1.0 } else { 1.0 };
+                [t.x, t.y, t.z, w]
+            })
+            .collect()
+    }
+
     /// Push one vertex's attributes (SoA) and return its index. Used by the
     /// transparency split so geometry lands directly in SoA layout — no AoS
     /// `Vertex`/`Mesh` intermediate that would have to be converted later.
@@ -132,14 +212,80 @@ impl MeshLayer {
         self.indices.reserve(indices);
     }
 
+    /// Split atlas-mapped geometry by atlas page. Page `n` geometry has U in
+    /// `[n, n + 1)` (see [`AtlasRegion::transform_uv`](crate::atlas::AtlasRegion::transform_uv));
+    /// each returned layer has the page offset removed so its UVs index that
+    /// page's texture directly. Pages are returned in ascending order and
+    /// pages without geometry are omitted.
+    pub fn split_atlas_pages(&self) -> Vec<(u32, MeshLayer)> {
+        // The centroid is strictly inside the region even when a vertex
+        // sits exactly on the page's right edge.
+        self.split_triangles(
+            |tri| {
+                let u = tri.iter().map(|&i| self.uvs[i as usize][0]).sum::<f32>() / 3.0;
+                u.floor().max(0.0) as u32
+            },
+            |page, [u, v]| [u - page as f32, v],
+        )
+    }
+
+    /// Split off emissive geometry: `(non-emissive, emissive)`, or None when
+    /// the layer has no emissive vertices. A triangle is emissive if any of
+    /// its vertices is.
+    pub fn split_emissive(&self) -> Option<(MeshLayer, MeshLayer)> {
+        if !self.emissive.contains(&true) {
+            return None;
+        }
+        let mut parts = self.split_triangles(|tri| tri.iter().any(|&i| self.is_emissive(i as usize)), |_, uv| uv);
+        let emissive = match parts.last() {
+            Some((true, _)) => parts.pop().map(|(_, layer)| layer).unwrap_or_default(),
+            _ => MeshLayer::new(),
+        };
+        let rest = parts.pop().map(|(_, layer)| layer).unwrap_or_default();
+        Some((rest, emissive))
+    }
+
+    /// Group triangles by `key`, copying their vertices (with `uv` applied)
+    /// into one layer per key. Keys are returned in ascending order.
+    fn split_triangles<K: Ord + Copy>(
+        &self,
+        key: impl Fn(&[u32]) -> K,
+        uv: impl Fn(K, [f32; 2]) -> [f32; 2],
+    ) -> Vec<(K, MeshLayer)> {
+        let mut groups: std::collections::BTreeMap<K, (MeshLayer, Vec<u32>)> = Default::default();
+        for tri in self.indices.chunks_exact(3) {
+            let k = key(tri);
+            let (layer, remap) = groups
+                .entry(k)
+                .or_insert_with(|| (MeshLayer::new(), vec![u32::MAX; self.positions.len()]));
+            for &i in tri {
+                let i = i as usize;
+                if remap[i] == u32::MAX {
+                    remap[i] = layer.push_vertex(self.positions[i], self.normals[i], uv(k, self.uvs[i]), self.colors[i]);
+                    if let Some(&texture_layer) = self.texture_layers.get(i) {
+                        layer.texture_layers.push(texture_layer);
+                    }
+                    layer.set_last_emissive(self.is_emissive(i));
+                }
+                layer.indices.push(remap[i]);
+            }
+        }
+        groups.into_iter().map(|(k, (layer, _))| (k, layer)).collect()
+    }
+
     /// Merge another layer into this one, offsetting indices appropriately.
     pub fn merge(&mut self, other: &MeshLayer) {
         let offset = self.positions.len() as u32;
+        if !self.emissive.is_empty() || !other.emissive.is_empty() {
+            self.emissive.resize(offset as usize, false);
+            self.emissive.extend((0..other.positions.len()).map(|i| other.is_emissive(i)));
+        }
         self.positions.extend_from_slice(&other.positions);
         self.normals.extend_from_slice(&other.normals);
         self.uvs.extend_from_slice(&other.uvs);
         self.colors.extend_from_slice(&other.colors);
         self.indices.extend(other.indices.iter().map(|&i| i + offset));
+        self.texture_layers.extend_from_slice(&other.texture_layers);
     }
 }
 
@@ -192,12 +338,18 @@ pub struct MeshOutput {
     pub transparent: MeshLayer,
     /// The texture atlas shared by the `opaque` / `cutout` / `transparent` layers.
     pub atlas: TextureAtlas,
+    /// The texture array, in texture-array mode. The layers then index it
+    /// through [`MeshLayer::texture_layers`], `atlas` is empty, and greedy
+    /// faces are part of the layers instead of `greedy_materials`.
+    pub texture_array: Option<TextureArray>,
     /// Greedy-merged materials — one per unique `(texture_path, AO pattern)`
     /// in the source geometry. Each has its own texture and tile-space UVs;
     /// exporters must render them as separate primitives with REPEAT sampling.
     pub greedy_materials: Vec<GreedyMaterialOutput>,
     /// Animated texture metadata for viewer-side frame cycling.
     pub animated_textures: Vec<AnimatedTextureExport>,
+    /// Blocks that were skipped, textures that were missing, and fallbacks taken.
+    pub diagnostics: MeshDiagnostics,
     /// Axis-aligned bounding box of the meshed region.
     pub bounds: BoundingBox,
     /// Chunk coordinate, set when meshed via [`ChunkIter`]. `None` for single-shot meshing.
@@ -278,9 +430,11 @@ impl MeshOutput {
             cutout_mesh: self.cutout.clone(),
             transparent_mesh: self.transparent.clone(),
             atlas: self.atlas.clone(),
+            texture_array: self.texture_array.clone(),
             bounds: self.bounds,
             greedy_materials,
             animated_textures: self.animated_textures.clone(),
+            diagnostics: self.diagnostics.clone(),
         }
     }
 }
@@ -332,6 +486,8 @@ pub(crate) fn mesh_to_layer(mesh: &crate::mesher::geometry::Mesh) -> MeshLayer {
         uvs,
         colors,
         indices: mesh.indices.clone(),
+        texture_layers: Vec::new(),
+        emissive: Vec::new(),
     }
 }
 
@@ -361,8 +517,10 @@ impl From<crate::mesher::MesherOutput> for MeshOutput {
             cutout: output.cutout_mesh,
             transparent: output.transparent_mesh,
             atlas: output.atlas,
+            texture_array: output.texture_array,
             greedy_materials,
             animated_textures: output.animated_textures,
+            diagnostics: output.diagnostics,
             bounds: output.bounds,
             chunk_coord: None,
             lod_level: 0,
@@ -382,6 +540,8 @@ mod tests {
             uvs: vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
             colors: vec![[1.0, 1.0, 1.0, 1.0]; 3],
             indices: vec![0, 1, 2],
+            texture_layers: Vec::new(),
+            emissive: Vec::new(),
         };
         let b = MeshLayer {
             positions: vec![[2.0, 0.0, 0.0], [3.0, 0.0, 0.0], [2.0, 1.0, 0.0]],
@@ -389,6 +549,8 @@ mod tests {
             uvs: vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
             colors: vec![[1.0, 1.0, 1.0, 1.0]; 3],
             indices: vec![0, 1, 2],
+            texture_layers: Vec::new(),
+            emissive: Vec::new(),
         };
         a.merge(&b);
 
@@ -399,6 +561,48 @@ mod tests {
         assert_eq!(a.positions[3], [2.0, 0.0, 0.0]);
     }
 
+    #[test]
+    fn test_compute_tangents_follow_u() {
+        // Up-facing quad; U runs along +X, V runs along +Z (down the image).
+        let mut layer = MeshLayer::new();
+        let up = [0.0, 1.0, 0.0];
+        let white = [1.0; 4];
+        layer.push_vertex([0.0, 1.0, 0.0], up, [0.0, 0.0], white);
+        layer.push_vertex([0.0, 1.0, 1.0], up, [0.0, 1.0], white);
+        layer.push_vertex([1.0, 1.0, 1.0], up, [1.0, 1.0], white);
+        layer.push_vertex([1.0, 1.0, 0.0], up, [1.0, 0.0], white);
+        layer.indices = vec![0, 1, 2, 0, 2, 3];
+
+        let tangents = layer.compute_tangents();
+        assert_eq!(tangents.len(), 4);
+        for t in &tangents {
+            assert!((t[0] - 1.0).abs() < 1e-5 && t[1].abs() < 1e-5 && t[2].abs() < 1e-5);
+            // Bitangent = cross(+Y, +X) * w = -Z * w must point to -Z (image up).
+            assert_eq!(t[3], 1.0);
+        }
+    }
+
+    #[test]
+    fn test_split_atlas_pages() {
+        let mut layer = MeshLayer::new();
+        let n = [0.0, 1.0, 0.0];
+        let c = [1.0; 4];
+        // Page 0 triangle touching the right edge, page 2 triangle.
+        layer.push_vertex([0.0; 3], n, [0.5, 0.0], c);
+        layer.push_vertex([1.0, 0.0, 0.0], n, [1.0, 0.0], c);
+        layer.push_vertex([0.0, 0.0, 1.0], n, [1.0, 1.0], c);
+        layer.push_vertex([0.0; 3], n, [2.25, 0.0], c);
+        layer.push_vertex([1.0, 0.0, 0.0], n, [2.5, 0.0], c);
+        layer.push_vertex([0.0, 0.0, 1.0], n, [2.5, 0.5], c);
+        layer.indices = vec![3, 4, 5, 0, 1, 2];
+
+        let pages = layer.split_atlas_pages();
+        assert_eq!(pages.iter().map(|(p, _)| *p).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(pages[0].1.uvs[1], [1.0, 0.0]);
+        assert_eq!(pages[1].1.uvs, vec![[0.25, 0.0], [0.5, 0.0], [0.5, 0.5]]);
+        assert_eq!(pages[1].1.indices, vec![0, 1, 2]);
+    }
+
     #[test]
     fn test_mesh_layer_bytes_zero_alloc() {
         let layer = MeshLayer {
@@ -407,6 +611,8 @@ mod tests {
             uvs: vec![[0.5, 0.5]],
             colors: vec![[1.0, 0.0, 0.0, 1.0]],
             indices: vec![0],
+            texture_layers: Vec::new(),
+            emissive: Vec::new(),
         };
 
         assert_eq!(layer.positions_bytes().len(), 12); // 3 * 4 bytes
@@ -424,6 +630,8 @@ mod tests {
             uvs: vec![[0.0, 0.0]],
             colors: vec![[1.0, 1.0, 1.0, 1.0]],
             indices: vec![0],
+            texture_layers: Vec::new(),
+            emissive: Vec::new(),
         };
         let cutout = MeshLayer {
             positions: vec![[1.0, 0.0, 0.0]],
@@ -431,6 +639,8 @@ mod tests {
             uvs: vec![[1.0, 0.0]],
             colors: vec![[1.0, 1.0, 1.0, 1.0]],
             indices: vec![0],
+            texture_layers: Vec::new(),
+            emissive: Vec::new(),
         };
         let transparent = MeshLayer {
             positions: vec![[2.0, 0.0, 0.0]],
@@ -438,6 +648,8 @@ mod tests {
             uvs: vec![[0.0, 1.0]],
             colors: vec![[1.0, 1.0, 1.0, 0.5]],
             indices: vec![0],
+            texture_layers: Vec::new(),
+            emissive: Vec::new(),
         };
 
         let output = MeshOutput {
@@ -445,8 +657,10 @@ mod tests {
             cutout,
             transparent,
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [3.0, 1.0, 1.0]),
             chunk_coord: None,
             lod_level: 0,
@@ -469,8 +683,10 @@ mod tests {
             cutout: MeshLayer::new(),
             transparent: MeshLayer::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]),
             chunk_coord: None,
             lod_level: 0,
@@ -495,9 +711,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         };
 
         let output = MeshOutput::from(&mesher_output);
@@ -540,9 +758,11 @@ mod tests {
             cutout_mesh: Mesh::new(),
             transparent_mesh: Mesh::new(),
             atlas: TextureAtlas::empty(),
+            texture_array: None,
             bounds: BoundingBox::new([0.0, 0.0, 0.0], [11.0, 0.0, h.max(1.0)]),
             greedy_materials: vec![greedy],
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
         }
     }
 
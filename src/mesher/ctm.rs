@@ -0,0 +1,394 @@
+//! Connected-texture tile selection.
+//!
+//! Given the [`CtmRule`]s loaded from a pack, picks the tile for one block face
+//! from its neighbours in the [`FaceCuller`] grid (which records block names
+//! when the pack has CTM rules). Neighbours are read in face-local
+//! coordinates: looking at the face from outside, `right` and `up` match the
+//! default UV orientation of that face.
+//!
+//! Connectivity needs the culler, so with `cull_hidden_faces` disabled every
+//! face is treated as isolated.
+
+use super::face_culler::FaceCuller;
+use crate::resource_pack::ctm::{CtmBlockMatch, CtmConnect, CtmMethod, CtmRule, CtmTile};
+use crate::types::{BlockPosition, Direction, InputBlock};
+use std::collections::HashMap;
+
+/// Full 47-tile CTM: tile index for each 8-neighbour mask. Bits, in order:
+/// left, down-left, down, down-right, right, up-right, up, up-left. Diagonals
+/// only matter when both adjacent edges connect.
+const CTM_TILE_INDEX: [u8; 256] = [
+    0, 3, 0, 3, 12, 5, 12, 15, 0, 3, 0, 3, 12, 5, 12, 15,
+    1, 2, 1, 2, 4, 7, 4, 29, 1, 2, 1, 2, 13, 31, 13, 14,
+    0, 3, 0, 3, 12, 5, 12, 15, 0, 3, 0, 3, 12, 5, 12, 15,
+    1, 2, 1, 2, 4, 7, 4, 29, 1, 2, 1, 2, 13, 31, 13, 14,
+    36, 17, 36, 17, 24, 19, 24, 43, 36, 17, 36, 17, 24, 19, 24, 43,
+    16, 18, 16, 18, 6, 46, 6, 21, 16, 18, 16, 18, 28, 9, 28, 22,
+    36, 17, 36, 17, 24, 19, 24, 43, 36, 17, 36, 17, 24, 19, 24, 43,
+    37, 40, 37, 40, 30, 8, 30, 10, 37, 40, 37, 40, 25, 23, 25, 45,
+    0, 3, 0, 3, 12, 5, 12, 15, 0, 3, 0, 3, 12, 5, 12, 15,
+    1, 2, 1, 2, 4, 7, 4, 29, 1, 2, 1, 2, 13, 31, 13, 14,
+    0, 3, 0, 3, 12, 5, 12, 15, 0, 3, 0, 3, 12, 5, 12, 15,
+    1, 2, 1, 2, 4, 7, 4, 29, 1, 2, 1, 2, 13, 31, 13, 14,
+    36, 39, 36, 39, 24, 41, 24, 27, 36, 39, 36, 39, 24, 41, 24, 27,
+    16, 42, 16, 42, 6, 20, 6, 34, 16, 42, 16, 42, 28, 35, 28, 44,
+    36, 39, 36, 39, 24, 41, 24, 27, 36, 39, 36, 39, 24, 41, 24, 27,
+    37, 38, 37, 38, 30, 11, 30, 32, 37, 38, 37, 38, 25, 33, 25, 26,
+];
+
+/// Neighbour offsets in [`CTM_TILE_INDEX`] bit order, as (right, up) steps.
+const NEIGHBOURS: [(i32, i32); 8] = [
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+];
+
+/// Tiles picked for one face.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct CtmSelection {
+    /// Replacement texture for the face, if a rule picked one.
+    pub texture: Option<String>,
+    /// Overlay textures to draw over the face, in order.
+    pub overlays: Vec<String>,
+}
+
+/// Rules indexed by the block ids and textures they match, in priority order.
+pub(crate) struct CtmIndex<'a> {
+    rules: Vec<&'a CtmRule>,
+    by_block: HashMap<&'a str, Vec<usize>>,
+    by_tile: HashMap<&'a str, Vec<usize>>,
+}
+
+impl<'a> CtmIndex<'a> {
+    /// Index `rules`; `None` when there are none.
+    pub fn new(rules: &'a [CtmRule]) -> Option<Self> {
+        if rules.is_empty() {
+            return None;
+        }
+        // Higher `weight` first, then file order.
+        let mut sorted: Vec<&CtmRule> = rules.iter().collect();
+        sorted.sort_by(|a, b| b.weight.cmp(&a.weight).then_with(|| a.source.cmp(&b.source)));
+
+        let mut by_block: HashMap<&str, Vec<usize>> = HashMap::new();
+        let mut by_tile: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, rule) in sorted.iter().enumerate() {
+            if rule.match_blocks.is_empty() {
+                for tile in &rule.match_tiles {
+                    by_tile.entry(tile.as_str()).or_default().push(i);
+                }
+            } else {
+                for m in &rule.match_blocks {
+                    by_block.entry(m.name.as_str()).or_default().push(i);
+                }
+            }
+        }
+        Some(Self { rules: sorted, by_block, by_tile })
+    }
+
+    /// Pick tiles for `texture` on the `face` of `block` at `pos`.
+    pub fn select(
+        &self,
+        culler: Option<&FaceCuller>,
+        pos: BlockPosition,
+        block: &InputBlock,
+        face: Direction,
+        texture: &str,
+    ) -> CtmSelection {
+        let full_name = full_block_name(&block.name);
+        let tile_key = texture.strip_prefix("minecraft:").unwrap_or(texture);
+        let mut candidates: Vec<usize> = self
+            .by_block
+            .get(full_name.as_str())
+            .into_iter()
+            .chain(self.by_tile.get(tile_key))
+            .flatten()
+            .copied()
+            .collect();
+        if candidates.is_empty() {
+            return CtmSelection::default();
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        let mut selection = CtmSelection::default();
+        for rule in candidates.into_iter().map(|i| self.rules[i]) {
+            if !rule.applies_to(block, texture, face) {
+                continue;
+            }
+            let ctx = FaceContext { culler, pos, face, block_name: &full_name, rule };
+            if rule.method == CtmMethod::Overlay {
+                if selection.overlays.is_empty() {
+                    selection.overlays = ctx
+                        .overlay_tiles()
+                        .into_iter()
+                        .filter_map(|i| match &rule.tiles[i] {
+                            CtmTile::Texture(t) => Some(t.clone()),
+                            CtmTile::Default | CtmTile::Skip => None,
+                        })
+                        .collect();
+                }
+            } else if selection.texture.is_none() {
+                let index = ctx.tile_index();
+                selection.texture = Some(match rule.tiles.get(index) {
+                    Some(CtmTile::Texture(t)) => t.clone(),
+                    _ => texture.to_string(),
+                });
+            }
+            if selection.texture.is_some() && !selection.overlays.is_empty() {
+                break;
+            }
+        }
+        selection
+    }
+}
+
+/// One face being textured by one rule.
+struct FaceContext<'r> {
+    culler: Option<&'r FaceCuller<'r>>,
+    pos: BlockPosition,
+    face: Direction,
+    block_name: &'r str,
+    rule: &'r CtmRule,
+}
+
+impl FaceContext<'_> {
+    /// World offsets of face-local `right` and `up`.
+    fn axes(&self) -> ([i32; 3], [i32; 3]) {
+        match self.face {
+            Direction::South => ([1, 0, 0], [0, 1, 0]),
+            Direction::North => ([-1, 0, 0], [0, 1, 0]),
+            Direction::East => ([0, 0, -1], [0, 1, 0]),
+            Direction::West => ([0, 0, 1], [0, 1, 0]),
+            Direction::Up => ([1, 0, 0], [0, 0, -1]),
+            Direction::Down => ([1, 0, 0], [0, 0, 1]),
+        }
+    }
+
+    fn offset(&self, right: i32, up: i32) -> BlockPosition {
+        let (r, u) = self.axes();
+        BlockPosition::new(
+            self.pos.x + r[0] * right + u[0] * up,
+            self.pos.y + r[1] * right + u[1] * up,
+            self.pos.z + r[2] * right + u[2] * up,
+        )
+    }
+
+    fn name_at(&self, pos: BlockPosition) -> Option<String> {
+        self.culler?.block_name_at(pos).map(full_block_name)
+    }
+
+    /// Whether the neighbour at face-local (`right`, `up`) continues the
+    /// texture: it matches, and its own face in this direction isn't covered
+    /// by another matching block.
+    fn connects(&self, right: i32, up: i32) -> bool {
+        let neighbour = self.offset(right, up);
+        if !self.same_texture(neighbour) {
+            return false;
+        }
+        !self.same_texture(neighbour.neighbor(self.face))
+    }
+
+    fn same_texture(&self, pos: BlockPosition) -> bool {
+        let Some(name) = self.name_at(pos) else {
+            return false;
+        };
+        match self.rule.connect {
+            CtmConnect::Block => name == self.block_name,
+            CtmConnect::Tile => {
+                name == self.block_name
+                    || self.rule.match_blocks.iter().any(|m| m.matches_name(&name))
+            }
+        }
+    }
+
+    fn tile_index(&self) -> usize {
+        match self.rule.method {
+            CtmMethod::Ctm => {
+                let mask = NEIGHBOURS
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (r, u))| self.connects(*r, *u))
+                    .fold(0usize, |mask, (bit, _)| mask | (1 << bit));
+                CTM_TILE_INDEX[mask] as usize
+            }
+            CtmMethod::Horizontal => {
+                match (self.connects(-1, 0), self.connects(1, 0)) {
+                    (false, true) => 0,
+                    (true, true) => 1,
+                    (true, false) => 2,
+                    (false, false) => 3,
+                }
+            }
+            CtmMethod::Vertical => match (self.connects(0, -1), self.connects(0, 1)) {
+                (false, true) => 0,
+                (true, true) => 1,
+                (true, false) => 2,
+                (false, false) => 3,
+            },
+            CtmMethod::Random => self.random_index(),
+            CtmMethod::Repeat { width, height } => {
+                let (r, u) = self.axes();
+                let p = [self.pos.x, self.pos.y, self.pos.z];
+                let dot = |a: [i32; 3]| a[0] * p[0] + a[1] * p[1] + a[2] * p[2];
+                let col = dot(r).rem_euclid(width as i32) as usize;
+                // Rows count downward from the top of the pattern.
+                let row = (-dot(u)).rem_euclid(height as i32) as usize;
+                row * width as usize + col
+            }
+            CtmMethod::Overlay => 0,
+        }
+    }
+
+    /// Deterministic per-position, per-face choice, honouring `weights`.
+    fn random_index(&self) -> usize {
+        let count = self.rule.tiles.len();
+        let face = crate::resource_pack::ctm::face_index(self.face) as u64;
+        let mut h = (self.pos.x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (self.pos.y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+            ^ (self.pos.z as u64).wrapping_mul(0x1656_67B1_9E37_79F9)
+            ^ face.wrapping_mul(0x27D4_EB2F_1656_67C5);
+        h ^= h >> 31;
+        h = h.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        h ^= h >> 29;
+
+        let weights = &self.rule.weights;
+        if weights.len() == count {
+            let total: u64 = weights.iter().map(|w| *w as u64).sum();
+            if total > 0 {
+                let mut pick = h % total;
+                for (i, w) in weights.iter().enumerate() {
+                    if pick < *w as u64 {
+                        return i;
+                    }
+                    pick -= *w as u64;
+                }
+            }
+        }
+        (h % count as u64) as usize
+    }
+
+    /// Overlay tiles for the sides/corners bordering `connect_blocks` /
+    /// `connect_tiles` neighbours. Tile layout (3×3 frame of a 7-wide sheet):
+    ///
+    /// ```text
+    ///  0  1  2 | 3  4      0-2, 7-9, 14-16: overlay entering from the
+    ///  7  8  9 |10 11      matching edges (8 = surrounded on all sides);
+    /// 14 15 16 |           3/4/10/11: up-left/up-right/down-left/down-right
+    ///                      corner only.
+    /// ```
+    ///
+    /// Opposite or three-sided edges produce several overlays.
+    fn overlay_tiles(&self) -> Vec<usize> {
+        let edge = |r, u| self.overlay_source(self.offset(r, u));
+        let (left, right, down, up) = (edge(-1, 0), edge(1, 0), edge(0, -1), edge(0, 1));
+        if left && right && down && up {
+            return vec![8];
+        }
+
+        let mut tiles = Vec::new();
+        let mut used = [false; 4]; // left, right, down, up
+        for (a, b, ia, ib, tile) in [
+            (up, left, 3, 0, 0),
+            (up, right, 3, 1, 2),
+            (down, left, 2, 0, 14),
+            (down, right, 2, 1, 16),
+        ] {
+            if a && b && !used[ia] && !used[ib] {
+                tiles.push(tile);
+                used[ia] = true;
+                used[ib] = true;
+            }
+        }
+        for (present, i, tile) in [(up, 3, 1), (left, 0, 7), (right, 1, 9), (down, 2, 15)] {
+            if present && !used[i] {
+                tiles.push(tile);
+                used[i] = true;
+            }
+        }
+        for (r, u, needs_a, needs_b, tile) in [
+            (-1, 1, 3, 0, 3),
+            (1, 1, 3, 1, 4),
+            (-1, -1, 2, 0, 10),
+            (1, -1, 2, 1, 11),
+        ] {
+            if !used[needs_a] && !used[needs_b] && edge(r, u) {
+                tiles.push(tile);
+            }
+        }
+        tiles
+    }
+
+    /// Whether the block at `pos` spreads an overlay onto this face. Only
+    /// exposed neighbours count (a block covered on this side can't bleed over).
+    fn overlay_source(&self, pos: BlockPosition) -> bool {
+        let Some(name) = self.name_at(pos) else {
+            return false;
+        };
+        if name == self.block_name {
+            return false;
+        }
+        let by_block = self.rule.connect_blocks.iter().any(|m: &CtmBlockMatch| m.matches_name(&name));
+        let by_tile = self.rule.connect_tiles.iter().any(|t| {
+            name.strip_prefix("minecraft:")
+                .is_some_and(|id| t.strip_prefix("block/") == Some(id))
+        });
+        (by_block || by_tile) && self.name_at(pos.neighbor(self.face)).is_none()
+    }
+}
+
+fn full_block_name(name: &str) -> String {
+    if name.contains(':') {
+        name.to_string()
+    } else {
+        format!("minecraft:{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ctm_table_matches_basic_tiles() {
+        let mask = |bits: &[usize]| bits.iter().fold(0usize, |m, b| m | (1 << b));
+        // (left, down, right, up) = bits 0, 2, 4, 6; corners 1, 3, 5, 7.
+        assert_eq!(CTM_TILE_INDEX[0], 0);
+        assert_eq!(CTM_TILE_INDEX[mask(&[4])], 1);
+        assert_eq!(CTM_TILE_INDEX[mask(&[0, 4])], 2);
+        assert_eq!(CTM_TILE_INDEX[mask(&[0])], 3);
+        assert_eq!(CTM_TILE_INDEX[mask(&[2])], 12);
+        assert_eq!(CTM_TILE_INDEX[mask(&[2, 6])], 24);
+        assert_eq!(CTM_TILE_INDEX[mask(&[6])], 36);
+        assert_eq!(CTM_TILE_INDEX[mask(&[2, 3, 4])], 13);
+        assert_eq!(CTM_TILE_INDEX[mask(&[0, 1, 2, 3, 4, 5, 6, 7])], 26);
+        // Corners without both adjacent edges are ignored.
+        assert_eq!(CTM_TILE_INDEX[mask(&[1, 3, 5, 7])], 0);
+    }
+
+    #[test]
+    fn isolated_face_without_culler() {
+        let rule = crate::resource_pack::ctm::parse_ctm_properties(
+            "minecraft",
+            "optifine/ctm/glass/glass.properties",
+            "matchBlocks=glass\nmethod=ctm\ntiles=0-46\n",
+        )
+        .unwrap()
+        .unwrap();
+        let rules = vec![rule];
+        let index = CtmIndex::new(&rules).unwrap();
+        let glass = InputBlock::new("minecraft:glass");
+        let selection = index.select(None, BlockPosition::new(0, 0, 0), &glass, Direction::North, "block/glass");
+        assert_eq!(selection.texture.as_deref(), Some("optifine/ctm/glass/0"));
+
+        let stone = InputBlock::new("minecraft:stone");
+        assert_eq!(
+            index.select(None, BlockPosition::new(0, 0, 0), &stone, Direction::North, "block/stone"),
+            CtmSelection::default()
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! Meshing diagnostics.
+//!
+//! The mesher never fails on unknown content — unresolvable blocks are skipped,
+//! missing textures get the magenta tile, odd property sets get the nearest
+//! variant. [`MeshDiagnostics`] records every one of those decisions so callers
+//! can warn users instead of discovering the gaps in a viewer.
+
+use crate::types::{BlockPosition, InputBlock};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Maximum number of example positions kept per diagnostic entry.
+pub const MAX_EXAMPLE_POSITIONS: usize = 8;
+
+/// Everything the mesher had to skip or guess while meshing.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MeshDiagnostics {
+    /// Block states that could not be resolved to any model (no geometry emitted).
+    pub unknown_blocks: Vec<BlockDiagnostic>,
+    /// `entity:` inputs with no built-in model (no geometry emitted).
+    pub unsupported_entities: Vec<BlockDiagnostic>,
+    /// Textures referenced by faces but absent from the pack; those faces use
+    /// the missing-texture tile.
+    pub missing_textures: Vec<MissingTextureDiagnostic>,
+    /// Non-fatal guesses made to produce geometry anyway.
+    pub fallbacks: Vec<FallbackDiagnostic>,
+}
+
+impl MeshDiagnostics {
+    /// `true` when meshing needed no skips or guesses.
+    pub fn is_empty(&self) -> bool {
+        self.unknown_blocks.is_empty()
+            && self.unsupported_entities.is_empty()
+            && self.missing_textures.is_empty()
+            && self.fallbacks.is_empty()
+    }
+
+    /// `true` when something was dropped or rendered with the missing-texture
+    /// tile (fallbacks alone don't count — they still render plausibly).
+    pub fn has_problems(&self) -> bool {
+        !self.unknown_blocks.is_empty()
+            || !self.unsupported_entities.is_empty()
+            || !self.missing_textures.is_empty()
+    }
+}
+
+/// A block state (or entity) that appeared in the input, grouped by full state.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BlockDiagnostic {
+    /// Full block state, e.g. `minecraft:oak_stairs[facing=north,half=top]`.
+    pub state: String,
+    /// Number of blocks with this state.
+    pub count: usize,
+    /// Up to [`MAX_EXAMPLE_POSITIONS`] positions, in meshing order.
+    pub example_positions: Vec<BlockPosition>,
+    /// Why it was skipped.
+    pub reason: String,
+}
+
+/// A texture path that faces referenced but the pack doesn't contain.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MissingTextureDiagnostic {
+    /// Texture path as referenced (e.g. `block/custom_ore`).
+    pub texture: String,
+    /// Number of emitted faces that fell back to the missing-texture tile.
+    pub face_count: usize,
+}
+
+/// The kind of guess the mesher made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FallbackKind {
+    /// Properties didn't match a variant key exactly; the most default-like
+    /// variant consistent with the given properties was used.
+    PartialVariant,
+    /// No variant was consistent with the given properties; the most
+    /// default-like variant overall was used.
+    DefaultVariant,
+    /// A face's `#texture` variable was never bound; the face uses the
+    /// missing-texture tile.
+    UnboundTextureVariable,
+    /// A face's texture isn't declared by the pack's `atlases/blocks.json`;
+    /// the face uses the missing-texture tile.
+    UndeclaredSprite,
+    /// A property value is outside the block's domain in the block registry;
+    /// the registry default was used instead.
+    InvalidPropertyValue,
+}
+
+/// One kind of guess, grouped by subject (block state, `state #variable`,
+/// `state texture` for undeclared sprites, or `block property=value` for
+/// invalid values).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FallbackDiagnostic {
+    /// What was guessed.
+    pub kind: FallbackKind,
+    /// The block state (and texture variable or path, for texture fallbacks).
+    pub subject: String,
+    /// Number of blocks (or faces, for texture fallbacks) affected.
+    pub count: usize,
+    /// Up to [`MAX_EXAMPLE_POSITIONS`] positions, in meshing order.
+    pub example_positions: Vec<BlockPosition>,
+}
+
+/// Accumulates diagnostics during meshing. One per `MeshBuilder`; per-chunk
+/// collectors are merged in chunk order so the result is deterministic.
+#[derive(Debug, Default)]
+pub(crate) struct DiagnosticsCollector {
+    unknown_blocks: BTreeMap<String, Entry>,
+    unsupported_entities: BTreeMap<String, Entry>,
+    missing_textures: BTreeMap<String, usize>,
+    fallbacks: BTreeMap<(FallbackKind, String), Entry>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Entry {
+    count: usize,
+    examples: Vec<BlockPosition>,
+    reason: String,
+}
+
+impl Entry {
+    fn record(&mut self, pos: BlockPosition) {
+        self.count += 1;
+        if self.examples.len() < MAX_EXAMPLE_POSITIONS {
+            self.examples.push(pos);
+        }
+    }
+
+    fn merge(&mut self, other: &Entry) {
+        self.count += other.count;
+        let room = MAX_EXAMPLE_POSITIONS.saturating_sub(self.examples.len());
+        self.examples.extend(other.examples.iter().take(room).copied());
+        if self.reason.is_empty() {
+            self.reason = other.reason.clone();
+        }
+    }
+}
+
+impl DiagnosticsCollector {
+    /// Record a block that resolved to nothing. `entity:` inputs are filed as
+    /// unsupported entities rather than unknown blocks.
+    pub(crate) fn unresolved_block(&mut self, pos: BlockPosition, block: &InputBlock, reason: &str) {
+        let map = if block.namespace() == "entity" {
+            &mut self.unsupported_entities
+        } else {
+            &mut self.unknown_blocks
+        };
+        let entry = map.entry(state_string(block)).or_default();
+        if entry.reason.is_empty() {
+            entry.reason = reason.to_string();
+        }
+        entry.record(pos);
+    }
+
+    pub(crate) fn fallback(&mut self, kind: FallbackKind, subject: String, pos: BlockPosition) {
+        self.fallbacks.entry((kind, subject)).or_default().record(pos);
+    }
+
+    pub(crate) fn missing_texture(&mut self, texture: &str, faces: usize) {
+        *self.missing_textures.entry(texture.to_string()).or_default() += faces;
+    }
+
+    pub(crate) fn merge(&mut self, other: &DiagnosticsCollector) {
+        for (k, v) in &other.unknown_blocks {
+            self.unknown_blocks.entry(k.clone()).or_default().merge(v);
+        }
+        for (k, v) in &other.unsupported_entities {
+            self.unsupported_entities.entry(k.clone()).or_default().merge(v);
+        }
+        for (k, v) in &other.missing_textures {
+            *self.missing_textures.entry(k.clone()).or_default() += v;
+        }
+        for (k, v) in &other.fallbacks {
+            self.fallbacks.entry(k.clone()).or_default().merge(v);
+        }
+    }
+
+    pub(crate) fn finish(self) -> MeshDiagnostics {
+        let blocks = |map: BTreeMap<String, Entry>| {
+            map.into_iter()
+                .map(|(state, e)| BlockDiagnostic {
+                    state,
+                    count: e.count,
+                    example_positions: e.examples,
+                    reason: e.reason,
+                })
+                .collect()
+        };
+        MeshDiagnostics {
+            unknown_blocks: blocks(self.unknown_blocks),
+            unsupported_entities: blocks(self.unsupported_entities),
+            missing_textures: self
+                .missing_textures
+                .into_iter()
+                .map(|(texture, face_count)| MissingTextureDiagnostic { texture, face_count })
+                .collect(),
+            fallbacks: self
+                .fallbacks
+                .into_iter()
+                .map(|((kind, subject), e)| FallbackDiagnostic {
+                    kind,
+                    subject,
+                    count: e.count,
+                    example_positions: e.examples,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Format a block as `name[k=v,...]` with sorted properties (just `name` when
+/// it has none).
+pub(crate) fn state_string(block: &InputBlock) -> String {
+    if block.properties.is_empty() {
+        return block.name.clone();
+    }
+    let mut props: Vec<_> = block.properties.iter().collect();
+    props.sort();
+    let props: Vec<String> = props.into_iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+    format!("{}[{}]", block.name, props.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_by_state_and_caps_examples() {
+        let mut c = DiagnosticsCollector::default();
+        let block = InputBlock::new("mymod:widget").with_property("powered", "true");
+        for i in 0..20 {
+            c.unresolved_block(BlockPosition::new(i, 0, 0), &block, "no blockstate");
+        }
+        c.unresolved_block(BlockPosition::new(0, 5, 0), &InputBlock::new("entity:warden"), "no model");
+
+        let d = c.finish();
+        assert_eq!(d.unknown_blocks.len(), 1);
+        assert_eq!(d.unknown_blocks[0].state, "mymod:widget[powered=true]");
+        assert_eq!(d.unknown_blocks[0].count, 20);
+        assert_eq!(d.unknown_blocks[0].example_positions.len(), MAX_EXAMPLE_POSITIONS);
+        assert_eq!(d.unsupported_entities.len(), 1);
+        assert_eq!(d.unsupported_entities[0].state, "entity:warden");
+        assert!(d.has_problems());
+    }
+
+    #[test]
+    fn merge_sums_counts() {
+        let mut a = DiagnosticsCollector::default();
+        a.missing_texture("block/foo", 3);
+        a.fallback(FallbackKind::PartialVariant, "minecraft:oak_stairs".into(), BlockPosition::new(0, 0, 0));
+        let mut b = DiagnosticsCollector::default();
+        b.missing_texture("block/foo", 2);
+        b.fallback(FallbackKind::PartialVariant, "minecraft:oak_stairs".into(), BlockPosition::new(1, 0, 0));
+
+        a.merge(&b);
+        let d = a.finish();
+        assert_eq!(d.missing_textures[0].face_count, 5);
+        assert_eq!(d.fallbacks[0].count, 2);
+        assert_eq!(
+            d.fallbacks[0].example_positions,
+            vec![BlockPosition::new(0, 0, 0), BlockPosition::new(1, 0, 0)]
+        );
+        assert!(d.has_problems());
+    }
+
+    #[test]
+    fn serializes_to_json() {
+        let mut c = DiagnosticsCollector::default();
+        c.fallback(FallbackKind::DefaultVariant, "minecraft:oak_stairs".into(), BlockPosition::new(1, 2, 3));
+
+        let json = serde_json::to_value(c.finish()).unwrap();
+        assert_eq!(json["fallbacks"][0]["kind"], "default_variant");
+        assert_eq!(json["fallbacks"][0]["example_positions"][0], serde_json::json!({"x": 1, "y": 2, "z": 3}));
+        assert_eq!(json["unknown_blocks"], serde_json::json!([]));
+    }
+}
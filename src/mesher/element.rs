@@ -1,6 +1,6 @@
 //! Convert model elements to mesh geometry.
 
-use crate::atlas::{AtlasBuilder, TextureAtlas};
+use crate::atlas::{AtlasBuilder, AtlasRegion, TextureArray, TextureArrayBuilder, TextureAtlas};
 use crate::error::{MesherError, Result};
 use crate::mesher::face_culler::FaceCuller;
 use crate::mesher::geometry::{Mesh, Vertex};
@@ -8,7 +8,8 @@ use crate::mesher::greedy::{FaceMergeKey, GreedyMesher, quantize_color};
 use crate::mesher::entity;
 use crate::mesher::liquid::{self, FluidState};
 use crate::mesher::MesherConfig;
-use crate::resolver::{resolve_block, ModelResolver, ResolvedModel};
+use crate::mesher::diagnostics::{DiagnosticsCollector, FallbackKind, MeshDiagnostics};
+use crate::resolver::{resolve_block_with_match, ModelResolver, ResolvedModel, VariantMatch};
 use crate::resource_pack::{ModelElement, ModelFace, ResourcePack, TextureData};
 
 // --- Fast-path coverage instrumentation (env `MESHER_STATS`) -----------------
@@ -48,6 +49,8 @@ struct FaceTextureMapping {
     texture_path: String,
     /// Whether this face uses a transparent texture.
     is_transparent: bool,
+    /// Whether this face glows (fullbright, flagged in [`crate::MeshLayer::emissive`]).
+    emissive: bool,
 }
 
 /// Tracks texture mapping for a greedy-merged face (bypasses atlas).
@@ -100,6 +103,17 @@ fn block_cache_key(block: &InputBlock) -> String {
     }
 }
 
+/// Per-block-state resolution result, cached alongside the models so the
+/// diagnostics can be recorded for every block, not just the first of a state.
+struct CachedResolution {
+    models: Vec<ResolvedModel>,
+    /// Resolution error, or `None` if the block resolved.
+    error: Option<String>,
+    variant_match: VariantMatch,
+    /// Property values the block registry rejected (empty without a registry).
+    invalid_properties: Vec<crate::resource_pack::InvalidProperty>,
+}
+
 /// Send-able accumulated geometry extracted from a per-chunk [`MeshBuilder`] so
 /// it can be merged on the main thread after parallel meshing. Deliberately
 /// excludes the thread-local resolve/model caches (which hold `Rc`/`RefCell`).
@@ -109,6 +123,7 @@ pub(crate) struct PartialMesh {
     face_textures: Vec<FaceTextureMapping>,
     greedy_face_textures: Vec<GreedyFaceMapping>,
     dynamic_textures: HashMap<String, TextureData>,
+    diagnostics: DiagnosticsCollector,
 }
 
 /// Builds a mesh from multiple blocks.
@@ -129,13 +144,13 @@ pub struct MeshBuilder<'a> {
     /// Cache of resolved models keyed by block identity (name + properties).
     /// Wrapped in `Rc` so per-block cache hits only bump a refcount instead of
     /// deep-cloning the resolved `BlockModel`s (hot path: millions of blocks).
-    resolve_cache: rustc_hash::FxHashMap<String, std::rc::Rc<Vec<ResolvedModel>>>,
+    resolve_cache: rustc_hash::FxHashMap<String, std::rc::Rc<CachedResolution>>,
     /// 1-entry memo of the last resolved block, keyed by its `InputBlock` pointer.
     /// Blocks are processed in spatial scan order, so terrain has long runs of the
     /// same palette entry (same pointer) — these skip the per-block string cache
     /// key entirely. Non-palette sources (distinct pointers) simply never hit it.
     last_block_ptr: usize,
-    last_resolved: std::rc::Rc<Vec<ResolvedModel>>,
+    last_resolved: std::rc::Rc<CachedResolution>,
     /// Block map for neighbor lookups (used by liquid geometry).
     block_map: Option<&'a rustc_hash::FxHashMap<BlockPosition, &'a InputBlock>>,
     /// Light map for brightness calculations.
@@ -143,12 +158,23 @@ pub struct MeshBuilder<'a> {
     /// Dynamic textures generated at build time (banners, inventories).
     /// Keys starting with `_` are synthetic texture paths.
     dynamic_textures: HashMap<String, TextureData>,
+    /// Unknown blocks, missing textures and fallback decisions seen so far.
+    diagnostics: DiagnosticsCollector,
+    /// Connected-texture rules from the pack, `None` if it has none.
+    ctm: Option<super::ctm::CtmIndex<'a>>,
+}
+
+/// A face's texture after `#variable` binding and connected-texture selection.
+struct FaceTexture {
+    path: String,
+    /// CTM overlay tiles drawn over the face.
+    overlays: Vec<String>,
 }
 
 /// Synthetic atlas key for the fallback "unknown texture" tile. Added to every
 /// atlas so faces whose declared texture couldn't be resolved have a valid
 /// region to sample, instead of leaking into full-atlas [0,1] UVs.
-const MISSING_TEXTURE_KEY: &str = "__missing__";
+pub(crate) const MISSING_TEXTURE_KEY: &str = "__missing__";
 
 /// Build a 16x16 magenta/black checkerboard texture, MC-style, to mark faces
 /// whose texture couldn't be found in the resource pack.
@@ -200,10 +226,17 @@ impl<'a> MeshBuilder<'a> {
             greedy,
             resolve_cache: rustc_hash::FxHashMap::default(),
             last_block_ptr: 0,
-            last_resolved: std::rc::Rc::new(Vec::new()),
+            last_resolved: std::rc::Rc::new(CachedResolution {
+                models: Vec::new(),
+                error: None,
+                variant_match: VariantMatch::Exact,
+                invalid_properties: Vec::new(),
+            }),
             block_map,
             light_map,
             dynamic_textures: HashMap::new(),
+            diagnostics: DiagnosticsCollector::default(),
+            ctm: super::ctm::CtmIndex::new(&resource_pack.ctm_rules),
         }
     }
 
@@ -220,7 +253,10 @@ impl<'a> MeshBuilder<'a> {
         block: &InputBlock,
     ) -> Result<()> {
         // Check if this is a mob entity — generate custom geometry, bypass model resolution
-        if let Some(mob_type) = entity::detect_mob(block) {
+        if let Some(mob_type) = entity::detect_mob_with_pack(block, self.resource_pack) {
+            if self.config.skip_rigged_entities && entity::rig::is_rigged_mob(mob_type) {
+                return Ok(());
+            }
             return self.add_mob(pos, block, mob_type);
         }
 
@@ -245,15 +281,31 @@ impl<'a> MeshBuilder<'a> {
                 std::rc::Rc::clone(cached)
             } else {
                 // Resolve the block to models
-                let resolved_models = match resolve_block(self.resource_pack, block) {
-                    Ok(models) => models,
+                let invalid_properties = self
+                    .resource_pack
+                    .block_registry
+                    .as_ref()
+                    .map(|registry| registry.invalid_properties(block))
+                    .unwrap_or_default();
+                let resolution = match resolve_block_with_match(self.resource_pack, block) {
+                    Ok((models, variant_match)) => CachedResolution {
+                        models,
+                        error: None,
+                        variant_match,
+                        invalid_properties,
+                    },
                     Err(e) => {
                         // Log warning but continue (don't return — entity check below)
                         eprintln!("Warning: Failed to resolve block {}: {}", block.name, e);
-                        Vec::new()
+                        CachedResolution {
+                            models: Vec::new(),
+                            error: Some(e.to_string()),
+                            variant_match: VariantMatch::Exact,
+                            invalid_properties,
+                        }
                     }
                 };
-                let rc = std::rc::Rc::new(resolved_models);
+                let rc = std::rc::Rc::new(resolution);
                 // Store in cache for future blocks with same identity
                 self.resolve_cache.insert(cache_key, std::rc::Rc::clone(&rc));
                 rc
@@ -263,14 +315,40 @@ impl<'a> MeshBuilder<'a> {
             rc
         };
 
+        if resolved.error.is_none() {
+            let fallback = match resolved.variant_match {
+                VariantMatch::Partial => Some(FallbackKind::PartialVariant),
+                VariantMatch::Fallback => Some(FallbackKind::DefaultVariant),
+                VariantMatch::Exact | VariantMatch::Default => None,
+            };
+            if let Some(kind) = fallback {
+                self.diagnostics.fallback(kind, super::diagnostics::state_string(block), pos);
+            }
+        }
+        for invalid in &resolved.invalid_properties {
+            let subject = format!("{} {}={}", block.name, invalid.property, invalid.value);
+            self.diagnostics.fallback(FallbackKind::InvalidPropertyValue, subject, pos);
+        }
+
         // Generate geometry for each model
-        for resolved in resolved.iter() {
+        for resolved in resolved.models.iter() {
             self.add_model(pos, block, resolved)?;
         }
 
         // Check for block entity — generates additive geometry
-        if let Some(entity_type) = entity::detect_block_entity(block) {
-            self.add_entity(pos, block, &entity_type)?;
+        let block_entity = entity::detect_block_entity(block);
+        if let Some(entity_type) = &block_entity {
+            if !(self.config.skip_rigged_entities
+                && entity::rig::is_rigged_block_entity(block, entity_type))
+            {
+                self.add_entity(pos, block, entity_type)?;
+            }
+        }
+
+        // Unresolvable states only count as unknown if no built-in geometry
+        // (block entity) stood in for them.
+        if let (Some(error), None) = (&resolved.error, &block_entity) {
+            self.diagnostics.unresolved_block(pos, block, error);
         }
 
         // Check for inventory property — render hologram above container
@@ -308,6 +386,7 @@ impl<'a> MeshBuilder<'a> {
             face_textures: self.face_textures,
             greedy_face_textures: self.greedy_face_textures,
             dynamic_textures: self.dynamic_textures,
+            diagnostics: self.diagnostics,
         }
     }
 
@@ -397,6 +476,7 @@ impl<'a> MeshBuilder<'a> {
                 index_start: idx_offset,
                 texture_path: ft.texture.to_string(),
                 is_transparent: ft.is_transparent,
+                emissive: false,
             });
             idx_offset += 6;
         }
@@ -449,7 +529,7 @@ impl<'a> MeshBuilder<'a> {
         }
 
         let (vertices, indices, face_textures) =
-            entity::generate_entity_geometry(block, entity_type);
+            entity::generate_entity_geometry_with_pack(self.resource_pack, block, entity_type);
 
         if vertices.is_empty() {
             return Ok(());
@@ -501,6 +581,7 @@ impl<'a> MeshBuilder<'a> {
                 index_start: idx_offset,
                 texture_path: ft.texture.clone(),
                 is_transparent: ft.is_transparent,
+                emissive: false,
             });
             idx_offset += 6;
         }
@@ -515,8 +596,15 @@ impl<'a> MeshBuilder<'a> {
         block: &InputBlock,
         mob_type: entity::MobType,
     ) -> Result<()> {
+        if matches!(
+            mob_type,
+            entity::MobType::BlockDisplay | entity::MobType::ItemDisplay | entity::MobType::TextDisplay
+        ) {
+            return self.add_display(pos, block, mob_type);
+        }
+
         let (vertices, indices, mut face_textures) =
-            entity::generate_mob_geometry(block, mob_type);
+            entity::generate_mob_geometry_with_pack(self.resource_pack, block, mob_type);
 
         // Villagers render as three stacked draw passes in MC: base skin +
         // biome overlay + profession overlay. Composite them into one texture.
@@ -573,6 +661,7 @@ impl<'a> MeshBuilder<'a> {
                     index_start: idx_offset,
                     texture_path: ft.texture.clone(),
                     is_transparent: ft.is_transparent,
+                    emissive: false,
                 });
                 idx_offset += 6;
             }
@@ -619,20 +708,7 @@ impl<'a> MeshBuilder<'a> {
 
         // Sheep: render wool overlay
         if matches!(mob_type, entity::MobType::Sheep) {
-            let mut wool_model = crate::mesher::entity::sheep::sheep_wool_model();
-            // Apply baby scaling to wool overlay too (head gets same extra 2× so
-            // it tracks the base sheep's big-head proportions).
-            if block.properties.get("is_baby").map(|v| v == "true").unwrap_or(false) {
-                if let Some(root) = wool_model.parts.first_mut() {
-                    root.pose.scale = [0.5, 0.5, 0.5];
-                    root.pose.position[1] = 12.0;
-                    if let Some(head) = root.children.first_mut() {
-                        head.pose.scale[0] *= 2.0;
-                        head.pose.scale[1] *= 2.0;
-                        head.pose.scale[2] *= 2.0;
-                    }
-                }
-            }
+            let wool_model = crate::mesher::entity::sheep::sheep_wool_for(block);
             let facing = block.properties.get("facing")
                 .map(|s| s.as_str())
                 .unwrap_or("south");
@@ -673,29 +749,7 @@ impl<'a> MeshBuilder<'a> {
         // Players: dynamic skin texture + direct model generation
         if matches!(mob_type, entity::MobType::Player) {
             let tex_key = format!("_player/{}_{}_{}", pos.x, pos.y, pos.z);
-            if !self.dynamic_textures.contains_key(&tex_key) {
-                // Try base64 skin property first (primary path for WASM/JS callers).
-                if let Some(skin_b64) = block.properties.get("skin_base64") {
-                    if let Some(skin_tex) = entity::skull::decode_base64_skin(skin_b64) {
-                        self.dynamic_textures.insert(tex_key.clone(), skin_tex);
-                    }
-                }
-                // Then try hex skin property (legacy/inline-in-Rust path).
-                if !self.dynamic_textures.contains_key(&tex_key) {
-                    if let Some(skin_hex) = block.properties.get("skin") {
-                        if let Some(skin_tex) = entity::skull::decode_hex_skin(skin_hex) {
-                            self.dynamic_textures.insert(tex_key.clone(), skin_tex);
-                        }
-                    }
-                }
-                // Finally fall back to Steve/Alex from the resource pack.
-                if !self.dynamic_textures.contains_key(&tex_key) {
-                    let fallback = entity::skull::player_skin_fallback_path(block);
-                    if let Some(tex) = self.resource_pack.get_texture(fallback) {
-                        self.dynamic_textures.insert(tex_key.clone(), tex.clone());
-                    }
-                }
-            }
+            self.load_player_skin(&tex_key, block);
 
             // Build player model with dynamic texture key
             let model = entity::player::player_model(block, &tex_key);
@@ -725,17 +779,40 @@ impl<'a> MeshBuilder<'a> {
             if !player_verts.is_empty() {
                 self.add_item_geometry(pos, &player_verts, &player_indices, &player_faces);
             }
-        }
 
-        // Armor stands and players: render armor overlay if armor properties are set
-        if matches!(mob_type, entity::MobType::ArmorStand | entity::MobType::Player) {
-            let facing = block.properties.get("facing")
-                .map(|s| s.as_str())
-                .unwrap_or("south");
-            let (armor_verts, armor_indices, armor_faces) =
-                entity::armor_stand::generate_armor_geometry(block, facing);
-            if !armor_verts.is_empty() {
-                self.add_item_geometry(pos, &armor_verts, &armor_indices, &armor_faces);
+            // The cape hangs from the body unless elytra wings take its
+            // texture; worn armor follows the player's pose.
+            let cape_key = format!("_player_cape/{}_{}_{}", pos.x, pos.y, pos.z);
+            if !self.dynamic_textures.contains_key(&cape_key) {
+                if let Some(cape) = entity::skin::player_cape(self.resource_pack, block) {
+                    self.dynamic_textures.insert(cape_key.clone(), cape);
+                }
+            }
+            let cape = self.dynamic_textures.contains_key(&cape_key).then_some(cape_key.as_str());
+            let mut overlays = Vec::new();
+            if let (Some(cape), Some(over_armor)) = (cape, entity::armor::cape_over_armor(self.resource_pack, block)) {
+                overlays.push(entity::equipment::EquipmentOverlay::new(
+                    entity::player::cape_model(&model, cape, over_armor),
+                ));
+            }
+            overlays.extend(entity::armor::armor_overlays(self.resource_pack, block, &model, cape));
+            self.add_equipment_overlays(pos, &facing_mat, overlays);
+        }
+
+        // Armor stands: render held items from "mainhand"/"offhand" properties
+        if matches!(mob_type, entity::MobType::ArmorStand) {
+            for (slot, left_hand) in [("mainhand", false), ("offhand", true)] {
+                if let Some(item) = block.properties.get(slot) {
+                    let hand = entity::armor_stand::hand_transform(block, left_hand);
+                    if let Some((item_verts, item_indices, item_faces)) =
+                        entity::item_render::render_held_item(
+                            self.resource_pack, &self.model_resolver,
+                            item, hand, left_hand,
+                        )
+                    {
+                        self.add_item_geometry(pos, &item_verts, &item_indices, &item_faces);
+                    }
+                }
             }
         }
 
@@ -745,11 +822,12 @@ impl<'a> MeshBuilder<'a> {
             | entity::MobType::DroppedItem
             | entity::MobType::ItemFrame
             | entity::MobType::GlowItemFrame
+            | entity::MobType::Painting
             | entity::MobType::Boat
             | entity::MobType::ChestBoat)
         {
-            let base_model = entity::mob::build_mob_model(mob_type, block);
-            let overlays = entity::equipment::overlays_for(mob_type, block, &base_model);
+            let base_model = entity::mob::build_mob_model(mob_type, block, Some(self.resource_pack));
+            let overlays = entity::equipment::overlays_for(self.resource_pack, mob_type, block, &base_model);
             if !overlays.is_empty() {
                 let facing = block.properties.get("facing")
                     .map(|s| s.as_str()).unwrap_or("south");
@@ -757,18 +835,7 @@ impl<'a> MeshBuilder<'a> {
                 let facing_mat = glam::Mat4::from_translation(glam::Vec3::new(0.5, 0.0, 0.5))
                     * glam::Mat4::from_rotation_y(facing_angle)
                     * glam::Mat4::from_translation(glam::Vec3::new(-0.5, 0.0, -0.5));
-                for overlay in overlays {
-                    let mut verts = Vec::new();
-                    let mut indices = Vec::new();
-                    let mut faces = Vec::new();
-                    entity::traverse_parts(
-                        &overlay.model.parts, glam::Mat4::IDENTITY, &facing_mat,
-                        &overlay.model, &mut verts, &mut indices, &mut faces,
-                    );
-                    if !verts.is_empty() {
-                        self.add_offset_geometry(pos, [0.0, 0.0, 0.0], &verts, &indices, &faces);
-                    }
-                }
+                self.add_equipment_overlays(pos, &facing_mat, overlays);
             }
         }
 
@@ -783,6 +850,180 @@ impl<'a> MeshBuilder<'a> {
         Ok(())
     }
 
+    /// Add equipment overlays (saddles, horse and worn armor) over a mob
+    /// placed by `facing_mat`: dyed layers are tinted, trim sprites the pack
+    /// lacks are recolored on the fly, and glint layers are translucent and
+    /// fullbright.
+    fn add_equipment_overlays(
+        &mut self,
+        pos: BlockPosition,
+        facing_mat: &glam::Mat4,
+        overlays: Vec<entity::equipment::EquipmentOverlay>,
+    ) {
+        for overlay in overlays {
+            if let Some(paletted) = &overlay.paletted {
+                if self.resource_pack.get_texture(&paletted.sprite).is_none()
+                    && !self.dynamic_textures.contains_key(&paletted.sprite)
+                {
+                    match self.resource_pack.paletted_texture(
+                        &paletted.texture, &paletted.palette_key, &paletted.palette,
+                    ) {
+                        Some(tex) => {
+                            self.dynamic_textures.insert(paletted.sprite.clone(), tex);
+                        }
+                        None => continue,
+                    }
+                }
+            }
+
+            let mut verts = Vec::new();
+            let mut indices = Vec::new();
+            let mut faces = Vec::new();
+            entity::traverse_parts(
+                &overlay.model.parts, glam::Mat4::IDENTITY, facing_mat,
+                &overlay.model, &mut verts, &mut indices, &mut faces,
+            );
+            if verts.is_empty() {
+                continue;
+            }
+            for v in &mut verts {
+                if let Some(tint) = overlay.tint {
+                    v.color[0] *= tint[0];
+                    v.color[1] *= tint[1];
+                    v.color[2] *= tint[2];
+                }
+                if overlay.glint {
+                    v.color[3] = entity::armor::GLINT_ALPHA;
+                }
+            }
+
+            let first_face = self.face_textures.len();
+            self.add_offset_geometry(pos, [0.0, 0.0, 0.0], &verts, &indices, &faces);
+            if overlay.glint {
+                for face in &mut self.face_textures[first_face..] {
+                    face.is_transparent = true;
+                    face.emissive = true;
+                }
+            }
+        }
+    }
+
+    /// Add a display entity: a block model, item or text panel placed by the
+    /// entity's orientation and `transformation` (see [`entity::display`]).
+    fn add_display(
+        &mut self,
+        pos: BlockPosition,
+        block: &InputBlock,
+        mob_type: entity::MobType,
+    ) -> Result<()> {
+        let placement = entity::display::placement(block, self.config.billboard_camera);
+        // Displays are lit at their position unless they override it.
+        let light_factor = match (self.light_map, entity::display::brightness_override(block)) {
+            (Some(lm), Some(level)) => lm.level_brightness(level),
+            (Some(lm), None) => lm.face_brightness(pos, Direction::Up),
+            (None, _) => 1.0,
+        };
+
+        if matches!(mob_type, entity::MobType::BlockDisplay) {
+            return self.add_block_display(pos, block, &placement, light_factor);
+        }
+
+        let geometry = if matches!(mob_type, entity::MobType::ItemDisplay) {
+            let Some(item) = block.properties.get("item") else { return Ok(()) };
+            entity::item_render::render_display_item(
+                self.resource_pack, &self.model_resolver,
+                item, entity::display::item_context(block),
+            )
+        } else {
+            let Some(text) = block.properties.get("text") else { return Ok(()) };
+            let (line_width, background, alignment) = entity::display::text_options(block);
+            entity::sign_text::render_text_panel(self.resource_pack, text, line_width, background, alignment)
+                .map(|(tex, size)| {
+                    let tex_key = format!("_text_display/{}_{}_{}", pos.x, pos.y, pos.z);
+                    self.dynamic_textures.insert(tex_key.clone(), tex);
+                    entity::display::text_panel(&tex_key, size)
+                })
+        };
+
+        if let Some((mut vertices, mut indices, face_textures)) = geometry {
+            entity::display::transform_geometry(&mut vertices, &mut indices, &placement);
+            for v in &mut vertices {
+                v.color[0] *= light_factor;
+                v.color[1] *= light_factor;
+                v.color[2] *= light_factor;
+            }
+            self.add_item_geometry(pos, &vertices, &indices, &face_textures);
+        }
+
+        Ok(())
+    }
+
+    /// Add a `block_display`'s block: its models (and block entity) meshed
+    /// at `pos` as a free-standing block, then moved by `placement`.
+    fn add_block_display(
+        &mut self,
+        pos: BlockPosition,
+        block: &InputBlock,
+        placement: &glam::Mat4,
+        light_factor: f32,
+    ) -> Result<()> {
+        let Some(mut state) = entity::display::block_state(block) else { return Ok(()) };
+        // The display's NBT was saved alongside the world's blocks.
+        if let Some(data_version) = self.config.data_version {
+            crate::resolver::upgrade::upgrade_block_in_place(&mut state, data_version);
+        }
+        let models = match resolve_block_with_match(self.resource_pack, &state) {
+            Ok((models, _)) => models,
+            Err(e) => {
+                self.diagnostics.unresolved_block(pos, &state, &e.to_string());
+                Vec::new()
+            }
+        };
+
+        // The block has no neighbours: no culling, AO, greedy merging or
+        // sampled light while its faces are generated.
+        let culler = self.culler.take();
+        let greedy = self.greedy.take();
+        let light_map = self.light_map.take();
+        let vertex_start = self.mesh.vertices.len();
+        let index_start = self.mesh.indices.len();
+
+        let mut result = Ok(());
+        for resolved in &models {
+            result = self.add_model(pos, &state, resolved);
+            if result.is_err() {
+                break;
+            }
+        }
+        if result.is_ok() {
+            if let Some(entity_type) = entity::detect_block_entity(&state) {
+                result = self.add_entity(pos, &state, &entity_type);
+            }
+        }
+
+        self.culler = culler;
+        self.greedy = greedy;
+        self.light_map = light_map;
+        result?;
+
+        // Block geometry spans pos ± 0.5; the display draws the block from its
+        // minimum corner at the entity origin.
+        let origin = Vec3::new(pos.x as f32 - 0.5, pos.y as f32 - 0.5, pos.z as f32 - 0.5);
+        let to_world = glam::Mat4::from_translation(origin) * *placement * glam::Mat4::from_translation(-origin);
+        entity::display::transform_geometry(
+            &mut self.mesh.vertices[vertex_start..],
+            &mut self.mesh.indices[index_start..],
+            &to_world,
+        );
+        for v in &mut self.mesh.vertices[vertex_start..] {
+            v.color[0] *= light_factor;
+            v.color[1] *= light_factor;
+            v.color[2] *= light_factor;
+        }
+
+        Ok(())
+    }
+
     /// Generate geometry for a rider sitting on `host_mob` and add it to the mesh.
     fn add_rider_on(
         &mut self,
@@ -801,7 +1042,7 @@ impl<'a> MeshBuilder<'a> {
         let host_facing = host_block.properties.get("facing")
             .map(|s| s.as_str()).unwrap_or("south");
         rider_block.properties.insert("facing".to_string(), host_facing.to_string());
-        for key in ["skin", "skin_base64", "uuid", "slim", "helmet", "chestplate",
+        for key in ["skin", "skin_base64", "textures", "uuid", "slim", "helmet", "chestplate",
                     "leggings", "boots", "color", "variant", "is_baby"] {
             if let Some(v) = host_block.properties.get(key) {
                 rider_block.properties.insert(key.to_string(), v.clone());
@@ -815,7 +1056,7 @@ impl<'a> MeshBuilder<'a> {
             }
         }
 
-        let rider_type = match entity::detect_mob(&rider_block) {
+        let rider_type = match entity::detect_mob_with_pack(&rider_block, self.resource_pack) {
             Some(t) => t,
             None => return Ok(()),
         };
@@ -838,26 +1079,7 @@ impl<'a> MeshBuilder<'a> {
         // Player riders use the dynamic-skin path; other mobs use the standard one.
         if matches!(rider_type, entity::MobType::Player) {
             let tex_key = format!("_player/{}_{}_{}_rider", host_pos.x, host_pos.y, host_pos.z);
-            if !self.dynamic_textures.contains_key(&tex_key) {
-                if let Some(skin_b64) = rider_block.properties.get("skin_base64") {
-                    if let Some(skin_tex) = entity::skull::decode_base64_skin(skin_b64) {
-                        self.dynamic_textures.insert(tex_key.clone(), skin_tex);
-                    }
-                }
-                if !self.dynamic_textures.contains_key(&tex_key) {
-                    if let Some(skin_hex) = rider_block.properties.get("skin") {
-                        if let Some(skin_tex) = entity::skull::decode_hex_skin(skin_hex) {
-                            self.dynamic_textures.insert(tex_key.clone(), skin_tex);
-                        }
-                    }
-                }
-                if !self.dynamic_textures.contains_key(&tex_key) {
-                    let fallback = entity::skull::player_skin_fallback_path(&rider_block);
-                    if let Some(tex) = self.resource_pack.get_texture(fallback) {
-                        self.dynamic_textures.insert(tex_key.clone(), tex.clone());
-                    }
-                }
-            }
+            self.load_player_skin(&tex_key, &rider_block);
 
             let model = entity::player::player_model(&rider_block, &tex_key);
             let facing_angle = entity::facing_rotation_rad(host_facing);
@@ -875,7 +1097,7 @@ impl<'a> MeshBuilder<'a> {
             self.add_offset_geometry(host_pos, saddle, &verts, &indices, &faces);
         } else {
             let (verts, indices, mut faces) =
-                entity::generate_mob_geometry(&rider_block, rider_type);
+                entity::generate_mob_geometry_with_pack(self.resource_pack, &rider_block, rider_type);
 
             // Villagers need the biome+profession compositing like the top-level
             // villager handling does; without it the rider is "naked".
@@ -943,6 +1165,7 @@ impl<'a> MeshBuilder<'a> {
                 index_start: idx_offset,
                 texture_path: ft.texture.clone(),
                 is_transparent: ft.is_transparent,
+                emissive: false,
             });
             idx_offset += 6;
         }
@@ -1001,6 +1224,7 @@ impl<'a> MeshBuilder<'a> {
                     index_start: idx_offset,
                     texture_path: ft.texture.clone(),
                     is_transparent: ft.is_transparent,
+                    emissive: false,
                 });
                 idx_offset += 6;
             }
@@ -1072,6 +1296,7 @@ impl<'a> MeshBuilder<'a> {
                 index_start: idx_offset,
                 texture_path: tex_key,
                 is_transparent: ft.is_transparent,
+                emissive: false,
             });
             idx_offset += 6;
         }
@@ -1190,7 +1415,9 @@ impl<'a> MeshBuilder<'a> {
             } else {
                 // Fallback to normal sign rendering (no font available)
                 let (vertices, indices, face_textures) =
-                    entity::generate_entity_geometry(block, &entity::BlockEntityType::Sign { wood, is_wall });
+                    entity::generate_entity_geometry_with_pack(
+                        self.resource_pack, block, &entity::BlockEntityType::Sign { wood, is_wall },
+                    );
                 if !vertices.is_empty() {
                     self.add_item_geometry(pos, &vertices, &indices, &face_textures);
                 }
@@ -1274,6 +1501,18 @@ impl<'a> MeshBuilder<'a> {
         Ok(())
     }
 
+    /// Resolve a player's skin into the dynamic texture `tex_key`: an inline
+    /// PNG, the profile `textures` via the skin library, or Steve/Alex (see
+    /// [`entity::skin`]).
+    fn load_player_skin(&mut self, tex_key: &str, block: &InputBlock) {
+        if self.dynamic_textures.contains_key(tex_key) {
+            return;
+        }
+        if let Some(skin) = entity::skin::player_skin(self.resource_pack, block) {
+            self.dynamic_textures.insert(tex_key.to_string(), skin);
+        }
+    }
+
     /// Add player head entity with skin texture support.
     fn add_player_head(
         &mut self,
@@ -1286,28 +1525,7 @@ impl<'a> MeshBuilder<'a> {
         // Determine texture key
         let tex_key = format!("_player_head/{}_{}_{}", pos.x, pos.y, pos.z);
 
-        // Prefer base64 skin (the WASM/JS-friendly path), then hex, then fallback.
-        if !self.dynamic_textures.contains_key(&tex_key) {
-            if let Some(skin_b64) = block.properties.get("skin_base64") {
-                if let Some(skin_tex) = entity::skull::decode_base64_skin(skin_b64) {
-                    self.dynamic_textures.insert(tex_key.clone(), skin_tex);
-                }
-            }
-            if !self.dynamic_textures.contains_key(&tex_key) {
-                if let Some(skin_hex) = block.properties.get("skin") {
-                    if let Some(skin_tex) = entity::skull::decode_hex_skin(skin_hex) {
-                        self.dynamic_textures.insert(tex_key.clone(), skin_tex);
-                    }
-                }
-            }
-            // If no skin decoded, load fallback from resource pack
-            if !self.dynamic_textures.contains_key(&tex_key) {
-                let fallback_path = entity::skull::player_skin_fallback_path(block);
-                if let Some(fallback_tex) = self.resource_pack.get_texture(fallback_path) {
-                    self.dynamic_textures.insert(tex_key.clone(), fallback_tex.clone());
-                }
-            }
-        }
+        self.load_player_skin(&tex_key, block);
 
         // Build player skull model with the texture key
         let model = entity::skull::player_skull_model(&tex_key);
@@ -1381,7 +1599,9 @@ impl<'a> MeshBuilder<'a> {
             } else {
                 // Fallback to normal hanging sign rendering (no font available)
                 let (vertices, indices, face_textures) =
-                    entity::generate_entity_geometry(block, &entity::BlockEntityType::HangingSign { wood, is_wall });
+                    entity::generate_entity_geometry_with_pack(
+                        self.resource_pack, block, &entity::BlockEntityType::HangingSign { wood, is_wall },
+                    );
                 if !vertices.is_empty() {
                     self.add_item_geometry(pos, &vertices, &indices, &face_textures);
                 }
@@ -1457,6 +1677,7 @@ impl<'a> MeshBuilder<'a> {
                 index_start: item_idx_offset,
                 texture_path: ft.texture.clone(),
                 is_transparent: ft.is_transparent,
+                emissive: false,
             });
             item_idx_offset += 6;
         }
@@ -1549,8 +1770,11 @@ impl<'a> MeshBuilder<'a> {
         resolved_textures: &std::collections::HashMap<String, String>,
         single_element: bool,
     ) -> Result<()> {
-        // Compute lighting factor for this block position
-        let is_emissive = self.light_map.map(|lm| lm.is_emissive(pos)).unwrap_or(false);
+        // Compute lighting factor for this block position. Light-emitting
+        // blocks and `light_emission` elements are fullbright; only the
+        // latter (and `_e` overlays below) are flagged emissive in the output.
+        let element_emissive = element.light_emission > 0;
+        let is_emissive = element_emissive || self.light_map.map(|lm| lm.is_emissive(pos)).unwrap_or(false);
 
         // Process each face. Iterate the fixed Direction::ALL order (not the
         // model's `faces` HashMap, whose iteration order is hash-random) so the
@@ -1575,8 +1799,26 @@ impl<'a> MeshBuilder<'a> {
             }
 
             // Resolve the texture reference
-            let texture_path = self.resolve_face_texture(&face.texture, resolved_textures);
+            let resolved = self.resolve_face_texture(&face.texture, resolved_textures, pos, block, world_direction);
+            let FaceTexture { path: texture_path, overlays } = match resolved {
+                Ok(texture) => texture,
+                Err((kind, reference)) => {
+                    let subject = format!(
+                        "{} {}",
+                        super::diagnostics::state_string(block),
+                        reference
+                    );
+                    self.diagnostics.fallback(kind, subject, pos);
+                    FaceTexture { path: "block/missing".to_string(), overlays: Vec::new() }
+                }
+            };
             self.texture_refs.insert(texture_path.clone());
+            self.texture_refs.extend(overlays.iter().cloned());
+
+            // Emissive overlay (`<texture>_e`), drawn fullbright on top.
+            let emissive_overlay = Some(self.resource_pack.emissive_map_path(&texture_path))
+                .filter(|path| self.resource_pack.get_texture(path).is_some());
+            self.texture_refs.extend(emissive_overlay.iter().cloned());
 
             // Fast-path coverage stats: classify this surviving face.
             if stats_enabled() {
@@ -1627,6 +1869,9 @@ impl<'a> MeshBuilder<'a> {
             // are excluded via `single_element` to avoid coplanar z-fighting.
             if self.greedy.is_some()
                 && single_element
+                && overlays.is_empty()
+                && emissive_overlay.is_none()
+                && !element_emissive
                 && self.is_greedy_eligible(element, face, transform)
             {
                 // Compute per-vertex AO; only fully-lit faces are eligible to merge.
@@ -1677,6 +1922,7 @@ impl<'a> MeshBuilder<'a> {
                 index_start,
                 texture_path,
                 is_transparent,
+                emissive: element_emissive,
             });
 
             // Calculate AO if enabled (use world direction for neighbor checks)
@@ -1692,26 +1938,67 @@ impl<'a> MeshBuilder<'a> {
 
             // Generate face geometry (with lighting applied)
             self.add_face(pos, block, element, *direction, face, transform, ao_values, light_factor, alpha_override)?;
+
+            // CTM overlays: the same quad again per overlay tile, blended on top.
+            for overlay in overlays {
+                self.face_textures.push(FaceTextureMapping {
+                    vertex_start: self.mesh.vertex_count() as u32,
+                    index_start: self.mesh.indices.len(),
+                    texture_path: overlay,
+                    is_transparent: true,
+                    emissive: element_emissive,
+                });
+                self.add_face(pos, block, element, *direction, face, transform, ao_values, light_factor, None)?;
+            }
+
+            if let Some(overlay) = emissive_overlay {
+                self.face_textures.push(FaceTextureMapping {
+                    vertex_start: self.mesh.vertex_count() as u32,
+                    index_start: self.mesh.indices.len(),
+                    texture_path: overlay,
+                    is_transparent: true,
+                    emissive: true,
+                });
+                // Emissive layers are never biome-tinted, even on tinted faces.
+                let untinted = ModelFace { tintindex: -1, ..face.clone() };
+                self.add_face(pos, block, element, *direction, &untinted, transform, None, 1.0, None)?;
+            }
         }
 
         Ok(())
     }
 
-    /// Resolve a texture reference to a path.
+    /// Resolve a texture reference to a path, then let connected-texture rules
+    /// swap in a tile (and add overlays) based on the face's neighbours.
+    /// Fails for a `#variable` the model chain never binds, and for a texture
+    /// a pack-defined blocks atlas doesn't declare (with the reference/path).
     fn resolve_face_texture(
         &self,
         reference: &str,
         resolved_textures: &std::collections::HashMap<String, String>,
-    ) -> String {
-        if reference.starts_with('#') {
-            let key = &reference[1..];
+        pos: BlockPosition,
+        block: &InputBlock,
+        direction: Direction,
+    ) -> std::result::Result<FaceTexture, (FallbackKind, String)> {
+        let path = if let Some(key) = reference.strip_prefix('#') {
             resolved_textures
                 .get(key)
                 .cloned()
-                .unwrap_or_else(|| "block/missing".to_string())
+                .ok_or_else(|| (FallbackKind::UnboundTextureVariable, reference.to_string()))?
         } else {
             reference.to_string()
+        };
+        if !self.resource_pack.is_block_sprite(&path) {
+            return Err((FallbackKind::UndeclaredSprite, path));
         }
+        let Some(ctm) = &self.ctm else {
+            return Ok(FaceTexture { path, overlays: Vec::new() });
+        };
+        let selection = ctm.select(self.culler, pos, block, direction, &path);
+        Ok(FaceTexture {
+            path: selection.texture.unwrap_or(path),
+            overlays: selection.overlays,
+        })
     }
 
     /// Add a face to the mesh.
@@ -1749,10 +2036,11 @@ impl<'a> MeshBuilder<'a> {
         let (positions, uvs) = self.generate_face_vertices(direction, from, to, uv, face.rotation);
 
         // Apply element rotation if present
-        let positions = if let Some(rot) = &element.rotation {
-            self.apply_element_rotation(&positions, rot)
+        let (positions, normal) = if let Some(rot) = &element.rotation {
+            let normal = element_rotation_matrix(rot, false) * Vec3::from(normal);
+            (self.apply_element_rotation(&positions, rot), normal.to_array())
         } else {
-            positions
+            (positions, normal)
         };
 
         // Apply block transform rotation
@@ -1919,53 +2207,21 @@ impl<'a> MeshBuilder<'a> {
         result
     }
 
-    /// Apply element rotation to positions.
+    /// Apply element rotation to positions. Each rotation step turns the
+    /// element around the origin, then rescales the two axes perpendicular
+    /// to it when `rescale` is set.
     fn apply_element_rotation(
         &self,
         positions: &[[f32; 3]; 4],
         rotation: &crate::types::ElementRotation,
     ) -> [[f32; 3]; 4] {
-        let origin = rotation.normalized_origin();
-        let angle = rotation.angle_radians();
-        let rescale = rotation.rescale_factor();
-
-        let rotation_matrix = match rotation.axis {
-            crate::types::Axis::X => Mat3::from_rotation_x(angle),
-            crate::types::Axis::Y => Mat3::from_rotation_y(angle),
-            crate::types::Axis::Z => Mat3::from_rotation_z(angle),
-        };
+        let origin = Vec3::from(rotation.normalized_origin());
+        let matrix = element_rotation_matrix(rotation, true);
 
         let mut result = [[0.0; 3]; 4];
         for (i, pos) in positions.iter().enumerate() {
-            // Translate to origin
-            let p = Vec3::new(pos[0] - origin[0], pos[1] - origin[1], pos[2] - origin[2]);
-
-            // Rotate
-            let rotated = rotation_matrix * p;
-
-            // Rescale if needed
-            let scaled = if rescale != 1.0 {
-                match rotation.axis {
-                    crate::types::Axis::X => {
-                        Vec3::new(rotated.x, rotated.y * rescale, rotated.z * rescale)
-                    }
-                    crate::types::Axis::Y => {
-                        Vec3::new(rotated.x * rescale, rotated.y, rotated.z * rescale)
-                    }
-                    crate::types::Axis::Z => {
-                        Vec3::new(rotated.x * rescale, rotated.y * rescale, rotated.z)
-                    }
-                }
-            } else {
-                rotated
-            };
-
-            // Translate back
-            result[i] = [
-                scaled.x + origin[0],
-                scaled.y + origin[1],
-                scaled.z + origin[2],
-            ];
+            let p = matrix * (Vec3::from(*pos) - origin) + origin;
+            result[i] = p.to_array();
         }
         result
     }
@@ -2032,34 +2288,56 @@ impl<'a> MeshBuilder<'a> {
                 quad.tint[2] as f32 / 255.0,
                 quad.tint[3] as f32 / 255.0,
             ];
+            // Texture arrays have no per-quad texture to bake AO into, so it
+            // goes into the corner colors like on unmerged faces.
+            let colors: [[f32; 4]; 4] = std::array::from_fn(|i| {
+                if self.config.texture_array && self.config.ambient_occlusion {
+                    apply_ao_and_light(base_color, quad.ao[i], self.config.ao_intensity, 1.0)
+                } else {
+                    base_color
+                }
+            });
 
             // Tiled UVs: [0, width] x [0, height] so texture repeats per block
             let w = quad.width as f32;
             let h = quad.height as f32;
             let uvs = [[0.0, 0.0], [w, 0.0], [w, h], [0.0, h]];
 
-            // Track greedy face separately (bypasses atlas UV remapping)
+            // Track greedy face separately (bypasses atlas UV remapping). In
+            // texture-array mode its layer repeats just as well, so it joins
+            // the regular faces.
             let vertex_start = self.mesh.vertex_count() as u32;
             let index_start = self.mesh.indices.len();
-            self.greedy_face_textures.push(GreedyFaceMapping {
-                vertex_start,
-                index_start,
-                texture_path: quad.texture.clone(),
-                is_transparent: quad.is_transparent,
-                ao: quad.ao,
-            });
+            if self.config.texture_array {
+                self.texture_refs.insert(quad.texture.clone());
+                self.face_textures.push(FaceTextureMapping {
+                    vertex_start,
+                    index_start,
+                    texture_path: quad.texture.clone(),
+                    is_transparent: quad.is_transparent,
+                    emissive: false,
+                });
+            } else {
+                self.greedy_face_textures.push(GreedyFaceMapping {
+                    vertex_start,
+                    index_start,
+                    texture_path: quad.texture.clone(),
+                    is_transparent: quad.is_transparent,
+                    ao: quad.ao,
+                });
+            }
 
             let v0 = self.mesh.add_vertex(
-                Vertex::new(positions[0], normal, uvs[0]).with_color(base_color),
+                Vertex::new(positions[0], normal, uvs[0]).with_color(colors[0]),
             );
             let v1 = self.mesh.add_vertex(
-                Vertex::new(positions[1], normal, uvs[1]).with_color(base_color),
+                Vertex::new(positions[1], normal, uvs[1]).with_color(colors[1]),
             );
             let v2 = self.mesh.add_vertex(
-                Vertex::new(positions[2], normal, uvs[2]).with_color(base_color),
+                Vertex::new(positions[2], normal, uvs[2]).with_color(colors[2]),
             );
             let v3 = self.mesh.add_vertex(
-                Vertex::new(positions[3], normal, uvs[3]).with_color(base_color),
+                Vertex::new(positions[3], normal, uvs[3]).with_color(colors[3]),
             );
 
             // Use AO-aware triangulation even though colors are uniform,
@@ -2081,22 +2359,35 @@ impl<'a> MeshBuilder<'a> {
     /// If `pre_built_atlas` is `Some`, it is used directly instead of building a new atlas.
     /// Dynamic textures (banners, signs, skins) that are NOT in the pre-built atlas will be
     /// added to it via a supplemental atlas build pass.
-    pub fn build(mut self, pre_built_atlas: Option<TextureAtlas>) -> Result<(crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, TextureAtlas, Vec<GreedyMaterial>, Vec<super::AnimatedTextureExport>)> {
+    ///
+    /// In texture-array mode the atlas is empty and a [`TextureArray`] is returned instead
+    /// (`pre_built_atlas` is ignored); greedy faces are part of the three meshes.
+    pub fn build(mut self, pre_built_atlas: Option<TextureAtlas>) -> Result<(crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, TextureAtlas, Option<TextureArray>, Vec<GreedyMaterial>, Vec<super::AnimatedTextureExport>, MeshDiagnostics)> {
         // Emit greedy-merged quads into the mesh before atlas building
         self.emit_greedy_quads();
 
-        let atlas = self.build_atlas(pre_built_atlas)?;
+        let (atlas, texture_array) = self.build_textures(pre_built_atlas)?;
 
         // Split faces into opaque/cutout/transparent layers, applying each face's
         // atlas UV transform during the copy (see `separate_by_transparency`).
-        let (opaque_mesh, cutout_mesh, transparent_mesh) = self.separate_by_transparency(&atlas);
+        let textures = TextureTarget::new(&atlas, texture_array.as_ref());
+        let (opaque_mesh, cutout_mesh, transparent_mesh) = self.separate_by_transparency(textures);
 
         // Build greedy materials: group greedy faces by texture path
         let greedy_materials = self.build_greedy_materials();
 
         let animated_exports = self.collect_dynamic_animated(&atlas);
 
-        Ok((opaque_mesh, cutout_mesh, transparent_mesh, atlas, greedy_materials, animated_exports))
+        Ok((
+            opaque_mesh,
+            cutout_mesh,
+            transparent_mesh,
+            atlas,
+            texture_array,
+            greedy_materials,
+            animated_exports,
+            std::mem::take(&mut self.diagnostics).finish(),
+        ))
     }
 
     /// Collect animated-texture sprite-sheet exports for any dynamic textures
@@ -2108,40 +2399,46 @@ impl<'a> MeshBuilder<'a> {
         let mut keys: Vec<&String> = self.dynamic_textures.keys().collect();
         keys.sort_unstable();
         for key in keys {
-            let tex = &self.dynamic_textures[key];
-            if tex.is_animated && tex.frame_count > 1 {
-                if let Some(region) = atlas.get_region(key) {
-                    let sprite_sheet_png = match tex.to_png() {
-                        Ok(png) => png,
-                        Err(_) => continue,
-                    };
-                    let anim = tex.animation.as_ref();
-                    let frame_width = anim.and_then(|a| a.frame_width).unwrap_or(tex.width);
-                    let frame_height = anim.and_then(|a| a.frame_height).unwrap_or(frame_width);
-                    let frametime = anim.map(|a| a.frametime).unwrap_or(1);
-                    let interpolate = anim.map(|a| a.interpolate).unwrap_or(false);
-                    let frames = anim
-                        .and_then(|a| a.frames.as_ref())
-                        .map(|fs| fs.iter().map(|f| f.index).collect());
-                    let atlas_x = (region.u_min * atlas.width as f32).round() as u32;
-                    let atlas_y = (region.v_min * atlas.height as f32).round() as u32;
-                    animated_exports.push(super::AnimatedTextureExport {
-                        sprite_sheet_png,
-                        frame_count: tex.frame_count,
-                        frametime,
-                        interpolate,
-                        frames,
-                        frame_width,
-                        frame_height,
-                        atlas_x,
-                        atlas_y,
-                    });
-                }
+            if let Some(region) = atlas.get_region(key) {
+                animated_exports.extend(super::AnimatedTextureExport::from_texture(
+                    key,
+                    &self.dynamic_textures[key],
+                    region,
+                    atlas,
+                    self.config.interpolated_frame_ticks,
+                ));
             }
         }
         animated_exports
     }
 
+    /// Build the atlas, or in texture-array mode the texture array (next to
+    /// an empty atlas).
+    fn build_textures(
+        &self,
+        pre_built_atlas: Option<TextureAtlas>,
+    ) -> Result<(TextureAtlas, Option<TextureArray>)> {
+        if self.config.texture_array {
+            return Ok((TextureAtlas::empty(), Some(self.build_texture_array())));
+        }
+        Ok((self.build_atlas(pre_built_atlas)?, None))
+    }
+
+    /// Build a texture array with one layer per texture in `self.texture_refs`
+    /// (+ dynamic textures), and the missing-texture tile.
+    fn build_texture_array(&self) -> TextureArray {
+        let mut array_builder = TextureArrayBuilder::new();
+        for texture_ref in &self.texture_refs {
+            if let Some(texture) = self.dynamic_textures.get(texture_ref) {
+                array_builder.add_texture(texture_ref.clone(), texture.first_frame());
+            } else if let Some(texture) = self.resource_pack.get_texture(texture_ref) {
+                array_builder.add_texture(texture_ref.clone(), texture.first_frame());
+            }
+        }
+        array_builder.add_texture(MISSING_TEXTURE_KEY.to_string(), make_missing_texture());
+        array_builder.build()
+    }
+
     /// Build the texture atlas from `self.texture_refs` (+ dynamic textures),
     /// or augment a `pre_built_atlas` with any missing dynamic textures.
     fn build_atlas(&self, pre_built_atlas: Option<TextureAtlas>) -> Result<TextureAtlas> {
@@ -2162,11 +2459,14 @@ impl<'a> MeshBuilder<'a> {
                 let mut atlas_builder = AtlasBuilder::new(
                     self.config.atlas_max_size,
                     self.config.atlas_padding,
-                );
+                )
+                .non_square(self.config.atlas_non_square)
+                .mip_levels(self.config.atlas_mip_levels);
                 // Re-add all existing textures from the pre-built atlas
                 for texture_ref in atlas.regions.keys() {
                     if let Some(texture) = self.resource_pack.get_texture(texture_ref) {
                         atlas_builder.add_texture(texture_ref.clone(), texture.first_frame());
+                        self.add_pbr_companions(&mut atlas_builder, texture_ref, texture);
                     }
                 }
                 // Add dynamic/missing textures
@@ -2181,7 +2481,9 @@ impl<'a> MeshBuilder<'a> {
             let mut atlas_builder = AtlasBuilder::new(
                 self.config.atlas_max_size,
                 self.config.atlas_padding,
-            );
+            )
+            .non_square(self.config.atlas_non_square)
+            .mip_levels(self.config.atlas_mip_levels);
 
             // Atlas packing order (and thus determinism) is handled inside
             // AtlasBuilder::build, which sorts by height + path — so add order
@@ -2192,6 +2494,7 @@ impl<'a> MeshBuilder<'a> {
                     atlas_builder.add_texture(texture_ref.clone(), texture.first_frame());
                 } else if let Some(texture) = self.resource_pack.get_texture(texture_ref) {
                     atlas_builder.add_texture(texture_ref.clone(), texture.first_frame());
+                    self.add_pbr_companions(&mut atlas_builder, texture_ref, texture);
                 }
             }
 
@@ -2205,12 +2508,24 @@ impl<'a> MeshBuilder<'a> {
         })
     }
 
+    /// Queue a pack texture's LabPBR `_n`/`_s` companions for the parallel
+    /// normal and specular atlases, cut to the frame `texture` shows.
+    fn add_pbr_companions(&self, atlas_builder: &mut AtlasBuilder, texture_ref: &str, texture: &TextureData) {
+        if let Some(normal) = self.resource_pack.get_normal_map(texture_ref) {
+            atlas_builder.add_normal_map(texture_ref.to_string(), texture.companion_frame(normal));
+        }
+        if let Some(specular) = self.resource_pack.get_specular_map(texture_ref) {
+            atlas_builder.add_specular_map(texture_ref.to_string(), texture.companion_frame(specular));
+        }
+    }
+
     /// Union only the cheap metadata (texture refs + dynamic textures) from a set
     /// of per-chunk partials into this builder — *not* the vertex/index buffers.
     /// Used by [`build_from_partials`](Self::build_from_partials) so the atlas can
     /// be built without first copying tens of millions of vertices into one mesh.
     pub(crate) fn merge_metadata_only(&mut self, partials: &[PartialMesh]) {
         for p in partials {
+            self.diagnostics.merge(&p.diagnostics);
             for t in &p.texture_refs {
                 if !self.texture_refs.contains(t) {
                     self.texture_refs.insert(t.clone());
@@ -2234,14 +2549,15 @@ impl<'a> MeshBuilder<'a> {
     /// `merge_metadata_only` must have been called with these `partials` first so
     /// the atlas covers every texture they reference.
     pub(crate) fn build_from_partials(
-        self,
+        mut self,
         partials: Vec<PartialMesh>,
         pre_built_atlas: Option<TextureAtlas>,
-    ) -> Result<(crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, TextureAtlas, Vec<GreedyMaterial>, Vec<super::AnimatedTextureExport>)>
+    ) -> Result<(crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, TextureAtlas, Option<TextureArray>, Vec<GreedyMaterial>, Vec<super::AnimatedTextureExport>, MeshDiagnostics)>
     {
         let _prof = std::env::var("MESHER_PROFILE").is_ok();
         let _t = super::prof_now();
-        let atlas = self.build_atlas(pre_built_atlas)?;
+        let (atlas, texture_array) = self.build_textures(pre_built_atlas)?;
+        let textures = TextureTarget::new(&atlas, texture_array.as_ref());
         if _prof {
             eprintln!("MPROFILE\t  build.atlas\t{}", _t.map_or(0, |t| t.elapsed().as_micros()));
         }
@@ -2266,35 +2582,32 @@ impl<'a> MeshBuilder<'a> {
         // ~40x above the memory-bandwidth floor, so it parallelizes well; the
         // final concat is the only bandwidth-bound copy.
         #[cfg(not(target_arch = "wasm32"))]
-        let per_chunk: Vec<(crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer)> = {
+        let per_chunk: Vec<SplitLayers> = {
             use rayon::prelude::*;
             partials
                 .par_iter()
                 .map(|p| {
-                    let mut op = crate::mesh_output::MeshLayer::new();
-                    op.reserve(p.mesh.vertices.len(), p.mesh.indices.len());
-                    let mut cut = crate::mesh_output::MeshLayer::new();
-                    let mut tr = crate::mesh_output::MeshLayer::new();
-                    split_faces_into(&p.mesh.vertices, &p.mesh.indices, &p.face_textures, &atlas, pack, &mut op, &mut cut, &mut tr);
-                    (op, cut, tr)
+                    let mut layers = SplitLayers::default();
+                    layers.opaque.reserve(p.mesh.vertices.len(), p.mesh.indices.len());
+                    split_faces_into(&p.mesh.vertices, &p.mesh.indices, &p.face_textures, textures, pack, &mut layers);
+                    layers
                 })
                 .collect()
         };
         #[cfg(target_arch = "wasm32")]
-        let per_chunk: Vec<(crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer, crate::mesh_output::MeshLayer)> = partials
+        let per_chunk: Vec<SplitLayers> = partials
             .iter()
             .map(|p| {
-                let mut op = crate::mesh_output::MeshLayer::new();
-                let mut cut = crate::mesh_output::MeshLayer::new();
-                let mut tr = crate::mesh_output::MeshLayer::new();
-                split_faces_into(&p.mesh.vertices, &p.mesh.indices, &p.face_textures, &atlas, pack, &mut op, &mut cut, &mut tr);
-                (op, cut, tr)
+                let mut layers = SplitLayers::default();
+                split_faces_into(&p.mesh.vertices, &p.mesh.indices, &p.face_textures, textures, pack, &mut layers);
+                layers
             })
             .collect();
-        for (op, cut, tr) in &per_chunk {
-            opaque_mesh.merge(op);
-            cutout_mesh.merge(cut);
-            transparent_mesh.merge(tr);
+        for layers in &per_chunk {
+            opaque_mesh.merge(&layers.opaque);
+            cutout_mesh.merge(&layers.cutout);
+            transparent_mesh.merge(&layers.transparent);
+            self.diagnostics.merge(&layers.diagnostics);
         }
         if _prof {
             eprintln!("MPROFILE\t  build.split\t{}", _t.map_or(0, |t| t.elapsed().as_micros()));
@@ -2315,6 +2628,7 @@ impl<'a> MeshBuilder<'a> {
             );
         }
         let greedy_materials = self.finalize_greedy_materials(greedy_map);
+        self.record_missing_greedy_textures(&greedy_materials);
         if _prof {
             eprintln!("MPROFILE\t  build.greedy\t{}", _t.map_or(0, |t| t.elapsed().as_micros()));
         }
@@ -2329,15 +2643,17 @@ impl<'a> MeshBuilder<'a> {
             cutout_mesh,
             transparent_mesh,
             atlas,
+            texture_array,
             greedy_materials,
             animated_exports,
+            self.diagnostics.finish(),
         ))
     }
 
     /// Build per-texture GreedyMaterial meshes from greedy faces.
     /// Groups by (texture_path, ao_pattern) so each AO variant gets its own
     /// baked texture with AO darkening applied at the pixel level.
-    fn build_greedy_materials(&self) -> Vec<GreedyMaterial> {
+    fn build_greedy_materials(&mut self) -> Vec<GreedyMaterial> {
         let mut material_map = std::collections::HashMap::new();
         accumulate_greedy_materials(
             &self.mesh.vertices,
@@ -2345,7 +2661,20 @@ impl<'a> MeshBuilder<'a> {
             &self.greedy_face_textures,
             &mut material_map,
         );
-        self.finalize_greedy_materials(material_map)
+        let materials = self.finalize_greedy_materials(material_map);
+        self.record_missing_greedy_textures(&materials);
+        materials
+    }
+
+    /// Greedy materials bypass the atlas, so their missing textures never hit
+    /// the split's missing-tile fallback; count them here instead.
+    fn record_missing_greedy_textures(&mut self, materials: &[GreedyMaterial]) {
+        for gm in materials {
+            if self.resource_pack.get_texture(&gm.texture_path).is_none() {
+                let quads = gm.opaque_mesh.vertex_count() / 4 + gm.transparent_mesh.vertex_count() / 4;
+                self.diagnostics.missing_texture(&gm.texture_path, quads);
+            }
+        }
     }
 
     /// Turn an accumulated `(texture, AO) -> (opaque, transparent)` map into final
@@ -2390,27 +2719,24 @@ impl<'a> MeshBuilder<'a> {
     /// - Cutout: binary alpha (texture has transparency but vertex alpha ≈ 1.0) — uses MASK mode
     /// - Transparent: semi-transparent (vertex alpha < 1.0, e.g. water) — uses BLEND mode
     fn separate_by_transparency(
-        &self,
-        atlas: &TextureAtlas,
+        &mut self,
+        textures: TextureTarget<'_>,
     ) -> (
         crate::mesh_output::MeshLayer,
         crate::mesh_output::MeshLayer,
         crate::mesh_output::MeshLayer,
     ) {
-        let mut opaque_mesh = crate::mesh_output::MeshLayer::new();
-        let mut cutout_mesh = crate::mesh_output::MeshLayer::new();
-        let mut transparent_mesh = crate::mesh_output::MeshLayer::new();
+        let mut layers = SplitLayers::default();
         split_faces_into(
             &self.mesh.vertices,
             &self.mesh.indices,
             &self.face_textures,
-            atlas,
+            textures,
             self.resource_pack,
-            &mut opaque_mesh,
-            &mut cutout_mesh,
-            &mut transparent_mesh,
+            &mut layers,
         );
-        (opaque_mesh, cutout_mesh, transparent_mesh)
+        self.diagnostics.merge(&layers.diagnostics);
+        (layers.opaque, layers.cutout, layers.transparent)
     }
 }
 
@@ -2461,27 +2787,64 @@ fn accumulate_greedy_materials(
     }
 }
 
+/// The layers [`split_faces_into`] sorts faces into, one per transparency
+/// class, plus the diagnostics it records on the way.
+#[derive(Default)]
+struct SplitLayers {
+    opaque: crate::mesh_output::MeshLayer,
+    cutout: crate::mesh_output::MeshLayer,
+    transparent: crate::mesh_output::MeshLayer,
+    diagnostics: DiagnosticsCollector,
+}
+
+/// What face UVs are mapped onto when splitting faces into layers.
+#[derive(Clone, Copy)]
+enum TextureTarget<'a> {
+    /// UVs are transformed into each texture's atlas region.
+    Atlas(&'a TextureAtlas),
+    /// UVs stay local and each vertex records its texture's layer.
+    Array(&'a TextureArray),
+}
+
+impl<'a> TextureTarget<'a> {
+    fn new(atlas: &'a TextureAtlas, texture_array: Option<&'a TextureArray>) -> Self {
+        match texture_array {
+            Some(array) => TextureTarget::Array(array),
+            None => TextureTarget::Atlas(atlas),
+        }
+    }
+
+    /// Atlas region or array layer of a texture; both `None` if it's absent.
+    fn lookup(self, texture_path: &str) -> Option<(Option<&'a AtlasRegion>, Option<u32>)> {
+        match self {
+            TextureTarget::Atlas(atlas) => atlas.get_region(texture_path).map(|r| (Some(r), None)),
+            TextureTarget::Array(array) => array.layer_index(texture_path).map(|l| (None, Some(l))),
+        }
+    }
+}
+
 /// Split one buffer of faces into opaque/cutout/transparent layer meshes,
-/// applying each face's atlas UV transform during the copy. Appends into the
+/// applying each face's atlas UV transform (or texture layer) during the copy. Appends into the
 /// provided meshes so it can be called once per source buffer (a single merged
 /// mesh, or each per-chunk partial directly — avoiding a merge copy).
 ///
 /// The atlas region is memoized across runs of same-textured faces (strong
-/// locality) and always borrowed, never cloned.
+/// locality) and always borrowed, never cloned. Faces that fall back to the
+/// missing-texture tile are counted into `layers.diagnostics`.
 fn split_faces_into(
     vertices: &[Vertex],
     indices: &[u32],
     face_textures: &[FaceTextureMapping],
-    atlas: &TextureAtlas,
+    textures: TextureTarget<'_>,
     resource_pack: &ResourcePack,
-    opaque_mesh: &mut crate::mesh_output::MeshLayer,
-    cutout_mesh: &mut crate::mesh_output::MeshLayer,
-    transparent_mesh: &mut crate::mesh_output::MeshLayer,
+    layers: &mut SplitLayers,
 ) {
-    let missing_region = atlas.get_region(MISSING_TEXTURE_KEY);
+    let missing = textures.lookup(MISSING_TEXTURE_KEY).unwrap_or((None, None));
     let mut warned_missing: HashSet<String> = HashSet::new();
+    let mut last_missing = false;
     let mut last_path: Option<&str> = None;
     let mut last_region = None;
+    let mut last_layer = None;
     // Memoized alongside the atlas region: does this texture have intermediate
     // alpha (slime, honey, stained glass) -> must alpha-BLEND, not alpha-test.
     let mut last_translucent = false;
@@ -2497,16 +2860,18 @@ fn split_faces_into(
         }
 
         if last_path != Some(face_mapping.texture_path.as_str()) {
-            last_region = match atlas.get_region(&face_mapping.texture_path) {
-                Some(r) => Some(r),
+            last_missing = false;
+            (last_region, last_layer) = match textures.lookup(&face_mapping.texture_path) {
+                Some(found) => found,
                 None => {
+                    last_missing = true;
                     if warned_missing.insert(face_mapping.texture_path.clone()) {
                         eprintln!(
                             "Warning: no atlas region for texture '{}' — falling back to missing-texture tile",
                             face_mapping.texture_path,
                         );
                     }
-                    missing_region
+                    missing
                 }
             };
             last_path = Some(face_mapping.texture_path.as_str());
@@ -2516,9 +2881,12 @@ fn split_faces_into(
                 .unwrap_or(false);
         }
         let region = last_region;
+        if last_missing {
+            layers.diagnostics.missing_texture(&face_mapping.texture_path, 1);
+        }
 
         let target_mesh = if !face_mapping.is_transparent {
-            &mut *opaque_mesh
+            &mut layers.opaque
         } else {
             // Distinguish cutout (alpha-test) from blend (translucent). A face
             // blends if EITHER its texture has intermediate alpha (slime, honey,
@@ -2526,9 +2894,9 @@ fn split_faces_into(
             // its alpha is binary (leaves, glass holes) -> cutout.
             let has_blend_alpha = (0..4).any(|i| vertices[vstart + i].color[3] < 0.99);
             if last_translucent || has_blend_alpha {
-                &mut *transparent_mesh
+                &mut layers.transparent
             } else {
-                &mut *cutout_mesh
+                &mut layers.cutout
             }
         };
 
@@ -2552,6 +2920,13 @@ fn split_faces_into(
         let v2 = target_mesh.push_vertex(p, n, u, c);
         let (p, n, u, c) = attrs(vstart + 3);
         let v3 = target_mesh.push_vertex(p, n, u, c);
+        if let Some(layer) = last_layer {
+            target_mesh.texture_layers.extend([layer; 4]);
+        }
+        if face_mapping.emissive || !target_mesh.emissive.is_empty() {
+            target_mesh.emissive.resize(target_mesh.vertex_count() - 4, false);
+            target_mesh.emissive.extend([face_mapping.emissive; 4]);
+        }
 
         // Directly read the 6 indices (2 triangles) from the tracked position
         for tri in 0..2 {
@@ -2649,7 +3024,7 @@ fn bake_ao_into_tile(
 }
 
 /// Standard Minecraft dye color RGB values.
-fn dye_rgb(color: &str) -> [f32; 4] {
+pub(crate) fn dye_rgb(color: &str) -> [f32; 4] {
     match color {
         "white" => [1.0, 1.0, 1.0, 1.0],
         "orange" => [0.85, 0.52, 0.18, 1.0],
@@ -2719,6 +3094,21 @@ fn composite_pot_side(pack: &ResourcePack, pattern: &str) -> Option<TextureData>
     })
 }
 
+/// Combined matrix of an element rotation's steps, optionally including the
+/// `rescale` scaling (normals want the rotation alone).
+fn element_rotation_matrix(rotation: &crate::types::ElementRotation, with_rescale: bool) -> Mat3 {
+    rotation.steps().fold(Mat3::IDENTITY, |matrix, (axis, angle)| {
+        let radians = angle.to_radians();
+        let scale = if with_rescale { rotation.rescale_factor(angle) } else { 1.0 };
+        let (step, scale) = match axis {
+            crate::types::Axis::X => (Mat3::from_rotation_x(radians), Vec3::new(1.0, scale, scale)),
+            crate::types::Axis::Y => (Mat3::from_rotation_y(radians), Vec3::new(scale, 1.0, scale)),
+            crate::types::Axis::Z => (Mat3::from_rotation_z(radians), Vec3::new(scale, scale, 1.0)),
+        };
+        Mat3::from_diagonal(scale) * step * matrix
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2752,6 +3142,7 @@ mod tests {
             to: [16.0, 16.0, 16.0],
             rotation: None,
             shade: true,
+            light_emission: 0,
             faces: HashMap::new(),
         }
     }
@@ -2791,6 +3182,7 @@ mod tests {
             to: [16.0, 8.0, 16.0],
             rotation: None,
             shade: true,
+            light_emission: 0,
             faces: HashMap::new(),
         };
         let face = full_face();
@@ -2850,6 +3242,72 @@ mod tests {
         assert!(!builder.is_greedy_eligible(&element, &face, &identity));
     }
 
+    #[test]
+    fn test_multi_axis_element_rotation() {
+        let pack = ResourcePack::new();
+        let config = MesherConfig::default();
+        let builder = MeshBuilder::new(&pack, &config, None, None, None);
+
+        // A point on the +X edge, turned 90° around X (no effect) then 90°
+        // around Z, ends up on +Y; rescale at 90° keeps the size.
+        let rotation: crate::types::ElementRotation = serde_json::from_str(
+            r#"{"origin": [8, 8, 8], "x": 90, "z": 90, "rescale": true}"#,
+        ).unwrap();
+        let positions = [[0.5, 0.0, 0.0]; 4];
+        let rotated = builder.apply_element_rotation(&positions, &rotation);
+        for (a, b) in rotated[0].iter().zip([0.0, 0.5, 0.0]) {
+            assert!((a - b).abs() < 1e-5, "{:?}", rotated[0]);
+        }
+
+        // 30° around Y with rescale widens X and Z by 1/cos(30°).
+        let rotation: crate::types::ElementRotation = serde_json::from_str(
+            r#"{"origin": [8, 8, 8], "y": 30, "rescale": true}"#,
+        ).unwrap();
+        let rotated = builder.apply_element_rotation(&positions, &rotation);
+        let length = (rotated[0][0].powi(2) + rotated[0][2].powi(2)).sqrt();
+        assert!((length - 0.5 / 30f32.to_radians().cos()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_element_rotation_turns_normals() {
+        let pack = ResourcePack::new();
+        let config = MesherConfig::default();
+        let mut builder = MeshBuilder::new(&pack, &config, None, None, None);
+
+        // A north face turned 45° around Y (with rescale, which must not
+        // skew the normal) faces north-west.
+        let element = ModelElement {
+            from: [0.0, 0.0, 0.0],
+            to: [16.0, 16.0, 16.0],
+            rotation: Some(serde_json::from_str(
+                r#"{"origin": [8, 8, 8], "axis": "y", "angle": 45, "rescale": true}"#,
+            ).unwrap()),
+            shade: true,
+            light_emission: 0,
+            faces: HashMap::new(),
+        };
+        builder
+            .add_face(
+                BlockPosition::new(0, 0, 0),
+                &InputBlock::new("minecraft:stone"),
+                &element,
+                Direction::North,
+                &full_face(),
+                &BlockTransform::default(),
+                None,
+                1.0,
+                None,
+            )
+            .unwrap();
+
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        for vertex in &builder.mesh.vertices {
+            for (a, b) in vertex.normal.iter().zip([-half, 0.0, -half]) {
+                assert!((a - b).abs() < 1e-5, "{:?}", vertex.normal);
+            }
+        }
+    }
+
     #[test]
     fn test_greedy_ineligible_element_rotation() {
         let pack = ResourcePack::new();
@@ -2863,9 +3321,13 @@ mod tests {
                 origin: [8.0, 8.0, 8.0],
                 axis: crate::types::Axis::Y,
                 angle: 22.5,
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
                 rescale: false,
             }),
             shade: true,
+            light_emission: 0,
             faces: HashMap::new(),
         };
         let face = full_face();
@@ -2873,4 +3335,501 @@ mod tests {
 
         assert!(!builder.is_greedy_eligible(&element, &face, &identity));
     }
+
+    #[test]
+    fn test_diagnostics_report_skips_and_fallbacks() {
+        let mut pack = ResourcePack::new();
+        let blockstate = |model: &str| -> crate::resource_pack::BlockstateDefinition {
+            serde_json::from_str(&format!(r#"{{"variants": {{"": {{"model": "{}"}}}}}}"#, model)).unwrap()
+        };
+        let cube = |texture: &str| -> crate::resource_pack::BlockModel {
+            serde_json::from_str(&format!(
+                r#"{{"elements": [{{"from": [0,0,0], "to": [16,16,16],
+                    "faces": {{"up": {{"texture": "{}"}}}}}}]}}"#,
+                texture
+            ))
+            .unwrap()
+        };
+        pack.add_blockstate("minecraft", "unbound", blockstate("block/unbound"));
+        pack.add_model("minecraft", "block/unbound", cube("#side"));
+        pack.add_blockstate("minecraft", "custom_ore", blockstate("block/custom_ore"));
+        pack.add_model("minecraft", "block/custom_ore", cube("block/custom_ore"));
+
+        let blocks = vec![
+            (BlockPosition::new(0, 0, 0), InputBlock::new("minecraft:unbound")),
+            (BlockPosition::new(2, 0, 0), InputBlock::new("minecraft:custom_ore")),
+            (BlockPosition::new(4, 0, 0), InputBlock::new("mymod:gizmo")),
+        ];
+        let mesher = crate::mesher::Mesher::new(pack);
+        let output = mesher
+            .mesh_blocks(
+                blocks.iter().map(|(p, b)| (*p, b)),
+                crate::types::BoundingBox::new([0.0, 0.0, 0.0], [5.0, 1.0, 1.0]),
+            )
+            .unwrap();
+
+        let d = &output.diagnostics;
+        assert_eq!(d.unknown_blocks.len(), 1);
+        assert_eq!(d.unknown_blocks[0].state, "mymod:gizmo");
+        assert_eq!(d.unknown_blocks[0].example_positions, vec![BlockPosition::new(4, 0, 0)]);
+        assert!(d.missing_textures.iter().any(|m| m.texture == "block/custom_ore" && m.face_count == 1));
+        assert_eq!(d.fallbacks.len(), 1);
+        assert_eq!(d.fallbacks[0].kind, FallbackKind::UnboundTextureVariable);
+        assert_eq!(d.fallbacks[0].subject, "minecraft:unbound #side");
+    }
+
+    #[test]
+    fn test_undeclared_sprites_fall_back_to_missing() {
+        let mut pack = ResourcePack::new();
+        let blockstate = |model: &str| -> crate::resource_pack::BlockstateDefinition {
+            serde_json::from_str(&format!(r#"{{"variants": {{"": {{"model": "{}"}}}}}}"#, model)).unwrap()
+        };
+        let cube = |texture: &str| -> crate::resource_pack::BlockModel {
+            serde_json::from_str(&format!(
+                r#"{{"elements": [{{"from": [0,0,0], "to": [16,16,16],
+                    "faces": {{"up": {{"texture": "{}"}}}}}}]}}"#,
+                texture
+            ))
+            .unwrap()
+        };
+        for name in ["stone", "secret"] {
+            pack.add_texture("minecraft", &format!("block/{}", name), TextureData::new(1, 1, vec![255; 4]));
+            pack.add_blockstate("minecraft", name, blockstate(&format!("block/{}", name)));
+            pack.add_model("minecraft", &format!("block/{}", name), cube(&format!("block/{}", name)));
+        }
+        let atlas: crate::resource_pack::AtlasDefinition =
+            serde_json::from_str(r#"{"sources": [{"type": "single", "resource": "block/stone"}]}"#).unwrap();
+        pack.add_atlas_sources("minecraft", "blocks", atlas.sources);
+        pack.apply_atlases();
+
+        let blocks = vec![
+            (BlockPosition::new(0, 0, 0), InputBlock::new("minecraft:stone")),
+            (BlockPosition::new(2, 0, 0), InputBlock::new("minecraft:secret")),
+        ];
+        let output = crate::mesher::Mesher::new(pack)
+            .mesh_blocks(
+                blocks.iter().map(|(p, b)| (*p, b)),
+                crate::types::BoundingBox::new([0.0, 0.0, 0.0], [3.0, 1.0, 1.0]),
+            )
+            .unwrap();
+
+        let d = &output.diagnostics;
+        assert_eq!(d.fallbacks.len(), 1);
+        assert_eq!(d.fallbacks[0].kind, FallbackKind::UndeclaredSprite);
+        assert_eq!(d.fallbacks[0].subject, "minecraft:secret block/secret");
+        assert!(output.atlas.contains("block/stone"));
+        assert!(!output.atlas.contains("block/secret"));
+    }
+
+    #[test]
+    fn test_pbr_companions_of_animated_textures_use_the_shown_frame() {
+        let mut pack = ResourcePack::new();
+        pack.add_blockstate(
+            "minecraft",
+            "lava_rock",
+            serde_json::from_str(r#"{"variants": {"": {"model": "block/lava_rock"}}}"#).unwrap(),
+        );
+        pack.add_model(
+            "minecraft",
+            "block/lava_rock",
+            serde_json::from_str(
+                r#"{"elements": [{"from": [0,0,0], "to": [16,16,16],
+                    "faces": {"up": {"texture": "block/lava_rock"}}}]}"#,
+            )
+            .unwrap(),
+        );
+        let strip = |first: [u8; 4], second: [u8; 4]| {
+            let mut pixels = first.repeat(256);
+            pixels.extend(second.repeat(256));
+            TextureData::new(16, 32, pixels)
+        };
+        let mut color = strip([90, 40, 20, 255], [120, 60, 30, 255]);
+        color.apply_mcmeta(crate::resource_pack::texture::parse_mcmeta(r#"{"animation": {}}"#).unwrap());
+        pack.add_texture("minecraft", "block/lava_rock", color);
+        pack.add_texture("minecraft", "block/lava_rock_n", strip([200, 60, 255, 255], [10, 10, 10, 255]));
+
+        let block = InputBlock::new("minecraft:lava_rock");
+        let output = crate::mesher::Mesher::new(pack)
+            .mesh_blocks(
+                std::iter::once((BlockPosition::new(0, 0, 0), &block)),
+                crate::types::BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+            )
+            .unwrap();
+
+        // One 16x16 tile, holding the normal map's first frame throughout.
+        let atlas = &output.atlas;
+        let region = atlas.get_region("block/lava_rock").unwrap();
+        let normal = atlas.normal_pixels.as_ref().unwrap();
+        let x0 = (region.u_min * atlas.width as f32).round() as usize;
+        let y0 = (region.v_min * atlas.height as f32).round() as usize;
+        let y1 = (region.v_max * atlas.height as f32).round() as usize;
+        assert_eq!(y1 - y0, 16);
+        for y in [y0, y1 - 1] {
+            let idx = (y * atlas.width as usize + x0) * 4;
+            assert_eq!(normal[idx..idx + 4], [200, 60, 255, 255]);
+        }
+    }
+
+    #[test]
+    fn test_non_block_atlas_does_not_gate_block_faces() {
+        let mut vanilla = ResourcePack::new();
+        vanilla.add_texture("minecraft", "block/stone", TextureData::new(1, 1, vec![255; 4]));
+        vanilla.add_blockstate(
+            "minecraft",
+            "stone",
+            serde_json::from_str(r#"{"variants": {"": {"model": "block/stone"}}}"#).unwrap(),
+        );
+        vanilla.add_model(
+            "minecraft",
+            "block/stone",
+            serde_json::from_str(
+                r#"{"elements": [{"from": [0,0,0], "to": [16,16,16],
+                    "faces": {"up": {"texture": "block/stone"}}}]}"#,
+            )
+            .unwrap(),
+        );
+
+        // A small pack that only adds a sign sprite.
+        let mut signs = ResourcePack::new();
+        signs.add_texture("minecraft", "entity/signs/custom", TextureData::new(1, 1, vec![255; 4]));
+        let atlas: crate::resource_pack::AtlasDefinition =
+            serde_json::from_str(r#"{"sources": [{"type": "single", "resource": "entity/signs/custom"}]}"#).unwrap();
+        signs.add_atlas_sources("minecraft", "signs", atlas.sources);
+        vanilla.overlay(signs);
+
+        let stone = InputBlock::new("minecraft:stone");
+        let output = crate::mesher::Mesher::new(vanilla)
+            .mesh_blocks(
+                std::iter::once((BlockPosition::new(0, 0, 0), &stone)),
+                crate::types::BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+            )
+            .unwrap();
+
+        assert!(output.diagnostics.fallbacks.is_empty());
+        assert!(output.atlas.contains("block/stone"));
+    }
+
+    #[test]
+    fn test_ctm_tiles_follow_neighbours() {
+        let mut pack = ResourcePack::new();
+        pack.add_blockstate(
+            "minecraft",
+            "glass",
+            serde_json::from_str(r#"{"variants": {"": {"model": "block/glass"}}}"#).unwrap(),
+        );
+        let faces: Vec<String> = ["down", "up", "north", "south", "west", "east"]
+            .iter()
+            .map(|d| format!(r##""{}": {{"texture": "#all", "cullface": "{}"}}"##, d, d))
+            .collect();
+        pack.add_model(
+            "minecraft",
+            "block/glass",
+            serde_json::from_str(&format!(
+                r##"{{"textures": {{"all": "block/glass"}},
+                    "elements": [{{"from": [0,0,0], "to": [16,16,16], "faces": {{{}}}}}]}}"##,
+                faces.join(",")
+            ))
+            .unwrap(),
+        );
+        pack.add_ctm_rule(
+            crate::resource_pack::ctm::parse_ctm_properties(
+                "minecraft",
+                "optifine/ctm/glass/glass.properties",
+                "matchBlocks=glass\nmethod=ctm\ntiles=0-46\n",
+            )
+            .unwrap()
+            .unwrap(),
+        );
+
+        // Tiles aren't in the pack, so each one shows up as a missing texture
+        // with its face count.
+        let glass = InputBlock::new("minecraft:glass");
+        let blocks = [(BlockPosition::new(0, 0, 0), &glass), (BlockPosition::new(1, 0, 0), &glass)];
+        let output = crate::mesher::Mesher::new(pack)
+            .mesh_blocks(blocks.into_iter(), crate::types::BoundingBox::new([0.0, 0.0, 0.0], [2.0, 1.0, 1.0]))
+            .unwrap();
+        let faces_with = |tile: &str| {
+            output
+                .diagnostics
+                .missing_textures
+                .iter()
+                .find(|m| m.texture == tile)
+                .map_or(0, |m| m.face_count)
+        };
+        assert_eq!(faces_with("optifine/ctm/glass/0"), 2);
+        assert_eq!(faces_with("optifine/ctm/glass/1"), 4);
+        assert_eq!(faces_with("optifine/ctm/glass/3"), 4);
+    }
+
+    #[test]
+    fn test_texture_array_mode_merges_greedy_faces() {
+        let mut pack = ResourcePack::new();
+        pack.add_blockstate(
+            "minecraft",
+            "stone",
+            serde_json::from_str(r#"{"variants": {"": {"model": "block/stone"}}}"#).unwrap(),
+        );
+        let faces: Vec<String> = ["down", "up", "north", "south", "west", "east"]
+            .iter()
+            .map(|d| format!(r##""{}": {{"texture": "#all", "cullface": "{}"}}"##, d, d))
+            .collect();
+        pack.add_model(
+            "minecraft",
+            "block/stone",
+            serde_json::from_str(&format!(
+                r##"{{"textures": {{"all": "block/stone"}},
+                    "elements": [{{"from": [0,0,0], "to": [16,16,16], "faces": {{{}}}}}]}}"##,
+                faces.join(",")
+            ))
+            .unwrap(),
+        );
+        pack.add_texture("minecraft", "block/stone", TextureData::new(16, 16, [120, 120, 120, 255].repeat(256)));
+
+        let config = MesherConfig {
+            greedy_meshing: true,
+            texture_array: true,
+            ..MesherConfig::default()
+        };
+        let stone = InputBlock::new("minecraft:stone");
+        let blocks = [(BlockPosition::new(0, 0, 0), &stone), (BlockPosition::new(1, 0, 0), &stone)];
+        let output = crate::mesher::Mesher::with_config(pack, config)
+            .mesh_blocks(blocks.into_iter(), crate::types::BoundingBox::new([0.0, 0.0, 0.0], [2.0, 1.0, 1.0]))
+            .unwrap();
+
+        assert!(output.greedy_materials.is_empty());
+        let array = output.texture_array.as_ref().unwrap();
+        let stone_layer = array.layer_index("block/stone").unwrap();
+        let layer = &output.opaque_mesh;
+        assert!(!layer.is_empty());
+        assert_eq!(layer.texture_layers.len(), layer.vertex_count());
+        assert!(layer.texture_layers.iter().all(|&l| l == stone_layer));
+        // The merged top face tiles its layer twice along the row.
+        assert!(layer.uvs.iter().any(|uv| uv[0] == 2.0 || uv[1] == 2.0));
+        assert!(crate::export::export_glb(&output).is_err());
+    }
+
+    #[test]
+    fn test_display_entities() {
+        let mut pack = ResourcePack::new();
+        pack.add_blockstate(
+            "minecraft",
+            "stone",
+            serde_json::from_str(r#"{"variants": {"": {"model": "block/stone"}}}"#).unwrap(),
+        );
+        pack.add_model(
+            "minecraft",
+            "block/stone",
+            serde_json::from_str(
+                r##"{"textures": {"all": "block/stone"},
+                    "elements": [{"from": [0,0,0], "to": [16,16,16], "faces": {
+                        "down": {"texture": "#all", "cullface": "down"}, "up": {"texture": "#all", "cullface": "up"},
+                        "north": {"texture": "#all"}, "south": {"texture": "#all"},
+                        "west": {"texture": "#all"}, "east": {"texture": "#all"}}}]}"##,
+            )
+            .unwrap(),
+        );
+        pack.add_texture("minecraft", "block/stone", TextureData::new(16, 16, [120, 120, 120, 255].repeat(256)));
+        // A font whose "A" (cell 1, 4) is 5 pixels wide.
+        let mut font = [0, 0, 0, 0].repeat(128 * 128);
+        for y in 32..39 {
+            for x in 8..13 {
+                font[(y * 128 + x) * 4..][..4].copy_from_slice(&[255; 4]);
+            }
+        }
+        pack.add_texture("minecraft", "font/ascii", TextureData::new(128, 128, font));
+
+        let floor = InputBlock::new("minecraft:stone");
+        let block_display = InputBlock::new("entity:block_display")
+            .with_property("block_state", "stone")
+            .with_property("transformation", "{translation:[0.25f,0f,0.25f],scale:[0.5f,0.5f,0.5f]}");
+        let text_display = InputBlock::new("entity:text_display")
+            .with_property("text", r#"{"text":"AA","color":"red"}"#)
+            .with_property("background", "-16777216");
+        // The floor under the block display doesn't cull its bottom face.
+        let blocks = [
+            (BlockPosition::new(0, -1, 0), &floor),
+            (BlockPosition::new(0, 0, 0), &block_display),
+            (BlockPosition::new(4, 0, 0), &text_display),
+        ];
+        let output = crate::mesher::Mesher::new(pack)
+            .mesh_blocks(blocks.into_iter(), crate::types::BoundingBox::new([0.0, -1.0, 0.0], [5.0, 1.0, 1.0]))
+            .unwrap();
+        let mesh = output.mesh();
+
+        // Half-size stone centered in its cell, standing on the floor.
+        let display: Vec<[f32; 3]> =
+            mesh.vertices.iter().map(|v| v.position).filter(|p| p[0].abs() < 0.3).collect();
+        assert_eq!(display.len(), 24);
+        for p in &display {
+            assert!((-0.25..=0.25).contains(&p[0]) && (-0.5..=0.0).contains(&p[1]), "{:?}", p);
+        }
+
+        // "AA" is 12 font pixels wide plus a 1-pixel border; one line is 10
+        // tall. The panel stands on the entity origin, facing south.
+        let text: Vec<&Vertex> = mesh.vertices.iter().filter(|v| v.position[0] > 3.0).collect();
+        assert_eq!(text.len(), 4);
+        let xs: Vec<f32> = text.iter().map(|v| v.position[0] - 3.5).collect();
+        let ys: Vec<f32> = text.iter().map(|v| v.position[1] + 0.5).collect();
+        let max = |v: &[f32]| v.iter().copied().fold(f32::MIN, f32::max);
+        assert!((max(&xs) - 0.175).abs() < 1e-4 && (max(&ys) - 0.25).abs() < 1e-4);
+        assert!(text.iter().all(|v| v.normal == [0.0, 0.0, 1.0]));
+        assert!(output.atlas.get_region("_text_display/4_0_0").is_some());
+    }
+
+    #[test]
+    fn test_block_display_state_is_upgraded() {
+        let mut pack = ResourcePack::new();
+        pack.add_blockstate(
+            "minecraft",
+            "short_grass",
+            serde_json::from_str(r#"{"variants": {"": {"model": "block/short_grass"}}}"#).unwrap(),
+        );
+        pack.add_model(
+            "minecraft",
+            "block/short_grass",
+            serde_json::from_str(
+                r#"{"elements": [{"from": [0,0,0], "to": [16,16,16],
+                    "faces": {"up": {"texture": "block/short_grass"}}}]}"#,
+            )
+            .unwrap(),
+        );
+        pack.add_texture("minecraft", "block/short_grass", TextureData::new(16, 16, [60, 160, 40, 255].repeat(256)));
+
+        // Saved by 1.20.2, before `grass` became `short_grass`.
+        let block_display = InputBlock::new("entity:block_display").with_property("block_state", "grass");
+        let config = crate::mesher::MesherConfig {
+            data_version: Some(crate::resolver::upgrade::data_version::V1_20_3 - 1),
+            ..Default::default()
+        };
+        let output = crate::mesher::Mesher::with_config(pack, config)
+            .mesh_blocks(
+                [(BlockPosition::new(0, 0, 0), &block_display)].into_iter(),
+                crate::types::BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+            )
+            .unwrap();
+        assert_eq!(output.mesh().vertices.len(), 4);
+        assert!(output.diagnostics.unknown_blocks.is_empty());
+    }
+
+    #[test]
+    fn test_emissive_overlay_and_light_emission() {
+        let mut pack = ResourcePack::new();
+        for (block, emission) in [("magma", 0), ("lamp", 15), ("grass_block", 0)] {
+            pack.add_blockstate(
+                "minecraft",
+                block,
+                serde_json::from_str(&format!(r#"{{"variants": {{"": {{"model": "block/{}"}}}}}}"#, block)).unwrap(),
+            );
+            pack.add_model(
+                "minecraft",
+                &format!("block/{}", block),
+                serde_json::from_str(&format!(
+                    r##"{{"textures": {{"all": "block/{}"}},
+                        "elements": [{{"from": [0,0,0], "to": [16,16,16], "light_emission": {},
+                            "faces": {{"up": {{"texture": "#all", "tintindex": 0}}}}}}]}}"##,
+                    block, emission
+                ))
+                .unwrap(),
+            );
+            pack.add_texture("minecraft", &format!("block/{}", block), TextureData::new(16, 16, [90, 40, 20, 255].repeat(256)));
+        }
+        let mut overlay = [0, 0, 0, 0].repeat(256);
+        overlay[..4].copy_from_slice(&[255, 160, 0, 255]);
+        pack.add_texture("minecraft", "block/magma_e", TextureData::new(16, 16, overlay.clone()));
+        pack.add_texture("minecraft", "block/grass_block_e", TextureData::new(16, 16, overlay));
+
+        let config = MesherConfig {
+            sky_light_level: 4,
+            enable_sky_light: true,
+            ..MesherConfig::default()
+        };
+        let magma = InputBlock::new("minecraft:magma");
+        let lamp = InputBlock::new("minecraft:lamp");
+        let grass = InputBlock::new("minecraft:grass_block");
+        let blocks = [
+            (BlockPosition::new(0, 0, 0), &magma),
+            (BlockPosition::new(2, 0, 0), &lamp),
+            (BlockPosition::new(4, 0, 0), &grass),
+        ];
+        let output = crate::mesher::Mesher::with_config(pack, config)
+            .mesh_blocks(blocks.into_iter(), crate::types::BoundingBox::new([0.0, 0.0, 0.0], [5.0, 1.0, 1.0]))
+            .unwrap();
+
+        // The magma face itself is lit normally; its overlay is a separate
+        // fullbright emissive quad.
+        let opaque = &output.opaque_mesh;
+        let on_block = |i: usize, x: f32| (opaque.positions[i][0] - x).abs() <= 0.5;
+        let lamp_vertices: Vec<usize> = (0..opaque.vertex_count()).filter(|&i| on_block(i, 2.0)).collect();
+        let magma_vertices: Vec<usize> = (0..opaque.vertex_count()).filter(|&i| on_block(i, 0.0)).collect();
+        let grass_vertices: Vec<usize> = (0..opaque.vertex_count()).filter(|&i| on_block(i, 4.0)).collect();
+        assert_eq!(lamp_vertices.len(), 4);
+        assert!(lamp_vertices.iter().all(|&i| opaque.is_emissive(i) && opaque.colors[i] == [1.0; 4]));
+        assert!(magma_vertices.iter().all(|&i| !opaque.is_emissive(i)));
+        // The grass face is biome-tinted...
+        assert!(grass_vertices.iter().all(|&i| opaque.colors[i] != [1.0; 4]));
+
+        // ...but both overlays, including the grass one, are untinted.
+        let cutout = &output.cutout_mesh;
+        assert_eq!(cutout.vertex_count(), 8);
+        assert!((0..8).all(|i| cutout.is_emissive(i) && cutout.colors[i] == [1.0; 4]));
+        assert!(output.atlas.get_region("block/magma_e").is_some());
+
+        let (rest, emissive) = opaque.split_emissive().unwrap();
+        assert_eq!((rest.vertex_count(), emissive.vertex_count()), (8, 4));
+    }
+
+    #[test]
+    fn test_armor_trim_and_glint() {
+        let mut pack = ResourcePack::new();
+        pack.add_texture("minecraft", "entity/equipment/humanoid/iron", TextureData::new(64, 32, [200, 200, 200, 255].repeat(64 * 32)));
+        pack.add_texture("minecraft", "trims/entity/humanoid/coast", TextureData::new(64, 32, [10, 10, 10, 255].repeat(64 * 32)));
+        pack.add_texture("minecraft", "trims/color_palettes/trim_palette", TextureData::new(1, 1, vec![10, 10, 10, 255]));
+        pack.add_texture("minecraft", "trims/color_palettes/gold", TextureData::new(1, 1, vec![250, 200, 0, 255]));
+        pack.add_texture("minecraft", "misc/enchanted_glint_armor", TextureData::new(16, 16, [120, 60, 200, 255].repeat(256)));
+
+        let zombie = InputBlock::new("entity:zombie").with_property(
+            "chestplate",
+            r#"iron_chestplate[trim={"pattern":"coast","material":"gold"},enchantment_glint_override=true]"#,
+        );
+        let blocks = [(BlockPosition::new(0, 0, 0), &zombie)];
+        let output = crate::mesher::Mesher::new(pack)
+            .mesh_blocks(blocks.into_iter(), crate::types::BoundingBox::new([0.0, 0.0, 0.0], [1.0, 2.0, 1.0]))
+            .unwrap();
+
+        // The pack has no armor_trims atlas, so the trim sprite is recolored
+        // on the fly.
+        assert!(output.atlas.get_region("trims/entity/humanoid/coast_gold").is_some());
+        assert!(output.atlas.get_region("entity/equipment/humanoid/iron").is_some());
+
+        // Body and both arms: 3 cubes of glint, translucent and fullbright.
+        let transparent = &output.transparent_mesh;
+        let glint: Vec<usize> = (0..transparent.vertex_count()).filter(|&i| transparent.is_emissive(i)).collect();
+        assert_eq!(glint.len(), 3 * 6 * 4);
+        assert!(glint.iter().all(|&i| (transparent.colors[i][3] - entity::armor::GLINT_ALPHA).abs() < 1e-4));
+    }
+
+    #[test]
+    fn test_player_cape_and_elytra() {
+        let mut pack = ResourcePack::new();
+        pack.add_texture("minecraft", "entity/player/wide/steve", TextureData::new(64, 64, [90, 60, 40, 255].repeat(64 * 64)));
+        pack.add_skin("c0ffee", TextureData::new(64, 32, [180, 20, 20, 255].repeat(64 * 32)));
+
+        let vertex_count = |pack: &ResourcePack, player: &InputBlock| {
+            let blocks = [(BlockPosition::new(0, 0, 0), player)];
+            let output = crate::mesher::Mesher::new(pack.clone())
+                .mesh_blocks(blocks.into_iter(), crate::types::BoundingBox::new([0.0, 0.0, 0.0], [1.0, 2.0, 1.0]))
+                .unwrap();
+            let cape_used = output.atlas.get_region("_player_cape/0_0_0").is_some();
+            let count = output.opaque_mesh.vertex_count() + output.cutout_mesh.vertex_count() + output.transparent_mesh.vertex_count();
+            (count, cape_used)
+        };
+
+        let (bare, _) = vertex_count(&pack, &InputBlock::new("entity:player"));
+        let caped = InputBlock::new("entity:player").with_property("cape", "c0ffee");
+        // The cape is one 10x16x1 cube.
+        assert_eq!(vertex_count(&pack, &caped), (bare + 24, true));
+        // Elytra replace the cape with two wings drawn with its texture.
+        let winged = caped.clone().with_property("chestplate", "elytra");
+        assert_eq!(vertex_count(&pack, &winged), (bare + 48, true));
+    }
 }
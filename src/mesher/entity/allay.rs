@@ -0,0 +1,53 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+
+/// Allay model — texture `entity/allay/allay`, 32x32.
+/// From AllayModel.java (MC 1.21.4). The body carries a slightly shrunken
+/// translucent robe cube; arms and flat wings are its children. Vanilla's
+/// own root sits at y=23.5, which dips the wing tips below the ground while
+/// idle; it's raised to y=22 here so a hovering allay clears the floor.
+pub(super) fn allay_model() -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+    let part = |name: &str, position: [f32; 3], cube: EntityCube| {
+        EntityPart::posed(name, position, no_rotation, vec![cube], vec![])
+    };
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, -4.0, 0.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-1.5, 0.0, -1.0], [3.0, 4.0, 2.0], [0, 10]),
+            EntityCube::new([-1.5, 0.0, -1.0], [3.0, 5.0, 2.0], [0, 16]).inflated(-0.2),
+        ],
+        vec![
+            part("right_arm", [-1.75, 0.5, 0.0],
+                EntityCube::new([-0.75, -0.5, -1.0], [1.0, 4.0, 2.0], [23, 0]).inflated(-0.01)),
+            part("left_arm", [1.75, 0.5, 0.0],
+                EntityCube::new([-0.25, -0.5, -1.0], [1.0, 4.0, 2.0], [23, 6]).inflated(-0.01)),
+            part("right_wing", [-0.5, 0.0, 0.6],
+                EntityCube::new([0.0, 1.0, 0.0], [0.0, 5.0, 8.0], [16, 14])),
+            part("left_wing", [0.5, 0.0, 0.6],
+                EntityCube::new([0.0, 1.0, 0.0], [0.0, 5.0, 8.0], [16, 14])),
+        ],
+    );
+
+    let allay_root = EntityPart::posed(
+        "allay",
+        [0.0, 22.0, 0.0],
+        no_rotation,
+        vec![],
+        vec![
+            part("head", [0.0, -3.99, 0.0],
+                EntityCube::new([-2.5, -5.0, -2.5], [5.0, 5.0, 5.0], [0, 0])),
+            body,
+        ],
+    );
+
+    EntityModelDef {
+        texture_path: "entity/allay/allay".to_string(),
+        texture_size: [32, 32],
+        parts: vec![mob_root(vec![allay_root])],
+        is_opaque: false, // Robe and wings are translucent
+    }
+}
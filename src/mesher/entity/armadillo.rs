@@ -0,0 +1,82 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+
+/// Armadillo model — texture `entity/armadillo`, 64x64.
+/// From ArmadilloModel.java (MC 1.21.4), unrolled. The shell is an inflated
+/// copy of the body cube; head, ears and tail hang off the body. Vanilla
+/// nests each ear cube in an unrotated parent part; those are merged here.
+pub(super) fn armadillo_model() -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, -2.0, -11.0],
+        no_rotation,
+        vec![],
+        vec![
+            EntityPart::posed(
+                "head_cube",
+                [0.0, 0.0, 0.0],
+                [-std::f32::consts::FRAC_PI_8, 0.0, 0.0],
+                vec![EntityCube::new([-1.5, -1.0, -1.0], [3.0, 5.0, 2.0], [43, 15])],
+                vec![],
+            ),
+            EntityPart::posed(
+                "right_ear",
+                [-1.5, -1.0, -0.6],
+                [0.1886, -0.3864, -0.0718],
+                vec![EntityCube::new([-2.0, -3.0, 0.0], [2.0, 5.0, 0.0], [43, 10])],
+                vec![],
+            ),
+            EntityPart::posed(
+                "left_ear",
+                [1.5, -1.0, -0.6],
+                [0.1886, 0.3864, 0.0718],
+                vec![EntityCube::new([0.0, -3.0, 0.0], [2.0, 5.0, 0.0], [47, 10])],
+                vec![],
+            ),
+        ],
+    );
+
+    let tail = EntityPart::posed(
+        "tail",
+        [0.0, -3.0, 1.0],
+        [0.5061, 0.0, 0.0],
+        vec![EntityCube::new([-0.5, -0.0865, 0.0933], [1.0, 6.0, 1.0], [44, 53])],
+        vec![],
+    );
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 21.0, 4.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-4.0, -7.0, -10.0], [8.0, 8.0, 12.0], [0, 20]).inflated(0.3),
+            EntityCube::new([-4.0, -7.0, -10.0], [8.0, 8.0, 12.0], [0, 40]),
+        ],
+        vec![head, tail],
+    );
+
+    let leg = |name: &str, x: f32, z: f32, tex_offset: [u32; 2]| {
+        EntityPart::posed(
+            name,
+            [x, 21.0, z],
+            no_rotation,
+            vec![EntityCube::new([-1.0, 0.0, -1.0], [2.0, 3.0, 2.0], tex_offset)],
+            vec![],
+        )
+    };
+
+    EntityModelDef {
+        texture_path: "entity/armadillo".to_string(),
+        texture_size: [64, 64],
+        parts: vec![mob_root(vec![
+            body,
+            leg("right_hind_leg", -2.0, 4.0, [51, 31]),
+            leg("left_hind_leg", 2.0, 4.0, [42, 31]),
+            leg("right_front_leg", -2.0, -4.0, [51, 43]),
+            leg("left_front_leg", 2.0, -4.0, [42, 43]),
+        ])],
+        is_opaque: false, // Ears are cut-out planes
+    }
+}
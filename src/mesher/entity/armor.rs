@@ -0,0 +1,504 @@
+//! Worn armor: helmets, chestplates, leggings and boots on humanoid wearers.
+//!
+//! Mirrors MC's `HumanoidArmorLayer` with 1.21.4 equipment assets. Each piece
+//! is an item stack in a slot property (`helmet`, `chestplate`, `leggings`,
+//! `boots`), e.g.
+//! `chestplate=leather_chestplate[dyed_color=16711680,trim={"pattern":"coast","material":"gold"}]`.
+//! Items made for another slot (`helmet=diamond_boots`) are skipped.
+//! The piece's equipment asset (its `equippable` component's `asset_id`, else
+//! the material named by the item id) lists the texture layers of the slot's
+//! layer type — `humanoid_leggings` for leggings, `humanoid` otherwise. Each
+//! layer is drawn on a copy of the wearer's model whose head, body, arm and
+//! leg parts carry the armor cubes instead of their own, inflated by 1.0
+//! (0.5 for leggings), so armor follows poses and baby scaling. A `trim`
+//! adds the pattern recolored with the material's palette, and enchantments
+//! (or `enchantment_glint_override=true`) add the glint. A chest item with
+//! `wings` layers (the elytra) draws the wings of `WingsLayer` instead.
+
+use super::equipment::{EquipmentOverlay, PalettedTexture};
+use super::mob::clear_cubes;
+use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose, MobType};
+use crate::resource_pack::item_definition::parse_color;
+use crate::resource_pack::{ItemStack, ResourcePack};
+use crate::types::InputBlock;
+use serde_json::Value;
+
+/// Texture of the armor enchantment glint layer.
+pub(crate) const GLINT_TEXTURE: &str = "misc/enchanted_glint_armor";
+
+/// Vertex alpha of the glint layer. Vanilla adds the glint on top of the
+/// armor; a translucent fullbright layer is the closest a static mesh gets.
+pub(crate) const GLINT_ALPHA: f32 = 0.4;
+
+/// Palette key the trim patterns are drawn in.
+const TRIM_PALETTE_KEY: &str = "trims/color_palettes/trim_palette";
+
+/// Layer type of the elytra.
+const WINGS_LAYER: &str = "wings";
+
+/// Slot properties in the order vanilla draws them.
+const SLOTS: [(&str, ArmorSlot); 4] = [
+    ("chestplate", ArmorSlot::Chest),
+    ("leggings", ArmorSlot::Legs),
+    ("boots", ArmorSlot::Feet),
+    ("helmet", ArmorSlot::Head),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArmorSlot {
+    Head,
+    Chest,
+    Legs,
+    Feet,
+}
+
+impl ArmorSlot {
+    /// The `equippable` component's name for the slot.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Head => "head",
+            Self::Chest => "chest",
+            Self::Legs => "legs",
+            Self::Feet => "feet",
+        }
+    }
+
+    fn layer_type(self) -> &'static str {
+        match self {
+            Self::Legs => "humanoid_leggings",
+            _ => "humanoid",
+        }
+    }
+
+    /// `HumanoidArmorModel` deformation: the inner (leggings) model sits
+    /// under the outer one.
+    fn inflate(self) -> f32 {
+        match self {
+            Self::Legs => 0.5,
+            _ => 1.0,
+        }
+    }
+
+    fn covers(self, part: &str) -> bool {
+        match self {
+            Self::Head => part == "head",
+            Self::Chest => matches!(part, "body" | "right_arm" | "left_arm"),
+            Self::Legs => matches!(part, "body" | "right_leg" | "left_leg"),
+            Self::Feet => matches!(part, "right_leg" | "left_leg"),
+        }
+    }
+}
+
+/// Whether mobs of this type draw armor from the slot properties.
+pub(crate) fn wears_armor(mob_type: MobType) -> bool {
+    matches!(
+        mob_type,
+        MobType::Player
+            | MobType::ArmorStand
+            | MobType::Zombie
+            | MobType::Skeleton
+            | MobType::WitherSkeleton
+            | MobType::Piglin
+    )
+}
+
+/// The armor layers worn by `wearer`, bottom to top: per piece its asset
+/// layers, then its trim, then its glint. `player_texture` is the wearer's
+/// cape, drawn by `use_player_texture` wings layers.
+pub(crate) fn armor_overlays(
+    resource_pack: &ResourcePack,
+    block: &InputBlock,
+    wearer: &EntityModelDef,
+    player_texture: Option<&str>,
+) -> Vec<EquipmentOverlay> {
+    let mut out = Vec::new();
+
+    for (property, slot) in SLOTS {
+        let Some(stack) = block.properties.get(property).and_then(|s| ItemStack::parse(s)) else {
+            continue;
+        };
+        if !fits_slot(&stack, slot) {
+            continue;
+        }
+        let Some(asset_id) = asset_id(&stack) else {
+            continue;
+        };
+        let Some(asset) = resource_pack.get_equipment_asset(&asset_id) else {
+            continue;
+        };
+        let wings = slot == ArmorSlot::Chest && !asset.layers(WINGS_LAYER).is_empty();
+        let layer_type = if wings { WINGS_LAYER } else { slot.layer_type() };
+        let model = |texture: String| {
+            if wings {
+                wings_model(wearer, texture)
+            } else {
+                armor_model(wearer, slot, texture)
+            }
+        };
+
+        for layer in asset.layers(layer_type) {
+            // Dyeable layers without a dye or an undyed color are skipped.
+            let tint = match &layer.dyeable {
+                None => None,
+                Some(dyeable) => match dyed_color(&stack)
+                    .or_else(|| dyeable.color_when_undyed.and_then(|c| parse_color(&Value::from(c))))
+                {
+                    Some(color) => Some(color),
+                    None => continue,
+                },
+            };
+            let texture = match player_texture {
+                Some(texture) if layer.use_player_texture => texture.to_string(),
+                _ => layer.texture_path(layer_type),
+            };
+            let mut overlay = EquipmentOverlay::new(model(texture));
+            overlay.tint = tint;
+            out.push(overlay);
+        }
+
+        if let Some(trim) = trim_texture(&stack, &asset_id, layer_type).filter(|_| !wings) {
+            let mut overlay = EquipmentOverlay::new(model(trim.sprite.clone()));
+            overlay.paletted = Some(trim);
+            out.push(overlay);
+        }
+
+        if has_glint(&stack) {
+            let mut overlay = EquipmentOverlay::new(model(GLINT_TEXTURE.to_string()));
+            overlay.glint = true;
+            out.push(overlay);
+        }
+    }
+
+    out
+}
+
+/// Where a player's cape goes given its chest slot: `None` when the slot
+/// holds wings, which hide the cape, else whether a chestplate pushes the
+/// cape out.
+pub(crate) fn cape_over_armor(resource_pack: &ResourcePack, block: &InputBlock) -> Option<bool> {
+    let Some(asset) = block
+        .properties
+        .get("chestplate")
+        .and_then(|s| ItemStack::parse(s))
+        .filter(|stack| fits_slot(stack, ArmorSlot::Chest))
+        .and_then(|stack| asset_id(&stack))
+        .and_then(|id| resource_pack.get_equipment_asset(&id))
+    else {
+        return Some(false);
+    };
+    if !asset.layers(WINGS_LAYER).is_empty() {
+        return None;
+    }
+    Some(!asset.layers(ArmorSlot::Chest.layer_type()).is_empty())
+}
+
+/// Equipment asset id of an armor item: the `equippable` component's
+/// `asset_id`, else the vanilla material of its id.
+fn asset_id(stack: &ItemStack) -> Option<String> {
+    if let Some(id) = stack
+        .component("equippable")
+        .and_then(|e| e.get("asset_id"))
+        .and_then(Value::as_str)
+    {
+        return Some(id.to_string());
+    }
+
+    let name = stack.id.strip_prefix("minecraft:")?;
+    match name {
+        "turtle_helmet" => return Some("minecraft:turtle_scute".to_string()),
+        "elytra" => return Some("minecraft:elytra".to_string()),
+        _ => {}
+    }
+    let (material, piece) = name.rsplit_once('_')?;
+    if !matches!(piece, "helmet" | "chestplate" | "leggings" | "boots") {
+        return None;
+    }
+    let asset = match material {
+        "golden" => "gold",
+        "leather" | "chainmail" | "iron" | "diamond" | "netherite" => material,
+        _ => return None,
+    };
+    Some(format!("minecraft:{}", asset))
+}
+
+/// Whether an item is worn in `slot`: the `equippable` component's `slot`,
+/// else the piece named by a vanilla item id. Items of neither kind are
+/// left to [`asset_id`].
+fn fits_slot(stack: &ItemStack, slot: ArmorSlot) -> bool {
+    if let Some(name) = stack
+        .component("equippable")
+        .and_then(|e| e.get("slot"))
+        .and_then(Value::as_str)
+    {
+        return name.strip_prefix("minecraft:").unwrap_or(name) == slot.name();
+    }
+    let name = stack.id.strip_prefix("minecraft:").unwrap_or(&stack.id);
+    let piece = match name {
+        "turtle_helmet" => ArmorSlot::Head,
+        "elytra" => ArmorSlot::Chest,
+        _ => match name.rsplit_once('_').map(|(_, piece)| piece) {
+            Some("helmet") => ArmorSlot::Head,
+            Some("chestplate") => ArmorSlot::Chest,
+            Some("leggings") => ArmorSlot::Legs,
+            Some("boots") => ArmorSlot::Feet,
+            _ => return true,
+        },
+    };
+    piece == slot
+}
+
+/// The `dyed_color` component: a packed RGB int or `{"rgb": int}`.
+fn dyed_color(stack: &ItemStack) -> Option<[f32; 3]> {
+    let color = stack.component("dyed_color")?;
+    parse_color(color.get("rgb").unwrap_or(color))
+}
+
+/// Glint from `enchantment_glint_override`, else from any enchantment
+/// (`{"sharpness": 1}` or the older `{"levels": {...}}`).
+fn has_glint(stack: &ItemStack) -> bool {
+    if let Some(glint) = stack.component("enchantment_glint_override") {
+        return glint.as_bool().unwrap_or(false) || glint.as_str() == Some("true");
+    }
+    match stack.component("enchantments") {
+        Some(Value::Object(map)) => match map.get("levels") {
+            Some(Value::Object(levels)) => !levels.is_empty(),
+            _ => !map.is_empty(),
+        },
+        _ => false,
+    }
+}
+
+/// The trim sprite of a piece: `trims/entity/<layer type>/<pattern>_<material>`,
+/// with the material's `_darker` palette on armor of the same material
+/// (vanilla's `override_armor_assets`), as the `armor_trims` atlas names it.
+fn trim_texture(stack: &ItemStack, asset_id: &str, layer_type: &str) -> Option<PalettedTexture> {
+    let trim = stack.component("trim")?;
+    let pattern = trim.get("pattern")?.as_str()?;
+    let material = trim.get("material")?.as_str()?;
+
+    let (namespace, pattern) = match pattern.split_once(':') {
+        Some(("minecraft", path)) => (None, path),
+        Some((ns, path)) => (Some(ns), path),
+        None => (None, pattern),
+    };
+    let material = material.rsplit(':').next().unwrap_or(material);
+    let mut palette = material.to_string();
+    if asset_id.rsplit(':').next() == Some(material) {
+        palette.push_str("_darker");
+    }
+
+    let texture = format!("trims/entity/{}/{}", layer_type, pattern);
+    let sprite = format!("{}_{}", texture, palette);
+    let located = |path: String| match namespace {
+        Some(ns) => format!("{}:{}", ns, path),
+        None => path,
+    };
+    Some(PalettedTexture {
+        sprite: located(sprite),
+        texture: located(texture),
+        palette_key: TRIM_PALETTE_KEY.to_string(),
+        palette: format!("trims/color_palettes/{}", palette),
+    })
+}
+
+/// A copy of the wearer's model with the slot's armor cubes in place of the
+/// wearer's own on the covered parts, and no cubes elsewhere.
+fn armor_model(wearer: &EntityModelDef, slot: ArmorSlot, texture_path: String) -> EntityModelDef {
+    let mut model = wearer.clone();
+    dress_parts(&mut model.parts, slot);
+    model.texture_path = texture_path;
+    model.texture_size = [64, 32];
+    model.is_opaque = false; // Armor textures have transparent regions
+    model
+}
+
+/// `ElytraModel` at rest (64x32): two wings spread 15° behind the wearer's
+/// back, added to the root of a copy of its model as `WingsLayer` draws them.
+fn wings_model(wearer: &EntityModelDef, texture_path: String) -> EntityModelDef {
+    let mut model = wearer.clone();
+    clear_cubes(&mut model.parts);
+    let spread = 15.0_f32.to_radians();
+    let wing = |name: &str, cube: EntityCube, x: f32, roll: f32| EntityPart {
+        name: name.into(),
+        cubes: vec![cube.inflated(1.0)],
+        pose: EntityPartPose {
+            position: [x, 0.0, 2.0],
+            rotation: [spread, 0.0, roll],
+            ..Default::default()
+        },
+        children: vec![],
+    };
+    if let Some(root) = model.parts.first_mut() {
+        root.children.push(wing(
+            "left_wing",
+            EntityCube::new([-10.0, 0.0, 0.0], [10.0, 20.0, 2.0], [22, 0]),
+            5.0,
+            -spread,
+        ));
+        root.children.push(wing(
+            "right_wing",
+            EntityCube::new([0.0, 0.0, 0.0], [10.0, 20.0, 2.0], [22, 0]).mirrored(),
+            -5.0,
+            spread,
+        ));
+    }
+    model.texture_path = texture_path;
+    model.texture_size = [64, 32];
+    model.is_opaque = false;
+    model
+}
+
+fn dress_parts(parts: &mut [EntityPart], slot: ArmorSlot) {
+    for part in parts {
+        part.cubes = armor_cube(&part.name, slot).into_iter().collect();
+        dress_parts(&mut part.children, slot);
+    }
+}
+
+/// The `HumanoidModel` cube of a part, inflated for the slot.
+fn armor_cube(part: &str, slot: ArmorSlot) -> Option<EntityCube> {
+    if !slot.covers(part) {
+        return None;
+    }
+    let cube = match part {
+        "head" => EntityCube::new([-4.0, -8.0, -4.0], [8.0, 8.0, 8.0], [0, 0]),
+        "body" => EntityCube::new([-4.0, 0.0, -2.0], [8.0, 12.0, 4.0], [16, 16]),
+        "right_arm" => EntityCube::new([-3.0, -2.0, -2.0], [4.0, 12.0, 4.0], [40, 16]),
+        "left_arm" => EntityCube::new([-1.0, -2.0, -2.0], [4.0, 12.0, 4.0], [40, 16]).mirrored(),
+        "right_leg" => EntityCube::new([-2.0, 0.0, -2.0], [4.0, 12.0, 4.0], [0, 16]),
+        "left_leg" => EntityCube::new([-2.0, 0.0, -2.0], [4.0, 12.0, 4.0], [0, 16]).mirrored(),
+        _ => return None,
+    };
+    Some(cube.inflated(slot.inflate()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesher::entity::mob::{build_mob_model, find_part_mut};
+    use crate::resource_pack::EquipmentAsset;
+
+    fn overlays(mob_type: MobType, props: &[(&str, &str)], pack: &ResourcePack) -> Vec<EquipmentOverlay> {
+        let mut block = InputBlock::new("entity:zombie");
+        for (key, value) in props {
+            block = block.with_property(*key, *value);
+        }
+        let wearer = build_mob_model(mob_type, &block, None);
+        armor_overlays(pack, &block, &wearer, None)
+    }
+
+    #[test]
+    fn test_armor_layers_dye_trim_and_glint() {
+        let pack = ResourcePack::new();
+        let layers = overlays(MobType::Zombie, &[
+            ("chestplate", r#"leather_chestplate[dyed_color=16711680,trim={"pattern":"minecraft:coast","material":"minecraft:gold"},enchantments={"protection":1}]"#),
+            ("helmet", r#"golden_helmet[trim={"pattern":"wild","material":"gold"}]"#),
+            ("leggings", "diamond_leggings"),
+            ("boots", "stick"),
+        ], &pack);
+        let textures: Vec<&str> = layers.iter().map(|o| o.model.texture_path.as_str()).collect();
+        assert_eq!(textures, [
+            "entity/equipment/humanoid/leather",
+            "entity/equipment/humanoid/leather_overlay",
+            "trims/entity/humanoid/coast_gold",
+            GLINT_TEXTURE,
+            "entity/equipment/humanoid_leggings/diamond",
+            "entity/equipment/humanoid/gold",
+            "trims/entity/humanoid/wild_gold_darker",
+        ]);
+
+        assert_eq!(layers[0].tint, Some([1.0, 0.0, 0.0]));
+        assert_eq!(layers[1].tint, None);
+        let trim = layers[2].paletted.as_ref().unwrap();
+        assert_eq!(trim.texture, "trims/entity/humanoid/coast");
+        assert_eq!(trim.palette, "trims/color_palettes/gold");
+        assert!(layers[3].glint && !layers[2].glint);
+        assert_eq!(layers[6].paletted.as_ref().unwrap().palette, "trims/color_palettes/gold_darker");
+
+        // Undyed leather uses the asset's undyed color.
+        let undyed = overlays(MobType::Zombie, &[("boots", "leather_boots")], &pack);
+        assert_eq!(undyed[0].tint, parse_color(&Value::from(0xA06540)));
+        assert!(!overlays(MobType::Zombie, &[("boots", "leather_boots[enchantment_glint_override=false,enchantments={\"levels\":{\"unbreaking\":1}}]")], &pack)
+            .iter()
+            .any(|o| o.glint));
+    }
+
+    #[test]
+    fn test_armor_from_pack_equipment_asset() {
+        let mut pack = ResourcePack::new();
+        let asset: EquipmentAsset = serde_json::from_str(r#"{"layers": {"humanoid": [
+            {"texture": "mypack:ruby"},
+            {"texture": "mypack:ruby_cloth", "dyeable": {}}
+        ]}}"#).unwrap();
+        pack.add_equipment_asset("mypack", "ruby", asset);
+
+        // The undyed, colorless dyeable layer is skipped; leggings have no layers.
+        let layers = overlays(MobType::Zombie, &[
+            ("chestplate", r#"mypack:ruby_chestplate[equippable={"slot":"chest","asset_id":"mypack:ruby"}]"#),
+            ("leggings", r#"mypack:ruby_leggings[equippable={"slot":"legs","asset_id":"mypack:ruby"}]"#),
+        ], &pack);
+        assert_eq!(layers.len(), 1);
+        assert_eq!(layers[0].model.texture_path, "mypack:entity/equipment/humanoid/ruby");
+    }
+
+    #[test]
+    fn test_armor_in_the_wrong_slot_is_skipped() {
+        let pack = ResourcePack::new();
+        assert!(overlays(MobType::Zombie, &[
+            ("helmet", "diamond_boots"),
+            ("leggings", "turtle_helmet"),
+            ("boots", r#"iron_boots[equippable={"slot":"head","asset_id":"minecraft:iron"}]"#),
+        ], &pack)
+        .is_empty());
+        let player = InputBlock::new("entity:player").with_property("chestplate", "iron_helmet");
+        assert_eq!(cape_over_armor(&pack, &player), Some(false));
+    }
+
+    #[test]
+    fn test_elytra_wings() {
+        let pack = ResourcePack::new();
+        let block = InputBlock::new("entity:zombie").with_property("chestplate", "elytra[enchantment_glint_override=true]");
+        let wearer = build_mob_model(MobType::Zombie, &block, None);
+
+        let mut layers = armor_overlays(&pack, &block, &wearer, Some("_player_cape/0_0_0"));
+        let textures: Vec<&str> = layers.iter().map(|o| o.model.texture_path.as_str()).collect();
+        assert_eq!(textures, ["_player_cape/0_0_0", GLINT_TEXTURE]);
+        let wings = &mut layers[0].model;
+        assert_eq!(wings.texture_size, [64, 32]);
+        assert!(find_part_mut(&mut wings.parts, "head").unwrap().cubes.is_empty());
+        let left = find_part_mut(&mut wings.parts, "left_wing").unwrap();
+        assert_eq!(left.cubes[0].inflate, 1.0);
+        assert!(find_part_mut(&mut wings.parts, "right_wing").unwrap().cubes[0].mirror);
+
+        let capeless = armor_overlays(&pack, &block, &wearer, None);
+        assert_eq!(capeless[0].model.texture_path, "entity/equipment/wings/elytra");
+
+        // Wings hide the cape; a chestplate pushes it out.
+        assert_eq!(cape_over_armor(&pack, &block), None);
+        let iron = InputBlock::new("entity:player").with_property("chestplate", "iron_chestplate");
+        assert_eq!(cape_over_armor(&pack, &iron), Some(true));
+        assert_eq!(cape_over_armor(&pack, &InputBlock::new("entity:player")), Some(false));
+    }
+
+    #[test]
+    fn test_armor_follows_wearer_pose() {
+        let pack = ResourcePack::new();
+        let block = InputBlock::new("entity:armor_stand")
+            .with_property("chestplate", "iron_chestplate")
+            .with_property("RightArmPose", "-90,0,0");
+        let mut stand = build_mob_model(MobType::ArmorStand, &block, None);
+        let mut layers = armor_overlays(&pack, &block, &stand, None);
+        assert_eq!(layers.len(), 1);
+
+        let armor = &mut layers[0].model;
+        assert_eq!(armor.texture_size, [64, 32]);
+        let arm = find_part_mut(&mut armor.parts, "right_arm").unwrap().clone();
+        let stand_arm = find_part_mut(&mut stand.parts, "right_arm").unwrap();
+        assert_eq!(arm.pose.rotation, stand_arm.pose.rotation);
+        assert_eq!(arm.cubes.len(), 1);
+        assert_eq!(arm.cubes[0].inflate, 1.0);
+        // Uncovered parts (head, legs, the stand's sticks) carry no cubes.
+        assert!(find_part_mut(&mut armor.parts, "head").unwrap().cubes.is_empty());
+        assert!(find_part_mut(&mut armor.parts, "base_plate").unwrap().cubes.is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::{mob_root, mob_variant};
+use crate::types::InputBlock;
+
+/// Axolotl model — texture `entity/axolotl/axolotl_<variant>`, 64x64.
+/// From AxolotlModel.java (MC 1.21.4). `variant`: `lucy` (default), `wild`,
+/// `gold`, `cyan` or `blue`, or the game's 0–4 id. Gills, legs and tail are
+/// flat planes. The legs are splayed out sideways (70°) like the
+/// resting-on-ground pose; the bind pose hangs them 2px below the floor.
+pub(super) fn axolotl_model(block: &InputBlock) -> EntityModelDef {
+    let head = EntityPart::posed(
+        "head",
+        [0.0, 0.0, -9.0],
+        [0.0, 0.0, 0.0],
+        vec![EntityCube::new([-4.0, -3.0, -5.0], [8.0, 5.0, 5.0], [0, 1])],
+        vec![
+            EntityPart::posed(
+                "top_gills",
+                [0.0, -3.0, -1.0],
+                [0.0, 0.0, 0.0],
+                vec![EntityCube::new([-4.0, -3.0, 0.0], [8.0, 3.0, 0.0], [3, 37])],
+                vec![],
+            ),
+            EntityPart::posed(
+                "left_gills",
+                [-4.0, 0.0, -1.0],
+                [0.0, 0.0, 0.0],
+                vec![EntityCube::new([-3.0, -5.0, 0.0], [3.0, 7.0, 0.0], [0, 40])],
+                vec![],
+            ),
+            EntityPart::posed(
+                "right_gills",
+                [4.0, 0.0, -1.0],
+                [0.0, 0.0, 0.0],
+                vec![EntityCube::new([0.0, -5.0, 0.0], [3.0, 7.0, 0.0], [11, 40])],
+                vec![],
+            ),
+        ],
+    );
+
+    let leg = |name: &str, x: f32, z: f32| {
+        let is_right = x < 0.0;
+        EntityPart::posed(
+            name,
+            [x, 1.0, z],
+            [0.0, 0.0, if is_right { 1.2 } else { -1.2 }],
+            vec![EntityCube::new([if is_right { -1.0 } else { -2.0 }, 0.0, 0.0], [3.0, 5.0, 0.0], [2, 13])],
+            vec![],
+        )
+    };
+
+    let tail = EntityPart::posed(
+        "tail",
+        [0.0, 0.0, 1.0],
+        [0.0, 0.0, 0.0],
+        vec![EntityCube::new([0.0, -3.0, 0.0], [0.0, 5.0, 12.0], [2, 19])],
+        vec![],
+    );
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 20.0, 5.0],
+        [0.0, 0.0, 0.0],
+        vec![
+            EntityCube::new([-4.0, -2.0, -9.0], [8.0, 4.0, 10.0], [0, 11]),
+            EntityCube::new([0.0, -3.0, -8.0], [0.0, 5.0, 9.0], [2, 17]),
+        ],
+        vec![
+            head,
+            leg("right_hind_leg", -3.5, -1.0),
+            leg("left_hind_leg", 3.5, -1.0),
+            leg("right_front_leg", -3.5, -8.0),
+            leg("left_front_leg", 3.5, -8.0),
+            tail,
+        ],
+    );
+
+    let variant = mob_variant(block, &["lucy", "wild", "gold", "cyan", "blue"]);
+
+    EntityModelDef {
+        texture_path: format!("entity/axolotl/axolotl_{}", variant),
+        texture_size: [64, 64],
+        parts: vec![mob_root(vec![body])],
+        is_opaque: false, // Gills and fins are cut-out planes
+    }
+}
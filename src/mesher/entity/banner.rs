@@ -19,6 +19,7 @@ pub(crate) fn banner_model(is_standing: bool, texture_path: &str) -> EntityModel
     // Pole (standing only)
     if is_standing {
         parts.push(EntityPart {
+            name: "pole".into(),
             cubes: vec![EntityCube {
                 origin: [-1.0, -42.0, -1.0],
                 dimensions: [2.0, 42.0, 2.0],
@@ -26,6 +27,7 @@ pub(crate) fn banner_model(is_standing: bool, texture_path: &str) -> EntityModel
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: Default::default(),
             children: vec![],
@@ -36,6 +38,7 @@ pub(crate) fn banner_model(is_standing: bool, texture_path: &str) -> EntityModel
     let bar_y = if is_standing { -44.0 } else { -20.5 };
     let bar_z = if is_standing { -1.0 } else { 9.5 };
     parts.push(EntityPart {
+        name: "bar".into(),
         cubes: vec![EntityCube {
             origin: [-10.0, bar_y, bar_z],
             dimensions: [20.0, 2.0, 2.0],
@@ -43,6 +46,7 @@ pub(crate) fn banner_model(is_standing: bool, texture_path: &str) -> EntityModel
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -52,6 +56,7 @@ pub(crate) fn banner_model(is_standing: bool, texture_path: &str) -> EntityModel
     let flag_offset_y = if is_standing { -44.0 } else { -20.5 };
     let flag_offset_z = if is_standing { 0.0 } else { 10.5 };
     parts.push(EntityPart {
+        name: "flag".into(),
         cubes: vec![EntityCube {
             origin: [-10.0, 0.0, -2.0],
             dimensions: [20.0, 40.0, 1.0],
@@ -59,6 +64,7 @@ pub(crate) fn banner_model(is_standing: bool, texture_path: &str) -> EntityModel
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, flag_offset_y, flag_offset_z],
@@ -74,6 +80,7 @@ pub(crate) fn banner_model(is_standing: bool, texture_path: &str) -> EntityModel
     // banner at the block's bottom, not its top.
     let scale = 2.0 / 3.0;
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 0.0, 8.0],
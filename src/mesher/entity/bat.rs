@@ -5,6 +5,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 pub(super) fn bat_model() -> EntityModelDef {
     // Ears are children of head.
     let right_ear = EntityPart {
+        name: "right_ear".into(),
         cubes: vec![EntityCube {
             origin: [-2.5, -4.0, 0.0],
             dimensions: [3.0, 5.0, 0.0],
@@ -12,6 +13,7 @@ pub(super) fn bat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-1.5, -2.0, 0.0],
@@ -21,6 +23,7 @@ pub(super) fn bat_model() -> EntityModelDef {
     };
 
     let left_ear = EntityPart {
+        name: "left_ear".into(),
         cubes: vec![EntityCube {
             origin: [-0.1, -3.0, 0.0],
             dimensions: [3.0, 5.0, 0.0],
@@ -28,6 +31,7 @@ pub(super) fn bat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [1.1, -3.0, 0.0],
@@ -37,6 +41,7 @@ pub(super) fn bat_model() -> EntityModelDef {
     };
 
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, -3.0, -1.0],
             dimensions: [4.0, 3.0, 2.0],
@@ -44,6 +49,7 @@ pub(super) fn bat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 17.0, 0.0],
@@ -54,6 +60,7 @@ pub(super) fn bat_model() -> EntityModelDef {
 
     // Wings and feet are children of body.
     let right_wing_tip = EntityPart {
+        name: "right_wing_tip".into(),
         cubes: vec![EntityCube {
             origin: [-6.0, -2.0, 0.0],
             dimensions: [6.0, 8.0, 0.0],
@@ -61,6 +68,7 @@ pub(super) fn bat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-2.0, 0.0, 0.0],
@@ -70,6 +78,7 @@ pub(super) fn bat_model() -> EntityModelDef {
     };
 
     let right_wing = EntityPart {
+        name: "right_wing".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, -2.0, 0.0],
             dimensions: [2.0, 7.0, 0.0],
@@ -77,6 +86,7 @@ pub(super) fn bat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-1.5, 0.0, 0.0],
@@ -86,6 +96,7 @@ pub(super) fn bat_model() -> EntityModelDef {
     };
 
     let left_wing_tip = EntityPart {
+        name: "left_wing_tip".into(),
         cubes: vec![EntityCube {
             origin: [0.0, -2.0, 0.0],
             dimensions: [6.0, 8.0, 0.0],
@@ -93,6 +104,7 @@ pub(super) fn bat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [2.0, 0.0, 0.0],
@@ -102,6 +114,7 @@ pub(super) fn bat_model() -> EntityModelDef {
     };
 
     let left_wing = EntityPart {
+        name: "left_wing".into(),
         cubes: vec![EntityCube {
             origin: [0.0, -2.0, 0.0],
             dimensions: [2.0, 7.0, 0.0],
@@ -109,6 +122,7 @@ pub(super) fn bat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [1.5, 0.0, 0.0],
@@ -118,6 +132,7 @@ pub(super) fn bat_model() -> EntityModelDef {
     };
 
     let feet = EntityPart {
+        name: "feet".into(),
         cubes: vec![EntityCube {
             origin: [-1.5, 0.0, 0.0],
             dimensions: [3.0, 2.0, 0.0],
@@ -125,6 +140,7 @@ pub(super) fn bat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 5.0, 0.0],
@@ -134,6 +150,7 @@ pub(super) fn bat_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-1.5, 0.0, -1.0],
             dimensions: [3.0, 5.0, 2.0],
@@ -141,6 +158,7 @@ pub(super) fn bat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 17.0, 0.0],
@@ -150,6 +168,7 @@ pub(super) fn bat_model() -> EntityModelDef {
     };
 
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
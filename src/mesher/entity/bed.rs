@@ -19,6 +19,7 @@ pub(super) fn bed_model(color: &str, is_head: bool) -> EntityModelDef {
     // foot skips Down (→-Z shared face).
     let shared_face = if is_head { Direction::Up } else { Direction::Down };
     let main = EntityPart {
+        name: "main".into(),
         cubes: vec![EntityCube {
             origin: [0.0, 0.0, 0.0],
             dimensions: [16.0, 16.0, 6.0],
@@ -26,6 +27,7 @@ pub(super) fn bed_model(color: &str, is_head: bool) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![shared_face],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 9.0, 0.0],
@@ -45,6 +47,7 @@ pub(super) fn bed_model(color: &str, is_head: bool) -> EntityModelDef {
     };
 
     let left_leg = EntityPart {
+        name: "left_leg".into(),
         cubes: vec![EntityCube {
             origin: [0.0, 0.0, leg_z],
             dimensions: [3.0, 3.0, 3.0],
@@ -52,12 +55,14 @@ pub(super) fn bed_model(color: &str, is_head: bool) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let right_leg = EntityPart {
+        name: "right_leg".into(),
         cubes: vec![EntityCube {
             origin: [13.0, 0.0, leg_z],
             dimensions: [3.0, 3.0, 3.0],
@@ -65,6 +70,7 @@ pub(super) fn bed_model(color: &str, is_head: bool) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
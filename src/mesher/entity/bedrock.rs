@@ -0,0 +1,376 @@
+//! Bedrock geometry (`.geo.json`) import.
+//!
+//! Converts Bedrock / Blockbench `minecraft:geometry` models into
+//! [`EntityModelDef`] so they render through the same path as the built-in
+//! Java models. Both the current format (`format_version` 1.12+, a
+//! `minecraft:geometry` array) and the legacy 1.8/1.10 format (top-level
+//! `geometry.<name>` keys) are read.
+//!
+//! Bedrock coordinates are absolute and Y-up, with bone pivots given in model
+//! space; Java parts are Y-down and positioned relative to their parent. The
+//! conversion is `(x, y, z) -> (x, 24 - y, z)` with pivots made relative, and
+//! rotations carry over unchanged (both formats apply them Z·Y·X). The result
+//! is wrapped in the same Y-down → Y-up root the built-in mobs use.
+
+use super::{EntityCube, EntityFaceUv, EntityModelDef, EntityPart, EntityPartPose};
+use crate::error::{MesherError, Result};
+use crate::types::Direction;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Parse every geometry in a `.geo.json` file.
+///
+/// Returns `(name, model)` pairs, where the name is the geometry identifier
+/// without its `geometry.` prefix (and without a legacy `:parent` suffix).
+/// The models have no texture path; Bedrock geometry doesn't name one.
+pub fn parse_geometry(json: &str) -> Result<Vec<(String, EntityModelDef)>> {
+    let root: serde_json::Value = serde_json::from_str(json)?;
+    let mut models = Vec::new();
+
+    if let Some(geometries) = root.get("minecraft:geometry") {
+        for geometry in Vec::<GeometryJson>::deserialize(geometries)? {
+            let description = geometry.description;
+            models.push((
+                geometry_name(&description.identifier),
+                convert(
+                    [description.texture_width, description.texture_height],
+                    &geometry.bones,
+                ),
+            ));
+        }
+    } else if let Some(object) = root.as_object() {
+        // Legacy format: one top-level key per geometry.
+        for (key, value) in object {
+            if !key.starts_with("geometry.") {
+                continue;
+            }
+            let geometry = LegacyGeometryJson::deserialize(value)?;
+            models.push((
+                geometry_name(key),
+                convert([geometry.texturewidth, geometry.textureheight], &geometry.bones),
+            ));
+        }
+    }
+
+    if models.is_empty() {
+        return Err(MesherError::EntityModel("no Bedrock geometry found".to_string()));
+    }
+    Ok(models)
+}
+
+/// `geometry.dragon:geometry.base` -> `dragon`.
+fn geometry_name(identifier: &str) -> String {
+    let name = identifier.split(':').next().unwrap_or(identifier);
+    name.strip_prefix("geometry.").unwrap_or(name).to_string()
+}
+
+// ── JSON Schema ─────────────────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct GeometryJson {
+    description: DescriptionJson,
+    #[serde(default)]
+    bones: Vec<BoneJson>,
+}
+
+#[derive(Deserialize)]
+struct DescriptionJson {
+    identifier: String,
+    #[serde(default = "default_texture_size")]
+    texture_width: u32,
+    #[serde(default = "default_texture_size")]
+    texture_height: u32,
+}
+
+#[derive(Deserialize)]
+struct LegacyGeometryJson {
+    #[serde(default = "default_texture_size")]
+    texturewidth: u32,
+    #[serde(default = "default_texture_size")]
+    textureheight: u32,
+    #[serde(default)]
+    bones: Vec<BoneJson>,
+}
+
+fn default_texture_size() -> u32 {
+    64
+}
+
+#[derive(Deserialize)]
+struct BoneJson {
+    name: String,
+    #[serde(default)]
+    parent: Option<String>,
+    #[serde(default)]
+    pivot: [f32; 3],
+    #[serde(default)]
+    rotation: [f32; 3],
+    #[serde(default)]
+    mirror: bool,
+    #[serde(default)]
+    inflate: f32,
+    #[serde(default, rename = "neverRender")]
+    never_render: bool,
+    #[serde(default)]
+    cubes: Vec<CubeJson>,
+}
+
+#[derive(Deserialize)]
+struct CubeJson {
+    origin: [f32; 3],
+    size: [f32; 3],
+    #[serde(default)]
+    uv: UvJson,
+    #[serde(default)]
+    inflate: Option<f32>,
+    #[serde(default)]
+    mirror: Option<bool>,
+    #[serde(default)]
+    pivot: Option<[f32; 3]>,
+    #[serde(default)]
+    rotation: Option<[f32; 3]>,
+}
+
+/// Box UV (`[u, v]`) or per-face UV (`{"north": {"uv": .., "uv_size": ..}}`).
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum UvJson {
+    Box([f32; 2]),
+    PerFace(HashMap<String, FaceUvJson>),
+}
+
+impl Default for UvJson {
+    fn default() -> Self {
+        UvJson::Box([0.0, 0.0])
+    }
+}
+
+#[derive(Deserialize)]
+struct FaceUvJson {
+    uv: [f32; 2],
+    #[serde(default)]
+    uv_size: Option<[f32; 2]>,
+}
+
+// ── Conversion ──────────────────────────────────────────────────────────────
+
+/// Bedrock model-space point to Java (Y-down) model space.
+fn to_java(p: [f32; 3]) -> [f32; 3] {
+    [p[0], 24.0 - p[1], p[2]]
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn radians(degrees: [f32; 3]) -> [f32; 3] {
+    degrees.map(f32::to_radians)
+}
+
+fn convert(texture_size: [u32; 2], bones: &[BoneJson]) -> EntityModelDef {
+    let index: HashMap<&str, usize> = bones.iter()
+        .enumerate()
+        .map(|(i, bone)| (bone.name.as_str(), i))
+        .collect();
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); bones.len()];
+    let mut roots = Vec::new();
+    for (i, bone) in bones.iter().enumerate() {
+        match bone.parent.as_deref().and_then(|p| index.get(p)) {
+            Some(&parent) if parent != i => children[parent].push(i),
+            _ => roots.push(i),
+        }
+    }
+
+    let mut visited = vec![false; bones.len()];
+    let parts = roots.iter()
+        .filter_map(|&i| build_part(i, [0.0, 0.0, 0.0], bones, &children, &mut visited))
+        .collect();
+
+    // Y-down → Y-up root wrapper, as in the built-in mob models.
+    let root = EntityPart {
+        name: "root".into(),
+        cubes: vec![],
+        pose: EntityPartPose {
+            position: [8.0, 24.0, 8.0],
+            rotation: [std::f32::consts::PI, 0.0, 0.0],
+            ..Default::default()
+        },
+        children: parts,
+    };
+
+    EntityModelDef {
+        texture_path: String::new(),
+        texture_size,
+        parts: vec![root],
+        is_opaque: false,
+    }
+}
+
+/// Build a part for bone `i`, positioned relative to its parent's pivot
+/// (both in Java space). `visited` guards against parent cycles.
+fn build_part(
+    i: usize,
+    parent_pivot: [f32; 3],
+    bones: &[BoneJson],
+    children: &[Vec<usize>],
+    visited: &mut [bool],
+) -> Option<EntityPart> {
+    if std::mem::replace(&mut visited[i], true) {
+        return None;
+    }
+    let bone = &bones[i];
+    let pivot = to_java(bone.pivot);
+
+    let mut cubes = Vec::new();
+    let mut rotated = Vec::new();
+    if !bone.never_render {
+        for cube in &bone.cubes {
+            match (cube.rotation, cube.pivot) {
+                // Cubes with their own rotation become a child part pivoting
+                // around the cube's pivot (Bedrock defaults it to the cube center).
+                (Some(rotation), cube_pivot) if rotation != [0.0; 3] => {
+                    let cube_pivot = to_java(cube_pivot.unwrap_or([
+                        cube.origin[0] + cube.size[0] / 2.0,
+                        cube.origin[1] + cube.size[1] / 2.0,
+                        cube.origin[2] + cube.size[2] / 2.0,
+                    ]));
+                    rotated.push(EntityPart {
+                        name: format!("{}_cube{}", bone.name, rotated.len()),
+                        cubes: vec![convert_cube(cube, bone, cube_pivot)],
+                        pose: EntityPartPose {
+                            position: sub(cube_pivot, pivot),
+                            rotation: radians(rotation),
+                            ..Default::default()
+                        },
+                        children: vec![],
+                    });
+                }
+                _ => cubes.push(convert_cube(cube, bone, pivot)),
+            }
+        }
+    }
+
+    let mut parts: Vec<EntityPart> = children[i].iter()
+        .filter_map(|&c| build_part(c, pivot, bones, children, visited))
+        .collect();
+    rotated.append(&mut parts);
+
+    Some(EntityPart {
+        name: bone.name.clone(),
+        cubes,
+        pose: EntityPartPose {
+            position: sub(pivot, parent_pivot),
+            rotation: radians(bone.rotation),
+            ..Default::default()
+        },
+        children: rotated,
+    })
+}
+
+/// Convert a cube to Java space relative to `pivot`.
+fn convert_cube(cube: &CubeJson, bone: &BoneJson, pivot: [f32; 3]) -> EntityCube {
+    // The Bedrock origin is the minimum corner; after the Y flip the Java
+    // minimum is the old top.
+    let min = to_java([cube.origin[0], cube.origin[1] + cube.size[1], cube.origin[2]]);
+
+    let (tex_offset, face_uvs) = match &cube.uv {
+        UvJson::Box(uv) => ([uv[0].round().max(0.0) as u32, uv[1].round().max(0.0) as u32], vec![]),
+        UvJson::PerFace(faces) => ([0, 0], convert_face_uvs(faces, cube.size)),
+    };
+
+    EntityCube {
+        inflate: cube.inflate.unwrap_or(bone.inflate),
+        mirror: cube.mirror.unwrap_or(bone.mirror),
+        face_uvs,
+        ..EntityCube::new(sub(min, pivot), cube.size, tex_offset)
+    }
+}
+
+/// Per-face UVs keyed by Bedrock face name. Faces are named in Bedrock's
+/// mirrored X convention and for Y-up, so east/west and up/down swap in Java
+/// space, and Bedrock's up/down rectangles are stored rotated 180°.
+fn convert_face_uvs(faces: &HashMap<String, FaceUvJson>, size: [f32; 3]) -> Vec<EntityFaceUv> {
+    let [w, h, d] = size;
+    let order = [
+        ("up", Direction::Down, [w, d]),
+        ("down", Direction::Up, [w, d]),
+        ("north", Direction::North, [w, h]),
+        ("south", Direction::South, [w, h]),
+        ("east", Direction::West, [d, h]),
+        ("west", Direction::East, [d, h]),
+    ];
+    order.iter()
+        .filter_map(|&(name, face, default_size)| {
+            let face_uv = faces.get(name)?;
+            let [su, sv] = face_uv.uv_size.unwrap_or(default_size);
+            let [u, v] = face_uv.uv;
+            let (uv, uv_size) = if matches!(face, Direction::Up | Direction::Down) {
+                ([u + su, v + sv], [-su, -sv])
+            } else {
+                ([u, v], [su, sv])
+            };
+            Some(EntityFaceUv { face, uv, uv_size })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COW_HEAD: &str = r#"{
+        "format_version": "1.12.0",
+        "minecraft:geometry": [{
+            "description": { "identifier": "geometry.cow", "texture_width": 64, "texture_height": 32 },
+            "bones": [
+                { "name": "body", "pivot": [0, 19, 2] },
+                { "name": "head", "parent": "body", "pivot": [0, 20, -8], "rotation": [10, 0, 0],
+                  "cubes": [{ "origin": [-4, 16, -14], "size": [8, 8, 6], "uv": [0, 0] }] }
+            ]
+        }]
+    }"#;
+
+    #[test]
+    fn test_bedrock_bones_become_relative_java_parts() {
+        let models = parse_geometry(COW_HEAD).unwrap();
+        assert_eq!(models.len(), 1);
+        let (name, model) = &models[0];
+        assert_eq!(name, "cow");
+        assert_eq!(model.texture_size, [64, 32]);
+
+        let body = &model.parts[0].children[0];
+        assert_eq!(body.pose.position, [0.0, 5.0, 2.0]);
+        let head = &body.children[0];
+        // Java CowModel: head at (0, 4, -8), box (-4, -4, -6, 8, 8, 6).
+        assert_eq!(head.pose.position, [0.0, -1.0, -10.0]);
+        assert!((head.pose.rotation[0] - 10f32.to_radians()).abs() < 1e-6);
+        assert_eq!(head.cubes[0].origin, [-4.0, -4.0, -6.0]);
+        assert_eq!(head.cubes[0].dimensions, [8.0, 8.0, 6.0]);
+    }
+
+    #[test]
+    fn test_legacy_format_and_per_face_uv() {
+        let models = parse_geometry(r#"{
+            "format_version": "1.10.0",
+            "geometry.statue:geometry.base": {
+                "texturewidth": 32, "textureheight": 32,
+                "bones": [{ "name": "base", "pivot": [0, 0, 0], "cubes": [{
+                    "origin": [-2, 0, -2], "size": [4, 4, 4],
+                    "uv": { "north": { "uv": [0, 0], "uv_size": [4, 4] }, "up": { "uv": [4, 0] } }
+                }] }]
+            }
+        }"#).unwrap();
+        let (name, model) = &models[0];
+        assert_eq!(name, "statue");
+        let cube = &model.parts[0].children[0].cubes[0];
+        assert_eq!(cube.origin, [-2.0, -4.0, -2.0]);
+        assert_eq!(cube.face_uvs.len(), 2);
+        assert_eq!(cube.face_uvs[0], EntityFaceUv { face: Direction::Down, uv: [8.0, 4.0], uv_size: [-4.0, -4.0] });
+        assert_eq!(cube.face_uvs[1].face, Direction::North);
+    }
+
+    #[test]
+    fn test_rejects_files_without_geometry() {
+        assert!(parse_geometry(r#"{ "format_version": "1.12.0" }"#).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+use crate::types::InputBlock;
+
+/// Bee model — texture `entity/bee/bee`, 64x64.
+/// From BeeModel.java (MC 1.21.4). Everything hangs off a `bone` part at
+/// y=19; the legs are flat 7x2 planes. `angry=true` and `nectar=true` pick
+/// the `bee_angry` / `bee_nectar` / `bee_angry_nectar` textures.
+pub(super) fn bee_model(block: &InputBlock) -> EntityModelDef {
+    let flag = |key: &str| block.properties.get(key).map(|v| v == "true").unwrap_or(false);
+
+    let antenna = |name: &str, x: f32, tex_offset: [u32; 2]| {
+        EntityPart::posed(
+            name,
+            [0.0, -2.0, -5.0],
+            [0.0, 0.0, 0.0],
+            vec![EntityCube::new([x, -2.0, -3.0], [1.0, 2.0, 3.0], tex_offset)],
+            vec![],
+        )
+    };
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0],
+        vec![EntityCube::new([-3.5, -4.0, -5.0], [7.0, 7.0, 10.0], [0, 0])],
+        vec![
+            EntityPart::posed(
+                "stinger",
+                [0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0],
+                vec![EntityCube::new([0.0, -1.0, 5.0], [0.0, 1.0, 2.0], [26, 7])],
+                vec![],
+            ),
+            antenna("left_antenna", 1.5, [2, 0]),
+            antenna("right_antenna", -2.5, [2, 3]),
+        ],
+    );
+
+    let right_wing = EntityPart::posed(
+        "right_wing",
+        [-1.5, -4.0, -3.0],
+        [0.0, -0.2618, 0.0],
+        vec![EntityCube::new([-9.0, 0.0, 0.0], [9.0, 0.0, 6.0], [0, 18]).inflated(0.001)],
+        vec![],
+    );
+    let left_wing = EntityPart::posed(
+        "left_wing",
+        [1.5, -4.0, -3.0],
+        [0.0, 0.2618, 0.0],
+        vec![EntityCube::new([0.0, 0.0, 0.0], [9.0, 0.0, 6.0], [0, 18]).inflated(0.001).mirrored()],
+        vec![],
+    );
+
+    let legs = |name: &str, z: f32, v: u32| {
+        EntityPart::posed(
+            name,
+            [1.5, 3.0, z],
+            [0.0, 0.0, 0.0],
+            vec![EntityCube::new([-5.0, 0.0, 0.0], [7.0, 2.0, 0.0], [26, v])],
+            vec![],
+        )
+    };
+
+    let bone = EntityPart::posed(
+        "bone",
+        [0.0, 19.0, 0.0],
+        [0.0, 0.0, 0.0],
+        vec![],
+        vec![
+            body,
+            right_wing,
+            left_wing,
+            legs("front_legs", -2.0, 1),
+            legs("middle_legs", 0.0, 3),
+            legs("back_legs", 2.0, 5),
+        ],
+    );
+
+    let texture_path = match (flag("angry"), flag("nectar")) {
+        (true, true) => "entity/bee/bee_angry_nectar",
+        (true, false) => "entity/bee/bee_angry",
+        (false, true) => "entity/bee/bee_nectar",
+        (false, false) => "entity/bee/bee",
+    };
+
+    EntityModelDef {
+        texture_path: texture_path.to_string(),
+        texture_size: [64, 64],
+        parts: vec![mob_root(vec![bone])],
+        is_opaque: false, // Wings are translucent
+    }
+}
@@ -4,6 +4,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 pub(super) fn bell_model() -> EntityModelDef {
     // Bell body: 6x7x6 at texOffs(0,0)
     let bell_body = EntityPart {
+        name: "bell_body".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, -7.0, -3.0],
             dimensions: [6.0, 7.0, 6.0],
@@ -11,6 +12,7 @@ pub(super) fn bell_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [8.0, 12.0, 8.0],
@@ -21,6 +23,7 @@ pub(super) fn bell_model() -> EntityModelDef {
 
     // Bell lip: 8x2x8 at bottom of body
     let bell_lip = EntityPart {
+        name: "bell_lip".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -9.0, -4.0],
             dimensions: [8.0, 2.0, 8.0],
@@ -28,6 +31,7 @@ pub(super) fn bell_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [8.0, 12.0, 8.0],
@@ -10,6 +10,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 /// vanilla seeds them with.
 pub(super) fn blaze_model() -> EntityModelDef {
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -4.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -17,6 +18,7 @@ pub(super) fn blaze_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -39,7 +41,8 @@ pub(super) fn blaze_model() -> EntityModelDef {
         [2.27, 10.6143, -4.455],
     ];
 
-    let rods = RODS.iter().map(|offset| EntityPart {
+    let rods = RODS.iter().enumerate().map(|(i, offset)| EntityPart {
+        name: format!("part{}", i),
         cubes: vec![EntityCube {
             origin: [0.0, 0.0, 0.0],
             dimensions: [2.0, 8.0, 2.0],
@@ -47,6 +50,7 @@ pub(super) fn blaze_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: *offset,
@@ -59,6 +63,7 @@ pub(super) fn blaze_model() -> EntityModelDef {
     // -4..4, which lands its top at 1.75 blocks over the feet — a blaze is
     // 1.8 tall, and the rods wrap the body below it.
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
@@ -15,6 +15,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
     const PADDLE_Y_RIGHT: f32 = std::f32::consts::PI - PADDLE_Y_LEFT;
 
     let bottom = EntityPart {
+        name: "bottom".into(),
         cubes: vec![EntityCube {
             origin: [-14.0, -9.0, -3.0],
             dimensions: [28.0, 16.0, 3.0],
@@ -22,6 +23,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 3.0, 1.0],
@@ -32,6 +34,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
     };
 
     let back = EntityPart {
+        name: "back".into(),
         cubes: vec![EntityCube {
             origin: [-13.0, -7.0, -1.0],
             dimensions: [18.0, 6.0, 2.0],
@@ -39,6 +42,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-15.0, 4.0, 4.0],
@@ -49,6 +53,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
     };
 
     let front = EntityPart {
+        name: "front".into(),
         cubes: vec![EntityCube {
             origin: [-8.0, -7.0, -1.0],
             dimensions: [16.0, 6.0, 2.0],
@@ -56,6 +61,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [15.0, 4.0, 0.0],
@@ -66,6 +72,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
     };
 
     let right_plank = EntityPart {
+        name: "right_plank".into(),
         cubes: vec![EntityCube {
             origin: [-14.0, -7.0, -1.0],
             dimensions: [28.0, 6.0, 2.0],
@@ -73,6 +80,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 4.0, -9.0],
@@ -83,6 +91,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
     };
 
     let left_plank = EntityPart {
+        name: "left_plank".into(),
         cubes: vec![EntityCube {
             origin: [-14.0, -7.0, -1.0],
             dimensions: [28.0, 6.0, 2.0],
@@ -90,6 +99,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 4.0, 9.0],
@@ -100,6 +110,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
 
     // Paddles have two cubes each — shaft + blade.
     let left_paddle = EntityPart {
+        name: "left_paddle".into(),
         cubes: vec![
             EntityCube {
                 origin: [-1.0, 0.0, -5.0],
@@ -108,6 +119,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
             EntityCube {
                 origin: [-1.001, -3.0, 8.0],
@@ -116,6 +128,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
         ],
         pose: EntityPartPose {
@@ -127,6 +140,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
     };
 
     let right_paddle = EntityPart {
+        name: "right_paddle".into(),
         cubes: vec![
             EntityCube {
                 origin: [-1.0, 0.0, -5.0],
@@ -135,6 +149,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
             EntityCube {
                 origin: [0.001, -3.0, 8.0],
@@ -143,6 +158,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
         ],
         pose: EntityPartPose {
@@ -157,6 +173,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
 
     if is_chest {
         parts.push(EntityPart {
+            name: "chest_bottom".into(),
             cubes: vec![EntityCube {
                 origin: [0.0, 0.0, 0.0],
                 dimensions: [12.0, 8.0, 12.0],
@@ -164,6 +181,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [-2.0, -5.0, -6.0],
@@ -173,6 +191,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
             children: vec![],
         });
         parts.push(EntityPart {
+            name: "chest_lid".into(),
             cubes: vec![EntityCube {
                 origin: [0.0, 0.0, 0.0],
                 dimensions: [12.0, 4.0, 12.0],
@@ -180,6 +199,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [-2.0, -9.0, -6.0],
@@ -189,6 +209,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
             children: vec![],
         });
         parts.push(EntityPart {
+            name: "chest_lock".into(),
             cubes: vec![EntityCube {
                 origin: [0.0, 0.0, 0.0],
                 dimensions: [2.0, 4.0, 1.0],
@@ -196,6 +217,7 @@ pub(crate) fn boat_model(wood: &str, is_chest: bool) -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [-1.0, -6.0, -1.0],
@@ -14,6 +14,7 @@ pub(super) fn book_model() -> EntityModelDef {
 
     // Left lid: yRot = PI + f5.
     let left_lid = EntityPart {
+        name: "left_lid".into(),
         cubes: vec![EntityCube {
             origin: [-6.0, -5.0, -0.005],
             dimensions: [6.0, 10.0, 0.005],
@@ -21,6 +22,7 @@ pub(super) fn book_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 0.0, -1.0],
@@ -32,6 +34,7 @@ pub(super) fn book_model() -> EntityModelDef {
 
     // Right lid: yRot = -f5.
     let right_lid = EntityPart {
+        name: "right_lid".into(),
         cubes: vec![EntityCube {
             origin: [0.0, -5.0, -0.005],
             dimensions: [6.0, 10.0, 0.005],
@@ -39,6 +42,7 @@ pub(super) fn book_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 0.0, 1.0],
@@ -50,6 +54,7 @@ pub(super) fn book_model() -> EntityModelDef {
 
     // Seam (spine): has a base PartPose rotation of (0, PI/2, 0) in MC.
     let seam = EntityPart {
+        name: "seam".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -5.0, 0.0],
             dimensions: [2.0, 10.0, 0.005],
@@ -57,6 +62,7 @@ pub(super) fn book_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             rotation: [0.0, std::f32::consts::FRAC_PI_2, 0.0],
@@ -67,6 +73,7 @@ pub(super) fn book_model() -> EntityModelDef {
 
     // Left pages: yRot = f5, x = sin(f5).
     let left_pages = EntityPart {
+        name: "left_pages".into(),
         cubes: vec![EntityCube {
             origin: [0.0, -4.0, -0.99],
             dimensions: [5.0, 8.0, 1.0],
@@ -74,6 +81,7 @@ pub(super) fn book_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [SIN_F5, 0.0, 0.0],
@@ -85,6 +93,7 @@ pub(super) fn book_model() -> EntityModelDef {
 
     // Right pages: yRot = -f5, x = sin(f5).
     let right_pages = EntityPart {
+        name: "right_pages".into(),
         cubes: vec![EntityCube {
             origin: [0.0, -4.0, -0.01],
             dimensions: [5.0, 8.0, 1.0],
@@ -92,6 +101,7 @@ pub(super) fn book_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [SIN_F5, 0.0, 0.0],
@@ -103,6 +113,7 @@ pub(super) fn book_model() -> EntityModelDef {
 
     // Flip pages: yRot = f5 - f5*2*flipAmount, x = sin(f5).
     let flip_page1 = EntityPart {
+        name: "flip_page1".into(),
         cubes: vec![EntityCube {
             origin: [0.0, -4.0, 0.0],
             dimensions: [5.0, 8.0, 0.005],
@@ -110,6 +121,7 @@ pub(super) fn book_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [SIN_F5, 0.0, 0.0],
@@ -120,6 +132,7 @@ pub(super) fn book_model() -> EntityModelDef {
     };
 
     let flip_page2 = EntityPart {
+        name: "flip_page2".into(),
         cubes: vec![EntityCube {
             origin: [0.0, -4.0, 0.0],
             dimensions: [5.0, 8.0, 0.005],
@@ -127,6 +140,7 @@ pub(super) fn book_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [SIN_F5, 0.0, 0.0],
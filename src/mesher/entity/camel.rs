@@ -0,0 +1,61 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+
+/// Camel model — texture `entity/camel/camel`, 128x128.
+/// From CamelModel.java (MC 1.21.4), standing. Hump, tail and the long
+/// head/neck/snout hang off the body; ears are children of the head. The
+/// saddle and reins are equipment layers and left out.
+pub(super) fn camel_model() -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+    let part = |name: &str, position: [f32; 3], cube: EntityCube| {
+        EntityPart::posed(name, position, no_rotation, vec![cube], vec![])
+    };
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, -3.0, -19.5],
+        no_rotation,
+        vec![
+            EntityCube::new([-3.5, -7.0, -15.0], [7.0, 8.0, 19.0], [60, 24]),
+            EntityCube::new([-3.5, -21.0, -15.0], [7.0, 14.0, 7.0], [21, 0]),
+            EntityCube::new([-2.5, -21.0, -21.0], [5.0, 5.0, 6.0], [50, 0]),
+        ],
+        vec![
+            part("left_ear", [3.0, -21.0, -9.5],
+                EntityCube::new([-0.5, 0.5, -1.0], [3.0, 1.0, 2.0], [45, 0])),
+            part("right_ear", [-3.0, -21.0, -9.5],
+                EntityCube::new([-2.5, 0.5, -1.0], [3.0, 1.0, 2.0], [67, 0])),
+        ],
+    );
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 4.0, 9.5],
+        no_rotation,
+        vec![EntityCube::new([-7.5, -12.0, -23.5], [15.0, 12.0, 27.0], [0, 25])],
+        vec![
+            part("hump", [0.0, -12.0, -10.0],
+                EntityCube::new([-4.5, -5.0, -5.5], [9.0, 5.0, 11.0], [74, 0])),
+            part("tail", [0.0, -9.0, 3.5],
+                EntityCube::new([-1.5, 0.0, 0.0], [3.0, 14.0, 0.0], [122, 0])),
+            head,
+        ],
+    );
+
+    let leg = |name: &str, x: f32, z: f32, tex_offset: [u32; 2]| {
+        part(name, [x, 1.0, z], EntityCube::new([-2.5, 2.0, -2.5], [5.0, 21.0, 5.0], tex_offset))
+    };
+
+    EntityModelDef {
+        texture_path: "entity/camel/camel".to_string(),
+        texture_size: [128, 128],
+        parts: vec![mob_root(vec![
+            body,
+            leg("left_hind_leg", 4.9, 9.5, [58, 16]),
+            leg("right_hind_leg", -4.9, 9.5, [94, 16]),
+            leg("left_front_leg", 4.9, -10.5, [0, 0]),
+            leg("right_front_leg", -4.9, -10.5, [0, 26]),
+        ])],
+        is_opaque: false, // Tail is a cut-out plane
+    }
+}
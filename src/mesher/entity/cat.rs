@@ -6,6 +6,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 pub(super) fn cat_model() -> EntityModelDef {
     // Head has 4 cubes in one part: main skull, nose, two ears.
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![
             EntityCube {
                 origin: [-2.5, -2.0, -3.0],
@@ -14,6 +15,7 @@ pub(super) fn cat_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
             EntityCube {
                 origin: [-1.5, -0.001, -4.0],
@@ -22,6 +24,7 @@ pub(super) fn cat_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
             EntityCube {
                 origin: [-2.0, -3.0, 0.0],
@@ -30,6 +33,7 @@ pub(super) fn cat_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
             EntityCube {
                 origin: [1.0, -3.0, 0.0],
@@ -38,6 +42,7 @@ pub(super) fn cat_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
         ],
         pose: EntityPartPose {
@@ -48,6 +53,7 @@ pub(super) fn cat_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 3.0, -8.0],
             dimensions: [4.0, 16.0, 6.0],
@@ -55,6 +61,7 @@ pub(super) fn cat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 12.0, -10.0],
@@ -65,6 +72,7 @@ pub(super) fn cat_model() -> EntityModelDef {
     };
 
     let tail1 = EntityPart {
+        name: "tail1".into(),
         cubes: vec![EntityCube {
             origin: [-0.5, 0.0, 0.0],
             dimensions: [1.0, 8.0, 1.0],
@@ -72,6 +80,7 @@ pub(super) fn cat_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 15.0, 8.0],
@@ -83,6 +92,7 @@ pub(super) fn cat_model() -> EntityModelDef {
 
     // tail2: MC's model has no rotation — 1.7278 was animation state leaking in.
     let tail2 = EntityPart {
+        name: "tail2".into(),
         cubes: vec![EntityCube {
             origin: [-0.5, 0.0, 0.0],
             dimensions: [1.0, 8.0, 1.0],
@@ -90,6 +100,7 @@ pub(super) fn cat_model() -> EntityModelDef {
             inflate: -0.02,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 20.0, 14.0],
@@ -99,8 +110,9 @@ pub(super) fn cat_model() -> EntityModelDef {
     };
 
     // Hind legs: shorter (6 tall), box(-1, 0, 1, 2, 6, 2), tex(8, 13).
-    fn hind_leg(x: f32) -> EntityPart {
+    fn hind_leg(name: &str, x: f32) -> EntityPart {
         EntityPart {
+            name: name.into(),
             cubes: vec![EntityCube {
                 origin: [-1.0, 0.0, 1.0],
                 dimensions: [2.0, 6.0, 2.0],
@@ -108,6 +120,7 @@ pub(super) fn cat_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [x, 18.0, 5.0],
@@ -118,8 +131,9 @@ pub(super) fn cat_model() -> EntityModelDef {
     }
 
     // Front legs: taller (10 tall), box(-1, 0, 0, 2, 10, 2), tex(40, 0).
-    fn front_leg(x: f32) -> EntityPart {
+    fn front_leg(name: &str, x: f32) -> EntityPart {
         EntityPart {
+            name: name.into(),
             cubes: vec![EntityCube {
                 origin: [-1.0, 0.0, 0.0],
                 dimensions: [2.0, 10.0, 2.0],
@@ -127,6 +141,7 @@ pub(super) fn cat_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [x, 14.1, -5.0],
@@ -136,14 +151,15 @@ pub(super) fn cat_model() -> EntityModelDef {
         }
     }
 
-    let right_hind_leg = hind_leg(-1.1);
-    let left_hind_leg  = hind_leg(1.1);
-    let right_front_leg = front_leg(-1.2);
-    let left_front_leg  = front_leg(1.2);
+    let right_hind_leg = hind_leg("right_hind_leg", -1.1);
+    let left_hind_leg  = hind_leg("left_hind_leg", 1.1);
+    let right_front_leg = front_leg("right_front_leg", -1.2);
+    let left_front_leg  = front_leg("left_front_leg", 1.2);
 
     // CatModel applies MeshTransformer.scaling(0.8). Feet land at model y=24,
     // post-scale at y=19.2, so the root translate matches.
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 19.2, 8.0],
@@ -14,6 +14,7 @@ pub(super) fn chest_model(variant: ChestVariant) -> EntityModelDef {
 
     // Bottom: origin [1,0,1], dims [14,10,14], texOffs(0,19)
     let bottom = EntityPart {
+        name: "bottom".into(),
         cubes: vec![EntityCube {
             origin: [1.0, 0.0, 1.0],
             dimensions: [14.0, 10.0, 14.0],
@@ -21,6 +22,7 @@ pub(super) fn chest_model(variant: ChestVariant) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -28,6 +30,7 @@ pub(super) fn chest_model(variant: ChestVariant) -> EntityModelDef {
 
     // Lid: origin [1,0,0], dims [14,5,14], texOffs(0,0), pose position=[0,9,1]
     let lid = EntityPart {
+        name: "lid".into(),
         cubes: vec![EntityCube {
             origin: [1.0, 0.0, 0.0],
             dimensions: [14.0, 5.0, 14.0],
@@ -35,6 +38,7 @@ pub(super) fn chest_model(variant: ChestVariant) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 9.0, 1.0],
@@ -45,6 +49,7 @@ pub(super) fn chest_model(variant: ChestVariant) -> EntityModelDef {
 
     // Lock: origin [7,-1,15], dims [2,4,1], texOffs(0,0), pose position=[0,8,0]
     let lock = EntityPart {
+        name: "lock".into(),
         cubes: vec![EntityCube {
             origin: [7.0, -1.0, 15.0],
             dimensions: [2.0, 4.0, 1.0],
@@ -52,6 +57,7 @@ pub(super) fn chest_model(variant: ChestVariant) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 8.0, 0.0],
@@ -90,6 +96,7 @@ pub(super) fn double_chest_model(variant: ChestVariant, side: DoubleChestSide) -
     };
 
     let bottom = EntityPart {
+        name: "bottom".into(),
         cubes: vec![EntityCube {
             origin: bottom_origin,
             dimensions: [15.0, 10.0, 14.0],
@@ -97,12 +104,14 @@ pub(super) fn double_chest_model(variant: ChestVariant, side: DoubleChestSide) -
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let lid = EntityPart {
+        name: "lid".into(),
         cubes: vec![EntityCube {
             origin: lid_origin,
             dimensions: [15.0, 5.0, 14.0],
@@ -110,6 +119,7 @@ pub(super) fn double_chest_model(variant: ChestVariant, side: DoubleChestSide) -
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 9.0, 1.0],
@@ -119,6 +129,7 @@ pub(super) fn double_chest_model(variant: ChestVariant, side: DoubleChestSide) -
     };
 
     let lock = EntityPart {
+        name: "lock".into(),
         cubes: vec![EntityCube {
             origin: lock_origin,
             dimensions: [lock_dims[0], lock_dims[1], lock_dims[2]],
@@ -126,6 +137,7 @@ pub(super) fn double_chest_model(variant: ChestVariant, side: DoubleChestSide) -
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 8.0, 0.0],
@@ -4,6 +4,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 /// From ChickenModel.java (MC 1.21.4).
 pub(super) fn chicken_model() -> EntityModelDef {
     let beak = EntityPart {
+        name: "beak".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, -4.0, -4.0],
             dimensions: [4.0, 2.0, 2.0],
@@ -11,12 +12,14 @@ pub(super) fn chicken_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let wattle = EntityPart {
+        name: "wattle".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -2.0, -3.0],
             dimensions: [2.0, 2.0, 2.0],
@@ -24,12 +27,14 @@ pub(super) fn chicken_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, -6.0, -2.0],
             dimensions: [4.0, 6.0, 3.0],
@@ -37,6 +42,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 15.0, -4.0],
@@ -46,6 +52,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, -4.0, -3.0],
             dimensions: [6.0, 8.0, 6.0],
@@ -53,6 +60,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 16.0, 0.0],
@@ -63,6 +71,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
     };
 
     let right_leg = EntityPart {
+        name: "right_leg".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, 0.0, -3.0],
             dimensions: [3.0, 5.0, 3.0],
@@ -70,6 +79,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-2.0, 19.0, 1.0],
@@ -79,6 +89,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
     };
 
     let left_leg = EntityPart {
+        name: "left_leg".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, 0.0, -3.0],
             dimensions: [3.0, 5.0, 3.0],
@@ -86,6 +97,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [1.0, 19.0, 1.0],
@@ -95,6 +107,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
     };
 
     let right_wing = EntityPart {
+        name: "right_wing".into(),
         cubes: vec![EntityCube {
             origin: [0.0, 0.0, -3.0],
             dimensions: [1.0, 4.0, 6.0],
@@ -102,6 +115,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-4.0, 13.0, 0.0],
@@ -111,6 +125,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
     };
 
     let left_wing = EntityPart {
+        name: "left_wing".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, 0.0, -3.0],
             dimensions: [1.0, 4.0, 6.0],
@@ -118,6 +133,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [4.0, 13.0, 0.0],
@@ -128,6 +144,7 @@ pub(super) fn chicken_model() -> EntityModelDef {
 
     // Y-down → Y-up root wrapper
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
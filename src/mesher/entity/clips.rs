@@ -0,0 +1,301 @@
+//! Procedural animation clips for entity rigs.
+//!
+//! Each clip samples one of Minecraft's `setupAnim` formulas (or a
+//! block entity renderer's lid/swing curve) over a loop and turns the angles
+//! into rotation keys for the rig's joints. Joints are found by part name, so
+//! a pack override that keeps the vanilla names animates too. A clip is only
+//! emitted when the rig has at least one of the parts it drives.
+//!
+//! Angles are added to the part's bind pose in the same Z·Y·X order as
+//! [`part_transform`](super::part_transform), i.e. in model space, before
+//! the root wrapper flips it upright.
+
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use glam::{EulerRot, Quat};
+
+use super::rig::EntityRig;
+use super::{BlockEntityType, MobType};
+
+/// LINEAR rotation keys of one joint: (seconds, quaternion xyzw).
+pub type RotationKeys = Vec<(f32, [f32; 4])>;
+
+/// A named animation clip over a rig's joints.
+#[derive(Debug, Clone)]
+pub struct EntityClip {
+    /// Clip name, e.g. "walk" or "open_close". Clips with the same name on
+    /// several entities export as one glTF animation, so a name always has
+    /// one duration (the flaps are per-mob: `chicken_flap`, `bat_flap`).
+    pub name: String,
+    /// Per animated joint: (joint index, rotation keys).
+    pub rotation_tracks: Vec<(usize, RotationKeys)>,
+}
+
+/// Angle offsets (x, y, z radians) of one part at a time in seconds.
+type PartCurve = Box<dyn Fn(f32) -> [f32; 3]>;
+
+/// Samples per clip loop; the last key repeats the first so loops close.
+const SAMPLES: usize = 24;
+/// MC's limb swing frequency (`limbSwing * 0.6662`).
+const LIMB_FREQ: f32 = 0.6662;
+
+/// Sample `curves` over `duration` seconds into a clip. Every part named in a
+/// curve's list gets the same offsets (a humanoid's hat follows its head),
+/// except parts nested under another part of the same list, which already
+/// inherit the motion.
+fn sample_clip(rig: &EntityRig, name: &str, duration: f32, curves: Vec<(&[&str], PartCurve)>) -> Option<EntityClip> {
+    let mut rotation_tracks = Vec::new();
+    for (parts, curve) in &curves {
+        let joints: Vec<usize> = parts.iter().filter_map(|part| rig.joint_index(part)).collect();
+        for &joint in &joints {
+            let mut ancestors = std::iter::successors(rig.joints[joint].parent, |&a| rig.joints[a].parent);
+            if ancestors.any(|a| joints.contains(&a)) {
+                continue;
+            }
+            let (bz, by, bx) = Quat::from_array(rig.joints[joint].rotation).to_euler(EulerRot::ZYX);
+            let keys = (0..=SAMPLES)
+                .map(|i| {
+                    let t = duration * i as f32 / SAMPLES as f32;
+                    let [x, y, z] = curve(t % duration);
+                    let rotation = Quat::from_euler(EulerRot::ZYX, bz + z, by + y, bx + x);
+                    (t, rotation.to_array())
+                })
+                .collect();
+            rotation_tracks.push((joint, keys));
+        }
+    }
+    (!rotation_tracks.is_empty()).then(|| EntityClip { name: name.to_string(), rotation_tracks })
+}
+
+/// Walk cycle: limb swing `p` advancing so one stride takes `duration`.
+fn walk_clip(rig: &EntityRig, mob_type: MobType) -> Option<EntityClip> {
+    let duration = 1.0;
+    // limbSwing over one loop: a full period of cos(p * LIMB_FREQ)
+    let swing = move |t: f32| t / duration * TAU / LIMB_FREQ;
+    let leg = move |phase: f32, amount: f32| -> PartCurve {
+        Box::new(move |t| [(swing(t) * LIMB_FREQ + phase).cos() * amount, 0.0, 0.0])
+    };
+
+    let curves: Vec<(&[&str], PartCurve)> = if matches!(mob_type, MobType::Spider) {
+        // SpiderModel: legs pairs sweep forward/back (yRot) and lift (zRot),
+        // each pair a quarter cycle apart.
+        const PAIRS: [(&[&str], &[&str], f32); 4] = [
+            (&["right_hind_leg"], &["left_hind_leg"], 0.0),
+            (&["right_middle_hind_leg"], &["left_middle_hind_leg"], PI),
+            (&["right_middle_front_leg"], &["left_middle_front_leg"], FRAC_PI_2),
+            (&["right_front_leg"], &["left_front_leg"], PI * 1.5),
+        ];
+        let mut curves: Vec<(&[&str], PartCurve)> = Vec::new();
+        for (right, left, phase) in PAIRS {
+            let sweep = move |t: f32| -((swing(t) * LIMB_FREQ * 2.0 + phase).cos() * 0.4);
+            let lift = move |t: f32| ((swing(t) * LIMB_FREQ + phase).sin() * 0.4).abs();
+            curves.push((right, Box::new(move |t| [0.0, sweep(t), lift(t)])));
+            curves.push((left, Box::new(move |t| [0.0, -sweep(t), -lift(t)])));
+        }
+        curves
+    } else {
+        vec![
+            // QuadrupedModel / HumanoidModel / ChickenModel legs
+            (&["right_hind_leg", "left_front_leg", "right_leg"], leg(0.0, 1.4)),
+            (&["left_hind_leg", "right_front_leg", "left_leg"], leg(PI, 1.4)),
+            // HumanoidModel arms swing against their legs
+            (&["right_arm"], leg(PI, 1.0)),
+            (&["left_arm"], leg(0.0, 1.0)),
+        ]
+    };
+    sample_clip(rig, "walk", duration, curves)
+}
+
+/// Idle head bob: a slow look around with a slight nod.
+fn idle_clip(rig: &EntityRig) -> Option<EntityClip> {
+    let duration = 4.0;
+    let look: PartCurve = Box::new(move |t| {
+        let a = t / duration * TAU;
+        [(a * 2.0).sin() * 0.15, a.sin() * 0.45, 0.0]
+    });
+    // Parts posed like the head in MC's setupAnim. Hats, snouts and beaks
+    // are siblings of the head on some models (chicken, player) and its
+    // children on others (pig, villager); children are skipped.
+    sample_clip(rig, "idle", duration, vec![(&["head", "hat", "snout", "beak", "wattle"], look)])
+}
+
+/// ChickenModel wing flap: `zRot = ±(sin(flap) + 1) * flapSpeed`.
+fn chicken_flap_clip(rig: &EntityRig) -> Option<EntityClip> {
+    let duration = 0.4;
+    let bob = move |t: f32| ((t / duration * TAU).sin() + 1.0) * 0.6;
+    sample_clip(rig, "chicken_flap", duration, vec![
+        (&["right_wing"], Box::new(move |t| [0.0, 0.0, bob(t)])),
+        (&["left_wing"], Box::new(move |t| [0.0, 0.0, -bob(t)])),
+    ])
+}
+
+/// BatModel flight: wings beat on `yRot = cos(ageInTicks * 74.48°) * π/4`
+/// with the tips trailing at half the angle.
+fn bat_flap_clip(rig: &EntityRig) -> Option<EntityClip> {
+    // 360° / 74.48° per tick ≈ 4.83 ticks per beat
+    let duration = 360.0 / 74.48 / 20.0;
+    let beat = move |t: f32| (t / duration * TAU).cos() * PI * 0.25;
+    sample_clip(rig, "bat_flap", duration, vec![
+        (&["right_wing"], Box::new(move |t| [0.0, beat(t), 0.0])),
+        (&["left_wing"], Box::new(move |t| [0.0, -beat(t), 0.0])),
+        (&["right_wing_tip"], Box::new(move |t| [0.0, beat(t) * 0.5, 0.0])),
+        (&["left_wing_tip"], Box::new(move |t| [0.0, -beat(t) * 0.5, 0.0])),
+    ])
+}
+
+/// Built-in clips for a mob's rig: walk, idle and the wing flaps. Vehicles
+/// and armor stands have none.
+pub fn mob_clips(rig: &EntityRig, mob_type: MobType) -> Vec<EntityClip> {
+    let mut clips = Vec::new();
+    match mob_type {
+        MobType::ArmorStand | MobType::Minecart | MobType::Boat | MobType::ChestBoat => {
+            return clips;
+        }
+        MobType::Bat => clips.extend(bat_flap_clip(rig)),
+        MobType::Chicken => clips.extend(chicken_flap_clip(rig)),
+        _ => {}
+    }
+    if !matches!(mob_type, MobType::Bat) {
+        clips.extend(walk_clip(rig, mob_type));
+    }
+    clips.extend(idle_clip(rig));
+    clips
+}
+
+/// Built-in clips for a block entity's rig: chest lids opening and closing
+/// and the bell swinging after a hit.
+pub fn block_entity_clips(rig: &EntityRig, entity_type: &BlockEntityType) -> Vec<EntityClip> {
+    match entity_type {
+        BlockEntityType::Chest(_) | BlockEntityType::DoubleChest { .. } => {
+            // ChestRenderer: lid.xRot = -(1 - (1 - openness)^3) * π/2, lock
+            // following the lid. Opens over 10 ticks, holds, then closes.
+            let duration = 2.0;
+            let lid: PartCurve = Box::new(move |t| {
+                let openness = match t {
+                    t if t < 0.5 => t / 0.5,
+                    t if t < 1.5 => 1.0,
+                    t => 1.0 - (t - 1.5) / 0.5,
+                };
+                [-(1.0 - (1.0 - openness).powi(3)) * FRAC_PI_2, 0.0, 0.0]
+            });
+            sample_clip(rig, "open_close", duration, vec![(&["lid", "lock"], lid)])
+                .into_iter()
+                .collect()
+        }
+        BlockEntityType::Bell => {
+            // BellRenderer: xRot = sin(ticks / π) / (4 + ticks / 3) for the
+            // 50 ticks after a hit.
+            let duration = 2.5;
+            let swing: PartCurve = Box::new(move |t| {
+                let ticks = t * 20.0;
+                [(ticks / PI).sin() / (4.0 + ticks / 3.0), 0.0, 0.0]
+            });
+            sample_clip(rig, "ring", duration, vec![(&["bell_body", "bell_lip"], swing)])
+                .into_iter()
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesher::entity::detect_block_entity;
+    use crate::mesher::entity::rig::{build_block_entity_rig, build_mob_rig};
+    use crate::resource_pack::ResourcePack;
+    use crate::types::InputBlock;
+    use glam::Vec3;
+
+    fn clip<'a>(clips: &'a [EntityClip], name: &str) -> &'a EntityClip {
+        clips.iter().find(|c| c.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_cow_walk_swings_legs_in_opposite_phase() {
+        let pack = ResourcePack::new();
+        let rig = build_mob_rig(&pack, &InputBlock::new("entity:cow"), MobType::Cow).unwrap();
+        let clips = mob_clips(&rig, MobType::Cow);
+        let walk = clip(&clips, "walk");
+        assert_eq!(walk.rotation_tracks.len(), 4);
+
+        let track = |name: &str| {
+            let joint = rig.joint_index(name).unwrap();
+            &walk.rotation_tracks.iter().find(|(j, _)| *j == joint).unwrap().1
+        };
+        // At t=0 the right hind leg is fully forward and the left fully back.
+        let right = Quat::from_array(track("right_hind_leg")[0].1).to_euler(EulerRot::ZYX).2;
+        let left = Quat::from_array(track("left_hind_leg")[0].1).to_euler(EulerRot::ZYX).2;
+        assert!((right - 1.4).abs() < 1e-4 && (left + 1.4).abs() < 1e-4);
+        // The loop closes.
+        let keys = track("right_hind_leg");
+        assert_eq!(keys.first().unwrap().1, keys.last().unwrap().1);
+        assert!(clips.iter().any(|c| c.name == "idle"));
+    }
+
+    #[test]
+    fn test_idle_skips_parts_nested_in_the_head() {
+        let pack = ResourcePack::new();
+        for (id, mob_type, nested) in [("pig", MobType::Pig, "snout"), ("villager", MobType::Villager, "hat")] {
+            let rig = build_mob_rig(&pack, &InputBlock::new(&format!("entity:{}", id)), mob_type).unwrap();
+            let clips = mob_clips(&rig, mob_type);
+            let idle = clip(&clips, "idle");
+            let head = rig.joint_index("head").unwrap();
+            let part = rig.joint_index(nested).unwrap();
+            assert_eq!(rig.joints[part].parent, Some(head), "{}", id);
+            // The head turns; the nested part follows it at its bind rotation.
+            assert!(idle.rotation_tracks.iter().any(|(j, _)| *j == head), "{}", id);
+            assert!(!idle.rotation_tracks.iter().any(|(j, _)| *j == part), "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_flying_mobs_flap() {
+        let pack = ResourcePack::new();
+        let bat = build_mob_rig(&pack, &InputBlock::new("entity:bat"), MobType::Bat).unwrap();
+        let clips = mob_clips(&bat, MobType::Bat);
+        assert_eq!(clip(&clips, "bat_flap").rotation_tracks.len(), 4);
+        assert!(!clips.iter().any(|c| c.name == "walk"));
+
+        let chicken = build_mob_rig(&pack, &InputBlock::new("entity:chicken"), MobType::Chicken).unwrap();
+        let clips = mob_clips(&chicken, MobType::Chicken);
+        assert_eq!(clip(&clips, "chicken_flap").rotation_tracks.len(), 2);
+        assert_eq!(clip(&clips, "walk").rotation_tracks.len(), 2);
+    }
+
+    #[test]
+    fn test_chest_lid_opens_upward() {
+        let pack = ResourcePack::new();
+        let block = InputBlock::new("minecraft:chest").with_property("facing", "south");
+        let entity_type = detect_block_entity(&block).unwrap();
+        let rig = build_block_entity_rig(&pack, &block, &entity_type).unwrap();
+        let clips = block_entity_clips(&rig, &entity_type);
+        let open = clip(&clips, "open_close");
+        assert_eq!(open.rotation_tracks.len(), 2);
+
+        // Pose the lid at its most open key and check the front edge rose.
+        let lid = rig.joint_index("lid").unwrap();
+        let keys = &open.rotation_tracks.iter().find(|(j, _)| *j == lid).unwrap().1;
+        let (_, open_rotation) = keys[SAMPLES / 2];
+        let mut joints = rig.joints.clone();
+        joints[lid].rotation = open_rotation;
+        let posed = EntityRig { joints, ..rig.clone() };
+        let bind = rig.world_matrices()[lid];
+        let open_world = posed.world_matrices()[lid];
+        let front = bind.inverse().transform_point3(Vec3::new(0.5, 14.0 / 16.0, 0.95));
+        assert!(open_world.transform_point3(front).y > 1.2);
+    }
+
+    #[test]
+    fn test_bell_ring_decays() {
+        let pack = ResourcePack::new();
+        let block = InputBlock::new("minecraft:bell");
+        let entity_type = detect_block_entity(&block).unwrap();
+        let rig = build_block_entity_rig(&pack, &block, &entity_type).unwrap();
+        let clips = block_entity_clips(&rig, &entity_type);
+        let ring = clip(&clips, "ring");
+        let keys = &ring.rotation_tracks[0].1;
+        let angle = |i: usize| Quat::from_array(keys[i].1).to_euler(EulerRot::ZYX).2.abs();
+        assert!(angle(1) > angle(SAMPLES - 1));
+    }
+}
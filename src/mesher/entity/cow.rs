@@ -13,6 +13,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 ///   left_horn: [3,-5,-4] 1x3x1 tex(22,0) at pos(0,0,0) — relative to head
 pub(super) fn cow_model() -> EntityModelDef {
     let right_horn = EntityPart {
+        name: "right_horn".into(),
         cubes: vec![EntityCube {
             origin: [-5.0, -5.0, -5.0],
             dimensions: [1.0, 3.0, 1.0],
@@ -20,12 +21,14 @@ pub(super) fn cow_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let left_horn = EntityPart {
+        name: "left_horn".into(),
         cubes: vec![EntityCube {
             origin: [4.0, -5.0, -5.0],
             dimensions: [1.0, 3.0, 1.0],
@@ -33,12 +36,14 @@ pub(super) fn cow_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![
             EntityCube {
                 origin: [-4.0, -4.0, -6.0],
@@ -47,6 +52,7 @@ pub(super) fn cow_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
             // Muzzle/snout (added in 1.21.5 for the new biome-variant cow textures).
             EntityCube {
@@ -56,6 +62,7 @@ pub(super) fn cow_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
         ],
         pose: EntityPartPose {
@@ -66,6 +73,7 @@ pub(super) fn cow_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![
             EntityCube {
                 origin: [-6.0, -10.0, -7.0],
@@ -74,6 +82,7 @@ pub(super) fn cow_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
             EntityCube {
                 origin: [-2.0, 2.0, -8.0],
@@ -82,6 +91,7 @@ pub(super) fn cow_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
         ],
         pose: EntityPartPose {
@@ -93,6 +103,7 @@ pub(super) fn cow_model() -> EntityModelDef {
     };
 
     let right_hind_leg = EntityPart {
+        name: "right_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -100,6 +111,7 @@ pub(super) fn cow_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-4.0, 12.0, 7.0],
@@ -109,6 +121,7 @@ pub(super) fn cow_model() -> EntityModelDef {
     };
 
     let left_hind_leg = EntityPart {
+        name: "left_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -116,6 +129,7 @@ pub(super) fn cow_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [4.0, 12.0, 7.0],
@@ -125,6 +139,7 @@ pub(super) fn cow_model() -> EntityModelDef {
     };
 
     let right_front_leg = EntityPart {
+        name: "right_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -132,6 +147,7 @@ pub(super) fn cow_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-4.0, 12.0, -5.0],
@@ -141,6 +157,7 @@ pub(super) fn cow_model() -> EntityModelDef {
     };
 
     let left_front_leg = EntityPart {
+        name: "left_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -148,6 +165,7 @@ pub(super) fn cow_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [4.0, 12.0, -5.0],
@@ -158,6 +176,7 @@ pub(super) fn cow_model() -> EntityModelDef {
 
     // Y-down → Y-up root wrapper
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
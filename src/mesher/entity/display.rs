@@ -0,0 +1,363 @@
+//! Display entities: `block_display`, `item_display` and `text_display`.
+//!
+//! Display entities draw a block model, an item or a text panel through an
+//! arbitrary affine `transformation`. The geometry itself comes from the
+//! regular block model, item and sign text paths (see
+//! `MeshBuilder::add_display`); this module reads the entity data and builds
+//! the placement.
+//!
+//! The entity origin is the block position's minimum corner, so an
+//! untransformed `block_display` fills its cell. Entity data is read from the
+//! block properties:
+//! - `transformation`: `{translation, left_rotation, scale, right_rotation}`
+//!   (rotations as `[x, y, z, w]` quaternions or `{angle, axis}`), or 16
+//!   row-major matrix floats. JSON or SNBT as written in a summon command.
+//! - `yaw` / `pitch`: entity rotation in degrees; `billboard`: `fixed`,
+//!   `vertical`, `horizontal` or `center`.
+//! - `brightness`: `{block, sky}` light override.
+//! - `block_state`, `item` + `item_display`, or `text` + `line_width`,
+//!   `background` and `alignment`, per kind.
+
+use super::sign_text::TextAlignment;
+use super::EntityFaceTexture;
+use crate::mesher::geometry::Vertex;
+use crate::resource_pack::ItemDisplayContext;
+use crate::types::InputBlock;
+use glam::{EulerRot, Mat3, Mat4, Quat, Vec3};
+use serde_json::Value;
+
+/// Text display pixels per block (`TextDisplayRenderer` scales by 0.025).
+const TEXT_SCALE: f32 = 0.025;
+
+/// Default text display background, ARGB.
+const DEFAULT_BACKGROUND: u32 = 0x4000_0000;
+
+/// Placement of a display's content in block-local space: the entity
+/// orientation, then its `transformation`.
+///
+/// Billboarded displays turn to `camera` (yaw, pitch in degrees, Minecraft
+/// convention) on their free axes; without a camera they keep their own
+/// rotation.
+pub(crate) fn placement(block: &InputBlock, camera: Option<[f32; 2]>) -> Mat4 {
+    let transformation = block
+        .properties
+        .get("transformation")
+        .and_then(|s| parse_transformation(s))
+        .unwrap_or(Mat4::IDENTITY);
+    Mat4::from_quat(orientation(block, camera)) * transformation
+}
+
+/// DisplayRenderer's orientation: `rotationYXZ(-yaw, pitch, 0)`, with the
+/// camera's angles substituted on the billboarded axes.
+fn orientation(block: &InputBlock, camera: Option<[f32; 2]>) -> Quat {
+    let angle = |key: &str| {
+        block.properties.get(key).and_then(|s| s.trim().parse::<f32>().ok()).unwrap_or(0.0)
+    };
+    let (mut yaw, mut pitch) = (angle("yaw"), angle("pitch"));
+    if let Some([camera_yaw, camera_pitch]) = camera {
+        let billboard = block.properties.get("billboard").map(|s| s.as_str()).unwrap_or("fixed");
+        if matches!(billboard, "vertical" | "center") {
+            yaw = camera_yaw - 180.0;
+        }
+        if matches!(billboard, "horizontal" | "center") {
+            pitch = -camera_pitch;
+        }
+    }
+    Quat::from_euler(EulerRot::YXZ, (-yaw).to_radians(), pitch.to_radians(), 0.0)
+}
+
+/// Parse a `transformation`: the decomposed form
+/// `translation * left_rotation * scale * right_rotation`, or a row-major
+/// 4x4 matrix.
+fn parse_transformation(s: &str) -> Option<Mat4> {
+    let value = parse_data(s)?;
+    if value.is_array() {
+        return Some(Mat4::from_cols_array(&floats::<16>(&value)?).transpose());
+    }
+    let vec3 = |key: &str, default: Vec3| {
+        value.get(key).and_then(floats::<3>).map(Vec3::from).unwrap_or(default)
+    };
+    let rotation = |key: &str| value.get(key).and_then(parse_rotation).unwrap_or(Quat::IDENTITY);
+    Some(
+        Mat4::from_translation(vec3("translation", Vec3::ZERO))
+            * Mat4::from_quat(rotation("left_rotation"))
+            * Mat4::from_scale(vec3("scale", Vec3::ONE))
+            * Mat4::from_quat(rotation("right_rotation")),
+    )
+}
+
+/// A rotation as an `[x, y, z, w]` quaternion or an `{angle, axis}` pair
+/// (radians).
+fn parse_rotation(value: &Value) -> Option<Quat> {
+    if let Some([x, y, z, w]) = floats::<4>(value) {
+        let q = Quat::from_xyzw(x, y, z, w);
+        return Some(if q.length_squared() > 0.0 { q.normalize() } else { Quat::IDENTITY });
+    }
+    let angle = value.get("angle")?.as_f64()? as f32;
+    let axis = Vec3::from(floats::<3>(value.get("axis")?)?);
+    Some(match axis.try_normalize() {
+        Some(axis) => Quat::from_axis_angle(axis, angle),
+        None => Quat::IDENTITY,
+    })
+}
+
+/// An array of exactly `N` numbers.
+fn floats<const N: usize>(value: &Value) -> Option<[f32; N]> {
+    let array = value.as_array()?;
+    if array.len() != N {
+        return None;
+    }
+    let mut out = [0.0; N];
+    for (out, v) in out.iter_mut().zip(array) {
+        *out = v.as_f64()? as f32;
+    }
+    Some(out)
+}
+
+/// Parse entity data written as JSON or SNBT.
+fn parse_data(s: &str) -> Option<Value> {
+    serde_json::from_str(&snbt_to_json(s)).ok()
+}
+
+/// Loosen SNBT (`{scale:[2f,2f,2f]}`, as copied from a summon command) into
+/// JSON: bare keys and strings are quoted and numeric type suffixes dropped.
+/// JSON passes through unchanged.
+fn snbt_to_json(s: &str) -> String {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+' | '.');
+    let mut out = String::with_capacity(s.len() + 16);
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                out.push('"');
+                while let Some(inner) = chars.next() {
+                    match inner {
+                        '\\' => {
+                            out.push('\\');
+                            out.extend(chars.next());
+                        }
+                        _ if inner == c => break,
+                        '"' => out.push_str("\\\""),
+                        _ => out.push(inner),
+                    }
+                }
+                out.push('"');
+            }
+            c if is_word(c) => {
+                let mut word = String::from(c);
+                while let Some(&next) = chars.peek().filter(|&&n| is_word(n)) {
+                    word.push(next);
+                    chars.next();
+                }
+                let mut whitespace = String::new();
+                while let Some(&next) = chars.peek().filter(|n| n.is_whitespace()) {
+                    whitespace.push(next);
+                    chars.next();
+                }
+                let numeric = word.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.'));
+                let number = numeric
+                    .then(|| {
+                        word.parse::<f64>().ok().or_else(|| {
+                            word.strip_suffix(|c: char| "bBsSlLfFdD".contains(c))?.parse().ok()
+                        })
+                    })
+                    .flatten();
+                match number {
+                    _ if chars.peek() == Some(&':') => out.push_str(&format!("\"{}\"", word)),
+                    Some(n) => out.push_str(&n.to_string()),
+                    None if matches!(word.as_str(), "true" | "false" | "null") => out.push_str(&word),
+                    None => out.push_str(&format!("\"{}\"", word)),
+                }
+                out.push_str(&whitespace);
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Light level of a display's `brightness` override, the brighter of its
+/// block and sky light.
+pub(crate) fn brightness_override(block: &InputBlock) -> Option<u8> {
+    let value = parse_data(block.properties.get("brightness")?)?;
+    let level = |key: &str| value.get(key).and_then(Value::as_f64).unwrap_or(0.0).clamp(0.0, 15.0) as u8;
+    Some(level("block").max(level("sky")))
+}
+
+/// The block state a `block_display` shows, written `id[key=value,...]` or
+/// as `{Name, Properties}` NBT.
+pub(crate) fn block_state(block: &InputBlock) -> Option<InputBlock> {
+    let s = block.properties.get("block_state")?.trim();
+    let namespaced = |name: &str| {
+        if name.contains(':') { name.to_string() } else { format!("minecraft:{}", name) }
+    };
+    if s.starts_with('{') {
+        let value = parse_data(s)?;
+        let mut state = InputBlock::new(namespaced(value.get("Name")?.as_str()?));
+        for (key, v) in value.get("Properties").and_then(Value::as_object).into_iter().flatten() {
+            let v = v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string());
+            state.properties.insert(key.clone(), v);
+        }
+        return Some(state);
+    }
+    let (name, properties) = match s.split_once('[') {
+        Some((name, rest)) => (name, rest.strip_suffix(']')?),
+        None => (s, ""),
+    };
+    if name.is_empty() {
+        return None;
+    }
+    let mut state = InputBlock::new(namespaced(name));
+    for pair in properties.split(',').filter(|p| !p.trim().is_empty()) {
+        let (key, value) = pair.split_once('=')?;
+        state.properties.insert(key.trim().to_string(), value.trim().to_string());
+    }
+    Some(state)
+}
+
+/// The `item_display` context of an item display; `None` for `none` (the
+/// default), which applies no display transform.
+pub(crate) fn item_context(block: &InputBlock) -> Option<ItemDisplayContext> {
+    ItemDisplayContext::from_name(block.properties.get("item_display")?)
+}
+
+/// Text display panel options: `line_width` in font pixels (default 200),
+/// `background` as an ARGB integer or `#AARRGGBB` (default 25% black), and
+/// `alignment`.
+pub(crate) fn text_options(block: &InputBlock) -> (u32, [u8; 4], TextAlignment) {
+    let line_width = block
+        .properties
+        .get("line_width")
+        .and_then(|s| s.trim().parse().ok())
+        .unwrap_or(200);
+    let [a, r, g, b] = block
+        .properties
+        .get("background")
+        .and_then(|s| parse_argb(s))
+        .unwrap_or(DEFAULT_BACKGROUND)
+        .to_be_bytes();
+    let alignment = match block.properties.get("alignment").map(|s| s.as_str()) {
+        Some("left") => TextAlignment::Left,
+        Some("right") => TextAlignment::Right,
+        _ => TextAlignment::Center,
+    };
+    (line_width, [r, g, b, a], alignment)
+}
+
+/// An ARGB color as a (possibly negative) integer or hex; six hex digits
+/// are opaque.
+fn parse_argb(s: &str) -> Option<u32> {
+    let s = s.trim();
+    match s.strip_prefix('#').or_else(|| s.strip_prefix("0x")) {
+        Some(hex) if hex.len() <= 6 => u32::from_str_radix(hex, 16).ok().map(|rgb| rgb | 0xFF00_0000),
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => s.parse::<i64>().ok().map(|v| v as u32),
+    }
+}
+
+/// The quad a text display's panel texture is drawn on, in display space:
+/// `size` font pixels, centered on the origin horizontally and standing on
+/// it, facing south.
+pub(crate) fn text_panel(texture: &str, size: [f32; 2]) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
+    let (x0, x1) = (-size[0] * TEXT_SCALE / 2.0, size[0] * TEXT_SCALE / 2.0);
+    let (y0, y1) = (0.0, size[1] * TEXT_SCALE);
+    let normal = [0.0, 0.0, 1.0];
+    let vertices = vec![
+        Vertex::new([x0, y1, 0.0], normal, [0.0, 0.0]),
+        Vertex::new([x1, y1, 0.0], normal, [1.0, 0.0]),
+        Vertex::new([x1, y0, 0.0], normal, [1.0, 1.0]),
+        Vertex::new([x0, y0, 0.0], normal, [0.0, 1.0]),
+    ];
+    let faces = vec![EntityFaceTexture { texture: texture.to_string(), is_transparent: true }];
+    (vertices, vec![0, 2, 1, 0, 3, 2], faces)
+}
+
+/// Apply an affine transform to geometry: positions by `mat`, normals by its
+/// inverse transpose, flipping triangle winding when `mat` mirrors.
+pub(crate) fn transform_geometry(vertices: &mut [Vertex], indices: &mut [u32], mat: &Mat4) {
+    let normal_mat = Mat3::from_mat4(*mat).inverse().transpose();
+    for v in vertices {
+        v.position = mat.transform_point3(Vec3::from(v.position)).to_array();
+        v.normal = (normal_mat * Vec3::from(v.normal)).normalize_or_zero().to_array();
+    }
+    if mat.determinant() < 0.0 {
+        for triangle in indices.chunks_exact_mut(3) {
+            triangle.swap(1, 2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn display(properties: &[(&str, &str)]) -> InputBlock {
+        properties
+            .iter()
+            .fold(InputBlock::new("entity:block_display"), |block, (k, v)| block.with_property(*k, *v))
+    }
+
+    #[test]
+    fn test_transformation_forms_agree() {
+        let snbt = display(&[(
+            "transformation",
+            "{left_rotation:[0f,0.7071068f,0f,0.7071068f],right_rotation:[0f,0f,0f,1f],\
+             translation:[0.5f,0f,0f],scale:[2f,1f,1f]}",
+        )]);
+        let json = display(&[(
+            "transformation",
+            r#"{"translation":[0.5,0,0],"left_rotation":{"angle":1.5707964,"axis":[0,1,0]},"scale":[2,1,1]}"#,
+        )]);
+        // translate(0.5,0,0) * rotY(90°) * scale(2,1,1), row-major.
+        let matrix = display(&[("transformation", "[0,0,1,0.5, 0,1,0,0, -2,0,0,0, 0,0,0,1]")]);
+
+        let p = Vec3::new(1.0, 0.0, 0.0);
+        let expected = Vec3::new(0.5, 0.0, -2.0);
+        for block in [snbt, json, matrix] {
+            let moved = placement(&block, None).transform_point3(p);
+            assert!((moved - expected).length() < 1e-5, "{:?}", moved);
+        }
+    }
+
+    #[test]
+    fn test_billboard_faces_camera() {
+        let text = |billboard: &str| display(&[("billboard", billboard), ("yaw", "90")]);
+        let normal = |block: &InputBlock, camera| placement(block, camera).transform_vector3(Vec3::Z);
+
+        // Fixed displays keep their yaw: 90° turns the front to face west.
+        assert!((normal(&text("fixed"), Some([0.0, 0.0])) - Vec3::NEG_X).length() < 1e-5);
+        // Without a camera billboards fall back to their own rotation.
+        assert!((normal(&text("center"), None) - Vec3::NEG_X).length() < 1e-5);
+        // A camera looking north sees the front of a centered billboard...
+        assert!((normal(&text("center"), Some([180.0, 0.0])) - Vec3::Z).length() < 1e-5);
+        // ...and one looking down from above sees it tilted up towards it.
+        assert!(normal(&text("center"), Some([180.0, 45.0])).y > 0.5);
+        // Vertical billboards only turn about Y.
+        assert!((normal(&text("vertical"), Some([180.0, 45.0])) - Vec3::Z).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_block_state_and_options() {
+        let state = block_state(&display(&[("block_state", "oak_stairs[facing=east, half=top]")])).unwrap();
+        assert_eq!(state.name, "minecraft:oak_stairs");
+        assert_eq!(state.properties["facing"], "east");
+        assert_eq!(state.properties["half"], "top");
+        let nbt = block_state(&display(&[("block_state", r#"{Name:"minecraft:lantern",Properties:{hanging:"true"}}"#)]))
+            .unwrap();
+        assert_eq!(nbt.name, "minecraft:lantern");
+        assert_eq!(nbt.properties["hanging"], "true");
+
+        assert_eq!(brightness_override(&display(&[("brightness", "{block:12,sky:4}")])), Some(12));
+        let (width, background, alignment) = text_options(&display(&[("background", "-16777216")]));
+        assert_eq!((width, background, alignment), (200, [0, 0, 0, 255], TextAlignment::Center));
+        assert_eq!(text_options(&display(&[])).1, [0, 0, 0, 0x40]);
+    }
+
+    #[test]
+    fn test_mirroring_transform_flips_winding() {
+        let (mut vertices, mut indices, _) = text_panel("t", [10.0, 10.0]);
+        transform_geometry(&mut vertices, &mut indices, &Mat4::from_scale(Vec3::new(-1.0, 1.0, 1.0)));
+        assert_eq!(indices, [0, 1, 2, 0, 2, 3]);
+        assert_eq!(vertices[0].normal, [0.0, 0.0, 1.0]);
+    }
+}
@@ -0,0 +1,75 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+use std::f32::consts::PI;
+
+/// Dolphin model — texture `entity/dolphin`, 64x64.
+/// From DolphinModel.java (MC 1.21.4). Head, fins and tail all hang off the
+/// body; the side fins are swept back at 60° and splayed at 120°.
+pub(super) fn dolphin_model() -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, -4.0, -3.0],
+        no_rotation,
+        vec![EntityCube::new([-4.0, -3.0, -3.0], [8.0, 7.0, 6.0], [0, 0])],
+        vec![EntityPart::posed(
+            "nose",
+            [0.0, 0.0, 0.0],
+            no_rotation,
+            vec![EntityCube::new([-1.0, 2.0, -7.0], [2.0, 2.0, 4.0], [0, 13])],
+            vec![],
+        )],
+    );
+
+    let back_fin = EntityPart::posed(
+        "back_fin",
+        [0.0, 0.0, 0.0],
+        [PI / 3.0, 0.0, 0.0],
+        vec![EntityCube::new([-0.5, 0.0, 8.0], [1.0, 4.0, 5.0], [51, 0])],
+        vec![],
+    );
+    let left_fin = EntityPart::posed(
+        "left_fin",
+        [2.0, -2.0, 4.0],
+        [PI / 3.0, 0.0, 2.0 * PI / 3.0],
+        vec![EntityCube::new([-0.5, -4.0, 0.0], [1.0, 4.0, 7.0], [48, 20]).mirrored()],
+        vec![],
+    );
+    let right_fin = EntityPart::posed(
+        "right_fin",
+        [-2.0, -2.0, 4.0],
+        [PI / 3.0, 0.0, -2.0 * PI / 3.0],
+        vec![EntityCube::new([-0.5, -4.0, 0.0], [1.0, 4.0, 7.0], [48, 20])],
+        vec![],
+    );
+
+    let tail = EntityPart::posed(
+        "tail",
+        [0.0, -2.5, 11.0],
+        [-0.10471976, 0.0, 0.0],
+        vec![EntityCube::new([-2.0, -2.5, 0.0], [4.0, 5.0, 11.0], [0, 19])],
+        vec![EntityPart::posed(
+            "tail_fin",
+            [0.0, 0.0, 9.0],
+            no_rotation,
+            vec![EntityCube::new([-5.0, -0.5, 0.0], [10.0, 1.0, 6.0], [19, 20])],
+            vec![],
+        )],
+    );
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 22.0, -5.0],
+        no_rotation,
+        vec![EntityCube::new([-4.0, -7.0, 0.0], [8.0, 7.0, 13.0], [22, 0])],
+        vec![head, back_fin, left_fin, right_fin, tail],
+    );
+
+    EntityModelDef {
+        texture_path: "entity/dolphin".to_string(),
+        texture_size: [64, 64],
+        parts: vec![mob_root(vec![body])],
+        is_opaque: true,
+    }
+}
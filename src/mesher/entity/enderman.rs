@@ -6,6 +6,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 pub(super) fn enderman_model() -> EntityModelDef {
     // Hat is a child of head (inflated overlay) — PartPose.ZERO relative to head.
     let hat = EntityPart {
+        name: "hat".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -13,12 +14,14 @@ pub(super) fn enderman_model() -> EntityModelDef {
             inflate: -0.5,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -26,6 +29,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, -13.0, 0.0],
@@ -35,6 +39,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, 0.0, -2.0],
             dimensions: [8.0, 12.0, 4.0],
@@ -42,6 +47,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, -14.0, 0.0],
@@ -52,6 +58,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
 
     // Long arms (30 tall, pivot at y=-12).
     let right_arm = EntityPart {
+        name: "right_arm".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -2.0, -1.0],
             dimensions: [2.0, 30.0, 2.0],
@@ -59,6 +66,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-5.0, -12.0, 0.0],
@@ -68,6 +76,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
     };
 
     let left_arm = EntityPart {
+        name: "left_arm".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -2.0, -1.0],
             dimensions: [2.0, 30.0, 2.0],
@@ -75,6 +84,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: true,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [5.0, -12.0, 0.0],
@@ -85,6 +95,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
 
     // Long legs (30 tall, pivot at y=-5 — feet land at y=25).
     let right_leg = EntityPart {
+        name: "right_leg".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, 0.0, -1.0],
             dimensions: [2.0, 30.0, 2.0],
@@ -92,6 +103,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-2.0, -5.0, 0.0],
@@ -101,6 +113,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
     };
 
     let left_leg = EntityPart {
+        name: "left_leg".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, 0.0, -1.0],
             dimensions: [2.0, 30.0, 2.0],
@@ -108,6 +121,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: true,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [2.0, -5.0, 0.0],
@@ -118,6 +132,7 @@ pub(super) fn enderman_model() -> EntityModelDef {
 
     // Root: enderman feet are at model y=25 (not the usual 24), so root y=25.
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 25.0, 8.0],
@@ -5,21 +5,48 @@
 //! inflated slightly and a different texture sampled.
 //!
 //! We reproduce that here with `inflated_overlay`, which clones a model and
-//! bumps every cube's `inflate` field while swapping the texture path.
+//! bumps every cube's `inflate` field while swapping the texture path. Worn
+//! armor on humanoids comes from [`super::armor`].
 
-use super::{EntityModelDef, EntityPart, MobType};
+use super::{armor, EntityModelDef, EntityPart, MobType};
+use crate::resource_pack::ResourcePack;
 use crate::types::InputBlock;
 
 /// An equipment overlay: a clone of the mob's model rendered with a different
 /// texture and a uniform inflate amount (to sit on top of the base skin).
 pub(crate) struct EquipmentOverlay {
     pub model: EntityModelDef,
+    /// Dye color multiplied into the layer (leather armor).
+    pub tint: Option<[f32; 3]>,
+    /// Draw the layer as the enchantment glint: translucent and fullbright.
+    pub glint: bool,
+    /// Set when the model's texture is a palette-permuted sprite (armor
+    /// trims) that the pack may not have materialized.
+    pub paletted: Option<PalettedTexture>,
+}
+
+impl EquipmentOverlay {
+    pub(crate) fn new(model: EntityModelDef) -> Self {
+        Self { model, tint: None, glint: false, paletted: None }
+    }
+}
+
+/// A sprite made by recoloring `texture` from `palette_key` to `palette`, as
+/// an atlas `paletted_permutations` source names it (`sprite`).
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PalettedTexture {
+    pub sprite: String,
+    pub texture: String,
+    pub palette_key: String,
+    pub palette: String,
 }
 
 /// Return any equipment overlays that apply to this mob's current state.
 /// Reads properties like `saddle`, `horse_armor`, or the `rider` prop (a ridden
-/// pig or horse always shows a saddle even if `saddle` wasn't set explicitly).
+/// pig or horse always shows a saddle even if `saddle` wasn't set explicitly),
+/// and the armor slots of humanoids.
 pub(crate) fn overlays_for(
+    resource_pack: &ResourcePack,
     mob_type: MobType,
     block: &InputBlock,
     base_model: &EntityModelDef,
@@ -32,31 +59,28 @@ pub(crate) fn overlays_for(
     match mob_type {
         MobType::Pig if saddled => {
             // MC's PIG_SADDLE layer uses the pig model with CubeDeformation(0.5).
-            out.push(EquipmentOverlay {
-                model: inflated_overlay(
-                    base_model.clone(),
-                    0.5,
-                    "entity/equipment/pig_saddle/saddle".to_string(),
-                ),
-            });
+            out.push(EquipmentOverlay::new(inflated_overlay(
+                base_model.clone(),
+                0.5,
+                "entity/equipment/pig_saddle/saddle".to_string(),
+            )));
         }
         MobType::Horse => {
             if saddled {
-                out.push(EquipmentOverlay {
-                    model: inflated_overlay(
-                        base_model.clone(),
-                        0.1,
-                        "entity/equipment/horse_saddle/saddle".to_string(),
-                    ),
-                });
+                out.push(EquipmentOverlay::new(inflated_overlay(
+                    base_model.clone(),
+                    0.1,
+                    "entity/equipment/horse_saddle/saddle".to_string(),
+                )));
             }
             if let Some(material) = block.properties.get("horse_armor") {
                 let path = format!("entity/equipment/horse_body/{}", material);
-                out.push(EquipmentOverlay {
-                    model: inflated_overlay(base_model.clone(), 0.1, path),
-                });
+                out.push(EquipmentOverlay::new(inflated_overlay(base_model.clone(), 0.1, path)));
             }
         }
+        _ if armor::wears_armor(mob_type) => {
+            out.extend(armor::armor_overlays(resource_pack, block, base_model, None));
+        }
         _ => {}
     }
 
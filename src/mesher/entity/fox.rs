@@ -0,0 +1,97 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::{mob_root, mob_variant};
+use crate::types::InputBlock;
+
+/// Fox model — texture `entity/fox/fox` (or `snow_fox`), 48x32.
+/// From FoxModel.java (MC 1.21.4). `variant`: `red` (default) or `snow`.
+/// The ears and nose are separate parts in vanilla with no offset of their
+/// own, so they're folded into the head here.
+pub(super) fn fox_model(block: &InputBlock) -> EntityModelDef {
+    let head = EntityPart::posed(
+        "head",
+        [-1.0, 16.5, -3.0],
+        [0.0, 0.0, 0.0],
+        vec![
+            EntityCube::new([-3.0, -2.0, -5.0], [8.0, 6.0, 6.0], [1, 5]),
+            EntityCube::new([-3.0, -4.0, -4.0], [2.0, 2.0, 1.0], [8, 1]),
+            EntityCube::new([3.0, -4.0, -4.0], [2.0, 2.0, 1.0], [15, 1]),
+            EntityCube::new([-1.0, 2.01, -8.0], [4.0, 2.0, 3.0], [6, 18]),
+        ],
+        vec![],
+    );
+
+    let tail = EntityPart::posed(
+        "tail",
+        [-4.0, 15.0, -1.0],
+        [-0.05235988, 0.0, 0.0],
+        vec![EntityCube::new([2.0, 0.0, -1.0], [4.0, 9.0, 5.0], [30, 0])],
+        vec![],
+    );
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 16.0, -6.0],
+        [std::f32::consts::FRAC_PI_2, 0.0, 0.0],
+        vec![EntityCube::new([-3.0, 3.999, -3.5], [6.0, 11.0, 6.0], [24, 15])],
+        vec![tail],
+    );
+
+    // Legs: box(2, 0.5, -1, 2, 6, 2) with a 0.001 deformation.
+    let leg = |name: &str, x: f32, z: f32, tex_offset: [u32; 2]| {
+        EntityPart::posed(
+            name,
+            [x, 17.5, z],
+            [0.0, 0.0, 0.0],
+            vec![EntityCube::new([2.0, 0.5, -1.0], [2.0, 6.0, 2.0], tex_offset).inflated(0.001)],
+            vec![],
+        )
+    };
+
+    let root = mob_root(vec![
+        head,
+        body,
+        leg("right_hind_leg", -5.0, 7.0, [13, 24]),
+        leg("left_hind_leg", -1.0, 7.0, [4, 24]),
+        leg("right_front_leg", -5.0, 0.0, [13, 24]),
+        leg("left_front_leg", -1.0, 0.0, [4, 24]),
+    ]);
+
+    let texture_path = match mob_variant(block, &["red", "snow"]) {
+        "snow" => "entity/fox/snow_fox",
+        _ => "entity/fox/fox",
+    };
+
+    EntityModelDef {
+        texture_path: texture_path.to_string(),
+        texture_size: [48, 32],
+        parts: vec![root],
+        is_opaque: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::mob::find_part_mut;
+
+    #[test]
+    fn test_fox_parts_and_variants() {
+        let mut model = fox_model(&InputBlock::new("entity:fox"));
+        assert_eq!(model.texture_path, "entity/fox/fox");
+        assert_eq!(model.texture_size, [48, 32]);
+        let root = &model.parts[0];
+        assert_eq!(root.children.len(), 6);
+        // Ears and nose are folded into the head.
+        assert_eq!(find_part_mut(&mut model.parts, "head").unwrap().cubes.len(), 4);
+        let body = find_part_mut(&mut model.parts, "body").unwrap();
+        assert_eq!(body.children[0].name, "tail");
+        for leg in ["right_hind_leg", "left_hind_leg", "right_front_leg", "left_front_leg"] {
+            assert_eq!(find_part_mut(&mut model.parts, leg).unwrap().cubes.len(), 1, "{}", leg);
+        }
+
+        let snow = fox_model(&InputBlock::new("entity:fox").with_property("variant", "snow"));
+        assert_eq!(snow.texture_path, "entity/fox/snow_fox");
+        let unknown = fox_model(&InputBlock::new("entity:fox").with_property("variant", "arctic"));
+        assert_eq!(unknown.texture_path, "entity/fox/fox");
+    }
+}
@@ -0,0 +1,145 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::{mob_root, mob_variant};
+use crate::types::InputBlock;
+
+/// Frog model — texture `entity/frog/<variant>_frog`, 48x48.
+/// From FrogModel.java (MC 1.21.4). `variant`: `temperate` (default), `warm`
+/// or `cold`. The croaking sac and tongue are hidden outside animations and
+/// left out. Webbed feet are flat 8x8 planes under each limb.
+pub(super) fn frog_model(block: &InputBlock) -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+
+    let eye = |name: &str, x: f32, tex_offset: [u32; 2]| {
+        EntityPart::posed(
+            name,
+            [x, -3.0, -6.5],
+            no_rotation,
+            vec![EntityCube::new([-1.5, -1.0, -1.5], [3.0, 2.0, 3.0], tex_offset)],
+            vec![],
+        )
+    };
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, -2.0, -1.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-3.5, -1.0, -7.0], [7.0, 0.0, 9.0], [23, 13]),
+            EntityCube::new([-3.5, -2.0, -7.0], [7.0, 3.0, 9.0], [0, 13]),
+        ],
+        vec![EntityPart::posed(
+            "eyes",
+            [-0.5, 0.0, 2.0],
+            no_rotation,
+            vec![],
+            vec![eye("right_eye", -1.5, [0, 0]), eye("left_eye", 2.5, [0, 5])],
+        )],
+    );
+
+    // Arms: 2x3x3 limb plus the webbed hand plane.
+    let arm = |name: &str, x: f32, limb: [u32; 2], hand: [u32; 2]| {
+        EntityPart::posed(
+            name,
+            [x, -1.0, -6.5],
+            no_rotation,
+            vec![
+                EntityCube::new([-1.0, 0.0, -1.0], [2.0, 3.0, 3.0], limb),
+                EntityCube::new([-4.0, 3.01, -5.0], [8.0, 0.0, 8.0], hand),
+            ],
+            vec![],
+        )
+    };
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, -2.0, 4.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-3.5, -2.0, -8.0], [7.0, 3.0, 9.0], [3, 1]),
+            EntityCube::new([-3.5, -1.0, -8.0], [7.0, 0.0, 9.0], [23, 22]),
+        ],
+        vec![
+            head,
+            arm("left_arm", 4.0, [0, 32], [18, 40]),
+            arm("right_arm", -4.0, [0, 38], [2, 40]),
+        ],
+    );
+
+    let left_leg = EntityPart::posed(
+        "left_leg",
+        [3.5, -3.0, 4.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-1.0, 0.0, -2.0], [3.0, 3.0, 4.0], [14, 25]),
+            EntityCube::new([-2.0, 3.01, -4.0], [8.0, 0.0, 8.0], [2, 32]),
+        ],
+        vec![],
+    );
+    let right_leg = EntityPart::posed(
+        "right_leg",
+        [-3.5, -3.0, 4.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-2.0, 0.0, -2.0], [3.0, 3.0, 4.0], [0, 25]),
+            EntityCube::new([-6.0, 3.01, -4.0], [8.0, 0.0, 8.0], [18, 32]),
+        ],
+        vec![],
+    );
+
+    // FrogModel's own root sits at y=24, on the ground.
+    let frog_root = EntityPart::posed(
+        "frog",
+        [0.0, 24.0, 0.0],
+        no_rotation,
+        vec![],
+        vec![body, left_leg, right_leg],
+    );
+
+    let variant = mob_variant(block, &["temperate", "warm", "cold"]);
+
+    EntityModelDef {
+        texture_path: format!("entity/frog/{}_frog", variant),
+        texture_size: [48, 48],
+        parts: vec![mob_root(vec![frog_root])],
+        is_opaque: false, // Webbed feet are cut-out planes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::mob::find_part_mut;
+
+    #[test]
+    fn test_frog_parts_and_variants() {
+        let mut model = frog_model(&InputBlock::new("entity:frog"));
+        assert_eq!(model.texture_path, "entity/frog/temperate_frog");
+        assert_eq!(model.texture_size, [48, 48]);
+        assert!(!model.is_opaque);
+        let frog = find_part_mut(&mut model.parts, "frog").unwrap();
+        let names: Vec<&str> = frog.children.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["body", "left_leg", "right_leg"]);
+        // Head and arms ride on the body; the eyes on the head.
+        let body = find_part_mut(&mut model.parts, "body").unwrap();
+        let names: Vec<&str> = body.children.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["head", "left_arm", "right_arm"]);
+        let eyes = find_part_mut(&mut model.parts, "eyes").unwrap();
+        assert!(eyes.cubes.is_empty());
+        assert_eq!(eyes.children.len(), 2);
+        // Each limb carries a flat webbed foot.
+        for limb in ["left_arm", "right_arm", "left_leg", "right_leg"] {
+            let part = find_part_mut(&mut model.parts, limb).unwrap();
+            assert_eq!(part.cubes.len(), 2, "{}", limb);
+            assert_eq!(part.cubes[1].dimensions[1], 0.0, "{}", limb);
+        }
+
+        for (variant, texture) in [
+            ("warm", "entity/frog/warm_frog"),
+            ("minecraft:cold", "entity/frog/cold_frog"),
+            ("1", "entity/frog/warm_frog"),
+        ] {
+            let block = InputBlock::new("entity:frog").with_property("variant", variant);
+            assert_eq!(frog_model(&block).texture_path, texture, "{}", variant);
+        }
+    }
+}
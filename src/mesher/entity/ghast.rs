@@ -10,6 +10,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 /// the ground plane.
 pub(super) fn ghast_model() -> EntityModelDef {
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-8.0, -8.0, -8.0],
             dimensions: [16.0, 16.0, 16.0],
@@ -17,6 +18,7 @@ pub(super) fn ghast_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 17.6, 0.0],
@@ -39,7 +41,8 @@ pub(super) fn ghast_model() -> EntityModelDef {
         [6.25, 5.0, 12.0],
     ];
 
-    let tentacles = TENTACLES.iter().map(|&[x, z, len]| EntityPart {
+    let tentacles = TENTACLES.iter().enumerate().map(|(i, &[x, z, len])| EntityPart {
+        name: format!("tentacle{}", i),
         cubes: vec![EntityCube {
             origin: [-1.0, 0.0, -1.0],
             dimensions: [2.0, len, 2.0],
@@ -47,6 +50,7 @@ pub(super) fn ghast_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [x, 24.6, z],
@@ -59,6 +63,7 @@ pub(super) fn ghast_model() -> EntityModelDef {
     // tentacle bottoms out at model y = 24.6 + 13 = 37.6; 37.6 * 4.5 = 169.2
     // puts its tip exactly on the ground plane.
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 169.2, 8.0],
@@ -0,0 +1,88 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+use crate::types::InputBlock;
+
+/// Goat model — texture `entity/goat/goat`, 64x64.
+/// From GoatModel.java (MC 1.21.4). Ears and goatee are head cubes, the
+/// horns and the tilted nose are child parts. `left_horn=false` /
+/// `right_horn=false` drop a horn, as after ramming.
+pub(super) fn goat_model(block: &InputBlock) -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+    let has = |key: &str| block.properties.get(key).map(|v| v != "false").unwrap_or(true);
+
+    let mut head_children = Vec::new();
+    if has("left_horn") {
+        head_children.push(EntityPart::posed(
+            "left_horn",
+            [0.0, 0.0, 0.0],
+            no_rotation,
+            vec![EntityCube::new([-0.01, -16.0, -10.0], [2.0, 7.0, 2.0], [12, 55])],
+            vec![],
+        ));
+    }
+    if has("right_horn") {
+        head_children.push(EntityPart::posed(
+            "right_horn",
+            [0.0, 0.0, 0.0],
+            no_rotation,
+            vec![EntityCube::new([-2.99, -16.0, -10.0], [2.0, 7.0, 2.0], [12, 55])],
+            vec![],
+        ));
+    }
+    head_children.push(EntityPart::posed(
+        "nose",
+        [0.0, -8.0, -8.0],
+        [0.9599, 0.0, 0.0],
+        vec![EntityCube::new([-3.0, -4.0, -8.0], [5.0, 7.0, 10.0], [34, 46])],
+        vec![],
+    ));
+
+    let head = EntityPart::posed(
+        "head",
+        [1.0, 14.0, 0.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-6.0, -11.0, -10.0], [3.0, 2.0, 1.0], [2, 61]),
+            EntityCube::new([2.0, -11.0, -10.0], [3.0, 2.0, 1.0], [2, 61]).mirrored(),
+            EntityCube::new([-0.5, -3.0, -14.0], [0.0, 7.0, 5.0], [23, 52]),
+        ],
+        head_children,
+    );
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 24.0, 0.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-4.0, -17.0, -7.0], [9.0, 11.0, 16.0], [1, 1]),
+            EntityCube::new([-5.0, -18.0, -8.0], [11.0, 14.0, 11.0], [0, 28]),
+        ],
+        vec![],
+    );
+
+    let leg = |name: &str, x: f32, z: f32, top: f32, height: f32, tex_offset: [u32; 2]| {
+        EntityPart::posed(
+            name,
+            [x, 14.0, z],
+            no_rotation,
+            vec![EntityCube::new([0.0, top, 0.0], [3.0, height, 3.0], tex_offset)],
+            vec![],
+        )
+    };
+
+    let root = mob_root(vec![
+        head,
+        body,
+        leg("left_hind_leg", 1.0, 4.0, 4.0, 6.0, [36, 29]),
+        leg("right_hind_leg", -3.0, 4.0, 4.0, 6.0, [49, 29]),
+        leg("left_front_leg", 1.0, -6.0, 0.0, 10.0, [49, 2]),
+        leg("right_front_leg", -3.0, -6.0, 0.0, 10.0, [35, 2]),
+    ]);
+
+    EntityModelDef {
+        texture_path: "entity/goat/goat".to_string(),
+        texture_size: [64, 64],
+        parts: vec![root],
+        is_opaque: false, // Goatee is a cut-out plane
+    }
+}
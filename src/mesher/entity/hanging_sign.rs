@@ -42,6 +42,7 @@ pub(super) fn hanging_sign_model(wood: SignWood, is_wall: bool) -> EntityModelDe
 
     // Board: origin [-7, 0, -1], dims [14, 10, 2], texOffs(0, 12)
     let board = EntityPart {
+        name: "board".into(),
         cubes: vec![EntityCube {
             origin: [-7.0, 0.0, -1.0],
             dimensions: [14.0, 10.0, 2.0],
@@ -49,6 +50,7 @@ pub(super) fn hanging_sign_model(wood: SignWood, is_wall: bool) -> EntityModelDe
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [pos_x, pos_y, pos_z],
@@ -65,6 +67,7 @@ pub(super) fn hanging_sign_model(wood: SignWood, is_wall: bool) -> EntityModelDe
 
         // Plank (V-bar): origin [-8, -6, -2], dims [16, 2, 4], texOffs(0, 0)
         let plank = EntityPart {
+            name: "plank".into(),
             cubes: vec![EntityCube {
                 origin: [-8.0, -6.0, -2.0],
                 dimensions: [16.0, 2.0, 4.0],
@@ -72,6 +75,7 @@ pub(super) fn hanging_sign_model(wood: SignWood, is_wall: bool) -> EntityModelDe
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [pos_x, pos_y, pos_z],
@@ -87,6 +91,7 @@ pub(super) fn hanging_sign_model(wood: SignWood, is_wall: bool) -> EntityModelDe
         // Right chain: mirrors on the other side
         // Approximate chains as thin cubes for simplicity
         let left_chain = EntityPart {
+            name: "left_chain".into(),
             cubes: vec![EntityCube {
                 origin: [-6.0, -6.0, -1.0],
                 dimensions: [2.0, 6.0, 2.0],
@@ -94,6 +99,7 @@ pub(super) fn hanging_sign_model(wood: SignWood, is_wall: bool) -> EntityModelDe
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [pos_x, pos_y, pos_z],
@@ -103,6 +109,7 @@ pub(super) fn hanging_sign_model(wood: SignWood, is_wall: bool) -> EntityModelDe
             children: vec![],
         };
         let right_chain = EntityPart {
+            name: "right_chain".into(),
             cubes: vec![EntityCube {
                 origin: [4.0, -6.0, -1.0],
                 dimensions: [2.0, 6.0, 2.0],
@@ -110,6 +117,7 @@ pub(super) fn hanging_sign_model(wood: SignWood, is_wall: bool) -> EntityModelDe
                 inflate: 0.0,
                 mirror: true,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [pos_x, pos_y, pos_z],
@@ -144,6 +152,7 @@ pub(crate) fn hanging_sign_model_upscaled(custom_texture: &str, is_wall: bool) -
     let scale = 1.0;
 
     let board = EntityPart {
+        name: "board".into(),
         cubes: vec![EntityCube {
             origin: [-7.0, 0.0, -1.0],
             dimensions: [14.0, 10.0, 2.0],
@@ -151,6 +160,7 @@ pub(crate) fn hanging_sign_model_upscaled(custom_texture: &str, is_wall: bool) -
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [pos_x, pos_y, pos_z],
@@ -164,6 +174,7 @@ pub(crate) fn hanging_sign_model_upscaled(custom_texture: &str, is_wall: bool) -
 
     if !is_wall {
         let plank = EntityPart {
+            name: "plank".into(),
             cubes: vec![EntityCube {
                 origin: [-8.0, -6.0, -2.0],
                 dimensions: [16.0, 2.0, 4.0],
@@ -171,6 +182,7 @@ pub(crate) fn hanging_sign_model_upscaled(custom_texture: &str, is_wall: bool) -
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [pos_x, pos_y, pos_z],
@@ -182,6 +194,7 @@ pub(crate) fn hanging_sign_model_upscaled(custom_texture: &str, is_wall: bool) -
         parts.push(plank);
 
         let left_chain = EntityPart {
+            name: "left_chain".into(),
             cubes: vec![EntityCube {
                 origin: [-6.0, -6.0, -1.0],
                 dimensions: [2.0, 6.0, 2.0],
@@ -189,6 +202,7 @@ pub(crate) fn hanging_sign_model_upscaled(custom_texture: &str, is_wall: bool) -
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [pos_x, pos_y, pos_z],
@@ -198,6 +212,7 @@ pub(crate) fn hanging_sign_model_upscaled(custom_texture: &str, is_wall: bool) -
             children: vec![],
         };
         let right_chain = EntityPart {
+            name: "right_chain".into(),
             cubes: vec![EntityCube {
                 origin: [4.0, -6.0, -1.0],
                 dimensions: [2.0, 6.0, 2.0],
@@ -205,6 +220,7 @@ pub(crate) fn hanging_sign_model_upscaled(custom_texture: &str, is_wall: bool) -
                 inflate: 0.0,
                 mirror: true,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [pos_x, pos_y, pos_z],
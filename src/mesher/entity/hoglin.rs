@@ -0,0 +1,65 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+use crate::types::InputBlock;
+
+/// Hoglin model — texture `entity/hoglin/hoglin`, 128x64.
+/// From HoglinModel.java (MC 1.21.4). The head is lowered 50°, with ears
+/// and tusks as its children; the mane is a flat plane along the back.
+/// `entity:zoglin` shares the model with `entity/hoglin/zoglin`.
+pub(super) fn hoglin_model(block: &InputBlock) -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+    let part = |name: &str, position: [f32; 3], rotation: [f32; 3], cube: EntityCube| {
+        EntityPart::posed(name, position, rotation, vec![cube], vec![])
+    };
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, 2.0, -12.0],
+        [0.87266463, 0.0, 0.0],
+        vec![EntityCube::new([-7.0, -3.0, -19.0], [14.0, 6.0, 19.0], [61, 1])],
+        vec![
+            part("right_ear", [-6.0, -2.0, -3.0], [0.0, 0.0, -0.6981317],
+                EntityCube::new([-6.0, -1.0, -2.0], [6.0, 1.0, 4.0], [1, 1])),
+            part("left_ear", [6.0, -2.0, -3.0], [0.0, 0.0, 0.6981317],
+                EntityCube::new([0.0, -1.0, -2.0], [6.0, 1.0, 4.0], [1, 6])),
+            part("right_horn", [-7.0, 2.0, -12.0], no_rotation,
+                EntityCube::new([-1.0, -11.0, -1.0], [2.0, 11.0, 2.0], [10, 13])),
+            part("left_horn", [7.0, 2.0, -12.0], no_rotation,
+                EntityCube::new([-1.0, -11.0, -1.0], [2.0, 11.0, 2.0], [1, 13])),
+        ],
+    );
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 7.0, 0.0],
+        no_rotation,
+        vec![EntityCube::new([-8.0, -7.0, -13.0], [16.0, 14.0, 26.0], [1, 1])],
+        vec![part("mane", [0.0, -14.0, -5.0], no_rotation,
+            EntityCube::new([0.0, 0.0, -9.0], [0.0, 10.0, 19.0], [90, 33]).inflated(0.001))],
+    );
+
+    let root = mob_root(vec![
+        head,
+        body,
+        part("right_front_leg", [-4.0, 10.0, -8.5], no_rotation,
+            EntityCube::new([-3.0, 0.0, -3.0], [6.0, 14.0, 6.0], [41, 42])),
+        part("left_front_leg", [4.0, 10.0, -8.5], no_rotation,
+            EntityCube::new([-3.0, 0.0, -3.0], [6.0, 14.0, 6.0], [66, 42])),
+        part("right_hind_leg", [-5.0, 13.0, 10.0], no_rotation,
+            EntityCube::new([-2.5, 0.0, -2.5], [5.0, 11.0, 5.0], [21, 45])),
+        part("left_hind_leg", [5.0, 13.0, 10.0], no_rotation,
+            EntityCube::new([-2.5, 0.0, -2.5], [5.0, 11.0, 5.0], [0, 45])),
+    ]);
+
+    let texture_path = match block.block_id() {
+        "zoglin" => "entity/hoglin/zoglin",
+        _ => "entity/hoglin/hoglin",
+    };
+
+    EntityModelDef {
+        texture_path: texture_path.to_string(),
+        texture_size: [128, 64],
+        parts: vec![root],
+        is_opaque: false, // Mane is a cut-out plane
+    }
+}
@@ -6,6 +6,7 @@ const DEG_30: f32 = 0.5235988;
 /// From AbstractEquineModel.java (MC 1.21.5).
 pub(super) fn horse_model() -> EntityModelDef {
     let left_ear = EntityPart {
+        name: "left_ear".into(),
         cubes: vec![EntityCube {
             origin: [0.55, -13.0, 4.0],
             dimensions: [2.0, 3.0, 1.0],
@@ -13,12 +14,14 @@ pub(super) fn horse_model() -> EntityModelDef {
             inflate: -0.001,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let right_ear = EntityPart {
+        name: "right_ear".into(),
         cubes: vec![EntityCube {
             origin: [-2.55, -13.0, 4.0],
             dimensions: [2.0, 3.0, 1.0],
@@ -26,6 +29,7 @@ pub(super) fn horse_model() -> EntityModelDef {
             inflate: -0.001,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -33,6 +37,7 @@ pub(super) fn horse_model() -> EntityModelDef {
 
     // Head: the forehead/upper skull cube, with ears attached.
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, -11.0, -2.0],
             dimensions: [6.0, 5.0, 7.0],
@@ -40,12 +45,14 @@ pub(super) fn horse_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![left_ear, right_ear],
     };
 
     let mane = EntityPart {
+        name: "mane".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -11.0, 5.01],
             dimensions: [2.0, 16.0, 2.0],
@@ -53,12 +60,14 @@ pub(super) fn horse_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let upper_mouth = EntityPart {
+        name: "upper_mouth".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, -11.0, -7.0],
             dimensions: [4.0, 5.0, 5.0],
@@ -66,6 +75,7 @@ pub(super) fn horse_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -73,6 +83,7 @@ pub(super) fn horse_model() -> EntityModelDef {
 
     // head_parts = neck box with head/mane/mouth as children.
     let head_parts = EntityPart {
+        name: "head_parts".into(),
         cubes: vec![EntityCube {
             origin: [-2.05, -6.0, -2.0],
             dimensions: [4.0, 12.0, 7.0],
@@ -80,6 +91,7 @@ pub(super) fn horse_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 4.0, -12.0],
@@ -91,6 +103,7 @@ pub(super) fn horse_model() -> EntityModelDef {
 
     // Tail is a CHILD of body in MC, positioned relative to the body.
     let tail = EntityPart {
+        name: "tail".into(),
         cubes: vec![EntityCube {
             origin: [-1.5, 0.0, 0.0],
             dimensions: [3.0, 14.0, 4.0],
@@ -98,6 +111,7 @@ pub(super) fn horse_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, -5.0, 2.0],
@@ -109,6 +123,7 @@ pub(super) fn horse_model() -> EntityModelDef {
 
     // Body box is already horizontal (22 long in Z). No rotation needed.
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-5.0, -8.0, -17.0],
             dimensions: [10.0, 10.0, 22.0],
@@ -116,6 +131,7 @@ pub(super) fn horse_model() -> EntityModelDef {
             inflate: 0.05,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 11.0, 5.0],
@@ -126,8 +142,9 @@ pub(super) fn horse_model() -> EntityModelDef {
 
     // Legs: right side uses origin (-1, -1.01, ...), left side is mirrored with
     // origin (-3, -1.01, ...). Hind Z origin -1, front Z origin -1.9.
-    fn leg(origin: [f32; 3], mirror: bool, position: [f32; 3]) -> EntityPart {
+    fn leg(name: &str, origin: [f32; 3], mirror: bool, position: [f32; 3]) -> EntityPart {
         EntityPart {
+            name: name.into(),
             cubes: vec![EntityCube {
                 origin,
                 dimensions: [4.0, 11.0, 4.0],
@@ -135,6 +152,7 @@ pub(super) fn horse_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position,
@@ -144,12 +162,13 @@ pub(super) fn horse_model() -> EntityModelDef {
         }
     }
 
-    let right_hind_leg = leg([-1.0, -1.01, -1.0], false, [-4.0, 14.0, 7.0]);
-    let left_hind_leg  = leg([-3.0, -1.01, -1.0], true,  [ 4.0, 14.0, 7.0]);
-    let right_front_leg = leg([-1.0, -1.01, -1.9], false, [-4.0, 14.0, -10.0]);
-    let left_front_leg  = leg([-3.0, -1.01, -1.9], true,  [ 4.0, 14.0, -10.0]);
+    let right_hind_leg = leg("right_hind_leg", [-1.0, -1.01, -1.0], false, [-4.0, 14.0, 7.0]);
+    let left_hind_leg  = leg("left_hind_leg", [-3.0, -1.01, -1.0], true,  [ 4.0, 14.0, 7.0]);
+    let right_front_leg = leg("right_front_leg", [-1.0, -1.01, -1.9], false, [-4.0, 14.0, -10.0]);
+    let left_front_leg  = leg("left_front_leg", [-3.0, -1.01, -1.9], true,  [ 4.0, 14.0, -10.0]);
 
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
@@ -1,6 +1,7 @@
 use crate::mesher::geometry::Vertex;
 use crate::resolver::ModelResolver;
-use crate::resource_pack::ResourcePack;
+use crate::resource_pack::item_definition::split_item_list;
+use crate::resource_pack::{ItemDisplayContext, ItemStack, ResourcePack};
 use super::EntityFaceTexture;
 
 /// Grid layout constants (fallback transparent grid).
@@ -21,14 +22,15 @@ const GUI_BOTTOM_PAD: u32 = 7; // border below last slot row
 /// Returns (vertices, indices, face_textures, texture_data) for a horizontal quad
 /// at y=1.5 above the block, displaying a grid of item icons.
 ///
-/// `inventory_str`: CSV of item IDs. Empty = empty slot. Optional `:count` suffix.
-/// Example: "diamond_sword,apple,,stone:64"
+/// `inventory_str`: CSV of item stacks. Empty = empty slot. Optional `:count` suffix
+/// and `[component=value,...]` components (see [`ItemStack::parse`]).
+/// Example: "diamond_sword,apple,,stone:64,compass[lodestone_tracker={}]"
 pub(crate) fn render_inventory_hologram(
     resource_pack: &ResourcePack,
     model_resolver: &ModelResolver,
     inventory_str: &str,
 ) -> Option<(Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>, crate::resource_pack::TextureData)> {
-    let slots: Vec<&str> = inventory_str.split(',').collect();
+    let slots = split_item_list(inventory_str);
     if slots.is_empty() {
         return None;
     }
@@ -75,19 +77,12 @@ fn render_gui_inventory(
 
     // Blit item icons at correct slot positions
     for (i, slot) in slots.iter().enumerate() {
-        let slot = slot.trim();
-        if slot.is_empty() {
-            continue;
-        }
-
-        let item_name = slot.split(':').next().unwrap_or(slot);
-        let item_id = if item_name.contains(':') {
-            item_name.to_string()
-        } else {
-            format!("minecraft:{}", item_name)
+        let stack = match ItemStack::parse(slot) {
+            Some(stack) => stack,
+            None => continue,
         };
 
-        if let Some(tex_path) = resolve_item_icon(resource_pack, model_resolver, &item_id) {
+        if let Some(tex_path) = resolve_item_icon(resource_pack, model_resolver, &stack) {
             if let Some(tex) = resource_pack.get_texture(&tex_path) {
                 let frame = tex.first_frame();
                 let col = (i as u32) % COLS;
@@ -131,19 +126,12 @@ fn render_fallback_inventory(
     let mut pixels = vec![0u8; (grid_w * grid_h * 4) as usize];
 
     for (i, slot) in slots.iter().enumerate() {
-        let slot = slot.trim();
-        if slot.is_empty() {
-            continue;
-        }
-
-        let item_name = slot.split(':').next().unwrap_or(slot);
-        let item_id = if item_name.contains(':') {
-            item_name.to_string()
-        } else {
-            format!("minecraft:{}", item_name)
+        let stack = match ItemStack::parse(slot) {
+            Some(stack) => stack,
+            None => continue,
         };
 
-        if let Some(tex_path) = resolve_item_icon(resource_pack, model_resolver, &item_id) {
+        if let Some(tex_path) = resolve_item_icon(resource_pack, model_resolver, &stack) {
             if let Some(tex) = resource_pack.get_texture(&tex_path) {
                 let frame = tex.first_frame();
                 let col = (i as u32) % COLS;
@@ -173,37 +161,27 @@ fn render_fallback_inventory(
 }
 
 /// Resolve an item to its primary icon texture path.
+///
+/// Uses the first model the item resolves to in the `gui` context (its item
+/// definition, or the `item/` then `block/` model).
 fn resolve_item_icon(
-    _resource_pack: &ResourcePack,
+    resource_pack: &ResourcePack,
     model_resolver: &ModelResolver,
-    item_id: &str,
+    stack: &ItemStack,
 ) -> Option<String> {
-    let name = item_id.strip_prefix("minecraft:").unwrap_or(item_id);
-
-    // Try item model first
-    let item_path = format!("minecraft:item/{}", name);
-    if let Ok(model) = model_resolver.resolve(&item_path) {
-        let textures = model_resolver.resolve_textures(&model);
-        // Generated items: layer0
-        if let Some(tex) = textures.get("layer0") {
-            return Some(tex.clone());
-        }
-        // Block items: check for common texture vars
-        for key in &["all", "top", "front", "side", "particle"] {
-            if let Some(tex) = textures.get(*key) {
-                return Some(tex.clone());
-            }
-        }
+    let models = super::item_render::resolve_item_models(
+        resource_pack, model_resolver, stack, ItemDisplayContext::Gui,
+    );
+    let (model, _) = models.first()?;
+    let textures = model_resolver.resolve_textures(model);
+    // Generated items: layer0
+    if let Some(tex) = textures.get("layer0") {
+        return Some(tex.clone());
     }
-
-    // Fallback: try block model
-    let block_path = format!("minecraft:block/{}", name);
-    if let Ok(model) = model_resolver.resolve(&block_path) {
-        let textures = model_resolver.resolve_textures(&model);
-        for key in &["all", "top", "front", "side", "particle"] {
-            if let Some(tex) = textures.get(*key) {
-                return Some(tex.clone());
-            }
+    // Block items: check for common texture vars
+    for key in &["all", "top", "front", "side", "particle"] {
+        if let Some(tex) = textures.get(*key) {
+            return Some(tex.clone());
         }
     }
 
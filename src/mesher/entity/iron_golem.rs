@@ -6,6 +6,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 pub(super) fn iron_golem_model() -> EntityModelDef {
     // Nose child of head
     let nose = EntityPart {
+        name: "nose".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -5.0, -7.9],
             dimensions: [2.0, 4.0, 2.0],
@@ -13,12 +14,14 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -12.0, -5.5],
             dimensions: [8.0, 10.0, 8.0],
@@ -26,6 +29,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, -7.0, -2.0],
@@ -35,6 +39,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-9.0, -2.0, -6.0],
             dimensions: [18.0, 12.0, 11.0],
@@ -42,6 +47,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, -7.0, 0.0],
@@ -52,6 +58,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
 
     // Waist/lower body — thinner torso section
     let waist = EntityPart {
+        name: "waist".into(),
         cubes: vec![EntityCube {
             origin: [-4.5, 0.0, -3.0],
             dimensions: [9.0, 5.0, 6.0],
@@ -59,6 +66,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
             inflate: 0.5,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 5.0, 0.0],
@@ -69,6 +77,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
 
     // Arms — 4×30×6 (very long and thick)
     let right_arm = EntityPart {
+        name: "right_arm".into(),
         cubes: vec![EntityCube {
             origin: [-13.0, -2.5, -3.0],
             dimensions: [4.0, 30.0, 6.0],
@@ -76,6 +85,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, -7.0, 0.0],
@@ -85,6 +95,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
     };
 
     let left_arm = EntityPart {
+        name: "left_arm".into(),
         cubes: vec![EntityCube {
             origin: [9.0, -2.5, -3.0],
             dimensions: [4.0, 30.0, 6.0],
@@ -92,6 +103,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, -7.0, 0.0],
@@ -102,6 +114,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
 
     // Legs — 6×16×5
     let right_leg = EntityPart {
+        name: "right_leg".into(),
         cubes: vec![EntityCube {
             origin: [-3.5, 0.0, -3.0],
             dimensions: [6.0, 16.0, 5.0],
@@ -109,6 +122,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-4.0, 11.0, 0.0],
@@ -118,6 +132,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
     };
 
     let left_leg = EntityPart {
+        name: "left_leg".into(),
         cubes: vec![EntityCube {
             origin: [-3.5, 0.0, -3.0],
             dimensions: [6.0, 16.0, 5.0],
@@ -125,6 +140,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: true,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [5.0, 11.0, 0.0],
@@ -137,6 +153,7 @@ pub(super) fn iron_golem_model() -> EntityModelDef {
     // leg pose.Y (11) + leg cube max (cube Y-range -3..13 → +13) = 24. Using
     // 43 puts the golem floating 19/16 blocks above the ground.
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
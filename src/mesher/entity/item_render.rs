@@ -1,18 +1,21 @@
-//! Item rendering for item frames and dropped items.
+//! Item rendering for item frames, dropped items and held items.
 //!
 //! Renders items inside item frames: flat sprite items with pixel edge extrusion
 //! (the "paper cutout" look) and 3D block items with display transforms.
-//! Also renders dropped items floating on the ground.
+//! Also renders dropped items floating on the ground, items held in an
+//! armor stand's hands and the items of item display entities. Items are
+//! resolved through the pack's item definitions (`items/*.json`) when
+//! present, falling back to `models/item`.
 
 use super::EntityFaceTexture;
 use crate::mesher::geometry::Vertex;
 use crate::resolver::ModelResolver;
-use crate::resource_pack::{BlockModel, ModelElement, ResourcePack};
+use crate::resource_pack::{BlockModel, ItemDisplayContext, ItemStack, ModelElement, ResourcePack};
 use crate::types::Direction;
 use glam::{Mat4, Vec3, Vec4};
 use std::collections::HashMap;
 
-/// Display transform parsed from a model's `display.fixed` context.
+/// Display transform parsed from a model's `display` block.
 struct DisplayTransform {
     rotation: [f32; 3],    // degrees
     translation: [f32; 3], // 1/16 block units
@@ -35,51 +38,41 @@ enum ItemRenderType {
     Generated {
         layers: Vec<String>,
         display: DisplayTransform,
+        tints: Vec<[f32; 3]>,
     },
     /// 3D block model item (stone, planks, etc.)
     BlockModel {
         model: BlockModel,
         resolved_textures: HashMap<String, String>,
         display: DisplayTransform,
+        tints: Vec<[f32; 3]>,
     },
 }
 
+impl ItemRenderType {
+    fn display(&self) -> &DisplayTransform {
+        match self {
+            ItemRenderType::Generated { display, .. } | ItemRenderType::BlockModel { display, .. } => display,
+        }
+    }
+}
+
 /// Render an item inside an item frame.
 ///
 /// Called from `element.rs::add_mob()` with access to resource pack and model resolver.
+/// `item` is an item stack string (see [`ItemStack::parse`]).
 /// Returns geometry (vertices, indices, face_textures) in item frame local space [0,1],
 /// already transformed for the frame's facing direction.
 pub fn render_item_in_frame(
     resource_pack: &ResourcePack,
     model_resolver: &ModelResolver,
-    item_id: &str,
+    item: &str,
     item_rotation: u8,
     facing: &str,
 ) -> Option<(Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>)> {
-    let item_type = resolve_item_type(resource_pack, model_resolver, item_id)?;
-
-    let (mut vertices, indices, face_textures) = match &item_type {
-        ItemRenderType::Generated { layers, display } => {
-            generate_flat_item(resource_pack, layers, display, item_rotation, facing)
-        }
-        ItemRenderType::BlockModel {
-            model,
-            resolved_textures,
-            display,
-        } => generate_block_item(model, resolved_textures, display, item_rotation, facing),
-    };
-
-    if vertices.is_empty() {
-        return None;
-    }
-
-    // Renormalize normals after transforms
-    for v in &mut vertices {
-        let n = Vec3::new(v.normal[0], v.normal[1], v.normal[2]).normalize_or_zero();
-        v.normal = [n.x, n.y, n.z];
-    }
-
-    Some((vertices, indices, face_textures))
+    render_item(resource_pack, model_resolver, item, ItemDisplayContext::Fixed, |display| {
+        build_item_transform(display, item_rotation, facing)
+    })
 }
 
 /// Render a dropped item floating on the ground.
@@ -89,47 +82,89 @@ pub fn render_item_in_frame(
 pub fn render_dropped_item(
     resource_pack: &ResourcePack,
     model_resolver: &ModelResolver,
-    item_id: &str,
+    item: &str,
     facing: &str,
 ) -> Option<(Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>)> {
-    let name = bare_name(item_id);
+    render_item(resource_pack, model_resolver, item, ItemDisplayContext::Ground, |display| {
+        build_dropped_item_transform(display, facing)
+    })
+}
 
-    // Resolve model (same logic as item frames)
-    let model_path = format!("item/{}", name);
-    let model = model_resolver.resolve(&format!("minecraft:{}", model_path))
-        .or_else(|_| model_resolver.resolve(&format!("minecraft:block/{}", name)))
-        .ok()?;
+/// Render an item held in an entity's hand.
+///
+/// `hand` maps the posed arm's local space to [0,1] block-local space (see
+/// `armor_stand::hand_transform`). Uses the `thirdperson_*hand` display
+/// transforms; left-hand items mirror the right-hand transform when the
+/// model has no left-hand one.
+pub fn render_held_item(
+    resource_pack: &ResourcePack,
+    model_resolver: &ModelResolver,
+    item: &str,
+    hand: Mat4,
+    left_hand: bool,
+) -> Option<(Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>)> {
+    let context = if left_hand {
+        ItemDisplayContext::ThirdPersonLeftHand
+    } else {
+        ItemDisplayContext::ThirdPersonRightHand
+    };
+    render_item(resource_pack, model_resolver, item, context, |display| {
+        build_held_item_transform(display, &hand, left_hand)
+    })
+}
 
-    let display = parse_display_ground(&model.display);
-    let resolved_textures = model_resolver.resolve_textures(&model);
+/// Render the item of an `item_display` entity.
+///
+/// Like ItemDisplayRenderer, the item is centered on the entity origin and
+/// turned 180° about Y, with the display transform of `context` (none for
+/// `None`, the entity's `item_display: none`). Returns geometry in display
+/// space, before the entity's `transformation`.
+pub fn render_display_item(
+    resource_pack: &ResourcePack,
+    model_resolver: &ModelResolver,
+    item: &str,
+    context: Option<ItemDisplayContext>,
+) -> Option<(Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>)> {
+    let resolve_context = context.unwrap_or(ItemDisplayContext::Fixed);
+    render_item(resource_pack, model_resolver, item, resolve_context, |display| {
+        let display_mat = match context {
+            Some(context) => display_matrix(display, context.is_left_hand()),
+            None => Mat4::IDENTITY,
+        };
+        Mat4::from_rotation_y(std::f32::consts::PI)
+            * display_mat
+            * Mat4::from_translation(Vec3::new(-0.5, -0.5, -0.5))
+    })
+}
 
-    let is_generated = model.parent.as_deref() == Some("builtin/generated")
-        || (model.elements.is_empty() && has_layer_textures(&resolved_textures));
+/// Resolve an item stack and build the geometry of every model it renders
+/// with, each placed by `transform` from its display transform.
+fn render_item(
+    resource_pack: &ResourcePack,
+    model_resolver: &ModelResolver,
+    item: &str,
+    context: ItemDisplayContext,
+    transform: impl Fn(&DisplayTransform) -> Mat4,
+) -> Option<(Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>)> {
+    let stack = ItemStack::parse(item)?;
 
-    let (mut vertices, indices, face_textures) = if is_generated {
-        let mut layers = Vec::new();
-        for i in 0..4 {
-            let key = format!("layer{}", i);
-            if let Some(tex) = resolved_textures.get(&key) {
-                layers.push(tex.clone());
-            } else {
-                break;
-            }
-        }
-        if layers.is_empty() {
-            return None;
-        }
-        generate_flat_dropped(resource_pack, &layers, &display, facing)
-    } else if !model.elements.is_empty() {
-        generate_block_dropped(&model, &resolved_textures, &display, facing)
-    } else {
-        return None;
-    };
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+    let mut face_textures = Vec::new();
+    for item_type in resolve_item_types(resource_pack, model_resolver, &stack, context) {
+        let (mut item_verts, item_indices, item_faces) = item_geometry(resource_pack, &item_type);
+        transform_vertices(&mut item_verts, &transform(item_type.display()));
+        let base = vertices.len() as u32;
+        vertices.extend(item_verts);
+        indices.extend(item_indices.iter().map(|i| i + base));
+        face_textures.extend(item_faces);
+    }
 
     if vertices.is_empty() {
         return None;
     }
 
+    // Renormalize normals after transforms
     for v in &mut vertices {
         let n = Vec3::new(v.normal[0], v.normal[1], v.normal[2]).normalize_or_zero();
         v.normal = [n.x, n.y, n.z];
@@ -138,148 +173,64 @@ pub fn render_dropped_item(
     Some((vertices, indices, face_textures))
 }
 
-/// Build transform for a dropped item on the ground.
+/// Resolve an item stack to the models it renders with, plus the tint color
+/// of each `tintindex`.
 ///
-/// Pipeline:
-/// 1. Center at origin
-/// 2. Display.ground transform
-/// 3. Facing rotation (Y-axis)
-/// 4. Translate to hover position (center of block, slight hover)
-fn build_dropped_item_transform(
-    display: &DisplayTransform,
-    facing: &str,
-) -> Mat4 {
-    let center = Mat4::from_translation(Vec3::new(-0.5, -0.5, -0.5));
-
-    let dt_translate = Mat4::from_translation(Vec3::new(
-        display.translation[0] / 16.0,
-        display.translation[1] / 16.0,
-        display.translation[2] / 16.0,
-    ));
-    let dt_rot_y = Mat4::from_rotation_y(display.rotation[1].to_radians());
-    let dt_rot_x = Mat4::from_rotation_x(display.rotation[0].to_radians());
-    let dt_rot_z = Mat4::from_rotation_z(display.rotation[2].to_radians());
-    let dt_scale = Mat4::from_scale(Vec3::new(
-        display.scale[0],
-        display.scale[1],
-        display.scale[2],
-    ));
-    let display_mat = dt_translate * dt_rot_y * dt_rot_x * dt_rot_z * dt_scale;
-
-    // Facing rotation
-    let facing_angle = match facing {
-        "north" => std::f32::consts::PI,
-        "south" => 0.0,
-        "east" => -std::f32::consts::FRAC_PI_2,
-        "west" => std::f32::consts::FRAC_PI_2,
-        _ => 0.0,
-    };
-    let facing_rot = Mat4::from_rotation_y(facing_angle);
-
-    // Place at block center, hovering slightly above ground
-    let to_world = Mat4::from_translation(Vec3::new(0.5, 0.125, 0.5));
-
-    to_world * facing_rot * display_mat * center
-}
-
-/// Generate flat dropped item geometry.
-fn generate_flat_dropped(
+/// Uses the pack's item definition when it has one, evaluated against the
+/// stack's components. Otherwise falls back to `item/<id>`, then `block/<id>`,
+/// as pre-1.21.4 packs do.
+pub(crate) fn resolve_item_models(
     resource_pack: &ResourcePack,
-    layers: &[String],
-    display: &DisplayTransform,
-    facing: &str,
-) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
-    let mut vertices = Vec::new();
-    let mut indices = Vec::new();
-    let mut face_textures = Vec::new();
-
-    for (layer_idx, texture_path) in layers.iter().enumerate() {
-        let tex_data = match resource_pack.get_texture(texture_path) {
-            Some(t) => t.first_frame(),
-            None => continue,
-        };
-        let tw = tex_data.width;
-        let th = tex_data.height;
-        if tw == 0 || th == 0 { continue; }
-
-        let z_offset = layer_idx as f32 * 0.01;
-        let fz = 0.5 + z_offset;
-        let bz = 0.5 - z_offset - 1.0 / 16.0;
-
-        add_flat_face(
-            &mut vertices, &mut indices, &mut face_textures,
-            [0.0, 0.0], [1.0, 1.0], fz, [0.0, 0.0, 1.0],
-            [0.0, 0.0, 1.0, 1.0], false, texture_path,
-        );
-        add_flat_face(
-            &mut vertices, &mut indices, &mut face_textures,
-            [0.0, 0.0], [1.0, 1.0], bz, [0.0, 0.0, -1.0],
-            [1.0, 0.0, 0.0, 1.0], true, texture_path,
-        );
-
-        generate_edges(
-            &tex_data.pixels, tw, th, fz, bz, texture_path,
-            &mut vertices, &mut indices, &mut face_textures,
-        );
-    }
-
-    let mat = build_dropped_item_transform(display, facing);
-    transform_vertices(&mut vertices, &mat);
-    (vertices, indices, face_textures)
-}
-
-/// Generate block dropped item geometry.
-fn generate_block_dropped(
-    model: &BlockModel,
-    resolved_textures: &HashMap<String, String>,
-    display: &DisplayTransform,
-    facing: &str,
-) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
-    let mut vertices = Vec::new();
-    let mut indices = Vec::new();
-    let mut face_textures = Vec::new();
-
-    for element in &model.elements {
-        add_block_element(element, resolved_textures, &mut vertices, &mut indices, &mut face_textures);
+    model_resolver: &ModelResolver,
+    stack: &ItemStack,
+    context: ItemDisplayContext,
+) -> Vec<(BlockModel, Vec<[f32; 3]>)> {
+    if let Some(definition) = resource_pack.get_item_definition(&stack.id) {
+        return definition
+            .model
+            .resolve(stack, context)
+            .into_iter()
+            .filter_map(|resolved| {
+                let model = model_resolver.resolve(&resolved.model).ok()?;
+                let tints = resolved
+                    .tints
+                    .iter()
+                    .map(|tint| tint.tint_color(stack, resource_pack))
+                    .collect();
+                Some((model, tints))
+            })
+            .collect();
     }
 
-    let mat = build_dropped_item_transform(display, facing);
-    transform_vertices(&mut vertices, &mat);
-    (vertices, indices, face_textures)
+    let (namespace, name) = stack.id.split_once(':').unwrap_or(("minecraft", &stack.id));
+    model_resolver
+        .resolve(&format!("{}:item/{}", namespace, name))
+        .or_else(|_| model_resolver.resolve(&format!("{}:block/{}", namespace, name)))
+        .map(|model| vec![(model, Vec::new())])
+        .unwrap_or_default()
 }
 
-/// Strip `minecraft:` prefix from an item ID.
-fn bare_name(item_id: &str) -> &str {
-    item_id
-        .strip_prefix("minecraft:")
-        .unwrap_or(item_id)
+/// Resolve an item stack to its render types.
+fn resolve_item_types(
+    resource_pack: &ResourcePack,
+    model_resolver: &ModelResolver,
+    stack: &ItemStack,
+    context: ItemDisplayContext,
+) -> Vec<ItemRenderType> {
+    resolve_item_models(resource_pack, model_resolver, stack, context)
+        .into_iter()
+        .filter_map(|(model, tints)| item_render_type(model_resolver, model, context, tints))
+        .collect()
 }
 
-/// Resolve an item ID to its render type by checking item and block model paths.
-fn resolve_item_type(
-    _resource_pack: &ResourcePack,
+/// Classify a resolved item model as a flat sprite or a block model.
+fn item_render_type(
     model_resolver: &ModelResolver,
-    item_id: &str,
+    model: BlockModel,
+    context: ItemDisplayContext,
+    tints: Vec<[f32; 3]>,
 ) -> Option<ItemRenderType> {
-    let name = bare_name(item_id);
-
-    // Try item model first, then block model
-    let model_path = format!("item/{}", name);
-    let resolved = model_resolver.resolve(&format!("minecraft:{}", model_path));
-
-    let model = match resolved {
-        Ok(m) => m,
-        Err(_) => {
-            // Fallback: try block model directly
-            let block_path = format!("block/{}", name);
-            match model_resolver.resolve(&format!("minecraft:{}", block_path)) {
-                Ok(m) => m,
-                Err(_) => return None,
-            }
-        }
-    };
-
-    let display = parse_display_fixed(&model.display);
+    let display = parse_display_context(&model.display, display_key(&model.display, context));
     let resolved_textures = model_resolver.resolve_textures(&model);
 
     // Check if this is a generated/flat item
@@ -299,12 +250,13 @@ fn resolve_item_type(
         if layers.is_empty() {
             return None;
         }
-        Some(ItemRenderType::Generated { layers, display })
+        Some(ItemRenderType::Generated { layers, display, tints })
     } else if !model.elements.is_empty() {
         Some(ItemRenderType::BlockModel {
             model,
             resolved_textures,
             display,
+            tints,
         })
     } else {
         None
@@ -316,6 +268,17 @@ fn has_layer_textures(textures: &HashMap<String, String>) -> bool {
     textures.contains_key("layer0")
 }
 
+/// The `display` entry to use for a context. Like the game, a missing
+/// left-hand entry falls back to the right-hand one (mirrored when applied).
+fn display_key(display: &Option<serde_json::Value>, context: ItemDisplayContext) -> &'static str {
+    let has = |key: &str| display.as_ref().is_some_and(|d| d.get(key).is_some());
+    if context.is_left_hand() && !has(context.as_str()) {
+        context.right_hand().as_str()
+    } else {
+        context.as_str()
+    }
+}
+
 /// Parse a display transform from a specific context (e.g., "fixed", "ground").
 fn parse_display_context(display: &Option<serde_json::Value>, context: &str) -> DisplayTransform {
     let display_val = match display {
@@ -339,16 +302,6 @@ fn parse_display_context(display: &Option<serde_json::Value>, context: &str) ->
     }
 }
 
-/// Convenience: parse `display.fixed`.
-fn parse_display_fixed(display: &Option<serde_json::Value>) -> DisplayTransform {
-    parse_display_context(display, "fixed")
-}
-
-/// Convenience: parse `display.ground`.
-fn parse_display_ground(display: &Option<serde_json::Value>) -> DisplayTransform {
-    parse_display_context(display, "ground")
-}
-
 /// Parse a JSON array of 3 floats, with a default fallback.
 fn parse_f32_array(val: Option<&serde_json::Value>, default: [f32; 3]) -> [f32; 3] {
     match val {
@@ -361,6 +314,26 @@ fn parse_f32_array(val: Option<&serde_json::Value>, default: [f32; 3]) -> [f32;
     }
 }
 
+/// Display transform as a matrix: translate(t/16) * rotY * rotX * rotZ * scale.
+/// `mirror` flips it for the left hand (negated X translation and Y/Z rotation).
+fn display_matrix(display: &DisplayTransform, mirror: bool) -> Mat4 {
+    let side = if mirror { -1.0 } else { 1.0 };
+    let dt_translate = Mat4::from_translation(Vec3::new(
+        side * display.translation[0] / 16.0,
+        display.translation[1] / 16.0,
+        display.translation[2] / 16.0,
+    ));
+    let dt_rot_y = Mat4::from_rotation_y((side * display.rotation[1]).to_radians());
+    let dt_rot_x = Mat4::from_rotation_x(display.rotation[0].to_radians());
+    let dt_rot_z = Mat4::from_rotation_z((side * display.rotation[2]).to_radians());
+    let dt_scale = Mat4::from_scale(Vec3::new(
+        display.scale[0],
+        display.scale[1],
+        display.scale[2],
+    ));
+    dt_translate * dt_rot_y * dt_rot_x * dt_rot_z * dt_scale
+}
+
 /// Build the combined transform matrix for an item in a frame.
 ///
 /// Pipeline:
@@ -378,20 +351,7 @@ fn build_item_transform(
     let center = Mat4::from_translation(Vec3::new(-0.5, -0.5, -0.5));
 
     // Step 2: display transform
-    let dt_translate = Mat4::from_translation(Vec3::new(
-        display.translation[0] / 16.0,
-        display.translation[1] / 16.0,
-        display.translation[2] / 16.0,
-    ));
-    let dt_rot_y = Mat4::from_rotation_y(display.rotation[1].to_radians());
-    let dt_rot_x = Mat4::from_rotation_x(display.rotation[0].to_radians());
-    let dt_rot_z = Mat4::from_rotation_z(display.rotation[2].to_radians());
-    let dt_scale = Mat4::from_scale(Vec3::new(
-        display.scale[0],
-        display.scale[1],
-        display.scale[2],
-    ));
-    let display_mat = dt_translate * dt_rot_y * dt_rot_x * dt_rot_z * dt_scale;
+    let display_mat = display_matrix(display, false);
 
     // Step 2b: Minecraft's ItemFrameRenderer applies an additional scale(0.5) after
     // display.fixed transforms (see RenderItemFrame.renderItem in MC source)
@@ -422,6 +382,60 @@ fn build_item_transform(
     facing_mat * to_frame * item_rot * renderer_scale * display_mat * center
 }
 
+/// Build transform for a dropped item on the ground.
+///
+/// Pipeline:
+/// 1. Center at origin
+/// 2. Display.ground transform
+/// 3. Facing rotation (Y-axis)
+/// 4. Translate to hover position (center of block, slight hover)
+fn build_dropped_item_transform(
+    display: &DisplayTransform,
+    facing: &str,
+) -> Mat4 {
+    let center = Mat4::from_translation(Vec3::new(-0.5, -0.5, -0.5));
+    let display_mat = display_matrix(display, false);
+
+    // Facing rotation
+    let facing_angle = match facing {
+        "north" => std::f32::consts::PI,
+        "south" => 0.0,
+        "east" => -std::f32::consts::FRAC_PI_2,
+        "west" => std::f32::consts::FRAC_PI_2,
+        _ => 0.0,
+    };
+    let facing_rot = Mat4::from_rotation_y(facing_angle);
+
+    // Place at block center, hovering slightly above ground
+    let to_world = Mat4::from_translation(Vec3::new(0.5, 0.125, 0.5));
+
+    to_world * facing_rot * display_mat * center
+}
+
+/// Build transform for an item held in a hand.
+///
+/// Pipeline (Minecraft's ItemInHandLayer):
+/// 1. Center at origin
+/// 2. Display.thirdperson transform, mirrored for the left hand
+/// 3. Move to the fist: translate(±1/16, 2/16, -10/16), then rotY(180) * rotX(-90)
+///    to point the item along the arm
+/// 4. Arm pose and entity placement (`hand`)
+fn build_held_item_transform(
+    display: &DisplayTransform,
+    hand: &Mat4,
+    left_hand: bool,
+) -> Mat4 {
+    let center = Mat4::from_translation(Vec3::new(-0.5, -0.5, -0.5));
+    let display_mat = display_matrix(display, left_hand);
+
+    let side = if left_hand { -1.0 } else { 1.0 };
+    let to_fist = Mat4::from_rotation_x(-std::f32::consts::FRAC_PI_2)
+        * Mat4::from_rotation_y(std::f32::consts::PI)
+        * Mat4::from_translation(Vec3::new(side / 16.0, 0.125, -0.625));
+
+    *hand * to_fist * display_mat * center
+}
+
 /// Apply a transform matrix to all vertices (positions and normals).
 fn transform_vertices(vertices: &mut [Vertex], mat: &Mat4) {
     for v in vertices {
@@ -432,18 +446,42 @@ fn transform_vertices(vertices: &mut [Vertex], mat: &Mat4) {
     }
 }
 
+/// Build an item's untransformed geometry in [0,1] model space.
+fn item_geometry(
+    resource_pack: &ResourcePack,
+    item_type: &ItemRenderType,
+) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
+    match item_type {
+        ItemRenderType::Generated { layers, tints, .. } => {
+            generate_flat_item(resource_pack, layers, tints)
+        }
+        ItemRenderType::BlockModel {
+            model,
+            resolved_textures,
+            tints,
+            ..
+        } => generate_block_item(model, resolved_textures, tints),
+    }
+}
+
+/// Multiply a vertex color by a tint.
+fn apply_tint(vertex: &mut Vertex, tint: [f32; 3]) {
+    vertex.color[0] *= tint[0];
+    vertex.color[1] *= tint[1];
+    vertex.color[2] *= tint[2];
+}
+
 // ── Flat Item Rendering ─────────────────────────────────────────────────────
 
 /// Generate flat item geometry with pixel edge extrusion.
 ///
 /// Creates front/back face quads plus thin edge quads at transparent boundaries,
 /// producing the characteristic Minecraft "paper cutout" look for items.
+/// Layer `i` is tinted by `tints[i]`, when present.
 fn generate_flat_item(
     resource_pack: &ResourcePack,
     layers: &[String],
-    display: &DisplayTransform,
-    item_rotation: u8,
-    facing: &str,
+    tints: &[[f32; 3]],
 ) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
     let mut vertices = Vec::new();
     let mut indices = Vec::new();
@@ -460,6 +498,7 @@ fn generate_flat_item(
         if tw == 0 || th == 0 {
             continue;
         }
+        let layer_start = vertices.len();
 
         // Z offset for multi-layer items to avoid z-fighting
         let z_offset = layer_idx as f32 * 0.01;
@@ -494,11 +533,13 @@ fn generate_flat_item(
             texture_path,
             &mut vertices, &mut indices, &mut face_textures,
         );
-    }
 
-    // Apply item transforms
-    let mat = build_item_transform(display, item_rotation, facing);
-    transform_vertices(&mut vertices, &mat);
+        if let Some(&tint) = tints.get(layer_idx) {
+            for v in &mut vertices[layer_start..] {
+                apply_tint(v, tint);
+            }
+        }
+    }
 
     (vertices, indices, face_textures)
 }
@@ -684,9 +725,7 @@ fn add_edge_quad(
 fn generate_block_item(
     model: &BlockModel,
     resolved_textures: &HashMap<String, String>,
-    display: &DisplayTransform,
-    item_rotation: u8,
-    facing: &str,
+    tints: &[[f32; 3]],
 ) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
     let mut vertices = Vec::new();
     let mut indices = Vec::new();
@@ -696,23 +735,22 @@ fn generate_block_item(
         add_block_element(
             element,
             resolved_textures,
+            tints,
             &mut vertices,
             &mut indices,
             &mut face_textures,
         );
     }
 
-    // Apply item transforms
-    let mat = build_item_transform(display, item_rotation, facing);
-    transform_vertices(&mut vertices, &mat);
-
     (vertices, indices, face_textures)
 }
 
 /// Generate geometry for a single model element (all 6 faces).
+/// Faces with a `tintindex` take their color from `tints`.
 fn add_block_element(
     element: &ModelElement,
     resolved_textures: &HashMap<String, String>,
+    tints: &[[f32; 3]],
     vertices: &mut Vec<Vertex>,
     indices: &mut Vec<u32>,
     face_textures: &mut Vec<EntityFaceTexture>,
@@ -794,9 +832,14 @@ fn add_block_element(
             ],
         };
 
+        let tint = usize::try_from(face.tintindex).ok().and_then(|i| tints.get(i));
         let v_start = vertices.len() as u32;
         for i in 0..4 {
-            vertices.push(Vertex::new(positions[i], normal, uvs[i]));
+            let mut vertex = Vertex::new(positions[i], normal, uvs[i]);
+            if let Some(&tint) = tint {
+                apply_tint(&mut vertex, tint);
+            }
+            vertices.push(vertex);
         }
 
         // CCW winding: (0,2,1)(0,3,2) — matches Mesh::add_quad
@@ -828,7 +871,7 @@ mod tests {
             }
         });
 
-        let dt = parse_display_fixed(&Some(json));
+        let dt = parse_display_context(&Some(json), "fixed");
         assert_eq!(dt.rotation, [0.0, 180.0, 0.0]);
         assert_eq!(dt.translation, [0.0, 0.0, 0.0]);
         assert_eq!(dt.scale, [0.5, 0.5, 0.5]);
@@ -836,7 +879,7 @@ mod tests {
 
     #[test]
     fn test_parse_display_missing() {
-        let dt = parse_display_fixed(&None);
+        let dt = parse_display_context(&None, "fixed");
         assert_eq!(dt.rotation, [0.0, 0.0, 0.0]);
         assert_eq!(dt.translation, [0.0, 0.0, 0.0]);
         assert_eq!(dt.scale, [1.0, 1.0, 1.0]);
@@ -850,7 +893,7 @@ mod tests {
                 "scale": [0.625, 0.625, 0.625]
             }
         });
-        let dt = parse_display_fixed(&Some(json));
+        let dt = parse_display_context(&Some(json), "fixed");
         assert_eq!(dt.rotation, [0.0, 0.0, 0.0]); // defaults
     }
 
@@ -935,6 +978,7 @@ mod tests {
             to: [16.0, 16.0, 16.0],
             rotation: None,
             shade: true,
+            light_emission: 0,
             faces,
         };
 
@@ -948,6 +992,7 @@ mod tests {
         add_block_element(
             &element,
             &resolved_textures,
+            &[],
             &mut vertices,
             &mut indices,
             &mut face_textures,
@@ -971,4 +1016,40 @@ mod tests {
         assert!((p.y - 0.5).abs() < 0.01);
         assert!((p.z - 15.0 / 16.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_item_definition_selects_model_and_tint() {
+        let mut pack = ResourcePack::new();
+        pack.add_texture("minecraft", "item/plain", crate::resource_pack::TextureData::new(1, 1, vec![255; 4]));
+        pack.add_texture("minecraft", "item/custom", crate::resource_pack::TextureData::new(1, 2, vec![255; 8]));
+        for (path, texture) in [("item/plain", "minecraft:item/plain"), ("item/custom", "minecraft:item/custom")] {
+            let mut model = BlockModel::new();
+            model.parent = Some("builtin/generated".to_string());
+            model.textures.insert("layer0".to_string(), texture.to_string());
+            pack.add_model("minecraft", path, model);
+        }
+        let definition = serde_json::from_value(serde_json::json!({"model": {
+            "type": "select",
+            "property": "custom_model_data",
+            "cases": [{"when": "gem", "model": {
+                "type": "model", "model": "item/custom",
+                "tints": [{"type": "constant", "value": 65280}]
+            }}],
+            "fallback": {"type": "model", "model": "item/plain"}
+        }}));
+        pack.add_item_definition("minecraft", "stick", definition.unwrap());
+        let resolver = ModelResolver::new(&pack);
+
+        let (_, _, faces) = render_item_in_frame(&pack, &resolver, "stick", 0, "south").unwrap();
+        assert!(faces.iter().all(|f| f.texture == "minecraft:item/plain"));
+
+        let item = r#"stick[custom_model_data={"strings":["gem"]}]"#;
+        let (verts, _, faces) = render_item_in_frame(&pack, &resolver, item, 0, "south").unwrap();
+        assert!(faces.iter().all(|f| f.texture == "minecraft:item/custom"));
+        assert!(verts.iter().all(|v| v.color == [0.0, 1.0, 0.0, 1.0]));
+
+        // Held and dropped items go through the same definition.
+        assert!(render_dropped_item(&pack, &resolver, item, "south").is_some());
+        assert!(render_held_item(&pack, &resolver, item, Mat4::IDENTITY, true).is_some());
+    }
 }
@@ -0,0 +1,105 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::{mob_root, mob_variant};
+use crate::types::InputBlock;
+
+/// Llama model — texture `entity/llama/<variant>`, 128x64.
+/// From LlamaModel.java (MC 1.21.4). `variant`: `creamy` (default), `white`,
+/// `brown` or `gray`, or the game's 0–3 id. Snout, neck and ears are all
+/// cubes of the head part. `chest=true` adds the side chests.
+pub(super) fn llama_model(block: &InputBlock) -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+    let half_pi = std::f32::consts::FRAC_PI_2;
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, 7.0, -6.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-2.0, -14.0, -10.0], [4.0, 4.0, 9.0], [0, 0]),
+            EntityCube::new([-4.0, -16.0, -6.0], [8.0, 18.0, 6.0], [0, 14]),
+            EntityCube::new([-4.0, -19.0, -4.0], [3.0, 3.0, 2.0], [17, 0]),
+            EntityCube::new([1.0, -19.0, -4.0], [3.0, 3.0, 2.0], [17, 0]),
+        ],
+        vec![],
+    );
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 5.0, 2.0],
+        [half_pi, 0.0, 0.0],
+        vec![EntityCube::new([-6.0, -10.0, -7.0], [12.0, 18.0, 10.0], [29, 0])],
+        vec![],
+    );
+
+    let leg = |name: &str, x: f32, z: f32| {
+        EntityPart::posed(
+            name,
+            [x, 10.0, z],
+            no_rotation,
+            vec![EntityCube::new([-2.0, 0.0, -2.0], [4.0, 14.0, 4.0], [29, 29])],
+            vec![],
+        )
+    };
+
+    let mut children = vec![
+        head,
+        body,
+        leg("right_hind_leg", -3.5, 6.0),
+        leg("left_hind_leg", 3.5, 6.0),
+        leg("right_front_leg", -3.5, -5.0),
+        leg("left_front_leg", 3.5, -5.0),
+    ];
+    if block.properties.get("chest").map(|v| v == "true").unwrap_or(false) {
+        for (name, x, tex_offset) in [("right_chest", -8.5, [45, 28]), ("left_chest", 5.5, [45, 41])] {
+            children.push(EntityPart::posed(
+                name,
+                [x, 3.0, 3.0],
+                [0.0, half_pi, 0.0],
+                vec![EntityCube::new([-3.0, 0.0, 0.0], [8.0, 8.0, 3.0], tex_offset)],
+                vec![],
+            ));
+        }
+    }
+
+    let variant = mob_variant(block, &["creamy", "white", "brown", "gray"]);
+
+    EntityModelDef {
+        texture_path: format!("entity/llama/{}", variant),
+        texture_size: [128, 64],
+        parts: vec![mob_root(children)],
+        is_opaque: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_llama_parts_and_variants() {
+        let model = llama_model(&InputBlock::new("entity:llama"));
+        assert_eq!(model.texture_path, "entity/llama/creamy");
+        assert_eq!(model.texture_size, [128, 64]);
+        let names: Vec<&str> = model.parts[0].children.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["head", "body", "right_hind_leg", "left_hind_leg", "right_front_leg", "left_front_leg"]
+        );
+        // Snout, neck and both ears are cubes of the head.
+        assert_eq!(model.parts[0].children[0].cubes.len(), 4);
+
+        let chested = llama_model(&InputBlock::new("entity:llama").with_property("chest", "true"));
+        let names: Vec<&str> = chested.parts[0].children.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names[6..], ["right_chest", "left_chest"]);
+
+        for (variant, texture) in [
+            ("white", "entity/llama/white"),
+            ("minecraft:brown", "entity/llama/brown"),
+            ("3", "entity/llama/gray"),
+            ("purple", "entity/llama/creamy"),
+        ] {
+            let block = InputBlock::new("entity:llama").with_property("variant", variant);
+            assert_eq!(llama_model(&block).texture_path, texture, "{}", variant);
+        }
+    }
+}
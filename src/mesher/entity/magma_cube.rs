@@ -14,6 +14,7 @@ pub(super) fn magma_cube_model() -> EntityModelDef {
                 _ => (32, 9 * i as u32 - 36),
             };
             EntityPart {
+                name: format!("cube{}", i),
                 cubes: vec![EntityCube {
                     origin: [-4.0, 16.0 + i as f32, -4.0],
                     dimensions: [8.0, 1.0, 8.0],
@@ -21,6 +22,7 @@ pub(super) fn magma_cube_model() -> EntityModelDef {
                     inflate: 0.0,
                     mirror: false,
                     skip_faces: vec![],
+                    ..Default::default()
                 }],
                 pose: Default::default(),
                 children: vec![],
@@ -29,6 +31,7 @@ pub(super) fn magma_cube_model() -> EntityModelDef {
         .collect();
 
     children.push(EntityPart {
+        name: "inside_cube".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 18.0, -2.0],
             dimensions: [4.0, 4.0, 4.0],
@@ -36,6 +39,7 @@ pub(super) fn magma_cube_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -43,6 +47,7 @@ pub(super) fn magma_cube_model() -> EntityModelDef {
 
     // Y-down -> Y-up root wrapper.
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
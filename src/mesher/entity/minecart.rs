@@ -4,6 +4,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 /// 5 flat panels forming an open-top box, from MinecartModel.java (MC 1.21.4).
 pub(super) fn minecart_model() -> EntityModelDef {
     let bottom = EntityPart {
+        name: "bottom".into(),
         cubes: vec![EntityCube {
             origin: [-10.0, -8.0, -1.0],
             dimensions: [20.0, 16.0, 2.0],
@@ -11,6 +12,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 4.0, 0.0],
@@ -21,6 +23,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
     };
 
     let front = EntityPart {
+        name: "front".into(),
         cubes: vec![EntityCube {
             origin: [-8.0, -9.0, -1.0],
             dimensions: [16.0, 8.0, 2.0],
@@ -28,6 +31,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-9.0, 4.0, 0.0],
@@ -38,6 +42,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
     };
 
     let back = EntityPart {
+        name: "back".into(),
         cubes: vec![EntityCube {
             origin: [-8.0, -9.0, -1.0],
             dimensions: [16.0, 8.0, 2.0],
@@ -45,6 +50,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [9.0, 4.0, 0.0],
@@ -55,6 +61,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
     };
 
     let left = EntityPart {
+        name: "left".into(),
         cubes: vec![EntityCube {
             origin: [-8.0, -9.0, -1.0],
             dimensions: [16.0, 8.0, 2.0],
@@ -62,6 +69,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 4.0, -7.0],
@@ -72,6 +80,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
     };
 
     let right = EntityPart {
+        name: "right".into(),
         cubes: vec![EntityCube {
             origin: [-8.0, -9.0, -1.0],
             dimensions: [16.0, 8.0, 2.0],
@@ -79,6 +88,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 4.0, 7.0],
@@ -93,6 +103,7 @@ pub(super) fn minecart_model() -> EntityModelDef {
     // with the +Z (south) direction used by humanoid mob models — so
     // `facing_rotation_rad` applies consistently for both the cart and its rider.
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 6.0, 8.0],
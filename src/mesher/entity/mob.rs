@@ -1,15 +1,35 @@
 use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose, MobType};
+use super::allay;
+use super::armadillo;
 use super::armor_stand;
+use super::axolotl;
 use super::bat;
+use super::bee;
 use super::boat;
+use super::camel;
 use super::cat;
 use super::chicken;
 use super::cow;
+use super::dolphin;
 use super::enderman;
+use super::fox;
+use super::frog;
+use super::goat;
+use super::hoglin;
 use super::horse;
 use super::iron_golem;
+use super::llama;
 use super::minecart;
+use super::parrot;
+use super::piglin;
+use super::pillager;
+use super::rabbit;
 use super::sheep;
+use super::sniffer;
+use super::squid;
+use super::strider;
+use super::turtle;
+use super::witch;
 use super::blaze;
 use super::ghast;
 use super::magma_cube;
@@ -17,9 +37,15 @@ use super::slime;
 use super::spider;
 use super::villager;
 use super::wolf;
+use crate::resource_pack::ResourcePack;
 use crate::types::InputBlock;
 
-pub(crate) fn build_mob_model(mob_type: MobType, block: &InputBlock) -> EntityModelDef {
+/// Build a mob's model, preferring the pack's override when one is given.
+pub(crate) fn build_mob_model(
+    mob_type: MobType,
+    block: &InputBlock,
+    resource_pack: Option<&ResourcePack>,
+) -> EntityModelDef {
     let mut model = match mob_type {
         MobType::Zombie => zombie_model(),
         MobType::Skeleton => skeleton_model(),
@@ -57,10 +83,43 @@ pub(crate) fn build_mob_model(mob_type: MobType, block: &InputBlock) -> EntityMo
             block.properties.get("wood").map(|s| s.as_str()).unwrap_or("oak"),
             true,
         ),
-        MobType::ItemFrame | MobType::GlowItemFrame | MobType::DroppedItem | MobType::Player => {
-            unreachable!("Item frames, dropped items, and players handled in generate_mob_geometry/add_mob")
+        MobType::Fox => fox::fox_model(block),
+        MobType::Bee => bee::bee_model(block),
+        MobType::Axolotl => axolotl::axolotl_model(block),
+        MobType::Frog => frog::frog_model(block),
+        MobType::Goat => goat::goat_model(block),
+        MobType::Llama => llama::llama_model(block),
+        MobType::Turtle => turtle::turtle_model(),
+        MobType::Dolphin => dolphin::dolphin_model(),
+        MobType::Squid => squid::squid_model(false),
+        MobType::GlowSquid => squid::squid_model(true),
+        MobType::Parrot => parrot::parrot_model(block),
+        MobType::Rabbit => rabbit::rabbit_model(block),
+        MobType::Piglin => piglin::piglin_model(block),
+        MobType::Hoglin => hoglin::hoglin_model(block),
+        MobType::Strider => strider::strider_model(block),
+        MobType::Allay => allay::allay_model(),
+        MobType::Camel => camel::camel_model(),
+        MobType::Sniffer => sniffer::sniffer_model(),
+        MobType::Armadillo => armadillo::armadillo_model(),
+        MobType::Witch => witch::witch_model(),
+        MobType::Pillager => pillager::pillager_model(),
+        MobType::Custom => custom_model(block, resource_pack),
+        MobType::ItemFrame
+        | MobType::GlowItemFrame
+        | MobType::Painting
+        | MobType::DroppedItem
+        | MobType::Player
+        | MobType::BlockDisplay
+        | MobType::ItemDisplay
+        | MobType::TextDisplay => {
+            unreachable!("Item frames, paintings, dropped items, players and displays handled in generate_mob_geometry/add_mob")
         }
     };
+    model = model.overridden_by(
+        resource_pack.zip(mob_type.model_id())
+            .and_then(|(pack, id)| pack.get_entity_model(id)),
+    );
 
     // Apply baby scaling if is_baby property is set
     if block.properties.get("is_baby").map(|v| v == "true").unwrap_or(false)
@@ -72,6 +131,33 @@ pub(crate) fn build_mob_model(mob_type: MobType, block: &InputBlock) -> EntityMo
     model
 }
 
+/// A custom entity's pack model. Without a texture of its own it uses
+/// `entity/<id>` in the model's namespace; without a model it draws nothing.
+fn custom_model(block: &InputBlock, resource_pack: Option<&ResourcePack>) -> EntityModelDef {
+    let id = super::custom_model_id(block);
+    match resource_pack.and_then(|pack| pack.get_entity_model(&id)) {
+        Some(model) => {
+            let mut model = model.clone();
+            if model.texture_path.is_empty() {
+                model.texture_path = match id.split_once(':') {
+                    Some((namespace, path)) if namespace != "minecraft" => {
+                        format!("{}:entity/{}", namespace, path)
+                    }
+                    Some((_, path)) => format!("entity/{}", path),
+                    None => format!("entity/{}", id),
+                };
+            }
+            model
+        }
+        None => EntityModelDef {
+            texture_path: String::new(),
+            texture_size: [64, 64],
+            parts: vec![],
+            is_opaque: true,
+        },
+    }
+}
+
 /// Mob types that support baby variants in Minecraft.
 fn supports_baby(mob_type: MobType) -> bool {
     matches!(
@@ -85,6 +171,11 @@ fn supports_baby(mob_type: MobType) -> bool {
             | MobType::Horse
             | MobType::Villager
             | MobType::Zombie
+            | MobType::Fox
+            | MobType::Goat
+            | MobType::Llama
+            | MobType::Turtle
+            | MobType::Hoglin
     )
 }
 
@@ -146,6 +237,7 @@ fn apply_baby_scaling(model: &mut EntityModelDef, mob_type: MobType) {
 /// so feet land at ground level (24/16 = 1.5 blocks up).
 pub(super) fn mob_root(children: Vec<EntityPart>) -> EntityPart {
     EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
@@ -156,9 +248,47 @@ pub(super) fn mob_root(children: Vec<EntityPart>) -> EntityPart {
     }
 }
 
+/// The `variant` property as one of `names`: the variant's name (optionally
+/// `minecraft:`-prefixed) or the game's numeric id, an index into `names`.
+/// Anything else falls back to the first name, the game's default.
+pub(super) fn mob_variant<'a>(block: &InputBlock, names: &[&'a str]) -> &'a str {
+    let Some(value) = block.properties.get("variant") else {
+        return names[0];
+    };
+    let value = value.strip_prefix("minecraft:").unwrap_or(value);
+    names
+        .iter()
+        .find(|name| **name == value)
+        .or_else(|| value.parse::<usize>().ok().and_then(|i| names.get(i)))
+        .copied()
+        .unwrap_or(names[0])
+}
+
+/// Find a part by name anywhere in a part tree.
+pub(super) fn find_part_mut<'a>(parts: &'a mut [EntityPart], name: &str) -> Option<&'a mut EntityPart> {
+    for part in parts {
+        if part.name == name {
+            return Some(part);
+        }
+        if let Some(found) = find_part_mut(&mut part.children, name) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+/// Remove every cube from a part tree, keeping the parts and their poses.
+pub(super) fn clear_cubes(parts: &mut [EntityPart]) {
+    for part in parts {
+        part.cubes.clear();
+        clear_cubes(&mut part.children);
+    }
+}
+
 /// Zombie model — texture `entity/zombie/zombie`, 64x64.
 fn zombie_model() -> EntityModelDef {
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -166,12 +296,14 @@ fn zombie_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let hat = EntityPart {
+        name: "hat".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -179,12 +311,14 @@ fn zombie_model() -> EntityModelDef {
             inflate: 0.5,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, 0.0, -2.0],
             dimensions: [8.0, 12.0, 4.0],
@@ -192,12 +326,14 @@ fn zombie_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let right_arm = EntityPart {
+        name: "right_arm".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, -2.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -205,6 +341,7 @@ fn zombie_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-5.0, 2.0, 0.0],
@@ -214,6 +351,7 @@ fn zombie_model() -> EntityModelDef {
     };
 
     let left_arm = EntityPart {
+        name: "left_arm".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -2.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -221,6 +359,7 @@ fn zombie_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: true,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [5.0, 2.0, 0.0],
@@ -230,6 +369,7 @@ fn zombie_model() -> EntityModelDef {
     };
 
     let right_leg = EntityPart {
+        name: "right_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -237,6 +377,7 @@ fn zombie_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-1.9, 12.0, 0.0],
@@ -246,6 +387,7 @@ fn zombie_model() -> EntityModelDef {
     };
 
     let left_leg = EntityPart {
+        name: "left_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -253,6 +395,7 @@ fn zombie_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: true,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [1.9, 12.0, 0.0],
@@ -275,6 +418,7 @@ fn zombie_model() -> EntityModelDef {
 /// Same structure as zombie but 2-wide arms/legs.
 fn skeleton_model() -> EntityModelDef {
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -282,12 +426,14 @@ fn skeleton_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, 0.0, -2.0],
             dimensions: [8.0, 12.0, 4.0],
@@ -295,12 +441,14 @@ fn skeleton_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let right_arm = EntityPart {
+        name: "right_arm".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -2.0, -1.0],
             dimensions: [2.0, 12.0, 2.0],
@@ -308,6 +456,7 @@ fn skeleton_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-5.0, 2.0, 0.0],
@@ -317,6 +466,7 @@ fn skeleton_model() -> EntityModelDef {
     };
 
     let left_arm = EntityPart {
+        name: "left_arm".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -2.0, -1.0],
             dimensions: [2.0, 12.0, 2.0],
@@ -324,6 +474,7 @@ fn skeleton_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: true,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [5.0, 2.0, 0.0],
@@ -333,6 +484,7 @@ fn skeleton_model() -> EntityModelDef {
     };
 
     let right_leg = EntityPart {
+        name: "right_leg".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, 0.0, -1.0],
             dimensions: [2.0, 12.0, 2.0],
@@ -340,6 +492,7 @@ fn skeleton_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-2.0, 12.0, 0.0],
@@ -349,6 +502,7 @@ fn skeleton_model() -> EntityModelDef {
     };
 
     let left_leg = EntityPart {
+        name: "left_leg".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, 0.0, -1.0],
             dimensions: [2.0, 12.0, 2.0],
@@ -356,6 +510,7 @@ fn skeleton_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: true,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [2.0, 12.0, 0.0],
@@ -378,6 +533,7 @@ fn skeleton_model() -> EntityModelDef {
 /// Quadruped with 4 identical short legs.
 fn creeper_model() -> EntityModelDef {
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -385,6 +541,7 @@ fn creeper_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 6.0, 0.0],
@@ -394,6 +551,7 @@ fn creeper_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, 0.0, -2.0],
             dimensions: [8.0, 12.0, 4.0],
@@ -401,6 +559,7 @@ fn creeper_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 6.0, 0.0],
@@ -410,6 +569,7 @@ fn creeper_model() -> EntityModelDef {
     };
 
     let right_hind_leg = EntityPart {
+        name: "right_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -417,6 +577,7 @@ fn creeper_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-2.0, 18.0, 4.0],
@@ -426,6 +587,7 @@ fn creeper_model() -> EntityModelDef {
     };
 
     let left_hind_leg = EntityPart {
+        name: "left_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -433,6 +595,7 @@ fn creeper_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [2.0, 18.0, 4.0],
@@ -442,6 +605,7 @@ fn creeper_model() -> EntityModelDef {
     };
 
     let right_front_leg = EntityPart {
+        name: "right_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -449,6 +613,7 @@ fn creeper_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-2.0, 18.0, -4.0],
@@ -458,6 +623,7 @@ fn creeper_model() -> EntityModelDef {
     };
 
     let left_front_leg = EntityPart {
+        name: "left_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -465,6 +631,7 @@ fn creeper_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [2.0, 18.0, -4.0],
@@ -487,6 +654,7 @@ fn creeper_model() -> EntityModelDef {
 /// Snout is a child of head. Body has RotX(PI/2).
 fn pig_model() -> EntityModelDef {
     let snout = EntityPart {
+        name: "snout".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -9.0],
             dimensions: [4.0, 3.0, 1.0],
@@ -494,12 +662,14 @@ fn pig_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -4.0, -8.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -507,6 +677,7 @@ fn pig_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 12.0, -6.0],
@@ -516,6 +687,7 @@ fn pig_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-5.0, -10.0, -7.0],
             dimensions: [10.0, 16.0, 8.0],
@@ -523,6 +695,7 @@ fn pig_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 11.0, 2.0],
@@ -533,6 +706,7 @@ fn pig_model() -> EntityModelDef {
     };
 
     let right_hind_leg = EntityPart {
+        name: "right_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -540,6 +714,7 @@ fn pig_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-3.0, 18.0, 7.0],
@@ -549,6 +724,7 @@ fn pig_model() -> EntityModelDef {
     };
 
     let left_hind_leg = EntityPart {
+        name: "left_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -556,6 +732,7 @@ fn pig_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [3.0, 18.0, 7.0],
@@ -565,6 +742,7 @@ fn pig_model() -> EntityModelDef {
     };
 
     let right_front_leg = EntityPart {
+        name: "right_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -572,6 +750,7 @@ fn pig_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-3.0, 18.0, -5.0],
@@ -581,6 +760,7 @@ fn pig_model() -> EntityModelDef {
     };
 
     let left_front_leg = EntityPart {
+        name: "left_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -588,6 +768,7 @@ fn pig_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [3.0, 18.0, -5.0],
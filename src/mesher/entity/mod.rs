@@ -7,34 +7,61 @@
 //! Follows the liquid module's integration pattern: detect entity type, generate
 //! vertices/indices/face textures, then integrate in MeshBuilder::add_block().
 
+mod allay;
+mod armadillo;
+pub(crate) mod armor;
 pub mod armor_stand;
+mod axolotl;
 mod bat;
+pub mod bedrock;
 pub(crate) mod banner;
 mod bed;
+mod bee;
 mod bell;
 mod book;
+mod camel;
 mod cat;
 mod chicken;
 mod chest;
+pub mod clips;
 mod cow;
 pub(crate) mod decorated_pot;
+pub(crate) mod display;
+mod dolphin;
 mod enderman;
+mod fox;
+mod frog;
+mod goat;
 pub(crate) mod hanging_sign;
+mod hoglin;
 mod horse;
 mod iron_golem;
 mod item_frame;
 pub(crate) mod inventory;
 pub mod item_render;
+mod llama;
 mod minecart;
+mod painting;
 pub(crate) mod mob;
+mod parrot;
 pub(crate) mod particle;
+mod piglin;
+mod pillager;
 pub(crate) mod player;
+mod rabbit;
+pub mod rig;
 pub(crate) mod sheep;
 mod shulker;
 pub(crate) mod sign;
 pub(crate) mod sign_text;
+pub(crate) mod skin;
 pub(crate) mod skull;
+mod sniffer;
+mod squid;
+mod strider;
+mod turtle;
 pub(crate) mod villager_texture;
+mod witch;
 pub(crate) mod boat;
 pub(crate) mod equipment;
 mod blaze;
@@ -45,14 +72,17 @@ mod spider;
 mod villager;
 mod wolf;
 
+use crate::error::Result;
 use crate::mesher::geometry::Vertex;
+use crate::resource_pack::ResourcePack;
 use crate::types::{Direction, InputBlock};
 use glam::{Mat4, Vec3, Vec4};
+use serde::{Deserialize, Serialize};
 
 // ── Data Structures ─────────────────────────────────────────────────────────
 
 /// A cube within an entity model part.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EntityCube {
     /// Origin in 1/16th block units.
     pub origin: [f32; 3],
@@ -61,15 +91,60 @@ pub struct EntityCube {
     /// UV offset (u0, v0) in pixels on the texture sheet.
     pub tex_offset: [u32; 2],
     /// Expansion from cube center.
+    #[serde(default)]
     pub inflate: f32,
     /// Mirror UVs horizontally.
+    #[serde(default)]
     pub mirror: bool,
     /// Faces to skip (used to prevent z-fighting at block boundaries).
+    #[serde(default)]
     pub skip_faces: Vec<Direction>,
+    /// Explicit per-face UV rectangles. When non-empty they replace the
+    /// box-unwrap layout from `tex_offset`, and faces not listed are not drawn.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub face_uvs: Vec<EntityFaceUv>,
+}
+
+impl EntityCube {
+    /// A box-unwrapped cube, as `texOffs(u, v).addBox(...)` builds it.
+    pub(crate) fn new(origin: [f32; 3], dimensions: [f32; 3], tex_offset: [u32; 2]) -> Self {
+        Self {
+            origin,
+            dimensions,
+            tex_offset,
+            ..Default::default()
+        }
+    }
+
+    /// Mirror the UVs (`.mirror()`).
+    pub(crate) fn mirrored(mut self) -> Self {
+        self.mirror = true;
+        self
+    }
+
+    /// Grow the cube by a `CubeDeformation`.
+    pub(crate) fn inflated(mut self, inflate: f32) -> Self {
+        self.inflate = inflate;
+        self
+    }
+}
+
+/// UV rectangle for one face of an [`EntityCube`], in texture pixels.
+///
+/// The rectangle is oriented like the face's region in the box-unwrap layout;
+/// a negative size flips it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EntityFaceUv {
+    pub face: Direction,
+    pub uv: [f32; 2],
+    pub uv_size: [f32; 2],
 }
 
 /// Pose/transform for an entity model part.
-#[derive(Debug, Clone)]
+///
+/// In JSON the rotation is written in degrees, like block model rotations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "PoseJson", into = "PoseJson")]
 pub struct EntityPartPose {
     /// Translation in 1/16th block units.
     pub position: [f32; 3],
@@ -89,27 +164,131 @@ impl Default for EntityPartPose {
     }
 }
 
+/// JSON form of [`EntityPartPose`]; every field is optional.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct PoseJson {
+    position: [f32; 3],
+    rotation: [f32; 3],
+    scale: [f32; 3],
+}
+
+impl Default for PoseJson {
+    fn default() -> Self {
+        EntityPartPose::default().into()
+    }
+}
+
+impl From<PoseJson> for EntityPartPose {
+    fn from(pose: PoseJson) -> Self {
+        Self {
+            position: pose.position,
+            rotation: pose.rotation.map(f32::to_radians),
+            scale: pose.scale,
+        }
+    }
+}
+
+impl From<EntityPartPose> for PoseJson {
+    fn from(pose: EntityPartPose) -> Self {
+        Self {
+            position: pose.position,
+            rotation: pose.rotation.map(f32::to_degrees),
+            scale: pose.scale,
+        }
+    }
+}
+
 /// A part in the entity model hierarchy.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EntityPart {
+    /// Part name, e.g. "head" or "left_hind_leg". Used as the joint name in
+    /// skinned exports; may be empty.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(default)]
     pub cubes: Vec<EntityCube>,
+    #[serde(default)]
     pub pose: EntityPartPose,
+    #[serde(default)]
     pub children: Vec<EntityPart>,
 }
 
+impl EntityPart {
+    /// A part placed like `PartPose.offsetAndRotation(position, rotation)`,
+    /// rotation in radians.
+    pub(crate) fn posed(
+        name: &str,
+        position: [f32; 3],
+        rotation: [f32; 3],
+        cubes: Vec<EntityCube>,
+        children: Vec<EntityPart>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            cubes,
+            pose: EntityPartPose {
+                position,
+                rotation,
+                ..Default::default()
+            },
+            children,
+        }
+    }
+}
+
 /// Complete entity model definition.
-#[derive(Debug, Clone)]
+///
+/// Built-in models are written in Rust; a resource pack can replace any of
+/// them with a JSON file of the same shape under `entity_models/` (see
+/// [`ResourcePack::get_entity_model`]).
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EntityModelDef {
-    /// Texture path (e.g., "entity/chest/normal").
+    /// Texture path (e.g., "entity/chest/normal"). Left empty in a pack
+    /// override to keep the built-in model's texture.
+    #[serde(default)]
     pub texture_path: String,
     /// Texture sheet dimensions in pixels.
     pub texture_size: [u32; 2],
     /// Top-level parts.
     pub parts: Vec<EntityPart>,
     /// Whether this entity's geometry is opaque.
+    #[serde(default = "default_opaque")]
     pub is_opaque: bool,
 }
 
+fn default_opaque() -> bool {
+    true
+}
+
+impl EntityModelDef {
+    /// Parse a model from its JSON form.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Serialize the model to pretty-printed JSON, e.g. to use a built-in
+    /// model as the starting point for a pack override.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Replace this model with a pack override, keeping the built-in
+    /// texture when the override doesn't name one.
+    fn overridden_by(self, pack_model: Option<&EntityModelDef>) -> Self {
+        match pack_model {
+            Some(pack_model) => {
+                let mut model = pack_model.clone();
+                if model.texture_path.is_empty() {
+                    model.texture_path = self.texture_path;
+                }
+                model
+            }
+            None => self,
+        }
+    }
+}
+
 /// Chest variants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChestVariant {
@@ -171,6 +350,9 @@ pub enum MobType {
     Minecart,
     ItemFrame,
     GlowItemFrame,
+    /// Wall painting; `variant` picks its size and texture (see
+    /// [`ResourcePack::get_painting_variant`]).
+    Painting,
     DroppedItem,
     Wolf,
     Cat,
@@ -187,9 +369,39 @@ pub enum MobType {
     Player,
     Boat,
     ChestBoat,
+    Fox,
+    Bee,
+    Axolotl,
+    Frog,
+    Goat,
+    Llama,
+    Turtle,
+    Dolphin,
+    Squid,
+    GlowSquid,
+    Parrot,
+    Rabbit,
+    Piglin,
+    Hoglin,
+    Strider,
+    Allay,
+    Camel,
+    Sniffer,
+    Armadillo,
+    Witch,
+    Pillager,
+    /// `block_display`, `item_display` and `text_display` entities, placed
+    /// by their `transformation` (see [`display`]).
+    BlockDisplay,
+    ItemDisplay,
+    TextDisplay,
+    /// An `entity:` id with no built-in model, drawn from the pack's entity
+    /// models (see [`detect_mob_with_pack`]).
+    Custom,
 }
 
 /// Face texture info for a generated entity face.
+#[derive(Debug, Clone)]
 pub struct EntityFaceTexture {
     pub texture: String,
     pub is_transparent: bool,
@@ -360,6 +572,7 @@ pub fn detect_mob(block: &InputBlock) -> Option<MobType> {
         "minecart" => Some(MobType::Minecart),
         "item_frame" => Some(MobType::ItemFrame),
         "glow_item_frame" => Some(MobType::GlowItemFrame),
+        "painting" => Some(MobType::Painting),
         "item" => Some(MobType::DroppedItem),
         "wolf" => Some(MobType::Wolf),
         "cat" => Some(MobType::Cat),
@@ -376,10 +589,150 @@ pub fn detect_mob(block: &InputBlock) -> Option<MobType> {
         "player" => Some(MobType::Player),
         "boat" => Some(MobType::Boat),
         "chest_boat" => Some(MobType::ChestBoat),
+        "fox" => Some(MobType::Fox),
+        "bee" => Some(MobType::Bee),
+        "axolotl" => Some(MobType::Axolotl),
+        "frog" => Some(MobType::Frog),
+        "goat" => Some(MobType::Goat),
+        "llama" | "trader_llama" => Some(MobType::Llama),
+        "turtle" => Some(MobType::Turtle),
+        "dolphin" => Some(MobType::Dolphin),
+        "squid" => Some(MobType::Squid),
+        "glow_squid" => Some(MobType::GlowSquid),
+        "parrot" => Some(MobType::Parrot),
+        "rabbit" => Some(MobType::Rabbit),
+        "piglin" | "piglin_brute" | "zombified_piglin" => Some(MobType::Piglin),
+        "hoglin" | "zoglin" => Some(MobType::Hoglin),
+        "strider" => Some(MobType::Strider),
+        "allay" => Some(MobType::Allay),
+        "camel" => Some(MobType::Camel),
+        "sniffer" => Some(MobType::Sniffer),
+        "armadillo" => Some(MobType::Armadillo),
+        "witch" => Some(MobType::Witch),
+        "pillager" => Some(MobType::Pillager),
+        "block_display" => Some(MobType::BlockDisplay),
+        "item_display" => Some(MobType::ItemDisplay),
+        "text_display" => Some(MobType::TextDisplay),
         _ => None,
     }
 }
 
+impl MobType {
+    /// Id a pack uses to override this mob's model (`entity_models/<id>.json`).
+    /// `None` for entities that aren't drawn from an [`EntityModelDef`].
+    pub fn model_id(self) -> Option<&'static str> {
+        Some(match self {
+            MobType::Zombie => "zombie",
+            MobType::Skeleton => "skeleton",
+            MobType::Creeper => "creeper",
+            MobType::Pig => "pig",
+            MobType::Chicken => "chicken",
+            MobType::Cow => "cow",
+            MobType::Sheep => "sheep",
+            MobType::Villager => "villager",
+            MobType::ArmorStand => "armor_stand",
+            MobType::Minecart => "minecart",
+            MobType::Wolf => "wolf",
+            MobType::Cat => "cat",
+            MobType::Spider => "spider",
+            MobType::Horse => "horse",
+            MobType::Enderman => "enderman",
+            MobType::Slime => "slime",
+            MobType::MagmaCube => "magma_cube",
+            MobType::Blaze => "blaze",
+            MobType::Ghast => "ghast",
+            MobType::WitherSkeleton => "wither_skeleton",
+            MobType::IronGolem => "iron_golem",
+            MobType::Bat => "bat",
+            MobType::Boat => "boat",
+            MobType::ChestBoat => "chest_boat",
+            MobType::Fox => "fox",
+            MobType::Bee => "bee",
+            MobType::Axolotl => "axolotl",
+            MobType::Frog => "frog",
+            MobType::Goat => "goat",
+            MobType::Llama => "llama",
+            MobType::Turtle => "turtle",
+            MobType::Dolphin => "dolphin",
+            MobType::Squid => "squid",
+            MobType::GlowSquid => "glow_squid",
+            MobType::Parrot => "parrot",
+            MobType::Rabbit => "rabbit",
+            MobType::Piglin => "piglin",
+            MobType::Hoglin => "hoglin",
+            MobType::Strider => "strider",
+            MobType::Allay => "allay",
+            MobType::Camel => "camel",
+            MobType::Sniffer => "sniffer",
+            MobType::Armadillo => "armadillo",
+            MobType::Witch => "witch",
+            MobType::Pillager => "pillager",
+            MobType::ItemFrame
+            | MobType::GlowItemFrame
+            | MobType::Painting
+            | MobType::DroppedItem
+            | MobType::Player
+            | MobType::BlockDisplay
+            | MobType::ItemDisplay
+            | MobType::TextDisplay
+            | MobType::Custom => return None,
+        })
+    }
+}
+
+impl BlockEntityType {
+    /// Id a pack uses to override this block entity's model
+    /// (`entity_models/<id>.json`). Variants that differ only in texture
+    /// (chest kinds, bed colors, sign woods) share one model.
+    pub fn model_id(&self) -> Option<&'static str> {
+        Some(match self {
+            BlockEntityType::Chest(_) => "chest",
+            BlockEntityType::DoubleChest { side: DoubleChestSide::Left, .. } => "double_chest_left",
+            BlockEntityType::DoubleChest { side: DoubleChestSide::Right, .. } => "double_chest_right",
+            BlockEntityType::Bed { is_head: true, .. } => "bed_head",
+            BlockEntityType::Bed { is_head: false, .. } => "bed_foot",
+            BlockEntityType::Bell => "bell",
+            BlockEntityType::Sign { is_wall: false, .. } => "sign",
+            BlockEntityType::Sign { is_wall: true, .. } => "wall_sign",
+            BlockEntityType::Skull(skull_type) => match skull_type {
+                SkullType::Skeleton => "skeleton_skull",
+                SkullType::WitherSkeleton => "wither_skeleton_skull",
+                SkullType::Zombie => "zombie_head",
+                SkullType::Creeper => "creeper_head",
+                SkullType::Piglin => "piglin_head",
+                SkullType::Dragon => "dragon_head",
+                SkullType::Player => "player_head",
+            },
+            BlockEntityType::ShulkerBox { .. } => "shulker_box",
+            BlockEntityType::Banner { is_wall: false, .. } => "banner",
+            BlockEntityType::Banner { is_wall: true, .. } => "wall_banner",
+            BlockEntityType::HangingSign { is_wall: false, .. } => "hanging_sign",
+            BlockEntityType::HangingSign { is_wall: true, .. } => "wall_hanging_sign",
+            BlockEntityType::Lectern | BlockEntityType::EnchantingTable => "book",
+            BlockEntityType::DecoratedPot => return None,
+        })
+    }
+}
+
+/// Like [`detect_mob`], but an `entity:` id without a built-in model is a
+/// [`MobType::Custom`] when the pack has an entity model for it.
+pub fn detect_mob_with_pack(block: &InputBlock, resource_pack: &ResourcePack) -> Option<MobType> {
+    detect_mob(block).or_else(|| {
+        (block.namespace() == "entity"
+            && resource_pack.get_entity_model(&custom_model_id(block)).is_some())
+            .then_some(MobType::Custom)
+    })
+}
+
+/// Pack model id for a custom entity: its `model` property (e.g.
+/// "mypack:statue"), else the entity id in the minecraft namespace.
+pub(crate) fn custom_model_id(block: &InputBlock) -> String {
+    match block.properties.get("model") {
+        Some(model) => model.clone(),
+        None => format!("minecraft:{}", block.block_id()),
+    }
+}
+
 fn detect_double_chest(block: &InputBlock) -> Option<DoubleChestSide> {
     match block.properties.get("type").map(|s| s.as_str()) {
         Some("left") => Some(DoubleChestSide::Left),
@@ -441,19 +794,32 @@ fn cube_face_uvs(
     let w = dimensions[0]; // X dimension
     let h = dimensions[1]; // Y dimension
     let d = dimensions[2]; // Z dimension
-    let tw = texture_size[0] as f32;
-    let th = texture_size[1] as f32;
 
     // UV region in pixel space (left, top, right, bottom)
-    let (left, top, right, bottom) = match face {
-        Direction::Down => (u0 + d, v0, u0 + d + w, v0 + d),
-        Direction::Up => (u0 + d + w, v0, u0 + d + w + w, v0 + d),
-        Direction::North => (u0 + d, v0 + d, u0 + d + w, v0 + d + h),
-        Direction::South => (u0 + d + w + d, v0 + d, u0 + d + w + d + w, v0 + d + h),
-        Direction::West => (u0, v0 + d, u0 + d, v0 + d + h),
-        Direction::East => (u0 + d + w, v0 + d, u0 + d + w + d, v0 + d + h),
+    let region = match face {
+        Direction::Down => [u0 + d, v0, u0 + d + w, v0 + d],
+        Direction::Up => [u0 + d + w, v0, u0 + d + w + w, v0 + d],
+        Direction::North => [u0 + d, v0 + d, u0 + d + w, v0 + d + h],
+        Direction::South => [u0 + d + w + d, v0 + d, u0 + d + w + d + w, v0 + d + h],
+        Direction::West => [u0, v0 + d, u0 + d, v0 + d + h],
+        Direction::East => [u0 + d + w, v0 + d, u0 + d + w + d, v0 + d + h],
     };
 
+    region_uvs(region, face, texture_size, mirror)
+}
+
+/// Map a pixel-space UV region (left, top, right, bottom) onto a face's four
+/// corners, in the order [`generate_cube_faces`] emits them.
+fn region_uvs(
+    region: [f32; 4],
+    face: Direction,
+    texture_size: [u32; 2],
+    mirror: bool,
+) -> [[f32; 2]; 4] {
+    let [left, top, right, bottom] = region;
+    let tw = texture_size[0] as f32;
+    let th = texture_size[1] as f32;
+
     // Normalize to [0,1] UV space
     let (nl, nt, nr, nb) = (left / tw, top / th, right / tw, bottom / th);
 
@@ -489,16 +855,53 @@ pub fn generate_entity_geometry(
     block: &InputBlock,
     entity_type: &BlockEntityType,
 ) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
-    let model = build_model_def(entity_type);
+    entity_geometry(block, entity_type, None)
+}
 
-    let facing = get_facing(block);
+/// Like [`generate_entity_geometry`], but uses the pack's model override for
+/// this block entity when it has one.
+pub fn generate_entity_geometry_with_pack(
+    resource_pack: &ResourcePack,
+    block: &InputBlock,
+    entity_type: &BlockEntityType,
+) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
+    entity_geometry(block, entity_type, Some(resource_pack))
+}
+
+fn entity_geometry(
+    block: &InputBlock,
+    entity_type: &BlockEntityType,
+    resource_pack: Option<&ResourcePack>,
+) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
+    let model = build_model_def(entity_type).overridden_by(
+        resource_pack.zip(entity_type.model_id())
+            .and_then(|(pack, id)| pack.get_entity_model(id)),
+    );
+
+    let facing_mat = entity_facing_matrix(block, entity_type);
 
     let mut vertices = Vec::new();
     let mut indices = Vec::new();
     let mut face_textures = Vec::new();
 
-    // Build facing rotation matrix
-    let facing_mat = if matches!(entity_type, BlockEntityType::Lectern | BlockEntityType::EnchantingTable) {
+    traverse_parts(
+        &model.parts,
+        Mat4::IDENTITY,
+        &facing_mat,
+        &model,
+        &mut vertices,
+        &mut indices,
+        &mut face_textures,
+    );
+
+    (vertices, indices, face_textures)
+}
+
+/// Placement of a block entity's model within its block: facing, standing
+/// rotation, wall offsets and the lectern/enchanting table book poses.
+fn entity_facing_matrix(block: &InputBlock, entity_type: &BlockEntityType) -> Mat4 {
+    let facing = get_facing(block);
+    if matches!(entity_type, BlockEntityType::Lectern | BlockEntityType::EnchantingTable) {
         // Match MC's EnchantTableRenderer / LecternRenderer poseStack exactly.
         match entity_type {
             BlockEntityType::EnchantingTable => {
@@ -580,19 +983,7 @@ pub fn generate_entity_geometry(
         } else {
             base
         }
-    };
-
-    traverse_parts(
-        &model.parts,
-        Mat4::IDENTITY,
-        &facing_mat,
-        &model,
-        &mut vertices,
-        &mut indices,
-        &mut face_textures,
-    );
-
-    (vertices, indices, face_textures)
+    }
 }
 
 /// Generate all geometry for a mob entity.
@@ -601,6 +992,24 @@ pub fn generate_entity_geometry(
 pub fn generate_mob_geometry(
     block: &InputBlock,
     mob_type: MobType,
+) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
+    mob_geometry(block, mob_type, None)
+}
+
+/// Like [`generate_mob_geometry`], but uses the pack's model override for
+/// this mob when it has one.
+pub fn generate_mob_geometry_with_pack(
+    resource_pack: &ResourcePack,
+    block: &InputBlock,
+    mob_type: MobType,
+) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
+    mob_geometry(block, mob_type, Some(resource_pack))
+}
+
+fn mob_geometry(
+    block: &InputBlock,
+    mob_type: MobType,
+    resource_pack: Option<&ResourcePack>,
 ) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
     // Item frames use block textures, not an entity texture sheet
     if matches!(mob_type, MobType::ItemFrame | MobType::GlowItemFrame) {
@@ -609,25 +1018,50 @@ pub fn generate_mob_geometry(
         return item_frame::generate_item_frame_geometry(facing, is_glow);
     }
 
-    // Dropped items and players are rendered entirely in add_mob() with resource pack access
-    if matches!(mob_type, MobType::DroppedItem | MobType::Player) {
-        return (Vec::new(), Vec::new(), Vec::new());
+    // Paintings use their variant texture plus `painting/back`
+    if matches!(mob_type, MobType::Painting) {
+        return painting::generate_painting_geometry(block, resource_pack);
     }
 
-    let model = mob::build_mob_model(mob_type, block);
+    // Dropped items, players and display entities are rendered entirely in
+    // add_mob() with resource pack access
+    if matches!(
+        mob_type,
+        MobType::DroppedItem | MobType::Player | MobType::BlockDisplay | MobType::ItemDisplay | MobType::TextDisplay
+    ) {
+        return (Vec::new(), Vec::new(), Vec::new());
+    }
 
-    let facing = get_facing(block);
-    let facing_angle = facing_rotation_rad(facing);
+    let model = mob::build_mob_model(mob_type, block, resource_pack);
+    let facing_mat = mob_facing_matrix(block, mob_type);
 
     let mut vertices = Vec::new();
     let mut indices = Vec::new();
     let mut face_textures = Vec::new();
 
+    traverse_parts(
+        &model.parts,
+        Mat4::IDENTITY,
+        &facing_mat,
+        &model,
+        &mut vertices,
+        &mut indices,
+        &mut face_textures,
+    );
+
+    (vertices, indices, face_textures)
+}
+
+/// Placement of a mob's model within its block: the facing rotation, or the
+/// boat render transform.
+fn mob_facing_matrix(block: &InputBlock, mob_type: MobType) -> Mat4 {
+    let facing = get_facing(block);
+
     // Boats have a unique render transform per AbstractBoatRenderer:
     //   T(0, 0.375, 0) · Ry(180° - yRot) · S(-1,-1,1) · Ry(90°)
     // No RotX(π)+T(8,24,8) root wrapper is needed — the model already renders
     // upright under this chain.
-    let facing_mat = if matches!(mob_type, MobType::Boat | MobType::ChestBoat) {
+    if matches!(mob_type, MobType::Boat | MobType::ChestBoat) {
         let yrot_deg = match facing {
             "south" => 0.0_f32,
             "west" => 90.0_f32,
@@ -643,21 +1077,22 @@ pub fn generate_mob_geometry(
         // Standard mobs use their root wrapper for Y-down→Y-up; this matrix
         // just rotates the model around the block center for facing.
         Mat4::from_translation(Vec3::new(0.5, 0.0, 0.5))
-            * Mat4::from_rotation_y(facing_angle)
+            * Mat4::from_rotation_y(facing_rotation_rad(facing))
             * Mat4::from_translation(Vec3::new(-0.5, 0.0, -0.5))
-    };
-
-    traverse_parts(
-        &model.parts,
-        Mat4::IDENTITY,
-        &facing_mat,
-        &model,
-        &mut vertices,
-        &mut indices,
-        &mut face_textures,
-    );
+    }
+}
 
-    (vertices, indices, face_textures)
+/// A part's local transform: translate -> rotateZYX -> scale.
+pub(crate) fn part_transform(pose: &EntityPartPose) -> Mat4 {
+    Mat4::from_translation(Vec3::new(
+        pose.position[0] / 16.0,
+        pose.position[1] / 16.0,
+        pose.position[2] / 16.0,
+    ))
+    * Mat4::from_rotation_z(pose.rotation[2])
+    * Mat4::from_rotation_y(pose.rotation[1])
+    * Mat4::from_rotation_x(pose.rotation[0])
+    * Mat4::from_scale(Vec3::new(pose.scale[0], pose.scale[1], pose.scale[2]))
 }
 
 /// Recursively traverse part hierarchy, accumulating transforms.
@@ -671,19 +1106,7 @@ pub(crate) fn traverse_parts(
     face_textures: &mut Vec<EntityFaceTexture>,
 ) {
     for part in parts {
-        // Build part's local transform: translate -> rotateZYX -> scale
-        let pose = &part.pose;
-        let local = Mat4::from_translation(Vec3::new(
-            pose.position[0] / 16.0,
-            pose.position[1] / 16.0,
-            pose.position[2] / 16.0,
-        ))
-        * Mat4::from_rotation_z(pose.rotation[2])
-        * Mat4::from_rotation_y(pose.rotation[1])
-        * Mat4::from_rotation_x(pose.rotation[0])
-        * Mat4::from_scale(Vec3::new(pose.scale[0], pose.scale[1], pose.scale[2]));
-
-        let combined = parent_transform * local;
+        let combined = parent_transform * part_transform(&part.pose);
 
         // Generate geometry for each cube in this part
         for cube in &part.cubes {
@@ -773,13 +1196,22 @@ fn generate_cube_faces(
             continue;
         }
 
-        let uvs = cube_face_uvs(
-            cube.tex_offset,
-            cube.dimensions,
-            direction,
-            model.texture_size,
-            cube.mirror,
-        );
+        let uvs = if cube.face_uvs.is_empty() {
+            cube_face_uvs(
+                cube.tex_offset,
+                cube.dimensions,
+                direction,
+                model.texture_size,
+                cube.mirror,
+            )
+        } else {
+            let Some(face_uv) = cube.face_uvs.iter().find(|f| f.face == direction) else {
+                continue;
+            };
+            let [u, v] = face_uv.uv;
+            let [su, sv] = face_uv.uv_size;
+            region_uvs([u, v, u + su, v + sv], direction, model.texture_size, false)
+        };
 
         // Compute normal from known direction, transformed by the full rotation
         let dn = direction.normal();
@@ -1194,6 +1626,36 @@ mod tests {
         assert_eq!(indices.len(), 22 * 6);
     }
 
+    #[test]
+    fn test_painting_geometry() {
+        let block = InputBlock::new("entity:painting")
+            .with_property("variant", "pool")
+            .with_property("facing", "east");
+        assert!(matches!(detect_mob(&block), Some(MobType::Painting)));
+        let (verts, _, faces) = generate_mob_geometry(&block, MobType::Painting);
+
+        // 2×1: front + 2 back + 2 top + 2 bottom + 2 sides
+        assert_eq!(faces.len(), 9);
+        assert_eq!(faces[0].texture, "painting/pool");
+        assert!(faces[1..].iter().all(|f| f.texture == "painting/back"));
+        // Back flush with the west wall, extending north (counter-clockwise)
+        let min = verts.iter().fold([f32::MAX; 3], |m, v| [0, 1, 2].map(|i| m[i].min(v.position[i])));
+        let max = verts.iter().fold([f32::MIN; 3], |m, v| [0, 1, 2].map(|i| m[i].max(v.position[i])));
+        for (got, want) in min.iter().chain(&max).zip([0.0, 0.0, -1.0, 0.0625, 1.0, 1.0]) {
+            assert!((got - want).abs() < 1e-5, "bounds {:?} {:?}", min, max);
+        }
+        assert!(verts[..4].iter().all(|v| (v.normal[0] - 1.0).abs() < 1e-5));
+
+        let mut pack = ResourcePack::new();
+        pack.set_painting_registry(crate::resource_pack::PaintingRegistry::from_json(
+            r#"{"mypack:sunrise": {"asset_id": "mypack:sunrise", "width": 3, "height": 3}}"#,
+        ).unwrap());
+        let block = InputBlock::new("entity:painting").with_property("variant", "mypack:sunrise");
+        let (_, _, faces) = generate_mob_geometry_with_pack(&pack, &block, MobType::Painting);
+        assert_eq!(faces.len(), 1 + 9 + 3 * 4);
+        assert_eq!(faces[0].texture, "mypack:painting/sunrise");
+    }
+
     #[test]
     fn test_detect_new_mobs() {
         assert!(matches!(detect_mob(&InputBlock::new("entity:chicken")), Some(MobType::Chicken)));
@@ -1262,6 +1724,54 @@ mod tests {
         assert!(matches!(detect_mob(&InputBlock::new("entity:bat")), Some(MobType::Bat)));
     }
 
+    #[test]
+    fn test_detect_new_mobs_wave3() {
+        let cases = [
+            ("fox", MobType::Fox), ("bee", MobType::Bee), ("axolotl", MobType::Axolotl),
+            ("frog", MobType::Frog), ("goat", MobType::Goat), ("trader_llama", MobType::Llama),
+            ("turtle", MobType::Turtle), ("dolphin", MobType::Dolphin), ("squid", MobType::Squid),
+            ("glow_squid", MobType::GlowSquid), ("parrot", MobType::Parrot), ("rabbit", MobType::Rabbit),
+            ("piglin_brute", MobType::Piglin), ("zoglin", MobType::Hoglin), ("strider", MobType::Strider),
+            ("allay", MobType::Allay), ("camel", MobType::Camel), ("sniffer", MobType::Sniffer),
+            ("armadillo", MobType::Armadillo), ("witch", MobType::Witch), ("pillager", MobType::Pillager),
+        ];
+        for (id, mob_type) in cases {
+            let block = InputBlock::new(&format!("entity:{}", id)).with_property("facing", "south");
+            assert_eq!(detect_mob(&block), Some(mob_type), "{}", id);
+            // Standing mobs rest on the ground plane; swimmers and fliers
+            // may dip a fin or leg tip up to a pixel under it.
+            let (verts, _, faces) = generate_mob_geometry(&block, mob_type);
+            assert!(!faces.is_empty(), "{}", id);
+            let min_y = verts.iter().map(|v| v.position[1]).fold(f32::MAX, f32::min);
+            assert!((-0.0625..0.2).contains(&min_y), "{} min y {}", id, min_y);
+        }
+    }
+
+    #[test]
+    fn test_mob_texture_variants() {
+        let texture = |id: &str, props: &[(&str, &str)]| {
+            let mut block = InputBlock::new(&format!("entity:{}", id));
+            for (key, value) in props {
+                block = block.with_property(*key, *value);
+            }
+            let mob_type = detect_mob(&block).unwrap();
+            mob::build_mob_model(mob_type, &block, None).texture_path
+        };
+        assert_eq!(texture("axolotl", &[]), "entity/axolotl/axolotl_lucy");
+        assert_eq!(texture("axolotl", &[("variant", "3")]), "entity/axolotl/axolotl_cyan");
+        assert_eq!(texture("fox", &[("variant", "snow")]), "entity/fox/snow_fox");
+        assert_eq!(texture("frog", &[("variant", "minecraft:cold")]), "entity/frog/cold_frog");
+        assert_eq!(texture("llama", &[("variant", "gray")]), "entity/llama/gray");
+        assert_eq!(texture("llama", &[("variant", "purple")]), "entity/llama/creamy");
+        assert_eq!(texture("parrot", &[("variant", "2")]), "entity/parrot/parrot_green");
+        assert_eq!(texture("rabbit", &[("variant", "99")]), "entity/rabbit/caerbannog");
+        assert_eq!(texture("bee", &[("angry", "true"), ("nectar", "true")]), "entity/bee/bee_angry_nectar");
+        assert_eq!(texture("strider", &[("cold", "true")]), "entity/strider/strider_cold");
+        assert_eq!(texture("piglin_brute", &[]), "entity/piglin/piglin_brute");
+        assert_eq!(texture("zoglin", &[]), "entity/hoglin/zoglin");
+        assert_eq!(texture("glow_squid", &[]), "entity/squid/glow_squid");
+    }
+
     #[test]
     fn test_wolf_geometry_count() {
         let block = InputBlock::new("entity:wolf").with_property("facing", "south");
@@ -1593,4 +2103,88 @@ mod tests {
         let table_avg_y: f32 = table_verts.iter().map(|v| v.position[1]).sum::<f32>() / table_verts.len() as f32;
         assert!(lectern_avg_y > table_avg_y, "Lectern book should be higher than enchanting table book");
     }
+
+    #[test]
+    fn test_entity_model_json_round_trip() {
+        let block = InputBlock::new("entity:cow");
+        let model = mob::build_mob_model(MobType::Cow, &block, None);
+        let parsed = EntityModelDef::from_json(&model.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.texture_path, model.texture_path);
+        assert_eq!(parsed.texture_size, model.texture_size);
+        assert_eq!(parsed.parts.len(), model.parts.len());
+        for (a, b) in parsed.parts.iter().zip(&model.parts) {
+            for i in 0..3 {
+                assert!((a.pose.rotation[i] - b.pose.rotation[i]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_entity_model_json_defaults_and_degrees() {
+        let model = EntityModelDef::from_json(r#"{
+            "texture_size": [64, 32],
+            "parts": [{
+                "pose": { "rotation": [90, 0, 0] },
+                "cubes": [{ "origin": [-4, 0, -4], "dimensions": [8, 8, 8], "tex_offset": [0, 0] }]
+            }]
+        }"#).unwrap();
+        assert!(model.texture_path.is_empty());
+        assert!(model.is_opaque);
+        let part = &model.parts[0];
+        assert!((part.pose.rotation[0] - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(part.pose.scale, [1.0, 1.0, 1.0]);
+        assert_eq!(part.cubes[0].inflate, 0.0);
+        assert!(part.children.is_empty());
+    }
+
+    #[test]
+    fn test_pack_entity_model_overrides_builtin() {
+        let mut pack = ResourcePack::new();
+        pack.add_entity_model("minecraft", "cow", EntityModelDef::from_json(r#"{
+            "texture_size": [64, 64],
+            "parts": [{ "cubes": [{ "origin": [0, 0, 0], "dimensions": [16, 16, 16], "tex_offset": [0, 0] }] }]
+        }"#).unwrap());
+        let block = InputBlock::new("entity:cow");
+
+        let (verts, _, faces) = generate_mob_geometry_with_pack(&pack, &block, MobType::Cow);
+        assert_eq!(faces.len(), 6, "override has a single cube");
+        assert_eq!(verts.len(), 24);
+        // No texture in the override: the built-in cow texture is kept.
+        assert_eq!(faces[0].texture, "entity/cow/temperate_cow");
+
+        // Mobs without an override still use the built-in model.
+        let (_, _, pig_faces) = generate_mob_geometry_with_pack(&pack, &block, MobType::Pig);
+        let (_, _, builtin_faces) = generate_mob_geometry(&block, MobType::Pig);
+        assert_eq!(pig_faces.len(), builtin_faces.len());
+    }
+
+    #[test]
+    fn test_custom_entity_renders_pack_bedrock_model() {
+        let mut pack = ResourcePack::new();
+        for (name, model) in bedrock::parse_geometry(r#"{
+            "format_version": "1.12.0",
+            "minecraft:geometry": [{
+                "description": { "identifier": "geometry.statue", "texture_width": 32, "texture_height": 32 },
+                "bones": [{ "name": "base", "pivot": [0, 0, 0], "cubes": [
+                    { "origin": [-4, 0, -4], "size": [8, 8, 8], "uv": [0, 0] },
+                    { "origin": [-1, 8, -1], "size": [2, 2, 2], "uv": { "north": { "uv": [0, 16] } } }
+                ] }]
+            }]
+        }"#).unwrap() {
+            pack.add_entity_model("minecraft", &name, model);
+        }
+
+        let block = InputBlock::new("entity:statue");
+        assert_eq!(detect_mob(&block), None);
+        assert_eq!(detect_mob_with_pack(&block, &pack), Some(MobType::Custom));
+        assert_eq!(detect_mob_with_pack(&InputBlock::new("entity:warden"), &pack), None);
+
+        let (verts, _, faces) = generate_mob_geometry_with_pack(&pack, &block, MobType::Custom);
+        // Box-UV cube draws 6 faces; the per-face cube only its north face.
+        assert_eq!(faces.len(), 7);
+        assert!(faces.iter().all(|f| f.texture == "entity/statue"));
+        let min_y = verts.iter().map(|v| v.position[1]).fold(f32::MAX, f32::min);
+        let max_y = verts.iter().map(|v| v.position[1]).fold(f32::MIN, f32::max);
+        assert!(min_y.abs() < 1e-5 && (max_y - 0.625).abs() < 1e-5);
+    }
 }
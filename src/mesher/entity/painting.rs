@@ -0,0 +1,162 @@
+use super::EntityFaceTexture;
+use crate::mesher::geometry::Vertex;
+use crate::resource_pack::{PaintingVariant, ResourcePack};
+use crate::types::InputBlock;
+use glam::{Mat4, Vec3, Vec4};
+
+/// Back and edge texture shared by every variant.
+const BACK_TEXTURE: &str = "painting/back";
+
+/// Distance from the block center to the painting's center, toward the wall
+/// (`Painting.calculateBoundingBox`: 0.5 - 1/32).
+const WALL_OFFSET: f32 = 0.46875;
+
+/// Painting thickness (1 pixel).
+const DEPTH: f32 = 1.0 / 16.0;
+
+/// Resolve the `variant` property: the pack's registry, then the vanilla
+/// variants. Unknown ids draw as 1×1 with `painting/<id>`.
+fn variant_for(block: &InputBlock, resource_pack: Option<&ResourcePack>) -> PaintingVariant {
+    let id = block.properties.get("variant").map(|s| s.as_str()).unwrap_or("kebab");
+    resource_pack
+        .and_then(|pack| pack.get_painting_variant(id))
+        .or_else(|| crate::resource_pack::painting::vanilla_variant(id))
+        .unwrap_or_else(|| PaintingVariant {
+            asset_id: id.to_string(),
+            width: 1,
+            height: 1,
+        })
+}
+
+/// Generate painting geometry directly (not via EntityModelDef).
+///
+/// Like item frames, paintings use a front texture plus the shared
+/// `painting/back` sprite rather than an entity texture sheet. The block
+/// position is the anchor cell: even sizes extend half a block to the
+/// facing's counter-clockwise side and upward, as `Painting` does, and the
+/// back sits flush against the wall block behind it.
+///
+/// Returns (vertices, indices, face_textures) ready for MeshBuilder integration.
+pub(super) fn generate_painting_geometry(
+    block: &InputBlock,
+    resource_pack: Option<&ResourcePack>,
+) -> (Vec<Vertex>, Vec<u32>, Vec<EntityFaceTexture>) {
+    let variant = variant_for(block, resource_pack);
+    let front_texture = variant.texture();
+    let (width, height) = (variant.width as f32, variant.height as f32);
+
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+    let mut face_textures = Vec::new();
+
+    // Built facing south (+Z); local +X is the counter-clockwise side.
+    let even_offset = |n: u32| if n.is_multiple_of(2) { 0.5 } else { 0.0 };
+    let x0 = even_offset(variant.width) - width / 2.0;
+    let x1 = x0 + width;
+    let y0 = even_offset(variant.height) - height / 2.0;
+    let y1 = y0 + height;
+    let z0 = -WALL_OFFSET - DEPTH / 2.0;
+    let z1 = z0 + DEPTH;
+    let edge = 1.0 / 16.0;
+
+    let mut quad = |corners: [[f32; 3]; 4], normal: [f32; 3], uv: [f32; 4], texture: &str| {
+        add_quad(corners, normal, uv, texture, &mut vertices, &mut indices, &mut face_textures);
+    };
+
+    // Front: the whole variant texture on one quad.
+    quad(
+        [[x0, y0, z1], [x1, y0, z1], [x1, y1, z1], [x0, y1, z1]],
+        [0.0, 0.0, 1.0],
+        [0.0, 0.0, 1.0, 1.0],
+        &front_texture,
+    );
+
+    // Back and edges: the back sprite repeated per block.
+    for i in 0..variant.width {
+        let cx0 = x0 + i as f32;
+        let cx1 = cx0 + 1.0;
+        for j in 0..variant.height {
+            let cy0 = y0 + j as f32;
+            let cy1 = cy0 + 1.0;
+            quad(
+                [[cx1, cy0, z0], [cx0, cy0, z0], [cx0, cy1, z0], [cx1, cy1, z0]],
+                [0.0, 0.0, -1.0],
+                [0.0, 0.0, 1.0, 1.0],
+                BACK_TEXTURE,
+            );
+        }
+        quad(
+            [[cx0, y1, z1], [cx1, y1, z1], [cx1, y1, z0], [cx0, y1, z0]],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0, edge],
+            BACK_TEXTURE,
+        );
+        quad(
+            [[cx0, y0, z0], [cx1, y0, z0], [cx1, y0, z1], [cx0, y0, z1]],
+            [0.0, -1.0, 0.0],
+            [0.0, 0.0, 1.0, edge],
+            BACK_TEXTURE,
+        );
+    }
+    for j in 0..variant.height {
+        let cy0 = y0 + j as f32;
+        let cy1 = cy0 + 1.0;
+        quad(
+            [[x1, cy0, z1], [x1, cy0, z0], [x1, cy1, z0], [x1, cy1, z1]],
+            [1.0, 0.0, 0.0],
+            [0.0, 0.0, edge, 1.0],
+            BACK_TEXTURE,
+        );
+        quad(
+            [[x0, cy0, z0], [x0, cy0, z1], [x0, cy1, z1], [x0, cy1, z0]],
+            [-1.0, 0.0, 0.0],
+            [0.0, 0.0, edge, 1.0],
+            BACK_TEXTURE,
+        );
+    }
+
+    // Turn the front toward `facing` and move into block-local [0,1] space.
+    let angle = match super::get_facing(block) {
+        "south" => 0.0,
+        "east" => std::f32::consts::FRAC_PI_2,
+        "west" => -std::f32::consts::FRAC_PI_2,
+        _ => std::f32::consts::PI,
+    };
+    let facing_mat = Mat4::from_translation(Vec3::splat(0.5)) * Mat4::from_rotation_y(angle);
+    for v in &mut vertices {
+        let p = facing_mat * Vec4::new(v.position[0], v.position[1], v.position[2], 1.0);
+        v.position = [p.x, p.y, p.z];
+        let n = facing_mat * Vec4::new(v.normal[0], v.normal[1], v.normal[2], 0.0);
+        let nv = Vec3::new(n.x, n.y, n.z).normalize_or_zero();
+        v.normal = [nv.x, nv.y, nv.z];
+    }
+
+    (vertices, indices, face_textures)
+}
+
+/// Add a quad from corners ordered bottom-left, bottom-right, top-right,
+/// top-left as seen from outside, with a [u0,v0,u1,v1] rect in [0,1].
+fn add_quad(
+    corners: [[f32; 3]; 4],
+    normal: [f32; 3],
+    uv: [f32; 4],
+    texture: &str,
+    vertices: &mut Vec<Vertex>,
+    indices: &mut Vec<u32>,
+    face_textures: &mut Vec<EntityFaceTexture>,
+) {
+    let [u0, v0, u1, v1] = uv;
+    let uvs = [[u0, v1], [u1, v1], [u1, v0], [u0, v0]];
+    let v_start = vertices.len() as u32;
+    for (corner, uv) in corners.iter().zip(uvs) {
+        vertices.push(Vertex::new(*corner, normal, uv));
+    }
+    indices.extend_from_slice(&[
+        v_start, v_start + 1, v_start + 2,
+        v_start, v_start + 2, v_start + 3,
+    ]);
+    face_textures.push(EntityFaceTexture {
+        texture: texture.to_string(),
+        is_transparent: false,
+    });
+}
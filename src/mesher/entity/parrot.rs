@@ -0,0 +1,56 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::{mob_root, mob_variant};
+use crate::types::InputBlock;
+
+/// Parrot model — texture `entity/parrot/parrot_<variant>`, 32x32.
+/// From ParrotModel.java (MC 1.21.4), standing pose. `variant`: `red_blue`
+/// (default), `blue`, `green`, `yellow_blue` or `gray`, or the game's 0–4 id.
+pub(super) fn parrot_model(block: &InputBlock) -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+    let part = |name: &str, position: [f32; 3], rotation: [f32; 3], cube: EntityCube| {
+        EntityPart::posed(name, position, rotation, vec![cube], vec![])
+    };
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, 15.69, -2.76],
+        no_rotation,
+        vec![EntityCube::new([-1.0, -1.5, -1.0], [2.0, 3.0, 2.0], [2, 2])],
+        vec![
+            part("head2", [0.0, -2.0, -1.0], no_rotation,
+                EntityCube::new([-1.0, -0.5, -2.0], [2.0, 1.0, 4.0], [10, 0])),
+            part("beak1", [0.0, -0.5, -1.5], no_rotation,
+                EntityCube::new([-0.5, -1.0, -0.5], [1.0, 2.0, 1.0], [11, 7])),
+            part("beak2", [0.0, -1.75, -2.45], no_rotation,
+                EntityCube::new([-0.5, 0.0, -0.5], [1.0, 2.0, 1.0], [16, 7])),
+            part("feather", [0.0, -2.15, 0.15], [-0.2214, 0.0, 0.0],
+                EntityCube::new([0.0, -4.0, -2.0], [0.0, 5.0, 4.0], [2, 18])),
+        ],
+    );
+
+    let wing_rotation = [-0.6981, -std::f32::consts::PI, 0.0];
+    let root = mob_root(vec![
+        head,
+        part("body", [0.0, 16.5, -3.0], [0.4937, 0.0, 0.0],
+            EntityCube::new([-1.5, 0.0, -1.5], [3.0, 6.0, 3.0], [2, 8])),
+        part("tail", [0.0, 21.07, 1.16], [1.015, 0.0, 0.0],
+            EntityCube::new([-1.5, -1.0, -1.0], [3.0, 4.0, 1.0], [22, 1])),
+        part("left_wing", [1.5, 16.94, -2.76], wing_rotation,
+            EntityCube::new([-0.5, 0.0, -1.5], [1.0, 5.0, 3.0], [19, 8])),
+        part("right_wing", [-1.5, 16.94, -2.76], wing_rotation,
+            EntityCube::new([-0.5, 0.0, -1.5], [1.0, 5.0, 3.0], [19, 8])),
+        part("left_leg", [1.0, 22.0, -1.05], [-0.0299, 0.0, 0.0],
+            EntityCube::new([-0.5, 0.0, -0.5], [1.0, 2.0, 1.0], [14, 18])),
+        part("right_leg", [-1.0, 22.0, -1.05], [-0.0299, 0.0, 0.0],
+            EntityCube::new([-0.5, 0.0, -0.5], [1.0, 2.0, 1.0], [14, 18])),
+    ]);
+
+    let variant = mob_variant(block, &["red_blue", "blue", "green", "yellow_blue", "gray"]);
+
+    EntityModelDef {
+        texture_path: format!("entity/parrot/parrot_{}", variant),
+        texture_size: [32, 32],
+        parts: vec![root],
+        is_opaque: false, // Head feather is a cut-out plane
+    }
+}
@@ -0,0 +1,63 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::find_part_mut;
+use super::player::player_model_with_arms;
+use crate::types::InputBlock;
+
+/// Piglin model — texture `entity/piglin/piglin`, 64x64.
+/// From PiglinModel.java (MC 1.21.4): the wide-armed player body with its
+/// overlay layers, and a wider head with snout, tusks and floppy ears in
+/// place of the player head and hat. `entity:piglin_brute` and
+/// `entity:zombified_piglin` share the model with their own skins.
+pub(super) fn piglin_model(block: &InputBlock) -> EntityModelDef {
+    let texture_path = match block.block_id() {
+        "piglin_brute" => "entity/piglin/piglin_brute",
+        "zombified_piglin" => "entity/piglin/zombified_piglin",
+        _ => "entity/piglin/piglin",
+    };
+    // Always wide arms: a player's slim/uuid properties mean nothing here.
+    let mut model = player_model_with_arms(block, texture_path, false);
+
+    let ear = |name: &str, x: f32, origin_x: f32, tex_offset: [u32; 2], roll: f32| {
+        EntityPart::posed(
+            name,
+            [x, -6.0, 0.0],
+            [0.0, 0.0, roll],
+            vec![EntityCube::new([origin_x, 0.0, -2.0], [1.0, 5.0, 4.0], tex_offset)],
+            vec![],
+        )
+    };
+
+    if let Some(head) = find_part_mut(&mut model.parts, "head") {
+        head.cubes = vec![
+            EntityCube::new([-5.0, -8.0, -4.0], [10.0, 8.0, 8.0], [0, 0]),
+            EntityCube::new([-2.0, -4.0, -5.0], [4.0, 4.0, 1.0], [31, 1]),
+            EntityCube::new([2.0, -2.0, -5.0], [1.0, 2.0, 1.0], [2, 4]),
+            EntityCube::new([-3.0, -2.0, -5.0], [1.0, 2.0, 1.0], [2, 0]),
+        ];
+        head.children = vec![
+            ear("left_ear", 4.5, 0.0, [51, 6], -std::f32::consts::FRAC_PI_6),
+            ear("right_ear", -4.5, -1.0, [39, 6], std::f32::consts::FRAC_PI_6),
+        ];
+    }
+
+    model
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piglin_arms_are_wide() {
+        // An odd first UUID digit would give a player Alex arms.
+        let block = InputBlock::new("entity:piglin")
+            .with_property("uuid", "1b2c3d4e-0000-0000-0000-000000000000")
+            .with_property("slim", "true");
+        let model = piglin_model(&block);
+        let root = &model.parts[0];
+        for arm in ["right_arm", "left_arm"] {
+            let arm = root.children.iter().find(|p| p.name == arm).unwrap();
+            assert_eq!(arm.cubes[0].dimensions[0], 4.0);
+        }
+    }
+}
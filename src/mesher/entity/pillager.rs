@@ -0,0 +1,45 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+
+/// Pillager model — texture `entity/illager/pillager`, 64x64.
+/// From IllagerModel.java (MC 1.21.4) as the pillager shows it: separate
+/// arms (the crossed-arms part is hidden) and no hat. The robe is an
+/// inflated 20-tall second body cube.
+pub(super) fn pillager_model() -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+    let part = |name: &str, position: [f32; 3], cubes: Vec<EntityCube>| {
+        EntityPart::posed(name, position, no_rotation, cubes, vec![])
+    };
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, 0.0, 0.0],
+        no_rotation,
+        vec![EntityCube::new([-4.0, -10.0, -4.0], [8.0, 10.0, 8.0], [0, 0])],
+        vec![part("nose", [0.0, -2.0, 0.0],
+            vec![EntityCube::new([-1.0, -1.0, -6.0], [2.0, 4.0, 2.0], [24, 0])])],
+    );
+
+    let root = mob_root(vec![
+        head,
+        part("body", [0.0, 0.0, 0.0], vec![
+            EntityCube::new([-4.0, 0.0, -3.0], [8.0, 12.0, 6.0], [16, 20]),
+            EntityCube::new([-4.0, 0.0, -3.0], [8.0, 20.0, 6.0], [0, 38]).inflated(0.5),
+        ]),
+        part("right_arm", [-5.0, 2.0, 0.0],
+            vec![EntityCube::new([-3.0, -2.0, -2.0], [4.0, 12.0, 4.0], [40, 46])]),
+        part("left_arm", [5.0, 2.0, 0.0],
+            vec![EntityCube::new([-1.0, -2.0, -2.0], [4.0, 12.0, 4.0], [40, 46]).mirrored()]),
+        part("right_leg", [-2.0, 12.0, 0.0],
+            vec![EntityCube::new([-2.0, 0.0, -2.0], [4.0, 12.0, 4.0], [0, 22])]),
+        part("left_leg", [2.0, 12.0, 0.0],
+            vec![EntityCube::new([-2.0, 0.0, -2.0], [4.0, 12.0, 4.0], [0, 22]).mirrored()]),
+    ]);
+
+    EntityModelDef {
+        texture_path: "entity/illager/pillager".to_string(),
+        texture_size: [64, 64],
+        parts: vec![root],
+        is_opaque: false, // Robe overlay has transparent pixels
+    }
+}
@@ -1,6 +1,7 @@
 use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 use super::armor_stand::parse_pose;
-use super::mob::mob_root;
+use super::mob::{clear_cubes, find_part_mut, mob_root};
+use super::skin::ProfileTextures;
 use crate::types::InputBlock;
 
 /// Determine whether the player should use slim (Alex) arms.
@@ -9,6 +10,10 @@ fn is_slim(block: &InputBlock) -> bool {
     if block.properties.get("slim").map(|v| v == "true").unwrap_or(false) {
         return true;
     }
+    // The profile's skin metadata
+    if let Some(profile) = ProfileTextures::of(block).filter(|p| p.skin.is_some()) {
+        return profile.slim;
+    }
     // UUID parity: odd first hex digit → Alex (slim)
     if let Some(uuid) = block.properties.get("uuid") {
         let first_char = uuid.chars().find(|c| c.is_ascii_hexdigit());
@@ -27,11 +32,17 @@ fn is_slim(block: &InputBlock) -> bool {
 /// 6 base parts + 6 overlay layers (each overlay is a child of its base part
 /// so it inherits pose transforms). Supports wide (Steve) and slim (Alex) arms.
 pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityModelDef {
-    let slim = is_slim(block);
+    player_model_with_arms(block, texture_path, is_slim(block))
+}
+
+/// [`player_model`] with the arm width chosen by the caller rather than by
+/// the block's `slim`, profile or `uuid` properties.
+pub(super) fn player_model_with_arms(block: &InputBlock, texture_path: &str, slim: bool) -> EntityModelDef {
     let arm_width: f32 = if slim { 3.0 } else { 4.0 };
 
     // ── Head + Hat overlay ──
     let mut head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -39,11 +50,13 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![
             // Hat overlay
             EntityPart {
+                name: "hat".into(),
                 cubes: vec![EntityCube {
                     origin: [-4.0, -8.0, -4.0],
                     dimensions: [8.0, 8.0, 8.0],
@@ -51,6 +64,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
                     inflate: 0.25,
                     mirror: false,
                     skip_faces: vec![],
+                    ..Default::default()
                 }],
                 pose: Default::default(),
                 children: vec![],
@@ -60,6 +74,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
 
     // ── Body + Jacket overlay ──
     let mut body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, 0.0, -2.0],
             dimensions: [8.0, 12.0, 4.0],
@@ -67,11 +82,13 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![
             // Jacket overlay
             EntityPart {
+                name: "jacket".into(),
                 cubes: vec![EntityCube {
                     origin: [-4.0, 0.0, -2.0],
                     dimensions: [8.0, 12.0, 4.0],
@@ -79,6 +96,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
                     inflate: 0.25,
                     mirror: false,
                     skip_faces: vec![],
+                    ..Default::default()
                 }],
                 pose: Default::default(),
                 children: vec![],
@@ -91,6 +109,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
     // Slim: origin [-2, -2, -2], dims [3, 12, 4]
     let right_arm_origin = if slim { [-2.0, -2.0, -2.0] } else { [-3.0, -2.0, -2.0] };
     let mut right_arm = EntityPart {
+        name: "right_arm".into(),
         cubes: vec![EntityCube {
             origin: right_arm_origin,
             dimensions: [arm_width, 12.0, 4.0],
@@ -98,6 +117,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-5.0, 2.0, 0.0],
@@ -106,6 +126,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
         children: vec![
             // Right sleeve overlay
             EntityPart {
+                name: "right_sleeve".into(),
                 cubes: vec![EntityCube {
                     origin: right_arm_origin,
                     dimensions: [arm_width, 12.0, 4.0],
@@ -113,6 +134,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
                     inflate: 0.25,
                     mirror: false,
                     skip_faces: vec![],
+                    ..Default::default()
                 }],
                 pose: Default::default(),
                 children: vec![],
@@ -125,6 +147,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
     // Wide: origin [-1, -2, -2], dims [4, 12, 4]
     // Slim: origin [-1, -2, -2], dims [3, 12, 4]
     let mut left_arm = EntityPart {
+        name: "left_arm".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -2.0, -2.0],
             dimensions: [arm_width, 12.0, 4.0],
@@ -132,6 +155,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [5.0, 2.0, 0.0],
@@ -140,6 +164,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
         children: vec![
             // Left sleeve overlay
             EntityPart {
+                name: "left_sleeve".into(),
                 cubes: vec![EntityCube {
                     origin: [-1.0, -2.0, -2.0],
                     dimensions: [arm_width, 12.0, 4.0],
@@ -147,6 +172,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
                     inflate: 0.25,
                     mirror: false,
                     skip_faces: vec![],
+                    ..Default::default()
                 }],
                 pose: Default::default(),
                 children: vec![],
@@ -156,6 +182,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
 
     // ── Right Leg + Right Pant overlay ──
     let mut right_leg = EntityPart {
+        name: "right_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -163,6 +190,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-1.9, 12.0, 0.0],
@@ -171,6 +199,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
         children: vec![
             // Right pant overlay
             EntityPart {
+                name: "right_pants".into(),
                 cubes: vec![EntityCube {
                     origin: [-2.0, 0.0, -2.0],
                     dimensions: [4.0, 12.0, 4.0],
@@ -178,6 +207,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
                     inflate: 0.25,
                     mirror: false,
                     skip_faces: vec![],
+                    ..Default::default()
                 }],
                 pose: Default::default(),
                 children: vec![],
@@ -188,6 +218,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
     // ── Left Leg + Left Pant overlay ──
     // Player left leg has its own UVs at (16, 48) — NOT mirrored from right leg
     let mut left_leg = EntityPart {
+        name: "left_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -195,6 +226,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [1.9, 12.0, 0.0],
@@ -203,6 +235,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
         children: vec![
             // Left pant overlay
             EntityPart {
+                name: "left_pants".into(),
                 cubes: vec![EntityCube {
                     origin: [-2.0, 0.0, -2.0],
                     dimensions: [4.0, 12.0, 4.0],
@@ -210,6 +243,7 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
                     inflate: 0.25,
                     mirror: false,
                     skip_faces: vec![],
+                    ..Default::default()
                 }],
                 pose: Default::default(),
                 children: vec![],
@@ -247,6 +281,32 @@ pub(crate) fn player_model(block: &InputBlock, texture_path: &str) -> EntityMode
     }
 }
 
+/// The cape layer (`PlayerCapeModel`, 64x32): a 10x16x1 cloak hanging from
+/// the back of the body at its resting 6° lean, on a copy of the player's
+/// model so it follows `BodyPose`. Over a chestplate it sits further out,
+/// as `CapeLayer` moves it.
+pub(crate) fn cape_model(player: &EntityModelDef, texture_path: &str, over_armor: bool) -> EntityModelDef {
+    let mut model = player.clone();
+    clear_cubes(&mut model.parts);
+    let position = if over_armor { [0.0, -0.85, 3.1] } else { [0.0, 0.0, 2.0] };
+    if let Some(body) = find_part_mut(&mut model.parts, "body") {
+        body.children.push(EntityPart {
+            name: "cape".into(),
+            cubes: vec![EntityCube::new([-5.0, 0.0, -1.0], [10.0, 16.0, 1.0], [0, 0])],
+            pose: EntityPartPose {
+                position,
+                rotation: [(-6.0_f32).to_radians(), std::f32::consts::PI, 0.0],
+                ..Default::default()
+            },
+            children: vec![],
+        });
+    }
+    model.texture_path = texture_path.to_string();
+    model.texture_size = [64, 32];
+    model.is_opaque = false;
+    model
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -341,6 +401,35 @@ mod tests {
         assert!((head.pose.rotation[0] - expected).abs() < 0.001);
     }
 
+    #[test]
+    fn test_cape_model() {
+        let block = InputBlock::new("entity:player").with_property("BodyPose", "10,0,0");
+        let player = player_model(&block, "test");
+        let mut cape = cape_model(&player, "_player_cape/0_0_0", false);
+        assert_eq!(cape.texture_size, [64, 32]);
+        assert_eq!(count_cubes(&cape.parts), 1);
+        let body = find_part_mut(&mut cape.parts, "body").unwrap();
+        assert!((body.pose.rotation[0] - 10.0_f32.to_radians()).abs() < 0.001);
+        let part = body.children.iter().find(|p| p.name == "cape").unwrap();
+        assert_eq!(part.pose.position, [0.0, 0.0, 2.0]);
+
+        let mut over_armor = cape_model(&player, "_player_cape/0_0_0", true);
+        assert_eq!(find_part_mut(&mut over_armor.parts, "cape").unwrap().pose.position, [0.0, -0.85, 3.1]);
+    }
+
+    #[test]
+    fn test_slim_from_profile() {
+        // {"textures":{"SKIN":{"url":"x/aa","metadata":{"model":"slim"}}}}
+        let value = "eyJ0ZXh0dXJlcyI6eyJTS0lOIjp7InVybCI6IngvYWEiLCJtZXRhZGF0YSI6eyJtb2RlbCI6InNsaW0ifX19fQ==";
+        let block = InputBlock::new("entity:player").with_property("textures", value);
+        assert!(is_slim(&block));
+        // The profile wins over UUID parity: {"textures":{"SKIN":{"url":"x/aa"}}}
+        let wide = InputBlock::new("entity:player")
+            .with_property("textures", "eyJ0ZXh0dXJlcyI6eyJTS0lOIjp7InVybCI6IngvYWEifX19")
+            .with_property("uuid", "1a2b");
+        assert!(!is_slim(&wide));
+    }
+
     #[test]
     fn test_detect_player_mob() {
         let block = InputBlock::new("entity:player");
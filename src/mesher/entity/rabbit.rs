@@ -0,0 +1,70 @@
+use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
+use super::mob::mob_variant;
+use crate::types::InputBlock;
+
+/// Rabbit model — texture `entity/rabbit/<variant>`, 64x32.
+/// From RabbitModel.java (MC 1.21.4). `variant`: `brown` (default), `white`,
+/// `black`, `white_splotched`, `gold` or `salt`, the game's 0–5 id, or
+/// `evil` / 99 for the killer bunny. Vanilla draws adults at 0.6x; the root
+/// carries that scale, lowered so the feet (model y = 24) stay on the ground.
+pub(super) fn rabbit_model(block: &InputBlock) -> EntityModelDef {
+    let tilt = [-0.34906584, 0.0, 0.0];
+    let part = |name: &str, position: [f32; 3], rotation: [f32; 3], cube: EntityCube| {
+        EntityPart::posed(name, position, rotation, vec![cube], vec![])
+    };
+
+    let children = vec![
+        part("head", [0.0, 16.0, -1.0], [0.0, 0.0, 0.0],
+            EntityCube::new([-2.5, -4.0, -5.0], [5.0, 4.0, 5.0], [32, 0])),
+        part("left_hind_foot", [3.0, 17.5, 3.7], [0.0, 0.0, 0.0],
+            EntityCube::new([-1.0, 5.5, -3.7], [2.0, 1.0, 7.0], [26, 24])),
+        part("right_hind_foot", [-3.0, 17.5, 3.7], [0.0, 0.0, 0.0],
+            EntityCube::new([-1.0, 5.5, -3.7], [2.0, 1.0, 7.0], [8, 24])),
+        part("left_haunch", [3.0, 17.5, 3.7], tilt,
+            EntityCube::new([-1.0, 0.0, 0.0], [2.0, 4.0, 5.0], [30, 15])),
+        part("right_haunch", [-3.0, 17.5, 3.7], tilt,
+            EntityCube::new([-1.0, 0.0, 0.0], [2.0, 4.0, 5.0], [16, 15])),
+        part("body", [0.0, 19.0, 8.0], tilt,
+            EntityCube::new([-3.0, -2.0, -10.0], [6.0, 5.0, 10.0], [0, 0])),
+        part("left_front_leg", [3.0, 17.0, -1.0], [-0.17453292, 0.0, 0.0],
+            EntityCube::new([-1.0, 0.0, -1.0], [2.0, 7.0, 2.0], [8, 15])),
+        part("right_front_leg", [-3.0, 17.0, -1.0], [-0.17453292, 0.0, 0.0],
+            EntityCube::new([-1.0, 0.0, -1.0], [2.0, 7.0, 2.0], [0, 15])),
+        part("right_ear", [0.0, 16.0, -1.0], [0.0, -0.2617994, 0.0],
+            EntityCube::new([-2.5, -9.0, -1.0], [2.0, 5.0, 1.0], [52, 0])),
+        part("left_ear", [0.0, 16.0, -1.0], [0.0, 0.2617994, 0.0],
+            EntityCube::new([0.5, -9.0, -1.0], [2.0, 5.0, 1.0], [58, 0])),
+        part("tail", [0.0, 20.0, 7.0], [-0.3490659, 0.0, 0.0],
+            EntityCube::new([-1.5, -1.5, 0.0], [3.0, 3.0, 2.0], [52, 6])),
+        part("nose", [0.0, 16.0, -1.0], [0.0, 0.0, 0.0],
+            EntityCube::new([-0.5, -2.5, -5.5], [1.0, 1.0, 1.0], [32, 9])),
+    ];
+
+    let root = EntityPart {
+        name: "root".into(),
+        cubes: vec![],
+        pose: EntityPartPose {
+            position: [8.0, 24.0 * 0.6, 8.0],
+            rotation: [std::f32::consts::PI, 0.0, 0.0],
+            scale: [0.6, 0.6, 0.6],
+        },
+        children,
+    };
+
+    let evil = matches!(
+        block.properties.get("variant").map(|s| s.as_str()),
+        Some("evil" | "minecraft:evil" | "99")
+    );
+    let variant = if evil {
+        "caerbannog"
+    } else {
+        mob_variant(block, &["brown", "white", "black", "white_splotched", "gold", "salt"])
+    };
+
+    EntityModelDef {
+        texture_path: format!("entity/rabbit/{}", variant),
+        texture_size: [64, 32],
+        parts: vec![root],
+        is_opaque: true,
+    }
+}
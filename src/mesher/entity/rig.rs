@@ -0,0 +1,362 @@
+//! Entity rigs — a model's part hierarchy kept as a skeleton.
+//!
+//! The regular mesher path flattens every part transform into vertex
+//! positions ([`traverse_parts`]). A rig produces the same bind-pose geometry
+//! but also keeps one joint per [`EntityPart`], with the part's pose as the
+//! joint's local transform, and records which joint each vertex follows. The
+//! animated glTF exporter turns this into a skin, so heads, limbs and lids can
+//! be posed after export instead of being baked.
+//!
+//! Joint 0 is the entity's placement in its block (facing, boat and lectern
+//! transforms); every top-level part hangs off it. Overlays drawn over the
+//! base model (sheep wool, saddles, horse armor) bind to the base joints of
+//! the same name, so they move with the body.
+
+use glam::{Mat4, Quat, Vec3};
+
+use super::{
+    armor, custom_model_id, entity_facing_matrix, equipment, generate_cube_faces, mob,
+    mob_facing_matrix, part_transform, sheep, BlockEntityType, EntityFaceTexture,
+    EntityModelDef, EntityPart, MobType, SkullType,
+};
+use crate::mesher::geometry::Vertex;
+use crate::resource_pack::ResourcePack;
+use crate::types::InputBlock;
+
+/// One joint of an [`EntityRig`]: a part's pose relative to its parent joint.
+#[derive(Debug, Clone)]
+pub struct EntityJoint {
+    /// Part name, or `part<index>` for unnamed parts.
+    pub name: String,
+    /// Parent joint index; `None` only for joint 0. Parents always precede
+    /// their children.
+    pub parent: Option<usize>,
+    /// Translation in blocks.
+    pub translation: [f32; 3],
+    /// Rotation quaternion (x, y, z, w).
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+}
+
+impl EntityJoint {
+    fn from_matrix(name: String, parent: Option<usize>, matrix: Mat4) -> Self {
+        let (scale, rotation, translation) = matrix.to_scale_rotation_translation();
+        Self {
+            name,
+            parent,
+            translation: translation.to_array(),
+            rotation: rotation.to_array(),
+            scale: scale.to_array(),
+        }
+    }
+
+    /// The joint's transform relative to its parent.
+    pub fn local_matrix(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(
+            Vec3::from(self.scale),
+            Quat::from_array(self.rotation),
+            Vec3::from(self.translation),
+        )
+    }
+}
+
+/// Entity geometry in bind pose plus the skeleton that drives it.
+#[derive(Debug, Clone)]
+pub struct EntityRig {
+    pub joints: Vec<EntityJoint>,
+    /// Bind-pose vertices, in the same block-local space as
+    /// [`generate_mob_geometry`](super::generate_mob_geometry).
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<u32>,
+    /// One entry per quad, as for the baked geometry.
+    pub face_textures: Vec<EntityFaceTexture>,
+    /// Per quad: whether it glows (the enchantment glint), like the baked
+    /// geometry's emissive faces. Shorter than `face_textures` (empty until a
+    /// quad glows); missing entries are `false`.
+    pub emissive: Vec<bool>,
+    /// Joint index each vertex follows.
+    pub vertex_joints: Vec<u16>,
+}
+
+impl EntityRig {
+    fn new(name: &str, placement: Mat4) -> Self {
+        Self {
+            joints: vec![EntityJoint::from_matrix(name.to_string(), None, placement)],
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            face_textures: Vec::new(),
+            emissive: Vec::new(),
+            vertex_joints: Vec::new(),
+        }
+    }
+
+    /// Index of the first joint called `name`.
+    pub fn joint_index(&self, name: &str) -> Option<usize> {
+        self.joints.iter().position(|j| j.name == name)
+    }
+
+    /// Each joint's transform relative to the rig origin, in bind pose.
+    pub fn world_matrices(&self) -> Vec<Mat4> {
+        let mut world: Vec<Mat4> = Vec::with_capacity(self.joints.len());
+        for joint in &self.joints {
+            let parent = joint.parent.map(|p| world[p]).unwrap_or(Mat4::IDENTITY);
+            world.push(parent * joint.local_matrix());
+        }
+        world
+    }
+
+    /// Move the whole rig (vertices and joint 0) by `offset` blocks.
+    pub fn translate(&mut self, offset: [f32; 3]) {
+        let offset = Vec3::from(offset);
+        for v in &mut self.vertices {
+            v.position = (Vec3::from(v.position) + offset).to_array();
+        }
+        let root = &mut self.joints[0].translation;
+        *root = (Vec3::from(*root) + offset).to_array();
+    }
+
+    /// Add a model's parts under joint 0. Named parts reuse an existing
+    /// sibling joint of the same name, so overlays follow the base model.
+    fn add_model(&mut self, model: &EntityModelDef) {
+        let placement = self.joints[0].local_matrix();
+        self.add_parts(&model.parts, 0, Mat4::IDENTITY, &placement, model);
+    }
+
+    fn add_parts(
+        &mut self,
+        parts: &[EntityPart],
+        parent: usize,
+        parent_transform: Mat4,
+        placement: &Mat4,
+        model: &EntityModelDef,
+    ) {
+        for part in parts {
+            let local = part_transform(&part.pose);
+            let combined = parent_transform * local;
+
+            let existing = (!part.name.is_empty())
+                .then(|| {
+                    self.joints.iter().position(|j| j.parent == Some(parent) && j.name == part.name)
+                })
+                .flatten();
+            let joint = existing.unwrap_or_else(|| {
+                let name = if part.name.is_empty() {
+                    format!("part{}", self.joints.len())
+                } else {
+                    part.name.clone()
+                };
+                self.joints.push(EntityJoint::from_matrix(name, Some(parent), local));
+                self.joints.len() - 1
+            });
+
+            for cube in &part.cubes {
+                generate_cube_faces(
+                    cube,
+                    &combined,
+                    placement,
+                    model,
+                    &mut self.vertices,
+                    &mut self.indices,
+                    &mut self.face_textures,
+                );
+            }
+            self.vertex_joints
+                .resize(self.vertices.len(), joint as u16);
+
+            self.add_parts(&part.children, joint, combined, placement, model);
+        }
+    }
+}
+
+/// Whether [`build_mob_rig`] covers this mob. Item frames, paintings, dropped
+/// items, players and display entities aren't a single part hierarchy and
+/// keep the baked geometry.
+pub fn is_rigged_mob(mob_type: MobType) -> bool {
+    !matches!(
+        mob_type,
+        MobType::ItemFrame
+            | MobType::GlowItemFrame
+            | MobType::Painting
+            | MobType::DroppedItem
+            | MobType::Player
+            | MobType::BlockDisplay
+            | MobType::ItemDisplay
+            | MobType::TextDisplay
+    )
+}
+
+/// Whether [`build_block_entity_rig`] covers this block entity. Banners,
+/// signs with text and player heads draw composited textures, and decorated
+/// pots aren't an entity model; those keep the baked geometry.
+pub fn is_rigged_block_entity(block: &InputBlock, entity_type: &BlockEntityType) -> bool {
+    let has_text = (1..=4).any(|i| block.properties.contains_key(&format!("text{}", i)));
+    match entity_type {
+        BlockEntityType::Banner { .. }
+        | BlockEntityType::Skull(SkullType::Player)
+        | BlockEntityType::DecoratedPot => false,
+        BlockEntityType::Sign { .. } | BlockEntityType::HangingSign { .. } => !has_text,
+        _ => true,
+    }
+}
+
+/// Build the rig for a mob: its base model plus wool and equipment overlays.
+/// Returns `None` when [`is_rigged_mob`] is false.
+pub fn build_mob_rig(
+    resource_pack: &ResourcePack,
+    block: &InputBlock,
+    mob_type: MobType,
+) -> Option<EntityRig> {
+    if !is_rigged_mob(mob_type) {
+        return None;
+    }
+
+    let model = mob::build_mob_model(mob_type, block, Some(resource_pack));
+    let name = match mob_type.model_id() {
+        Some(id) => id.to_string(),
+        None => custom_model_id(block),
+    };
+    let mut rig = EntityRig::new(&name, mob_facing_matrix(block, mob_type));
+    rig.add_model(&model);
+
+    // The mesher composites the villager skin under this key (see add_mob).
+    if matches!(mob_type, MobType::Villager) {
+        let biome = block.properties.get("biome").map(|s| s.as_str()).unwrap_or("plains");
+        let profession = block.properties.get("profession").map(|s| s.as_str()).unwrap_or("none");
+        let tex_key = format!("_villager/{}/{}", biome, profession);
+        for ft in &mut rig.face_textures {
+            if ft.texture == "entity/villager/villager" {
+                ft.texture = tex_key.clone();
+            }
+        }
+    }
+
+    if matches!(mob_type, MobType::Sheep) {
+        let first_vertex = rig.vertices.len();
+        rig.add_model(&sheep::sheep_wool_for(block));
+        let dye = block.properties.get("color")
+            .map(|c| crate::mesher::element::dye_rgb(c))
+            .unwrap_or([1.0, 1.0, 1.0, 1.0]);
+        for v in &mut rig.vertices[first_vertex..] {
+            v.color[0] *= dye[0];
+            v.color[1] *= dye[1];
+            v.color[2] *= dye[2];
+        }
+    }
+
+    if !matches!(mob_type, MobType::Boat | MobType::ChestBoat) {
+        for overlay in equipment::overlays_for(resource_pack, mob_type, block, &model) {
+            let first_vertex = rig.vertices.len();
+            let first_face = rig.face_textures.len();
+            rig.add_model(&overlay.model);
+            for v in &mut rig.vertices[first_vertex..] {
+                if let Some(tint) = overlay.tint {
+                    v.color[0] *= tint[0];
+                    v.color[1] *= tint[1];
+                    v.color[2] *= tint[2];
+                }
+                if overlay.glint {
+                    v.color[3] = armor::GLINT_ALPHA;
+                }
+            }
+            // Translucent and fullbright, as add_equipment_overlays marks it.
+            if overlay.glint {
+                for ft in &mut rig.face_textures[first_face..] {
+                    ft.is_transparent = true;
+                }
+                rig.emissive.resize(first_face, false);
+                rig.emissive.resize(rig.face_textures.len(), true);
+            }
+        }
+    }
+
+    Some(rig)
+}
+
+/// Build the rig for a block entity (chest lids, bell, book pages, ...).
+/// Returns `None` when [`is_rigged_block_entity`] is false.
+pub fn build_block_entity_rig(
+    resource_pack: &ResourcePack,
+    block: &InputBlock,
+    entity_type: &BlockEntityType,
+) -> Option<EntityRig> {
+    if !is_rigged_block_entity(block, entity_type) {
+        return None;
+    }
+    let model_id = entity_type.model_id()?;
+    let model = super::build_model_def(entity_type)
+        .overridden_by(resource_pack.get_entity_model(model_id));
+    let mut rig = EntityRig::new(model_id, entity_facing_matrix(block, entity_type));
+    rig.add_model(&model);
+    Some(rig)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesher::entity::generate_mob_geometry_with_pack;
+
+    #[test]
+    fn test_rig_bind_pose_matches_baked_geometry() {
+        let pack = ResourcePack::new();
+        let block = InputBlock::new("entity:cow").with_property("facing", "east");
+        let rig = build_mob_rig(&pack, &block, MobType::Cow).unwrap();
+        let (vertices, indices, _) = generate_mob_geometry_with_pack(&pack, &block, MobType::Cow);
+
+        assert_eq!(rig.vertices.len(), vertices.len());
+        assert_eq!(rig.indices, indices);
+        assert_eq!(rig.vertex_joints.len(), rig.vertices.len());
+        for (a, b) in rig.vertices.iter().zip(&vertices) {
+            for i in 0..3 {
+                assert!((a.position[i] - b.position[i]).abs() < 1e-5);
+            }
+        }
+
+        assert_eq!(rig.joints[0].name, "cow");
+        let root = rig.joint_index("root").unwrap();
+        let head = rig.joint_index("head").unwrap();
+        assert_eq!(rig.joints[root].parent, Some(0));
+        assert_eq!(rig.joints[head].parent, Some(root));
+        assert!(rig.vertex_joints.iter().any(|&j| j as usize == head));
+    }
+
+    #[test]
+    fn test_rig_joint_pivots_follow_part_poses() {
+        let pack = ResourcePack::new();
+        let block = InputBlock::new("entity:cow");
+        let rig = build_mob_rig(&pack, &block, MobType::Cow).unwrap();
+        let world = rig.world_matrices();
+
+        // Every vertex of the head lies within a block of the head pivot, and
+        // the pivot sits above the legs' pivots.
+        let head = rig.joint_index("head").unwrap();
+        let leg = rig.joint_index("right_hind_leg").unwrap();
+        let head_pivot = world[head].w_axis.truncate();
+        let leg_pivot = world[leg].w_axis.truncate();
+        assert!(head_pivot.y > leg_pivot.y);
+        for (v, &j) in rig.vertices.iter().zip(&rig.vertex_joints) {
+            if j as usize == head {
+                assert!((Vec3::from(v.position) - head_pivot).length() < 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sheep_wool_binds_to_base_joints() {
+        let pack = ResourcePack::new();
+        let block = InputBlock::new("entity:sheep");
+        let rig = build_mob_rig(&pack, &block, MobType::Sheep).unwrap();
+        assert_eq!(rig.joints.iter().filter(|j| j.name == "head").count(), 1);
+        assert!(rig.face_textures.iter().any(|ft| ft.texture == "entity/sheep/sheep_wool"));
+    }
+
+    #[test]
+    fn test_chest_rig_has_lid_joint() {
+        let pack = ResourcePack::new();
+        let block = InputBlock::new("minecraft:chest").with_property("facing", "north");
+        let entity_type = super::super::detect_block_entity(&block).unwrap();
+        let rig = build_block_entity_rig(&pack, &block, &entity_type).unwrap();
+        assert_eq!(rig.joints[0].name, "chest");
+        assert!(rig.joint_index("lid").is_some());
+        assert!(rig.joint_index("lock").is_some());
+    }
+}
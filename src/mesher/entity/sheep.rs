@@ -1,4 +1,5 @@
 use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
+use crate::types::InputBlock;
 
 /// Sheep base model — texture `entity/sheep/sheep`, 64x32.
 /// From SheepModel.java (MC 1.21.4). Extends QuadrupedModel with leg_height=12.
@@ -10,6 +11,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 pub(super) fn sheep_model() -> EntityModelDef {
     // Sheep overrides QuadrupedModel's head and body with smaller dims.
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, -4.0, -6.0],
             dimensions: [6.0, 6.0, 8.0],
@@ -17,6 +19,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 6.0, -8.0],
@@ -26,6 +29,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -10.0, -7.0],
             dimensions: [8.0, 16.0, 6.0],
@@ -33,6 +37,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 5.0, 2.0],
@@ -43,6 +48,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
     };
 
     let right_hind_leg = EntityPart {
+        name: "right_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -50,6 +56,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-3.0, 12.0, 7.0],
@@ -59,6 +66,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
     };
 
     let left_hind_leg = EntityPart {
+        name: "left_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -66,6 +74,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [3.0, 12.0, 7.0],
@@ -75,6 +84,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
     };
 
     let right_front_leg = EntityPart {
+        name: "right_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -82,6 +92,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-3.0, 12.0, -5.0],
@@ -91,6 +102,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
     };
 
     let left_front_leg = EntityPart {
+        name: "left_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -98,6 +110,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [3.0, 12.0, -5.0],
@@ -108,6 +121,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
 
     // Y-down → Y-up root wrapper
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
@@ -137,6 +151,7 @@ pub(super) fn sheep_model() -> EntityModelDef {
 pub(crate) fn sheep_wool_model() -> EntityModelDef {
     // Wool overlay per SheepFurModel: head 6x6x6 (not 6x6x8!), legs only 6 tall.
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, -4.0, -4.0],
             dimensions: [6.0, 6.0, 6.0],
@@ -144,6 +159,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
             inflate: 0.6,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 6.0, -8.0],
@@ -153,6 +169,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -10.0, -7.0],
             dimensions: [8.0, 16.0, 6.0],
@@ -160,6 +177,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
             inflate: 1.75,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 5.0, 2.0],
@@ -170,6 +188,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
     };
 
     let right_hind_leg = EntityPart {
+        name: "right_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -177,6 +196,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
             inflate: 0.5,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-3.0, 12.0, 7.0],
@@ -186,6 +206,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
     };
 
     let left_hind_leg = EntityPart {
+        name: "left_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -193,6 +214,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
             inflate: 0.5,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [3.0, 12.0, 7.0],
@@ -202,6 +224,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
     };
 
     let right_front_leg = EntityPart {
+        name: "right_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -209,6 +232,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
             inflate: 0.5,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-3.0, 12.0, -5.0],
@@ -218,6 +242,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
     };
 
     let left_front_leg = EntityPart {
+        name: "left_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 6.0, 4.0],
@@ -225,6 +250,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
             inflate: 0.5,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [3.0, 12.0, -5.0],
@@ -235,6 +261,7 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
 
     // Y-down → Y-up root wrapper
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
@@ -251,3 +278,22 @@ pub(crate) fn sheep_wool_model() -> EntityModelDef {
         is_opaque: false, // Wool texture has transparent regions
     }
 }
+
+/// The wool overlay for a sheep block, with the same baby scaling as the base
+/// model (the head gets the same extra 2× so it tracks the base sheep's
+/// big-head proportions).
+pub(crate) fn sheep_wool_for(block: &InputBlock) -> EntityModelDef {
+    let mut wool_model = sheep_wool_model();
+    if block.properties.get("is_baby").map(|v| v == "true").unwrap_or(false) {
+        if let Some(root) = wool_model.parts.first_mut() {
+            root.pose.scale = [0.5, 0.5, 0.5];
+            root.pose.position[1] = 12.0;
+            if let Some(head) = root.children.first_mut() {
+                head.pose.scale[0] *= 2.0;
+                head.pose.scale[1] *= 2.0;
+                head.pose.scale[2] *= 2.0;
+            }
+        }
+    }
+    wool_model
+}
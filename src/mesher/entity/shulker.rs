@@ -14,6 +14,7 @@ pub(super) fn shulker_model(color: Option<&str>) -> EntityModelDef {
     // Base: origin (-8, -8, -8), dims [16, 8, 16], texOffset [0, 28]
     // Skip Down face: after Y-flip it becomes the top (y=0.5), hidden inside lid volume.
     let base = EntityPart {
+        name: "base".into(),
         cubes: vec![EntityCube {
             origin: [-8.0, -8.0, -8.0],
             dimensions: [16.0, 8.0, 16.0],
@@ -21,6 +22,7 @@ pub(super) fn shulker_model(color: Option<&str>) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![Direction::Down],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 24.0, 0.0],
@@ -32,6 +34,7 @@ pub(super) fn shulker_model(color: Option<&str>) -> EntityModelDef {
     // Lid: origin (-8, -16, -8), dims [16, 12, 16], texOffset [0, 0]
     // Skip Up face: after Y-flip it becomes the bottom (y=0.25), hidden inside base volume.
     let lid = EntityPart {
+        name: "lid".into(),
         cubes: vec![EntityCube {
             origin: [-8.0, -16.0, -8.0],
             dimensions: [16.0, 12.0, 16.0],
@@ -39,6 +42,7 @@ pub(super) fn shulker_model(color: Option<&str>) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![Direction::Up],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 24.0, 0.0],
@@ -49,6 +53,7 @@ pub(super) fn shulker_model(color: Option<&str>) -> EntityModelDef {
 
     // Root wrapper: Y-down → Y-up conversion (same pattern as mob models)
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
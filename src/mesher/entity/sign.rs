@@ -33,6 +33,7 @@ pub(super) fn sign_model(wood: SignWood, is_wall: bool) -> EntityModelDef {
 
     // Board: origin [-12,-14,-1], dims [24,12,2], texOffs(0,0)
     let board = EntityPart {
+        name: "board".into(),
         cubes: vec![EntityCube {
             origin: [-12.0, -14.0, -1.0],
             dimensions: [24.0, 12.0, 2.0],
@@ -40,6 +41,7 @@ pub(super) fn sign_model(wood: SignWood, is_wall: bool) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [pos_x, pos_y, pos_z],
@@ -55,6 +57,7 @@ pub(super) fn sign_model(wood: SignWood, is_wall: bool) -> EntityModelDef {
     if !is_wall {
         // Stick: origin [-1,-2,-1], dims [2,14,2], texOffs(0,14)
         let stick = EntityPart {
+            name: "stick".into(),
             cubes: vec![EntityCube {
                 origin: [-1.0, -2.0, -1.0],
                 dimensions: [2.0, 14.0, 2.0],
@@ -62,6 +65,7 @@ pub(super) fn sign_model(wood: SignWood, is_wall: bool) -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [pos_x, pos_y, pos_z],
@@ -94,6 +98,7 @@ pub(crate) fn sign_model_upscaled(custom_texture: &str, is_wall: bool) -> Entity
     let scale = 2.0 / 3.0;
 
     let board = EntityPart {
+        name: "board".into(),
         cubes: vec![EntityCube {
             origin: [-12.0, -14.0, -1.0],
             dimensions: [24.0, 12.0, 2.0],
@@ -101,6 +106,7 @@ pub(crate) fn sign_model_upscaled(custom_texture: &str, is_wall: bool) -> Entity
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [pos_x, pos_y, pos_z],
@@ -114,6 +120,7 @@ pub(crate) fn sign_model_upscaled(custom_texture: &str, is_wall: bool) -> Entity
 
     if !is_wall {
         let stick = EntityPart {
+            name: "stick".into(),
             cubes: vec![EntityCube {
                 origin: [-1.0, -2.0, -1.0],
                 dimensions: [2.0, 14.0, 2.0],
@@ -121,6 +128,7 @@ pub(crate) fn sign_model_upscaled(custom_texture: &str, is_wall: bool) -> Entity
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: EntityPartPose {
                 position: [pos_x, pos_y, pos_z],
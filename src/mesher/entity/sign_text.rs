@@ -292,6 +292,130 @@ pub(crate) fn composite_sign_with_text(
     })
 }
 
+/// Horizontal alignment of text display lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TextAlignment {
+    Center,
+    Left,
+    Right,
+}
+
+/// Rasterize a text display's text onto its background.
+///
+/// Lines break at `\n` and wrap at spaces past `line_width` font pixels.
+/// Like TextDisplayRenderer, lines are 9 font pixels apart with a 1-pixel
+/// border of `background` (RGBA) around the block. Returns the texture and
+/// its size in font pixels.
+pub(crate) fn render_text_panel(
+    rp: &ResourcePack,
+    text: &str,
+    line_width: u32,
+    background: [u8; 4],
+    alignment: TextAlignment,
+) -> Option<(TextureData, [f32; 2])> {
+    let font = load_font(rp)?;
+    // Texture pixels per font pixel (glyph cells are 8 font pixels tall).
+    let unit = (font.cell_h / 8).max(1);
+    let line_height = 9 * unit;
+
+    let segments = parse_text_component(text, "white");
+    let lines = wrap_segments(&segments, &font, line_width * unit);
+    let widths: Vec<u32> = lines.iter().map(|line| segments_width(line, &font, 1)).collect();
+    let text_w = widths.iter().copied().max().unwrap_or(0);
+
+    let out_w = text_w + 2 * unit;
+    let out_h = lines.len() as u32 * line_height + unit;
+    let mut pixels = background.repeat((out_w * out_h) as usize);
+
+    for (i, (line, width)) in lines.iter().zip(&widths).enumerate() {
+        let x = unit
+            + match alignment {
+                TextAlignment::Left => 0,
+                TextAlignment::Right => text_w - width,
+                TextAlignment::Center => (text_w - width) / 2,
+            };
+        let y = unit + i as u32 * line_height;
+        render_segments(&mut pixels, out_w, &font, 1, line, x, y, out_w, false);
+    }
+
+    let size = [out_w as f32 / unit as f32, out_h as f32 / unit as f32];
+    Some((
+        TextureData {
+            width: out_w,
+            height: out_h,
+            pixels,
+            is_animated: false,
+            frame_count: 1,
+            animation: None,
+        },
+        size,
+    ))
+}
+
+/// Split segments into lines at `\n`, wrapping at the last space before a
+/// line grows past `max_width` pixels (a single long word breaks anywhere).
+/// Always returns at least one line.
+fn wrap_segments(segments: &[TextSegment], font: &FontData, max_width: u32) -> Vec<Vec<TextSegment>> {
+    let glyph_width = |ch: u8| font.glyph_widths[ch as usize] as u32;
+
+    // Each line as (byte, segment index) pairs.
+    let mut lines: Vec<Vec<(u8, usize)>> = vec![Vec::new()];
+    let mut width = 0;
+    let mut last_space: Option<usize> = None;
+    for (seg_idx, seg) in segments.iter().enumerate() {
+        for ch in seg.text.bytes() {
+            if ch == b'\n' {
+                lines.push(Vec::new());
+                width = 0;
+                last_space = None;
+                continue;
+            }
+            let line = lines.last_mut().unwrap();
+            if width + glyph_width(ch) > max_width && !line.is_empty() {
+                let rest = match last_space {
+                    Some(space) => {
+                        let rest = line.split_off(space + 1);
+                        line.pop();
+                        rest
+                    }
+                    None => Vec::new(),
+                };
+                width = rest.iter().map(|&(c, _)| glyph_width(c)).sum();
+                lines.push(rest);
+                last_space = None;
+            }
+            let line = lines.last_mut().unwrap();
+            if ch == b' ' {
+                if line.is_empty() {
+                    continue;
+                }
+                last_space = Some(line.len());
+            }
+            line.push((ch, seg_idx));
+            width += glyph_width(ch);
+        }
+    }
+
+    lines
+        .into_iter()
+        .map(|line| {
+            let mut out: Vec<(Vec<u8>, usize)> = Vec::new();
+            for (ch, seg_idx) in line {
+                match out.last_mut() {
+                    Some((bytes, idx)) if *idx == seg_idx => bytes.push(ch),
+                    _ => out.push((vec![ch], seg_idx)),
+                }
+            }
+            out.into_iter()
+                .map(|(bytes, seg_idx)| TextSegment {
+                    text: String::from_utf8_lossy(&bytes).into_owned(),
+                    color: segments[seg_idx].color.clone(),
+                })
+                .collect()
+        })
+        .collect()
+}
+
 /// Render a line of text segments into the pixel buffer.
 fn render_segments(
     pixels: &mut [u8],
@@ -493,6 +617,25 @@ mod tests {
         assert_eq!(outline[2], 0);  // 0 * 0.25
     }
 
+    #[test]
+    fn test_wrap_segments_at_spaces() {
+        let mut glyph_widths = [6u8; 256];
+        glyph_widths[b' ' as usize] = 4;
+        let font = FontData { pixels: Vec::new(), width: 128, height: 128, glyph_widths, cell_w: 8, cell_h: 8 };
+        let segment = |text: &str, color: &str| TextSegment { text: text.into(), color: color.into() };
+
+        let lines = wrap_segments(&[segment("aaa bbb", "red"), segment(" cc\ndd", "blue")], &font, 40);
+        assert_eq!(
+            lines,
+            vec![
+                vec![segment("aaa", "red")],
+                vec![segment("bbb", "red"), segment(" cc", "blue")],
+                vec![segment("dd", "blue")],
+            ]
+        );
+        assert_eq!(wrap_segments(&[], &font, 40), vec![Vec::<TextSegment>::new()]);
+    }
+
     #[test]
     fn test_parse_empty_text() {
         let segments = parse_text_component("", "black");
@@ -0,0 +1,248 @@
+//! Player skins and capes.
+//!
+//! A player's (or player head's) skin comes from, in order: `skin_base64`
+//! (a base64 PNG), `skin` (a hex PNG), the game profile's `textures`
+//! property looked up in the pack's
+//! [`SkinLibrary`](crate::resource_pack::SkinLibrary), and finally Steve or
+//! Alex by UUID. Legacy 64x32 skins are converted to the 64x64 layout as the
+//! game does on download. A cape comes from `cape_base64`, a `cape` library
+//! key or pack texture, or the profile's `CAPE` entry.
+
+use super::skull::{decode_base64, decode_base64_skin, decode_hex_skin, player_skin_fallback_path};
+use crate::resource_pack::{ResourcePack, TextureData};
+use crate::types::InputBlock;
+
+/// Textures named by a game profile `textures` property: base64 JSON like
+/// `{"textures": {"SKIN": {"url": ..., "metadata": {"model": "slim"}}, "CAPE": {"url": ...}}}`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ProfileTextures {
+    /// Skin texture URL.
+    pub skin: Option<String>,
+    /// Whether the skin is drawn with slim (Alex) arms.
+    pub slim: bool,
+    /// Cape texture URL.
+    pub cape: Option<String>,
+}
+
+impl ProfileTextures {
+    /// Decode a `textures` property value.
+    pub(crate) fn decode(value: &str) -> Option<Self> {
+        let json: serde_json::Value = serde_json::from_slice(&decode_base64(value)?).ok()?;
+        let textures = json.get("textures")?;
+        let url = |kind: &str| textures.get(kind)?.get("url")?.as_str().map(str::to_string);
+        let model = textures.pointer("/SKIN/metadata/model").and_then(|m| m.as_str());
+        Some(Self {
+            skin: url("SKIN"),
+            slim: model == Some("slim"),
+            cape: url("CAPE"),
+        })
+    }
+
+    /// The profile of a block's `textures` property.
+    pub(crate) fn of(block: &InputBlock) -> Option<Self> {
+        block.properties.get("textures").and_then(|value| Self::decode(value))
+    }
+}
+
+/// The skin of a player or player head, in the 64x64 layout.
+pub(crate) fn player_skin(resource_pack: &ResourcePack, block: &InputBlock) -> Option<TextureData> {
+    let props = &block.properties;
+    let skin = props
+        .get("skin_base64")
+        .and_then(|b64| decode_base64_skin(b64))
+        .or_else(|| props.get("skin").and_then(|hex| decode_hex_skin(hex)))
+        .or_else(|| {
+            let url = ProfileTextures::of(block)?.skin?;
+            resource_pack.get_skin(&url).cloned()
+        })
+        .or_else(|| resource_pack.get_texture(player_skin_fallback_path(block)).cloned())?;
+    Some(process_skin(skin))
+}
+
+/// The cape of a player (a 64x32 texture), if it wears one.
+pub(crate) fn player_cape(resource_pack: &ResourcePack, block: &InputBlock) -> Option<TextureData> {
+    let props = &block.properties;
+    if let Some(b64) = props.get("cape_base64") {
+        return decode_base64_skin(b64);
+    }
+    if let Some(cape) = props.get("cape") {
+        return resource_pack
+            .get_skin(cape)
+            .or_else(|| resource_pack.get_texture(cape))
+            .cloned();
+    }
+    let url = ProfileTextures::of(block)?.cape?;
+    resource_pack.get_skin(&url).cloned()
+}
+
+/// Legacy skin conversion of `SkinTextureDownloader.processLegacySkin`: the
+/// left arm and leg of a 64x32 skin are copied, mirrored, from the right
+/// ones. Rects are (x, y, dx, dy, width, height).
+const LEGACY_COPIES: [(u32, u32, i32, i32, u32, u32); 12] = [
+    (4, 16, 16, 32, 4, 4),
+    (8, 16, 16, 32, 4, 4),
+    (0, 20, 24, 32, 4, 12),
+    (4, 20, 16, 32, 4, 12),
+    (8, 20, 8, 32, 4, 12),
+    (12, 20, 16, 32, 4, 12),
+    (44, 16, -8, 32, 4, 4),
+    (48, 16, -8, 32, 4, 4),
+    (40, 20, 0, 32, 4, 12),
+    (44, 20, -8, 32, 4, 12),
+    (48, 20, -16, 32, 4, 12),
+    (52, 20, -8, 32, 4, 12),
+];
+
+/// Bring a skin to the 64x64 layout and clear stray alpha like the game:
+/// a legacy 64x32 skin gets its left limbs from the right ones and, when its
+/// hat region has no transparent pixel, loses the hat (old editors filled it
+/// with a solid color); the base layer is made opaque. Skins of other sizes
+/// are returned unchanged.
+pub(crate) fn process_skin(skin: TextureData) -> TextureData {
+    if skin.width != 64 || !(skin.height == 32 || skin.height == 64) {
+        return skin;
+    }
+    let legacy = skin.height == 32;
+    let mut pixels = skin.pixels;
+    pixels.resize(64 * 64 * 4, 0);
+
+    if legacy {
+        for (x, y, dx, dy, width, height) in LEGACY_COPIES {
+            for j in 0..height {
+                for i in 0..width {
+                    let src = pixel_index(x + i, y + j);
+                    let dst_x = (x + width - 1 - i) as i32 + dx;
+                    let dst = pixel_index(dst_x as u32, ((y + j) as i32 + dy) as u32);
+                    pixels.copy_within(src..src + 4, dst);
+                }
+            }
+        }
+    }
+
+    set_opaque(&mut pixels, 0, 0, 32, 16);
+    if legacy {
+        clear_if_opaque(&mut pixels, 32, 0, 64, 32);
+    }
+    set_opaque(&mut pixels, 0, 16, 64, 32);
+    set_opaque(&mut pixels, 16, 48, 48, 64);
+
+    TextureData::new(64, 64, pixels)
+}
+
+fn pixel_index(x: u32, y: u32) -> usize {
+    ((y * 64 + x) * 4) as usize
+}
+
+fn region(x0: u32, y0: u32, x1: u32, y1: u32) -> impl Iterator<Item = usize> {
+    (y0..y1).flat_map(move |y| (x0..x1).map(move |x| pixel_index(x, y) + 3))
+}
+
+fn set_opaque(pixels: &mut [u8], x0: u32, y0: u32, x1: u32, y1: u32) {
+    for alpha in region(x0, y0, x1, y1) {
+        pixels[alpha] = 255;
+    }
+}
+
+/// Vanilla's "Notch transparency hack": an overlay region without a single
+/// transparent pixel is cleared entirely.
+fn clear_if_opaque(pixels: &mut [u8], x0: u32, y0: u32, x1: u32, y1: u32) {
+    if region(x0, y0, x1, y1).any(|alpha| pixels[alpha] < 128) {
+        return;
+    }
+    for alpha in region(x0, y0, x1, y1) {
+        pixels[alpha] = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource_pack::SkinLibrary;
+
+    fn encode_base64(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let mut out = String::new();
+        for chunk in bytes.chunks(3) {
+            let n = chunk.iter().enumerate().fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+            for i in 0..=chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            }
+        }
+        out
+    }
+
+    fn profile(json: &str) -> String {
+        encode_base64(json.as_bytes())
+    }
+
+    #[test]
+    fn test_decode_profile_textures() {
+        let value = profile(r#"{"timestamp": 0, "profileName": "Alex", "textures": {
+            "SKIN": {"url": "http://textures.minecraft.net/texture/aa11", "metadata": {"model": "slim"}},
+            "CAPE": {"url": "http://textures.minecraft.net/texture/cc22"}
+        }}"#);
+        let textures = ProfileTextures::decode(&value).unwrap();
+        assert_eq!(textures.skin.as_deref(), Some("http://textures.minecraft.net/texture/aa11"));
+        assert!(textures.slim);
+        assert_eq!(textures.cape.as_deref(), Some("http://textures.minecraft.net/texture/cc22"));
+
+        let wide = ProfileTextures::decode(&profile(r#"{"textures": {"SKIN": {"url": "x/bb"}}}"#)).unwrap();
+        assert!(!wide.slim && wide.cape.is_none());
+        assert!(ProfileTextures::decode("not base64!").is_none());
+    }
+
+    #[test]
+    fn test_legacy_skin_conversion() {
+        let mut legacy = TextureData::new(64, 32, vec![0; 64 * 32 * 4]);
+        // Right leg front, left column; the hat check region fully opaque.
+        let put = |tex: &mut TextureData, x: u32, y: u32, rgba: [u8; 4]| {
+            let i = ((y * 64 + x) * 4) as usize;
+            tex.pixels[i..i + 4].copy_from_slice(&rgba);
+        };
+        put(&mut legacy, 4, 20, [200, 10, 10, 255]);
+        for y in 0..32 {
+            for x in 32..64 {
+                put(&mut legacy, x, y, [0, 0, 255, 255]);
+            }
+        }
+
+        let skin = process_skin(legacy);
+        assert_eq!((skin.width, skin.height), (64, 64));
+        // Mirrored onto the left leg front: (4 + 3 - 0 + 16, 20 + 32).
+        assert_eq!(skin.get_pixel(23, 52), [200, 10, 10, 255]);
+        // The solid hat is cleared; the base layer is opaque.
+        assert_eq!(skin.get_pixel(40, 8)[3], 0);
+        assert_eq!(skin.get_pixel(0, 0)[3], 255);
+        // The new overlay rows stay transparent.
+        assert_eq!(skin.get_pixel(0, 40)[3], 0);
+
+        let hd = TextureData::new(128, 128, vec![0; 128 * 128 * 4]);
+        assert_eq!(process_skin(hd).height, 128);
+    }
+
+    #[test]
+    fn test_player_skin_from_profile_library() {
+        let mut pack = ResourcePack::new();
+        let mut library = SkinLibrary::default();
+        library.insert("aa11", TextureData::new(64, 32, vec![255; 64 * 32 * 4]));
+        library.insert("cc22", TextureData::new(64, 32, vec![255; 64 * 32 * 4]));
+        pack.set_skin_library(library);
+
+        let value = profile(r#"{"textures": {
+            "SKIN": {"url": "http://textures.minecraft.net/texture/aa11"},
+            "CAPE": {"url": "http://textures.minecraft.net/texture/cc22"}
+        }}"#);
+        let block = InputBlock::new("minecraft:player_head").with_property("textures", value.as_str());
+        assert_eq!(player_skin(&pack, &block).map(|t| t.height), Some(64));
+        assert!(player_cape(&pack, &block).is_some());
+
+        // Unknown hashes fall back to the pack's Steve, absent here.
+        let unknown = InputBlock::new("minecraft:player_head")
+            .with_property("textures", profile(r#"{"textures": {"SKIN": {"url": "x/ff"}}}"#).as_str());
+        assert!(player_skin(&pack, &unknown).is_none());
+        assert!(player_cape(&pack, &unknown).is_none());
+
+        let named = InputBlock::new("entity:player").with_property("cape", "cc22");
+        assert!(player_cape(&pack, &named).is_some());
+    }
+}
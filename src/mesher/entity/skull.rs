@@ -24,6 +24,7 @@ pub(super) fn skull_model(skull_type: SkullType) -> EntityModelDef {
     // Head: 8x8x8 at texOffs(0,0)
     // Skull uses Y-down entity coords — wrap in root with RotX(PI) to flip Y-up.
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -31,6 +32,7 @@ pub(super) fn skull_model(skull_type: SkullType) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -42,6 +44,7 @@ pub(super) fn skull_model(skull_type: SkullType) -> EntityModelDef {
     let has_hat = matches!(skull_type, SkullType::Zombie | SkullType::Piglin | SkullType::Player);
     if has_hat {
         inner_parts.push(EntityPart {
+            name: "hat".into(),
             cubes: vec![EntityCube {
                 origin: [-4.0, -8.0, -4.0],
                 dimensions: [8.0, 8.0, 8.0],
@@ -49,6 +52,7 @@ pub(super) fn skull_model(skull_type: SkullType) -> EntityModelDef {
                 inflate: 0.25,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             }],
             pose: Default::default(),
             children: vec![],
@@ -57,6 +61,7 @@ pub(super) fn skull_model(skull_type: SkullType) -> EntityModelDef {
 
     // Root wrapper: RotX(PI) for Y-down→Y-up, position centers skull on block.
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 0.0, 8.0],
@@ -77,6 +82,7 @@ pub(super) fn skull_model(skull_type: SkullType) -> EntityModelDef {
 /// Build a player skull model with a custom texture path.
 pub(crate) fn player_skull_model(texture_path: &str) -> EntityModelDef {
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -84,6 +90,7 @@ pub(crate) fn player_skull_model(texture_path: &str) -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -91,6 +98,7 @@ pub(crate) fn player_skull_model(texture_path: &str) -> EntityModelDef {
 
     // Hat overlay always present for player heads
     let hat = EntityPart {
+        name: "hat".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -8.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -98,12 +106,14 @@ pub(crate) fn player_skull_model(texture_path: &str) -> EntityModelDef {
             inflate: 0.25,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 0.0, 8.0],
@@ -148,7 +158,7 @@ pub(crate) fn decode_base64_skin(b64: &str) -> Option<TextureData> {
     png_bytes_to_texture(&bytes)
 }
 
-fn png_bytes_to_texture(bytes: &[u8]) -> Option<TextureData> {
+pub(super) fn png_bytes_to_texture(bytes: &[u8]) -> Option<TextureData> {
     let img = image::load_from_memory(bytes).ok()?;
     let rgba = img.to_rgba8();
     Some(TextureData {
@@ -163,7 +173,7 @@ fn png_bytes_to_texture(bytes: &[u8]) -> Option<TextureData> {
 
 /// Base64 decoder. Supports both `+/` and URL-safe `-_` alphabets; `=` padding
 /// is tolerated but not required. Returns None on invalid characters.
-fn decode_base64(s: &str) -> Option<Vec<u8>> {
+pub(super) fn decode_base64(s: &str) -> Option<Vec<u8>> {
     let mut out = Vec::with_capacity(s.len() * 3 / 4);
     let mut buf: u32 = 0;
     let mut bits: u32 = 0;
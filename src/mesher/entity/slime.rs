@@ -6,6 +6,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 pub(super) fn slime_model() -> EntityModelDef {
     // Outer translucent cube (8x8x8 per MC 1.21.5 createOuterBodyLayer)
     let outer_cube = EntityPart {
+        name: "outer_cube".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, 16.0, -4.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -13,6 +14,7 @@ pub(super) fn slime_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -20,6 +22,7 @@ pub(super) fn slime_model() -> EntityModelDef {
 
     // Inner cube (6x6x6 per MC 1.21.5 createInnerBodyLayer)
     let inner_cube = EntityPart {
+        name: "inner_cube".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, 17.0, -3.0],
             dimensions: [6.0, 6.0, 6.0],
@@ -27,6 +30,7 @@ pub(super) fn slime_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -34,6 +38,7 @@ pub(super) fn slime_model() -> EntityModelDef {
 
     // Right eye
     let right_eye = EntityPart {
+        name: "right_eye".into(),
         cubes: vec![EntityCube {
             origin: [-3.25, 18.0, -3.5],
             dimensions: [2.0, 2.0, 2.0],
@@ -41,6 +46,7 @@ pub(super) fn slime_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -48,6 +54,7 @@ pub(super) fn slime_model() -> EntityModelDef {
 
     // Left eye
     let left_eye = EntityPart {
+        name: "left_eye".into(),
         cubes: vec![EntityCube {
             origin: [1.25, 18.0, -3.5],
             dimensions: [2.0, 2.0, 2.0],
@@ -55,6 +62,7 @@ pub(super) fn slime_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -62,6 +70,7 @@ pub(super) fn slime_model() -> EntityModelDef {
 
     // Mouth
     let mouth = EntityPart {
+        name: "mouth".into(),
         cubes: vec![EntityCube {
             origin: [0.0, 21.0, -3.5],
             dimensions: [1.0, 1.0, 1.0],
@@ -69,6 +78,7 @@ pub(super) fn slime_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -76,6 +86,7 @@ pub(super) fn slime_model() -> EntityModelDef {
 
     // Y-down → Y-up root wrapper
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
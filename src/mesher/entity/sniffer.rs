@@ -0,0 +1,73 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+
+/// Sniffer model — texture `entity/sniffer/sniffer`, 192x192.
+/// From SnifferModel.java (MC 1.21.4). Everything hangs off a `bone` part;
+/// the body carries its moss layer as an inflated second cube and a flat
+/// belly plane, and the six legs are short 7x10x8 blocks.
+pub(super) fn sniffer_model() -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+    let part = |name: &str, position: [f32; 3], cube: EntityCube| {
+        EntityPart::posed(name, position, no_rotation, vec![cube], vec![])
+    };
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 0.0, 0.0],
+        no_rotation,
+        vec![
+            EntityCube::new([-12.5, -14.0, -20.0], [25.0, 29.0, 40.0], [62, 68]),
+            EntityCube::new([-12.5, -14.0, -20.0], [25.0, 24.0, 40.0], [62, 0]).inflated(0.5),
+            EntityCube::new([-12.5, 12.0, -20.0], [25.0, 0.0, 40.0], [87, 68]),
+        ],
+        vec![],
+    );
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, 6.5, -19.48],
+        no_rotation,
+        vec![
+            EntityCube::new([-6.5, -7.5, -11.5], [13.0, 18.0, 11.0], [8, 15]),
+            EntityCube::new([-6.5, 7.5, -11.5], [13.0, 0.0, 11.0], [8, 4]),
+        ],
+        vec![
+            part("left_ear", [6.51, -7.5, -4.51],
+                EntityCube::new([0.0, 0.0, -3.0], [1.0, 19.0, 7.0], [2, 0])),
+            part("right_ear", [-6.51, -7.5, -4.51],
+                EntityCube::new([-1.0, 0.0, -3.0], [1.0, 19.0, 7.0], [48, 0])),
+            part("nose", [0.0, -4.5, -11.5],
+                EntityCube::new([-6.5, -2.0, -9.0], [13.0, 2.0, 9.0], [10, 45])),
+            part("lower_beak", [0.0, 2.5, -12.5],
+                EntityCube::new([-6.5, -7.0, -8.0], [13.0, 12.0, 9.0], [10, 57])),
+        ],
+    );
+
+    let leg = |name: &str, x: f32, z: f32, tex_offset: [u32; 2]| {
+        part(name, [x, 10.0, z], EntityCube::new([-3.5, -1.0, -4.0], [7.0, 10.0, 8.0], tex_offset))
+    };
+
+    let bone = EntityPart::posed(
+        "bone",
+        [0.0, 5.0, 0.0],
+        no_rotation,
+        vec![],
+        vec![
+            head,
+            body,
+            leg("right_front_leg", -7.5, -15.0, [32, 87]),
+            leg("right_mid_leg", -7.5, 0.0, [32, 105]),
+            leg("right_hind_leg", -7.5, 15.0, [32, 123]),
+            leg("left_front_leg", 7.5, -15.0, [0, 87]),
+            leg("left_mid_leg", 7.5, 0.0, [0, 105]),
+            leg("left_hind_leg", 7.5, 15.0, [0, 123]),
+        ],
+    );
+
+    EntityModelDef {
+        texture_path: "entity/sniffer/sniffer".to_string(),
+        texture_size: [192, 192],
+        parts: vec![mob_root(vec![bone])],
+        is_opaque: false, // Moss layer has cut-out pixels
+    }
+}
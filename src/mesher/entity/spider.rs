@@ -4,6 +4,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 /// From SpiderModel.java (MC 1.21.5).
 pub(super) fn spider_model() -> EntityModelDef {
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -4.0, -8.0],
             dimensions: [8.0, 8.0, 8.0],
@@ -11,6 +12,7 @@ pub(super) fn spider_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 15.0, -3.0],
@@ -21,6 +23,7 @@ pub(super) fn spider_model() -> EntityModelDef {
 
     // body0 (neck)
     let neck = EntityPart {
+        name: "neck".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, -3.0, -3.0],
             dimensions: [6.0, 6.0, 6.0],
@@ -28,6 +31,7 @@ pub(super) fn spider_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 15.0, 0.0],
@@ -38,6 +42,7 @@ pub(super) fn spider_model() -> EntityModelDef {
 
     // body1 (abdomen)
     let abdomen = EntityPart {
+        name: "abdomen".into(),
         cubes: vec![EntityCube {
             origin: [-5.0, -4.0, -6.0],
             dimensions: [10.0, 8.0, 12.0],
@@ -45,6 +50,7 @@ pub(super) fn spider_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 15.0, 9.0],
@@ -61,25 +67,26 @@ pub(super) fn spider_model() -> EntityModelDef {
     let f3 = 0.58119464_f32; // inner-leg Z rotation
 
     let legs = [
-        // (side_is_right, position, rot_y, rot_z)
-        (true,  [-4.0, 15.0,  2.0],  f,  -f),
-        (false, [ 4.0, 15.0,  2.0], -f,   f),
-        (true,  [-4.0, 15.0,  1.0],  f2, -f3),
-        (false, [ 4.0, 15.0,  1.0], -f2,  f3),
-        (true,  [-4.0, 15.0,  0.0], -f2, -f3),
-        (false, [ 4.0, 15.0,  0.0],  f2,  f3),
-        (true,  [-4.0, 15.0, -1.0], -f,  -f),
-        (false, [ 4.0, 15.0, -1.0],  f,   f),
+        // (name, side_is_right, position, rot_y, rot_z)
+        ("right_hind_leg",         true,  [-4.0, 15.0,  2.0],  f,  -f),
+        ("left_hind_leg",          false, [ 4.0, 15.0,  2.0], -f,   f),
+        ("right_middle_hind_leg",  true,  [-4.0, 15.0,  1.0],  f2, -f3),
+        ("left_middle_hind_leg",   false, [ 4.0, 15.0,  1.0], -f2,  f3),
+        ("right_middle_front_leg", true,  [-4.0, 15.0,  0.0], -f2, -f3),
+        ("left_middle_front_leg",  false, [ 4.0, 15.0,  0.0],  f2,  f3),
+        ("right_front_leg",        true,  [-4.0, 15.0, -1.0], -f,  -f),
+        ("left_front_leg",         false, [ 4.0, 15.0, -1.0],  f,   f),
     ];
 
     let leg_parts: Vec<EntityPart> = legs.iter()
-        .map(|&(right, pos, ry, rz)| spider_leg(right, pos, ry, rz))
+        .map(|&(name, right, pos, ry, rz)| spider_leg(name, right, pos, ry, rz))
         .collect();
 
     // Y-down → Y-up root wrapper
     let mut children = vec![head, neck, abdomen];
     children.extend(leg_parts);
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
@@ -97,13 +104,14 @@ pub(super) fn spider_model() -> EntityModelDef {
     }
 }
 
-fn spider_leg(is_right: bool, position: [f32; 3], rot_y: f32, rot_z: f32) -> EntityPart {
+fn spider_leg(name: &str, is_right: bool, position: [f32; 3], rot_y: f32, rot_z: f32) -> EntityPart {
     let (origin, mirror) = if is_right {
         ([-15.0, -1.0, -1.0], false)
     } else {
         ([-1.0, -1.0, -1.0], true)
     };
     EntityPart {
+        name: name.into(),
         cubes: vec![EntityCube {
             origin,
             dimensions: [16.0, 2.0, 2.0],
@@ -111,6 +119,7 @@ fn spider_leg(is_right: bool, position: [f32; 3], rot_y: f32, rot_z: f32) -> Ent
             inflate: 0.0,
             mirror,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position,
@@ -0,0 +1,46 @@
+use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
+
+/// Squid model — texture `entity/squid/squid` (or `glow_squid`), 64x32.
+/// From SquidModel.java (MC 1.21.4): a 12x16x12 body over eight 2x18x2
+/// tentacles in a radius-5 ring, each turned to face outward. Squids swim
+/// upright, so the root is lifted until the tentacle tips (model y = 33)
+/// touch the ground plane.
+pub(super) fn squid_model(glow: bool) -> EntityModelDef {
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 8.0, 0.0],
+        [0.0, 0.0, 0.0],
+        vec![EntityCube::new([-6.0, -8.0, -6.0], [12.0, 16.0, 12.0], [0, 0])],
+        vec![],
+    );
+
+    let tentacles = (0..8).map(|i| {
+        let angle = i as f32 * std::f32::consts::PI * 2.0 / 8.0;
+        EntityPart::posed(
+            &format!("tentacle{}", i),
+            [angle.cos() * 5.0, 15.0, angle.sin() * 5.0],
+            [0.0, i as f32 * std::f32::consts::PI * -2.0 / 8.0 + std::f32::consts::FRAC_PI_2, 0.0],
+            vec![EntityCube::new([-1.0, 0.0, -1.0], [2.0, 18.0, 2.0], [48, 0])],
+            vec![],
+        )
+    });
+
+    // Y-down -> Y-up root, lifted so the tentacle tips rest on the ground.
+    let root = EntityPart {
+        name: "root".into(),
+        cubes: vec![],
+        pose: EntityPartPose {
+            position: [8.0, 33.0, 8.0],
+            rotation: [std::f32::consts::PI, 0.0, 0.0],
+            ..Default::default()
+        },
+        children: std::iter::once(body).chain(tentacles).collect(),
+    };
+
+    EntityModelDef {
+        texture_path: if glow { "entity/squid/glow_squid" } else { "entity/squid/squid" }.to_string(),
+        texture_size: [64, 32],
+        parts: vec![root],
+        is_opaque: true,
+    }
+}
@@ -0,0 +1,58 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+use crate::types::InputBlock;
+
+/// Strider model — texture `entity/strider/strider`, 64x128.
+/// From StriderModel.java (MC 1.21.4): a 16x14x16 body on two long legs,
+/// with three flat bristle planes fanned out on each side. `cold=true` (out
+/// of lava) uses `strider_cold`.
+pub(super) fn strider_model(block: &InputBlock) -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+
+    let bristle = |name: &str, position: [f32; 3], roll: f32, v: u32, left: bool| {
+        let cube = if left {
+            EntityCube::new([0.0, 0.0, 0.0], [12.0, 0.0, 16.0], [16, v]).mirrored()
+        } else {
+            EntityCube::new([-12.0, 0.0, 0.0], [12.0, 0.0, 16.0], [16, v])
+        };
+        EntityPart::posed(name, position, [0.0, 0.0, roll], vec![cube], vec![])
+    };
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 1.0, 0.0],
+        no_rotation,
+        vec![EntityCube::new([-8.0, -6.0, -8.0], [16.0, 14.0, 16.0], [0, 0])],
+        vec![
+            bristle("right_bottom_bristle", [-8.0, 4.0, -8.0], -1.2217305, 65, false),
+            bristle("right_middle_bristle", [-8.0, -1.0, -8.0], -1.134464, 49, false),
+            bristle("right_top_bristle", [-8.0, -5.0, -8.0], -0.8726646, 33, false),
+            bristle("left_top_bristle", [8.0, -6.0, -8.0], 0.8726646, 33, true),
+            bristle("left_middle_bristle", [8.0, -2.0, -8.0], 1.2217305, 49, true),
+            bristle("left_bottom_bristle", [8.0, 3.0, -8.0], 1.2217305, 65, true),
+        ],
+    );
+
+    let leg = |name: &str, x: f32, tex_offset: [u32; 2]| {
+        EntityPart::posed(
+            name,
+            [x, 8.0, 0.0],
+            no_rotation,
+            vec![EntityCube::new([-2.0, 0.0, -2.0], [4.0, 16.0, 4.0], tex_offset)],
+            vec![],
+        )
+    };
+
+    let cold = block.properties.get("cold").map(|v| v == "true").unwrap_or(false);
+
+    EntityModelDef {
+        texture_path: if cold { "entity/strider/strider_cold" } else { "entity/strider/strider" }.to_string(),
+        texture_size: [64, 128],
+        parts: vec![mob_root(vec![
+            body,
+            leg("right_leg", -4.0, [0, 32]),
+            leg("left_leg", 4.0, [0, 55]),
+        ])],
+        is_opaque: false, // Bristles are cut-out planes
+    }
+}
@@ -0,0 +1,49 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::mob_root;
+
+/// Turtle model — texture `entity/turtle/big_sea_turtle`, 128x64.
+/// From TurtleModel.java (MC 1.21.4). Shell and belly are one body part
+/// rotated flat like a quadruped's; the flippers are 1px thick slabs. The
+/// egg belly only shows on turtles carrying eggs and is left out.
+pub(super) fn turtle_model() -> EntityModelDef {
+    let no_rotation = [0.0, 0.0, 0.0];
+
+    let head = EntityPart::posed(
+        "head",
+        [0.0, 19.0, -10.0],
+        no_rotation,
+        vec![EntityCube::new([-3.0, -1.0, -3.0], [6.0, 5.0, 6.0], [3, 0])],
+        vec![],
+    );
+
+    let body = EntityPart::posed(
+        "body",
+        [0.0, 11.0, -10.0],
+        [std::f32::consts::FRAC_PI_2, 0.0, 0.0],
+        vec![
+            EntityCube::new([-9.5, 3.0, -10.0], [19.0, 20.0, 6.0], [7, 37]),
+            EntityCube::new([-5.5, 3.0, -13.0], [11.0, 18.0, 3.0], [31, 1]),
+        ],
+        vec![],
+    );
+
+    let flipper = |name: &str, position: [f32; 3], origin: [f32; 3], dimensions: [f32; 3], tex_offset: [u32; 2]| {
+        EntityPart::posed(name, position, no_rotation, vec![EntityCube::new(origin, dimensions, tex_offset)], vec![])
+    };
+
+    let root = mob_root(vec![
+        head,
+        body,
+        flipper("right_hind_leg", [-3.5, 22.0, 11.0], [-2.0, 0.0, 0.0], [4.0, 1.0, 10.0], [1, 23]),
+        flipper("left_hind_leg", [3.5, 22.0, 11.0], [-2.0, 0.0, 0.0], [4.0, 1.0, 10.0], [1, 12]),
+        flipper("right_front_leg", [-5.0, 21.0, -4.0], [-13.0, 0.0, -2.0], [13.0, 1.0, 5.0], [27, 30]),
+        flipper("left_front_leg", [5.0, 21.0, -4.0], [0.0, 0.0, -2.0], [13.0, 1.0, 5.0], [27, 24]),
+    ]);
+
+    EntityModelDef {
+        texture_path: "entity/turtle/big_sea_turtle".to_string(),
+        texture_size: [128, 64],
+        parts: vec![root],
+        is_opaque: true,
+    }
+}
@@ -5,6 +5,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 pub(super) fn villager_model() -> EntityModelDef {
     // Nose: child of head
     let nose = EntityPart {
+        name: "nose".into(),
         cubes: vec![EntityCube {
             origin: [-1.0, -1.0, -6.0],
             dimensions: [2.0, 4.0, 2.0],
@@ -12,6 +13,7 @@ pub(super) fn villager_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, -2.0, 0.0],
@@ -22,6 +24,7 @@ pub(super) fn villager_model() -> EntityModelDef {
 
     // Hat rim: grandchild of head (child of hat)
     let hat_rim = EntityPart {
+        name: "hat_rim".into(),
         cubes: vec![EntityCube {
             origin: [-8.0, -8.0, -6.0],
             dimensions: [16.0, 16.0, 1.0],
@@ -29,6 +32,7 @@ pub(super) fn villager_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             rotation: [-std::f32::consts::FRAC_PI_2, 0.0, 0.0],
@@ -39,6 +43,7 @@ pub(super) fn villager_model() -> EntityModelDef {
 
     // Hat: child of head (inflated overlay)
     let hat = EntityPart {
+        name: "hat".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -10.0, -4.0],
             dimensions: [8.0, 10.0, 8.0],
@@ -46,6 +51,7 @@ pub(super) fn villager_model() -> EntityModelDef {
             inflate: 0.51,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![hat_rim],
@@ -53,6 +59,7 @@ pub(super) fn villager_model() -> EntityModelDef {
 
     // Head
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, -10.0, -4.0],
             dimensions: [8.0, 10.0, 8.0],
@@ -60,6 +67,7 @@ pub(super) fn villager_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![hat, nose],
@@ -67,6 +75,7 @@ pub(super) fn villager_model() -> EntityModelDef {
 
     // Jacket: child of body (inflated overlay)
     let jacket = EntityPart {
+        name: "jacket".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, 0.0, -3.0],
             dimensions: [8.0, 20.0, 6.0],
@@ -74,6 +83,7 @@ pub(super) fn villager_model() -> EntityModelDef {
             inflate: 0.5,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
@@ -81,6 +91,7 @@ pub(super) fn villager_model() -> EntityModelDef {
 
     // Body
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-4.0, 0.0, -3.0],
             dimensions: [8.0, 12.0, 6.0],
@@ -88,6 +99,7 @@ pub(super) fn villager_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![jacket],
@@ -98,6 +110,7 @@ pub(super) fn villager_model() -> EntityModelDef {
     // Left arm cube: [4,-2,-2] 4x8x4 at tex(44,22) mirrored
     // Cross piece: [-4,2,-2] 8x4x4 at tex(40,38)
     let arms = EntityPart {
+        name: "arms".into(),
         cubes: vec![
             EntityCube {
                 origin: [-8.0, -2.0, -2.0],
@@ -106,6 +119,7 @@ pub(super) fn villager_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
             EntityCube {
                 origin: [4.0, -2.0, -2.0],
@@ -114,6 +128,7 @@ pub(super) fn villager_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: true,
                 skip_faces: vec![],
+                ..Default::default()
             },
             EntityCube {
                 origin: [-4.0, 2.0, -2.0],
@@ -122,6 +137,7 @@ pub(super) fn villager_model() -> EntityModelDef {
                 inflate: 0.0,
                 mirror: false,
                 skip_faces: vec![],
+                ..Default::default()
             },
         ],
         pose: EntityPartPose {
@@ -134,6 +150,7 @@ pub(super) fn villager_model() -> EntityModelDef {
 
     // Legs
     let right_leg = EntityPart {
+        name: "right_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -141,6 +158,7 @@ pub(super) fn villager_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-2.0, 12.0, 0.0],
@@ -150,6 +168,7 @@ pub(super) fn villager_model() -> EntityModelDef {
     };
 
     let left_leg = EntityPart {
+        name: "left_leg".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, 0.0, -2.0],
             dimensions: [4.0, 12.0, 4.0],
@@ -157,6 +176,7 @@ pub(super) fn villager_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: true,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [2.0, 12.0, 0.0],
@@ -167,6 +187,7 @@ pub(super) fn villager_model() -> EntityModelDef {
 
     // Y-down → Y-up root wrapper
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
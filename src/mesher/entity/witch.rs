@@ -0,0 +1,58 @@
+use super::{EntityCube, EntityModelDef, EntityPart};
+use super::mob::find_part_mut;
+use super::villager::villager_model;
+
+/// Witch model — texture `entity/witch`, 64x128.
+/// From WitchModel.java (MC 1.21.4): the villager body with the villager's
+/// hat replaced by the four-tier pointed hat, and a mole on the nose.
+pub(super) fn witch_model() -> EntityModelDef {
+    let mut model = villager_model();
+
+    let hat4 = EntityPart::posed(
+        "hat4",
+        [1.75, -2.0, 2.0],
+        [-0.20943952, 0.0, 0.10471976],
+        vec![EntityCube::new([0.0, 0.0, 0.0], [1.0, 2.0, 1.0], [0, 95]).inflated(0.25)],
+        vec![],
+    );
+    let hat3 = EntityPart::posed(
+        "hat3",
+        [1.75, -4.0, 2.0],
+        [-0.10471976, 0.0, 0.05235988],
+        vec![EntityCube::new([0.0, 0.0, 0.0], [4.0, 4.0, 4.0], [0, 87])],
+        vec![hat4],
+    );
+    let hat2 = EntityPart::posed(
+        "hat2",
+        [1.75, -4.0, 2.0],
+        [-0.05235988, 0.0, 0.02617994],
+        vec![EntityCube::new([0.0, 0.0, 0.0], [7.0, 4.0, 7.0], [0, 76])],
+        vec![hat3],
+    );
+    let hat = EntityPart::posed(
+        "hat",
+        [-5.0, -10.03125, -5.0],
+        [0.0, 0.0, 0.0],
+        vec![EntityCube::new([0.0, 0.0, 0.0], [10.0, 2.0, 10.0], [0, 64])],
+        vec![hat2],
+    );
+    let mole = EntityPart::posed(
+        "mole",
+        [0.0, -2.0, 0.0],
+        [0.0, 0.0, 0.0],
+        vec![EntityCube::new([0.0, 3.0, -6.75], [1.0, 1.0, 1.0], [0, 0]).inflated(-0.25)],
+        vec![],
+    );
+
+    if let Some(head) = find_part_mut(&mut model.parts, "head") {
+        head.children.retain(|part| part.name != "hat");
+        head.children.push(hat);
+        if let Some(nose) = find_part_mut(&mut head.children, "nose") {
+            nose.children.push(mole);
+        }
+    }
+
+    model.texture_path = "entity/witch".to_string();
+    model.texture_size = [64, 128];
+    model
+}
@@ -4,6 +4,7 @@ use super::{EntityCube, EntityModelDef, EntityPart, EntityPartPose};
 /// From WolfModel.java (MC 1.21.4).
 pub(super) fn wolf_model() -> EntityModelDef {
     let right_ear = EntityPart {
+        name: "right_ear".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, -5.0, 0.0],
             dimensions: [2.0, 2.0, 1.0],
@@ -11,12 +12,14 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let left_ear = EntityPart {
+        name: "left_ear".into(),
         cubes: vec![EntityCube {
             origin: [2.0, -5.0, 0.0],
             dimensions: [2.0, 2.0, 1.0],
@@ -24,12 +27,14 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let snout = EntityPart {
+        name: "snout".into(),
         cubes: vec![EntityCube {
             origin: [-0.5, -0.001, -5.0],
             dimensions: [3.0, 3.0, 4.0],
@@ -37,12 +42,14 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: Default::default(),
         children: vec![],
     };
 
     let head = EntityPart {
+        name: "head".into(),
         cubes: vec![EntityCube {
             origin: [-2.0, -3.0, -2.0],
             dimensions: [6.0, 6.0, 4.0],
@@ -50,6 +57,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-1.0, 13.5, -7.0],
@@ -59,6 +67,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
     };
 
     let body = EntityPart {
+        name: "body".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, -2.0, -3.0],
             dimensions: [6.0, 9.0, 6.0],
@@ -66,6 +75,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.0, 14.0, 2.0],
@@ -78,6 +88,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
     // Tail cube extends from pivot along +X (box origin x=0). MC's wolf tail
     // is tilted down ~36° (0.62831855 rad) around X.
     let tail = EntityPart {
+        name: "tail".into(),
         cubes: vec![EntityCube {
             origin: [0.0, 0.0, -1.0],
             dimensions: [2.0, 8.0, 2.0],
@@ -85,6 +96,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-1.0, 12.0, 8.0],
@@ -96,6 +108,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
 
     // Upper body (chest/shoulders) — missing in the old port.
     let upper_body = EntityPart {
+        name: "upper_body".into(),
         cubes: vec![EntityCube {
             origin: [-3.0, -3.0, -3.0],
             dimensions: [8.0, 6.0, 7.0],
@@ -103,6 +116,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-1.0, 14.0, -3.0],
@@ -113,6 +127,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
     };
 
     let right_hind_leg = EntityPart {
+        name: "right_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [0.0, 0.0, -1.0],
             dimensions: [2.0, 8.0, 2.0],
@@ -120,6 +135,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-2.5, 16.0, 7.0],
@@ -129,6 +145,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
     };
 
     let left_hind_leg = EntityPart {
+        name: "left_hind_leg".into(),
         cubes: vec![EntityCube {
             origin: [0.0, 0.0, -1.0],
             dimensions: [2.0, 8.0, 2.0],
@@ -136,6 +153,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.5, 16.0, 7.0],
@@ -145,6 +163,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
     };
 
     let right_front_leg = EntityPart {
+        name: "right_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [0.0, 0.0, -1.0],
             dimensions: [2.0, 8.0, 2.0],
@@ -152,6 +171,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [-2.5, 16.0, -4.0],
@@ -161,6 +181,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
     };
 
     let left_front_leg = EntityPart {
+        name: "left_front_leg".into(),
         cubes: vec![EntityCube {
             origin: [0.0, 0.0, -1.0],
             dimensions: [2.0, 8.0, 2.0],
@@ -168,6 +189,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
             inflate: 0.0,
             mirror: false,
             skip_faces: vec![],
+            ..Default::default()
         }],
         pose: EntityPartPose {
             position: [0.5, 16.0, -4.0],
@@ -178,6 +200,7 @@ pub(super) fn wolf_model() -> EntityModelDef {
 
     // Y-down → Y-up root wrapper
     let root = EntityPart {
+        name: "root".into(),
         cubes: vec![],
         pose: EntityPartPose {
             position: [8.0, 24.0, 8.0],
@@ -48,6 +48,12 @@ pub struct FaceCuller<'a> {
     pack: &'a ResourcePack,
     /// Cache of block name -> cull type results.
     cull_cache: rustc_hash::FxHashMap<String, BlockCullType>,
+    /// Per-cell block id (index into `block_names`, 0 = empty), parallel to
+    /// `grid`. Only filled when the pack has connected-texture rules, which
+    /// need neighbour identity rather than just opacity.
+    block_ids: Vec<u32>,
+    /// Block names referenced by `block_ids` (index 0 unused).
+    block_names: Vec<String>,
 }
 
 impl<'a> FaceCuller<'a> {
@@ -89,6 +95,8 @@ impl<'a> FaceCuller<'a> {
             grid_size,
             pack,
             cull_cache: rustc_hash::FxHashMap::default(),
+            block_ids: Vec::new(),
+            block_names: Vec::new(),
         };
 
         // Classify every block to fill the flat opacity grid. Classification
@@ -171,9 +179,42 @@ impl<'a> FaceCuller<'a> {
             }
         }
 
+        if !pack.ctm_rules.is_empty() {
+            culler.fill_block_ids(blocks);
+        }
+
         culler
     }
 
+    /// Record each cell's block name for [`block_name_at`](Self::block_name_at).
+    fn fill_block_ids(&mut self, blocks: &[(BlockPosition, &InputBlock)]) {
+        let mut ids: rustc_hash::FxHashMap<&str, u32> = rustc_hash::FxHashMap::default();
+        self.block_names = vec![String::new()];
+        self.block_ids = vec![0; self.grid.len()];
+        for (pos, block) in blocks {
+            if block.is_air() {
+                continue;
+            }
+            let id = *ids.entry(block.name.as_str()).or_insert_with(|| {
+                self.block_names.push(block.name.clone());
+                (self.block_names.len() - 1) as u32
+            });
+            if let Some(idx) = self.grid_index(*pos) {
+                self.block_ids[idx] = id;
+            }
+        }
+    }
+
+    /// Name of the block at `pos`, if known. Only available when the pack has
+    /// connected-texture rules; `None` otherwise and for empty cells.
+    pub fn block_name_at(&self, pos: BlockPosition) -> Option<&str> {
+        let idx = self.grid_index(pos)?;
+        match *self.block_ids.get(idx)? {
+            0 => None,
+            id => Some(self.block_names[id as usize].as_str()),
+        }
+    }
+
     /// Convert a block position to a flat grid index, or None if out of bounds.
     #[inline]
     fn grid_index(&self, pos: BlockPosition) -> Option<usize> {
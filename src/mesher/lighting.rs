@@ -432,6 +432,12 @@ impl LightMap {
         brightness_from_level(max_light, self.config.ambient_light)
     }
 
+    /// Brightness multiplier for a fixed light level, e.g. an entity's
+    /// brightness override.
+    pub fn level_brightness(&self, level: u8) -> f32 {
+        brightness_from_level(level, self.config.ambient_light)
+    }
+
     /// Check if a block is emissive (light source).
     pub fn is_emissive(&self, pos: BlockPosition) -> bool {
         if let Some(idx) = Self::grid_index_static(pos, self.grid_min, self.grid_size) {
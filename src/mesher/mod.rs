@@ -11,6 +11,8 @@
 //! with a shared texture atlas.
 
 pub mod geometry;
+mod ctm;
+pub mod diagnostics;
 pub mod element;
 pub mod entity;
 pub mod face_culler;
@@ -23,12 +25,15 @@ const PARALLEL_BLOCK_THRESHOLD: usize = 50_000;
 pub mod liquid;
 pub mod tint;
 
+pub use diagnostics::{
+    BlockDiagnostic, FallbackDiagnostic, FallbackKind, MeshDiagnostics, MissingTextureDiagnostic,
+};
 pub use geometry::{Mesh, Vertex};
 pub use tint::{TintColors, TintProvider};
 
-use crate::atlas::TextureAtlas;
+use crate::atlas::{AtlasRegion, TextureArray, TextureAtlas};
 use crate::error::Result;
-use crate::resource_pack::ResourcePack;
+use crate::resource_pack::{ResourcePack, TextureData};
 use crate::types::{BlockPosition, BlockSource, BoundingBox, InputBlock};
 
 /// Wasm-safe profiling clock. `std::time::Instant::now()` panics on
@@ -52,10 +57,30 @@ pub(crate) fn prof_now() -> Option<std::time::Instant> {
 pub struct MesherConfig {
     /// Enable face culling between adjacent blocks.
     pub cull_hidden_faces: bool,
-    /// Maximum texture atlas dimension.
+    /// Maximum texture atlas page dimension. Textures that don't fit spill
+    /// onto additional pages of this size.
     pub atlas_max_size: u32,
     /// Padding between textures in the atlas.
     pub atlas_padding: u32,
+    /// Allow rectangular (2:1) atlas pages when they are smaller than the
+    /// next square size.
+    pub atlas_non_square: bool,
+    /// Mip levels the atlas must stay bleed-free for (0 = off). Aligns
+    /// regions to `2^n` pixels, widens the gutters to match (adding one when
+    /// `atlas_padding` is 0), and fills [`TextureAtlas::mip_chain`] with a
+    /// coverage-preserving mip chain.
+    pub atlas_mip_levels: u32,
+    /// Output a [`TextureArray`] with one layer per texture (one array per
+    /// texture size) instead of an atlas. Vertices carry their layer in
+    /// [`MeshLayer::texture_layers`](crate::MeshLayer::texture_layers) and UVs
+    /// stay texture-local, so greedy-merged faces tile with a REPEAT sampler
+    /// and are emitted into the regular layers (with AO in vertex colors)
+    /// rather than as greedy materials. Takes precedence over `pre_built_atlas`.
+    pub texture_array: bool,
+    /// Bake interpolating (`"interpolate": true`) mcmeta animations into
+    /// pre-blended frames, one every this many ticks (0 = off), for exports
+    /// that play sprite sheets at a fixed rate instead of blending live.
+    pub interpolated_frame_ticks: u32,
     /// Include air blocks in output.
     pub include_air: bool,
     /// Tint provider for block coloring (grass, foliage, water, redstone, etc.)
@@ -80,6 +105,18 @@ pub struct MesherConfig {
     /// If set, skip per-chunk atlas building and use this pre-built atlas instead.
     /// UVs will be remapped to this atlas's regions. Used for global atlas workflows.
     pub pre_built_atlas: Option<TextureAtlas>,
+    /// DataVersion the blocks were saved with. When set, blocks from older
+    /// versions are upgraded to current names/properties before resolution
+    /// (see [`crate::resolver::upgrade`]). `None` = blocks are already current.
+    pub data_version: Option<u32>,
+    /// Leave out the mobs and block entities that [`entity::rig`] can rig
+    /// (and their riders), so a skinned export can add them back as
+    /// skeletons. The blocks' own models (a lectern's stand) still mesh.
+    pub skip_rigged_entities: bool,
+    /// Camera (yaw, pitch) in degrees, Minecraft convention (yaw 0 looks
+    /// south, positive pitch looks down), that billboarded display entities
+    /// turn to face. `None` draws them with their own rotation.
+    pub billboard_camera: Option<[f32; 2]>,
 }
 
 impl Default for MesherConfig {
@@ -90,6 +127,10 @@ impl Default for MesherConfig {
             greedy_meshing: false,
             atlas_max_size: 4096,
             atlas_padding: 1,
+            atlas_non_square: false,
+            atlas_mip_levels: 0,
+            texture_array: false,
+            interpolated_frame_ticks: 0,
             include_air: false,
             tint_provider: TintProvider::new(),
             ambient_occlusion: true,
@@ -99,6 +140,9 @@ impl Default for MesherConfig {
             sky_light_level: 15,
             enable_particles: true,
             pre_built_atlas: None,
+            data_version: None,
+            skip_rigged_entities: false,
+            billboard_camera: None,
         }
     }
 }
@@ -120,6 +164,8 @@ impl MesherConfig {
 /// Animation metadata for a texture in the atlas, exported for viewer-side frame cycling.
 #[derive(Debug, Clone)]
 pub struct AnimatedTextureExport {
+    /// Texture path (e.g. `"block/water_still"`).
+    pub texture_path: String,
     /// The sprite sheet PNG (all frames stacked vertically).
     pub sprite_sheet_png: Vec<u8>,
     /// Number of animation frames.
@@ -130,6 +176,9 @@ pub struct AnimatedTextureExport {
     pub interpolate: bool,
     /// Explicit frame order (indices into sprite sheet). None = sequential.
     pub frames: Option<Vec<u32>>,
+    /// Tick duration of each entry in `frames`: the mcmeta per-frame `time`,
+    /// else `frametime`. None when `frames` is None.
+    pub frame_times: Option<Vec<u32>>,
     /// Frame width in pixels.
     pub frame_width: u32,
     /// Frame height in pixels.
@@ -138,6 +187,79 @@ pub struct AnimatedTextureExport {
     pub atlas_x: u32,
     /// Atlas region: pixel Y offset.
     pub atlas_y: u32,
+    /// Atlas page holding the region.
+    pub atlas_page: u32,
+}
+
+impl AnimatedTextureExport {
+    /// Describe an animated texture packed into `atlas` at `region`. With
+    /// `interpolated_frame_ticks > 0`, interpolating animations are exported
+    /// with their blended frames baked in (see [`TextureData::bake_interpolation`]).
+    /// Returns None for textures that aren't animated.
+    pub(crate) fn from_texture(
+        texture_path: &str,
+        texture: &TextureData,
+        region: &AtlasRegion,
+        atlas: &TextureAtlas,
+        interpolated_frame_ticks: u32,
+    ) -> Option<Self> {
+        if !texture.is_animated || texture.frame_count <= 1 {
+            return None;
+        }
+        let baked = texture.bake_interpolation(interpolated_frame_ticks);
+        let texture = baked.as_ref().unwrap_or(texture);
+
+        // Encode the full sprite sheet as PNG
+        let sprite_sheet_png = texture.to_png().ok()?;
+
+        let anim = texture.animation.as_ref();
+        let frame_width = anim.and_then(|a| a.frame_width).unwrap_or(texture.width);
+        let frame_height = anim.and_then(|a| a.frame_height).unwrap_or(frame_width);
+        let frametime = anim.map(|a| a.frametime).unwrap_or(1);
+        let interpolate = anim.map(|a| a.interpolate).unwrap_or(false);
+        let frames = anim.and_then(|a| a.frames.as_ref()).map(|fs| {
+            fs.iter().map(|f| f.index).collect()
+        });
+        let frame_times = anim.and_then(|a| a.frames.as_ref()).map(|fs| {
+            fs.iter().map(|f| f.time.unwrap_or(frametime)).collect()
+        });
+
+        // Convert atlas region from UV (0-1) to pixel coordinates
+        let atlas_x = (region.u_min * atlas.width as f32).round() as u32;
+        let atlas_y = (region.v_min * atlas.height as f32).round() as u32;
+
+        Some(AnimatedTextureExport {
+            texture_path: texture_path.to_string(),
+            sprite_sheet_png,
+            frame_count: texture.frame_count,
+            frametime,
+            interpolate,
+            frames,
+            frame_times,
+            frame_width,
+            frame_height,
+            atlas_x,
+            atlas_y,
+            atlas_page: region.page,
+        })
+    }
+
+    /// Frames in playback order as `(sprite sheet frame, duration in ticks)`.
+    /// Out-of-range frame indices are clamped to the last frame.
+    pub fn frame_sequence(&self) -> Vec<(u32, u32)> {
+        let last = self.frame_count.saturating_sub(1);
+        match &self.frames {
+            Some(frames) => frames
+                .iter()
+                .enumerate()
+                .map(|(i, &frame)| {
+                    let ticks = self.frame_times.as_ref().and_then(|t| t.get(i).copied());
+                    (frame.min(last), ticks.unwrap_or(self.frametime))
+                })
+                .collect(),
+            None => (0..self.frame_count).map(|frame| (frame, self.frametime)).collect(),
+        }
+    }
 }
 
 /// Output from the mesher.
@@ -154,12 +276,16 @@ pub struct MesherOutput {
     pub transparent_mesh: crate::mesh_output::MeshLayer,
     /// The texture atlas.
     pub atlas: TextureAtlas,
+    /// The texture array, when meshed with [`MesherConfig::texture_array`].
+    pub texture_array: Option<TextureArray>,
     /// Bounding box of the mesh.
     pub bounds: BoundingBox,
     /// Per-texture materials for greedy-merged faces (bypass atlas, use REPEAT wrapping).
     pub greedy_materials: Vec<element::GreedyMaterial>,
     /// Animated texture metadata for viewer-side frame cycling.
     pub animated_textures: Vec<AnimatedTextureExport>,
+    /// Blocks that were skipped, textures that were missing, and fallbacks taken.
+    pub diagnostics: MeshDiagnostics,
 }
 
 impl MesherOutput {
@@ -274,8 +400,11 @@ impl Mesher {
 
         // Collect blocks for face culling
         let tc = prof_now();
-        let blocks: Vec<_> = blocks.collect();
+        let mut blocks: Vec<_> = blocks.collect();
         phase!(tc, "  collect_vec");
+
+        let mut upgraded_storage = Vec::new();
+        self.upgrade_blocks(&mut blocks, &mut upgraded_storage);
         // NOTE: meshing order = the source's iteration order. The whole-schematic
         // source (VecBlockSource) yields blocks in deterministic volume-scan order
         // (spatially coherent, x-fastest), so the output is reproducible *and*
@@ -417,7 +546,7 @@ impl Mesher {
         // partials straight into the layer-split (no intermediate merge copy of the
         // full vertex buffer); the sequential path builds from the single mesh.
         let tbuild = prof_now();
-        let (opaque_mesh, cutout_mesh, transparent_mesh, atlas, greedy_materials, dynamic_animated) =
+        let (opaque_mesh, cutout_mesh, transparent_mesh, atlas, texture_array, greedy_materials, dynamic_animated, diagnostics) =
             match partials {
                 Some(partials) => {
                     mesh_builder.merge_metadata_only(&partials);
@@ -445,7 +574,11 @@ impl Mesher {
         }
 
         // Collect animated texture metadata for viewer-side frame cycling
-        let mut animated_textures = Self::collect_animated_textures(&self.resource_pack, &atlas);
+        let mut animated_textures = Self::collect_animated_textures(
+            &self.resource_pack,
+            &atlas,
+            self.config.interpolated_frame_ticks,
+        );
         animated_textures.extend(dynamic_animated);
 
         Ok(MesherOutput {
@@ -453,9 +586,11 @@ impl Mesher {
             cutout_mesh,
             transparent_mesh,
             atlas,
+            texture_array,
             bounds,
             greedy_materials,
             animated_textures,
+            diagnostics,
         })
     }
 
@@ -503,7 +638,9 @@ impl Mesher {
     /// Runs the full face processing pipeline to collect texture references, but the
     /// resulting mesh is discarded. Use this to pre-scan blocks for a global atlas.
     pub fn discover_textures<S: BlockSource>(&self, source: &S) -> std::collections::HashSet<String> {
-        let blocks: Vec<_> = source.iter_blocks().collect();
+        let mut blocks: Vec<_> = source.iter_blocks().collect();
+        let mut upgraded_storage = Vec::new();
+        self.upgrade_blocks(&mut blocks, &mut upgraded_storage);
         let block_map: rustc_hash::FxHashMap<BlockPosition, &InputBlock> =
             blocks.iter().map(|(pos, block)| (*pos, *block)).collect();
         let culler = if self.config.cull_hidden_faces {
@@ -538,10 +675,41 @@ impl Mesher {
         mesh_builder.texture_refs().clone()
     }
 
+    /// Upgrade blocks saved by older game versions (`config.data_version`).
+    /// Upgrades are computed once per distinct `&InputBlock` (palette entry)
+    /// and re-pointed at `storage`, so the builder's same-pointer resolve fast
+    /// path still hits.
+    fn upgrade_blocks<'a>(&self, blocks: &mut [(BlockPosition, &'a InputBlock)], storage: &'a mut Vec<InputBlock>) {
+        let Some(data_version) = self.config.data_version else {
+            return;
+        };
+        let mut slot_by_ptr: rustc_hash::FxHashMap<usize, Option<usize>> = Default::default();
+        let slots: Vec<Option<usize>> = blocks
+            .iter()
+            .map(|(_, block)| {
+                *slot_by_ptr
+                    .entry(*block as *const InputBlock as usize)
+                    .or_insert_with(|| {
+                        crate::resolver::upgrade_block(block, data_version).map(|b| {
+                            storage.push(b);
+                            storage.len() - 1
+                        })
+                    })
+            })
+            .collect();
+        let storage: &'a [InputBlock] = storage;
+        for ((_, block), slot) in blocks.iter_mut().zip(slots) {
+            if let Some(i) = slot {
+                *block = &storage[i];
+            }
+        }
+    }
+
     /// Collect animation metadata for textures that are animated and present in the atlas.
     fn collect_animated_textures(
         resource_pack: &ResourcePack,
         atlas: &TextureAtlas,
+        interpolated_frame_ticks: u32,
     ) -> Vec<AnimatedTextureExport> {
         let mut result = Vec::new();
 
@@ -551,41 +719,16 @@ impl Mesher {
         paths.sort_unstable();
         for texture_path in paths {
             let region = &atlas.regions[texture_path];
-            let texture = match resource_pack.get_texture(texture_path) {
-                Some(t) if t.is_animated && t.frame_count > 1 => t,
-                _ => continue,
-            };
-
-            // Encode the full sprite sheet as PNG
-            let sprite_sheet_png = match texture.to_png() {
-                Ok(png) => png,
-                Err(_) => continue,
+            let Some(texture) = resource_pack.get_texture(texture_path) else {
+                continue;
             };
-
-            let anim = texture.animation.as_ref();
-            let frame_width = anim.and_then(|a| a.frame_width).unwrap_or(texture.width);
-            let frame_height = anim.and_then(|a| a.frame_height).unwrap_or(frame_width);
-            let frametime = anim.map(|a| a.frametime).unwrap_or(1);
-            let interpolate = anim.map(|a| a.interpolate).unwrap_or(false);
-            let frames = anim.and_then(|a| a.frames.as_ref()).map(|fs| {
-                fs.iter().map(|f| f.index).collect()
-            });
-
-            // Convert atlas region from UV (0-1) to pixel coordinates
-            let atlas_x = (region.u_min * atlas.width as f32).round() as u32;
-            let atlas_y = (region.v_min * atlas.height as f32).round() as u32;
-
-            result.push(AnimatedTextureExport {
-                sprite_sheet_png,
-                frame_count: texture.frame_count,
-                frametime,
-                interpolate,
-                frames,
-                frame_width,
-                frame_height,
-                atlas_x,
-                atlas_y,
-            });
+            result.extend(AnimatedTextureExport::from_texture(
+                texture_path,
+                texture,
+                region,
+                atlas,
+                interpolated_frame_ticks,
+            ));
         }
 
         result
@@ -647,8 +790,10 @@ impl<'s, S: BlockSource> Iterator for ChunkIter<'s, S> {
                 cutout: crate::mesh_output::MeshLayer::new(),
                 transparent: crate::mesh_output::MeshLayer::new(),
                 atlas: crate::atlas::TextureAtlas::empty(),
+                texture_array: None,
                 greedy_materials: Vec::new(),
                 animated_textures: Vec::new(),
+                diagnostics: MeshDiagnostics::default(),
                 bounds: chunk_bounds,
                 chunk_coord: Some((cx, cy, cz)),
                 lod_level: 0,
@@ -766,4 +911,35 @@ mod chunk_tests {
         // -1 div_euclid 4 = -1, 0 div_euclid 4 = 0
         assert_eq!(coords, vec![(-1, 0, 0), (0, 0, 0)]);
     }
+
+    #[test]
+    fn test_discover_textures_upgrades_old_blocks() {
+        let mut pack = crate::ResourcePack::new();
+        pack.add_blockstate(
+            "minecraft",
+            "short_grass",
+            serde_json::from_str(r#"{"variants": {"": {"model": "block/short_grass"}}}"#).unwrap(),
+        );
+        pack.add_model(
+            "minecraft",
+            "block/short_grass",
+            serde_json::from_str(
+                r#"{"elements": [{"from": [0,0,0], "to": [16,16,16],
+                    "faces": {"up": {"texture": "block/short_grass"}}}]}"#,
+            )
+            .unwrap(),
+        );
+        let source = TestBlockSource {
+            bounds: BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+            blocks: vec![(BlockPosition::new(0, 0, 0), InputBlock::new("minecraft:grass"))],
+        };
+
+        // Saved by 1.20.2, before `grass` became `short_grass`.
+        let config = MesherConfig {
+            data_version: Some(crate::resolver::upgrade::data_version::V1_20_3 - 1),
+            ..MesherConfig::default()
+        };
+        let textures = Mesher::with_config(pack, config).discover_textures(&source);
+        assert!(textures.contains("block/short_grass"));
+    }
 }
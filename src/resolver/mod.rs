@@ -6,9 +6,11 @@
 pub mod state_resolver;
 pub mod model_resolver;
 pub mod multipart;
+pub mod upgrade;
 
-pub use state_resolver::StateResolver;
+pub use state_resolver::{StateResolver, VariantMatch};
 pub use model_resolver::ModelResolver;
+pub use upgrade::upgrade_block;
 
 use crate::resource_pack::{BlockModel, BlockstateDefinition, ModelVariant, ResourcePack};
 use crate::types::InputBlock;
@@ -28,11 +30,20 @@ pub fn resolve_block(
     pack: &ResourcePack,
     block: &InputBlock,
 ) -> Result<Vec<ResolvedModel>> {
+    resolve_block_with_match(pack, block).map(|(models, _)| models)
+}
+
+/// Resolve a block to its model(s), also reporting how its properties were
+/// matched against the blockstate.
+pub fn resolve_block_with_match(
+    pack: &ResourcePack,
+    block: &InputBlock,
+) -> Result<(Vec<ResolvedModel>, VariantMatch)> {
     let state_resolver = StateResolver::new(pack);
     let model_resolver = ModelResolver::new(pack);
 
     // Get model variants from blockstate
-    let variants = state_resolver.resolve(block)?;
+    let (variants, variant_match) = state_resolver.resolve_with_match(block)?;
 
     // Resolve each variant's model inheritance
     let mut resolved = Vec::new();
@@ -48,5 +59,5 @@ pub fn resolve_block(
         });
     }
 
-    Ok(resolved)
+    Ok((resolved, variant_match))
 }
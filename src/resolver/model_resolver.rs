@@ -180,6 +180,7 @@ mod tests {
                 to: [16.0, 16.0, 16.0],
                 rotation: None,
                 shade: true,
+                light_emission: 0,
                 faces: Direction::ALL
                     .iter()
                     .map(|d| {
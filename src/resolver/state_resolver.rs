@@ -6,6 +6,21 @@ use crate::resource_pack::{
 };
 use crate::types::InputBlock;
 
+/// How a block's properties were matched against a blockstate definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantMatch {
+    /// The property string matched a variant key exactly (or multipart conditions applied).
+    Exact,
+    /// The blockstate has a single unconditional `""` variant.
+    Default,
+    /// No exact key; picked the most default-like variant consistent with the
+    /// properties that were given.
+    Partial,
+    /// Nothing was consistent with the given properties; picked the most
+    /// default-like variant overall.
+    Fallback,
+}
+
 /// Resolves block states to model variants.
 pub struct StateResolver<'a> {
     pack: &'a ResourcePack,
@@ -18,6 +33,21 @@ impl<'a> StateResolver<'a> {
 
     /// Resolve a block to its model variants.
     pub fn resolve(&self, block: &InputBlock) -> Result<Vec<ModelVariant>> {
+        self.resolve_with_match(block).map(|(variants, _)| variants)
+    }
+
+    /// Resolve a block to its model variants, also reporting how exactly the
+    /// block's properties matched (used for mesh diagnostics).
+    pub fn resolve_with_match(&self, block: &InputBlock) -> Result<(Vec<ModelVariant>, VariantMatch)> {
+        // With a block registry, fill in unspecified/invalid properties exactly
+        // as the game would before matching.
+        let completed = self
+            .pack
+            .block_registry
+            .as_ref()
+            .and_then(|registry| registry.complete_state(block));
+        let block = completed.as_ref().unwrap_or(block);
+
         // Get the blockstate definition
         let blockstate = self.pack.get_blockstate(&block.name).ok_or_else(|| {
             MesherError::BlockstateResolution(format!(
@@ -28,10 +58,15 @@ impl<'a> StateResolver<'a> {
 
         match blockstate {
             BlockstateDefinition::Variants(variants) => {
+                if completed.is_some() {
+                    if let Some(variant_list) = find_variant_for_full_state(variants, block) {
+                        return Ok((vec![variant_list[0].clone()], VariantMatch::Exact));
+                    }
+                }
                 self.resolve_variants(variants, block)
             }
             BlockstateDefinition::Multipart(cases) => {
-                self.resolve_multipart(cases, block)
+                self.resolve_multipart(cases, block).map(|v| (v, VariantMatch::Exact))
             }
         }
     }
@@ -41,18 +76,18 @@ impl<'a> StateResolver<'a> {
         &self,
         variants: &std::collections::HashMap<String, Vec<ModelVariant>>,
         block: &InputBlock,
-    ) -> Result<Vec<ModelVariant>> {
+    ) -> Result<(Vec<ModelVariant>, VariantMatch)> {
         // Build the property string to look up
         let prop_string = build_property_string(&block.properties);
 
         // Try exact match first
         if let Some(variant_list) = variants.get(&prop_string) {
-            return Ok(vec![variant_list[0].clone()]);
+            return Ok((vec![variant_list[0].clone()], VariantMatch::Exact));
         }
 
         // Try empty string (default variant)
         if let Some(variant_list) = variants.get("") {
-            return Ok(vec![variant_list[0].clone()]);
+            return Ok((vec![variant_list[0].clone()], VariantMatch::Default));
         }
 
         // Find all variants that match the user's specified properties
@@ -69,12 +104,12 @@ impl<'a> StateResolver<'a> {
                 .into_iter()
                 .max_by_key(|(key, _)| self.calculate_default_score_for_unspecified(key, &block.properties))
                 .unwrap();
-            return Ok(vec![best.1[0].clone()]);
+            return Ok((vec![best.1[0].clone()], VariantMatch::Partial));
         }
 
         // Last resort: find the most "default-like" variant overall
         if let Some((_, variant_list)) = self.find_default_variant(variants) {
-            return Ok(vec![variant_list[0].clone()]);
+            return Ok((vec![variant_list[0].clone()], VariantMatch::Fallback));
         }
 
         Err(MesherError::BlockstateResolution(format!(
@@ -279,6 +314,31 @@ impl<'a> StateResolver<'a> {
     }
 }
 
+/// Find the variant the game would pick for a fully specified state: the key
+/// whose every `prop=value` pair holds. Keys may name a subset of the state's
+/// properties (e.g. hopper variants ignore `enabled`). If several keys match,
+/// the most specific wins, ties broken by key order for determinism.
+fn find_variant_for_full_state<'v>(
+    variants: &'v std::collections::HashMap<String, Vec<ModelVariant>>,
+    block: &InputBlock,
+) -> Option<&'v Vec<ModelVariant>> {
+    variants
+        .iter()
+        .filter(|(key, _)| {
+            key.split(',')
+                .filter(|pair| !pair.is_empty())
+                .all(|pair| match pair.split_once('=') {
+                    Some((prop, value)) => block.properties.get(prop).is_some_and(|v| v == value),
+                    None => false,
+                })
+        })
+        .max_by(|(a, _), (b, _)| {
+            let specificity = |k: &str| if k.is_empty() { 0 } else { k.split(',').count() };
+            specificity(a).cmp(&specificity(b)).then_with(|| b.cmp(a))
+        })
+        .map(|(_, variant_list)| variant_list)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -337,6 +397,43 @@ mod tests {
         assert_eq!(variants[0].y, 90);
     }
 
+    #[test]
+    fn test_registry_defaults_override_heuristics() {
+        let mut pack = ResourcePack::new();
+        let hopper_json = r#"{
+            "variants": {
+                "facing=down": { "model": "block/hopper" },
+                "facing=east": { "model": "block/hopper_side", "y": 270 },
+                "facing=north": { "model": "block/hopper_side", "y": 180 },
+                "facing=south": { "model": "block/hopper_side" },
+                "facing=west": { "model": "block/hopper_side", "y": 90 }
+            }
+        }"#;
+        let hopper_def: BlockstateDefinition = serde_json::from_str(hopper_json).unwrap();
+        pack.add_blockstate("minecraft", "hopper", hopper_def);
+
+        // Without a registry the heuristics prefer facing=north.
+        let block = InputBlock::new("minecraft:hopper");
+        let (variants, matched) = StateResolver::new(&pack).resolve_with_match(&block).unwrap();
+        assert_eq!(variants[0].model, "block/hopper_side");
+        assert_eq!(matched, VariantMatch::Partial);
+
+        // The game's default is facing=down; `enabled` isn't part of any key.
+        let registry = crate::resource_pack::BlockRegistry::from_json(r#"{
+            "minecraft:hopper": {
+                "properties": {
+                    "enabled": ["true", "false"],
+                    "facing": ["down", "north", "south", "west", "east"]
+                },
+                "states": [{"id": 1, "default": true, "properties": {"enabled": "true", "facing": "down"}}]
+            }
+        }"#).unwrap();
+        pack.set_block_registry(registry);
+        let (variants, matched) = StateResolver::new(&pack).resolve_with_match(&block).unwrap();
+        assert_eq!(variants[0].model, "block/hopper");
+        assert_eq!(matched, VariantMatch::Exact);
+    }
+
     #[test]
     fn test_missing_blockstate() {
         let pack = create_test_pack();
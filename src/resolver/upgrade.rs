@@ -0,0 +1,241 @@
+//! DataVersion-aware block state upgrading.
+//!
+//! Schematics record the `DataVersion` of the game that saved them. Blocks from
+//! older versions can use names and properties that a current resource pack no
+//! longer has (`grass` became `short_grass`, wall sides went from booleans to
+//! `none`/`low`/`tall`, ...). [`upgrade_block`] replays the vanilla renames
+//! between the schematic's version and the current one so the result resolves
+//! against a modern pack.
+//!
+//! Steps are keyed on the DataVersion of the first *release* containing the new
+//! form; a schematic saved by a snapshot between two releases is treated as the
+//! older release. Only post-flattening (1.13+) string states are handled —
+//! numeric pre-1.13 IDs must be converted by the schematic reader.
+//!
+//! The table is limited to the fixes in `UPGRADE_STEPS`. The only
+//! orientation change among them is jigsaw `facing` → `orientation`; the
+//! pre-1.13 facing conventions (data-value bits for stairs, doors, pistons, …)
+//! belong to the flattening and are not rewritten here.
+
+use crate::types::InputBlock;
+
+/// DataVersions of the releases that introduced upgrade steps.
+pub mod data_version {
+    /// 1.13 — the flattening; the oldest version this module understands.
+    pub const V1_13: u32 = 1519;
+    /// 1.14 — wood sign variants, `stone_slab` split.
+    pub const V1_14: u32 = 1952;
+    /// 1.16 — wall side heights, jigsaw orientation, redstone dot.
+    pub const V1_16: u32 = 2566;
+    /// 1.17 — dirt path, filled cauldrons.
+    pub const V1_17: u32 = 2724;
+    /// 1.20.3 — `grass` renamed to `short_grass`.
+    pub const V1_20_3: u32 = 3698;
+    /// The newest DataVersion with an upgrade step. Blocks at or above this
+    /// version are left untouched.
+    pub const LATEST: u32 = V1_20_3;
+}
+
+/// One vanilla data fix: blocks saved before `data_version` get `apply`ed.
+struct UpgradeStep {
+    data_version: u32,
+    /// Rewrites the block in place, given its id without namespace. Returns
+    /// `true` if anything changed.
+    apply: fn(&str, &mut InputBlock) -> bool,
+}
+
+/// Upgrade steps in ascending DataVersion order; applied in sequence.
+const UPGRADE_STEPS: &[UpgradeStep] = &[
+    UpgradeStep { data_version: data_version::V1_14, apply: rename_signs },
+    UpgradeStep { data_version: data_version::V1_14, apply: rename_stone_slab },
+    UpgradeStep { data_version: data_version::V1_16, apply: wall_side_heights },
+    UpgradeStep { data_version: data_version::V1_16, apply: jigsaw_orientation },
+    UpgradeStep { data_version: data_version::V1_16, apply: redstone_dot },
+    UpgradeStep { data_version: data_version::V1_17, apply: rename_grass_path },
+    UpgradeStep { data_version: data_version::V1_17, apply: split_cauldron },
+    UpgradeStep { data_version: data_version::V1_20_3, apply: rename_grass },
+];
+
+/// Upgrade a block saved at `data_version` to current names and properties.
+///
+/// Returns `None` when no step applies, so callers can keep borrowing the
+/// original. Non-vanilla namespaces are never touched.
+pub fn upgrade_block(block: &InputBlock, data_version: u32) -> Option<InputBlock> {
+    if data_version >= data_version::LATEST || vanilla_id(block).is_none() {
+        return None;
+    }
+    let mut upgraded = block.clone();
+    upgrade_block_in_place(&mut upgraded, data_version).then_some(upgraded)
+}
+
+/// In-place variant of [`upgrade_block`]. Returns `true` if the block changed.
+pub fn upgrade_block_in_place(block: &mut InputBlock, data_version: u32) -> bool {
+    let mut changed = false;
+    for step in UPGRADE_STEPS.iter().filter(|s| data_version < s.data_version) {
+        let Some(id) = vanilla_id(block).map(str::to_string) else {
+            break;
+        };
+        changed |= (step.apply)(&id, block);
+    }
+    changed
+}
+
+/// The block id without namespace, or `None` for non-vanilla blocks.
+/// Unprefixed names are treated as vanilla.
+fn vanilla_id(block: &InputBlock) -> Option<&str> {
+    match block.name.split_once(':') {
+        Some(("minecraft", id)) => Some(id),
+        Some(_) => None,
+        None => Some(&block.name),
+    }
+}
+
+/// Replace the block id, keeping whatever namespace form the input used.
+fn rename(block: &mut InputBlock, new_id: &str) {
+    block.name = if block.name.contains(':') {
+        format!("minecraft:{}", new_id)
+    } else {
+        new_id.to_string()
+    };
+}
+
+fn rename_signs(id: &str, block: &mut InputBlock) -> bool {
+    match id {
+        "sign" => rename(block, "oak_sign"),
+        "wall_sign" => rename(block, "oak_wall_sign"),
+        _ => return false,
+    }
+    true
+}
+
+/// Pre-1.14 `stone_slab` is what is now `smooth_stone_slab`.
+fn rename_stone_slab(id: &str, block: &mut InputBlock) -> bool {
+    if id != "stone_slab" {
+        return false;
+    }
+    rename(block, "smooth_stone_slab");
+    true
+}
+
+/// Wall sides became `none`/`low`/`tall`; old boolean sides map to `none`/`low`.
+fn wall_side_heights(id: &str, block: &mut InputBlock) -> bool {
+    if !id.ends_with("_wall") {
+        return false;
+    }
+    let mut changed = false;
+    for side in ["north", "east", "south", "west"] {
+        if let Some(value) = block.properties.get_mut(side) {
+            let new = match value.as_str() {
+                "true" => "low",
+                "false" => "none",
+                _ => continue,
+            };
+            *value = new.to_string();
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Jigsaw `facing` became `orientation` (front direction + top direction).
+fn jigsaw_orientation(id: &str, block: &mut InputBlock) -> bool {
+    if id != "jigsaw" {
+        return false;
+    }
+    let Some(facing) = block.properties.remove("facing") else {
+        return false;
+    };
+    let orientation = match facing.as_str() {
+        "down" => "down_south".to_string(),
+        "up" => "up_north".to_string(),
+        other => format!("{}_up", other),
+    };
+    block.properties.insert("orientation".to_string(), orientation);
+    true
+}
+
+/// Before 1.16 unconnected redstone rendered as a cross; it is now a dot
+/// unless every side is `side`.
+fn redstone_dot(id: &str, block: &mut InputBlock) -> bool {
+    if id != "redstone_wire" {
+        return false;
+    }
+    let sides = ["north", "east", "south", "west"];
+    let unconnected = sides
+        .iter()
+        .all(|s| block.properties.get(*s).is_none_or(|v| v == "none"));
+    if !unconnected {
+        return false;
+    }
+    for side in sides {
+        block.properties.insert(side.to_string(), "side".to_string());
+    }
+    true
+}
+
+fn rename_grass_path(id: &str, block: &mut InputBlock) -> bool {
+    if id != "grass_path" {
+        return false;
+    }
+    rename(block, "dirt_path");
+    true
+}
+
+/// Filled cauldrons became `water_cauldron`; `level=0` stays a plain cauldron.
+fn split_cauldron(id: &str, block: &mut InputBlock) -> bool {
+    if id != "cauldron" {
+        return false;
+    }
+    let Some(level) = block.properties.remove("level") else {
+        return false;
+    };
+    if level != "0" {
+        rename(block, "water_cauldron");
+        block.properties.insert("level".to_string(), level);
+    }
+    true
+}
+
+fn rename_grass(id: &str, block: &mut InputBlock) -> bool {
+    if id != "grass" {
+        return false;
+    }
+    rename(block, "short_grass");
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renames_grass_only_before_1_20_3() {
+        let grass = InputBlock::new("minecraft:grass");
+        let upgraded = upgrade_block(&grass, data_version::V1_20_3 - 1).unwrap();
+        assert_eq!(upgraded.name, "minecraft:short_grass");
+        assert!(upgrade_block(&grass, data_version::V1_20_3).is_none());
+        assert!(upgrade_block(&InputBlock::new("mymod:grass"), data_version::V1_13).is_none());
+    }
+
+    #[test]
+    fn chains_steps_from_old_versions() {
+        let wall = InputBlock::new("minecraft:cobblestone_wall")
+            .with_property("north", "true")
+            .with_property("south", "false")
+            .with_property("up", "true");
+        let upgraded = upgrade_block(&wall, data_version::V1_13).unwrap();
+        assert_eq!(upgraded.properties["north"], "low");
+        assert_eq!(upgraded.properties["south"], "none");
+        assert_eq!(upgraded.properties["up"], "true");
+
+        let cauldron = InputBlock::new("cauldron").with_property("level", "2");
+        let upgraded = upgrade_block(&cauldron, data_version::V1_14).unwrap();
+        assert_eq!(upgraded.name, "water_cauldron");
+        assert_eq!(upgraded.properties["level"], "2");
+
+        let jigsaw = InputBlock::new("minecraft:jigsaw").with_property("facing", "up");
+        let upgraded = upgrade_block(&jigsaw, data_version::V1_14).unwrap();
+        assert_eq!(upgraded.properties["orientation"], "up_north");
+        assert!(!upgraded.properties.contains_key("facing"));
+    }
+}
@@ -0,0 +1,386 @@
+//! OptiFine/Continuity connected-texture (CTM) rules.
+//!
+//! Parses `assets/<namespace>/optifine/ctm/**/*.properties`. Each file is one
+//! [`CtmRule`] saying which blocks or textures it applies to, which faces, how
+//! to pick a tile (`method`) and the tile textures. Tile PNGs next to the
+//! properties file are loaded into [`ResourcePack::textures`](super::ResourcePack)
+//! under their pack path (e.g. `optifine/ctm/glass/0`), so they resolve like
+//! any other texture. Tile selection happens at meshing time in
+//! [`crate::mesher::ctm`].
+
+use crate::types::{Direction, InputBlock};
+use std::collections::HashMap;
+
+/// How a rule picks a tile for a face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CtmMethod {
+    /// Full 47-tile connected texture (edges and inner corners).
+    Ctm,
+    /// 4 tiles, connecting left/right.
+    Horizontal,
+    /// 4 tiles, connecting up/down.
+    Vertical,
+    /// A (weighted) random tile per block face.
+    Random,
+    /// A `width`×`height` pattern repeated across the world grid.
+    Repeat { width: u32, height: u32 },
+    /// 17 overlay tiles drawn over the face where it borders `connect_blocks`
+    /// / `connect_tiles` neighbours.
+    Overlay,
+}
+
+/// What counts as a connecting neighbour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CtmConnect {
+    /// Same block id.
+    Block,
+    /// A block this rule also applies to (approximates "same texture").
+    Tile,
+}
+
+/// One tile slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CtmTile {
+    /// Texture path, e.g. `optifine/ctm/glass/0` or `block/glass`.
+    Texture(String),
+    /// `<default>`: keep the face's own texture.
+    Default,
+    /// `<skip>`: draw nothing extra (overlay) / keep the texture.
+    Skip,
+}
+
+/// A block matcher from `matchBlocks`/`connectBlocks`: id plus optional
+/// property constraints (`oak_log:axis=x,z`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CtmBlockMatch {
+    /// Full block id (`minecraft:oak_log`).
+    pub name: String,
+    /// Property → allowed values. Empty = any state.
+    pub properties: Vec<(String, Vec<String>)>,
+}
+
+impl CtmBlockMatch {
+    pub fn matches(&self, block: &InputBlock) -> bool {
+        self.matches_name(&block.name)
+            && self.properties.iter().all(|(prop, values)| {
+                block.properties.get(prop).is_some_and(|v| values.contains(v))
+            })
+    }
+
+    /// Match on id only (neighbour lookups don't carry properties).
+    pub fn matches_name(&self, name: &str) -> bool {
+        if name.contains(':') {
+            self.name == name
+        } else {
+            self.name.strip_prefix("minecraft:") == Some(name)
+        }
+    }
+}
+
+/// A parsed CTM properties file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CtmRule {
+    /// Pack path of the properties file, used for ordering and diagnostics.
+    pub source: String,
+    pub method: CtmMethod,
+    pub tiles: Vec<CtmTile>,
+    pub match_blocks: Vec<CtmBlockMatch>,
+    /// Texture paths (`block/glass`) the rule replaces.
+    pub match_tiles: Vec<String>,
+    /// Faces the rule applies to, indexed by [`Direction`] order.
+    pub faces: [bool; 6],
+    pub connect: CtmConnect,
+    /// Overlay only: neighbours that cause an overlay.
+    pub connect_blocks: Vec<CtmBlockMatch>,
+    /// Overlay only: neighbour textures that cause an overlay (approximated by
+    /// the rules that match them).
+    pub connect_tiles: Vec<String>,
+    /// Random only: per-tile weights (empty = uniform).
+    pub weights: Vec<u32>,
+    /// Rule priority; higher wins when several rules match a face.
+    pub weight: i32,
+}
+
+impl CtmRule {
+    /// Whether this rule applies to `texture` on `face` of `block`.
+    pub fn applies_to(&self, block: &InputBlock, texture: &str, face: Direction) -> bool {
+        if !self.faces[face_index(face)] {
+            return false;
+        }
+        let texture = texture.strip_prefix("minecraft:").unwrap_or(texture);
+        (!self.match_blocks.is_empty() || !self.match_tiles.is_empty())
+            && (self.match_blocks.is_empty() || self.match_blocks.iter().any(|m| m.matches(block)))
+            && (self.match_tiles.is_empty() || self.match_tiles.iter().any(|t| t == texture))
+    }
+}
+
+pub(crate) fn face_index(face: Direction) -> usize {
+    Direction::ALL.iter().position(|d| *d == face).unwrap_or(0)
+}
+
+/// Parse a CTM properties file. `namespace` and `path` locate it in the pack
+/// (`path` relative to the namespace root, e.g. `optifine/ctm/glass/glass.properties`).
+///
+/// Returns `Ok(None)` for valid files using a method this crate doesn't
+/// implement, and `Err` for files that can't form a rule.
+pub fn parse_ctm_properties(
+    namespace: &str,
+    path: &str,
+    contents: &str,
+) -> Result<Option<CtmRule>, String> {
+    let props = parse_properties(contents);
+    let dir = path.rsplit_once('/').map(|(d, _)| d).unwrap_or("");
+    let file_stem = path
+        .rsplit('/')
+        .next()
+        .unwrap_or(path)
+        .trim_end_matches(".properties");
+
+    let method = match props.get("method").map(String::as_str).unwrap_or("ctm") {
+        "ctm" | "glass" => CtmMethod::Ctm,
+        "horizontal" | "bookshelf" => CtmMethod::Horizontal,
+        "vertical" => CtmMethod::Vertical,
+        "random" => CtmMethod::Random,
+        "repeat" => {
+            let dim = |key: &str| props.get(key).and_then(|v| v.trim().parse::<u32>().ok());
+            match (dim("width"), dim("height")) {
+                (Some(width), Some(height)) if width > 0 && height > 0 => {
+                    CtmMethod::Repeat { width, height }
+                }
+                _ => return Err("method=repeat needs positive width and height".to_string()),
+            }
+        }
+        "overlay" => CtmMethod::Overlay,
+        _ => return Ok(None),
+    };
+
+    let tiles: Vec<CtmTile> = props
+        .get("tiles")
+        .map(|t| parse_tiles(namespace, dir, t))
+        .unwrap_or_default();
+    let required = match method {
+        CtmMethod::Ctm => 47,
+        CtmMethod::Horizontal | CtmMethod::Vertical => 4,
+        CtmMethod::Overlay => 17,
+        CtmMethod::Repeat { width, height } => (width * height) as usize,
+        CtmMethod::Random => 1,
+    };
+    if tiles.len() < required {
+        return Err(format!(
+            "method needs {} tiles, found {}",
+            required,
+            tiles.len()
+        ));
+    }
+
+    let mut match_blocks = props
+        .get("matchBlocks")
+        .map(|v| parse_block_matches(v))
+        .unwrap_or_default();
+    let mut match_tiles = props
+        .get("matchTiles")
+        .map(|v| v.split_whitespace().map(normalize_tile_name).collect::<Vec<_>>())
+        .unwrap_or_default();
+    // Without explicit matches, the file name decides: `block_<id>.properties`
+    // matches that block, anything else is a texture name.
+    if match_blocks.is_empty() && match_tiles.is_empty() {
+        match file_stem.strip_prefix("block_") {
+            Some(id) => match_blocks = parse_block_matches(id),
+            None => match_tiles.push(normalize_tile_name(file_stem)),
+        }
+    }
+
+    let faces = match props.get("faces") {
+        Some(list) => parse_faces(list),
+        None => [true; 6],
+    };
+    let connect = match props.get("connect").map(String::as_str) {
+        Some("block") => CtmConnect::Block,
+        Some("tile") | Some("material") => CtmConnect::Tile,
+        _ if !match_blocks.is_empty() => CtmConnect::Block,
+        _ => CtmConnect::Tile,
+    };
+
+    Ok(Some(CtmRule {
+        source: format!("{}:{}", namespace, path),
+        method,
+        tiles,
+        match_blocks,
+        match_tiles,
+        faces,
+        connect,
+        connect_blocks: props
+            .get("connectBlocks")
+            .map(|v| parse_block_matches(v))
+            .unwrap_or_default(),
+        connect_tiles: props
+            .get("connectTiles")
+            .map(|v| v.split_whitespace().map(normalize_tile_name).collect())
+            .unwrap_or_default(),
+        weights: props
+            .get("weights")
+            .map(|v| v.split_whitespace().filter_map(|w| w.parse().ok()).collect())
+            .unwrap_or_default(),
+        weight: props
+            .get("weight")
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(0),
+    }))
+}
+
+/// Java-properties-style `key=value` lines (`#`/`!` comments, `:` separator).
+fn parse_properties(contents: &str) -> HashMap<String, String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#') && !l.starts_with('!'))
+        .filter_map(|l| {
+            let split = l.find(['=', ':'])?;
+            Some((l[..split].trim().to_string(), l[split + 1..].trim().to_string()))
+        })
+        .collect()
+}
+
+/// Tile list: numbers and `a-b` ranges name PNGs next to the properties file;
+/// names containing `/` are relative to the namespace root.
+fn parse_tiles(namespace: &str, dir: &str, list: &str) -> Vec<CtmTile> {
+    let prefix = if namespace == "minecraft" {
+        String::new()
+    } else {
+        format!("{}:", namespace)
+    };
+    let local = |name: &str| {
+        let name = name.trim_end_matches(".png");
+        if name.contains('/') {
+            let name = name.strip_prefix("textures/").unwrap_or(name);
+            CtmTile::Texture(format!("{}{}", prefix, name))
+        } else {
+            CtmTile::Texture(format!("{}{}/{}", prefix, dir, name))
+        }
+    };
+
+    let mut tiles = Vec::new();
+    for token in list.split_whitespace() {
+        match token {
+            "<skip>" => tiles.push(CtmTile::Skip),
+            "<default>" => tiles.push(CtmTile::Default),
+            _ => match token.split_once('-').map(|(a, b)| (a.parse::<u32>(), b.parse::<u32>())) {
+                Some((Ok(start), Ok(end))) if start <= end => {
+                    tiles.extend((start..=end).map(|i| local(&i.to_string())));
+                }
+                _ => tiles.push(local(token)),
+            },
+        }
+    }
+    tiles
+}
+
+/// `[namespace:]id[:prop=v1,v2...]` entries, whitespace separated.
+fn parse_block_matches(list: &str) -> Vec<CtmBlockMatch> {
+    list.split_whitespace()
+        .filter_map(|entry| {
+            let mut parts = entry.split(':').peekable();
+            let first = parts.next()?;
+            let name = match parts.peek() {
+                Some(second) if !second.contains('=') => {
+                    let id = parts.next()?;
+                    format!("{}:{}", first, id)
+                }
+                _ => format!("minecraft:{}", first),
+            };
+            if name.split(':').nth(1)?.parse::<u32>().is_ok() {
+                return None; // legacy numeric id
+            }
+            let properties = parts
+                .filter_map(|p| {
+                    let (key, values) = p.split_once('=')?;
+                    Some((key.to_string(), values.split(',').map(str::to_string).collect()))
+                })
+                .collect();
+            Some(CtmBlockMatch { name, properties })
+        })
+        .collect()
+}
+
+/// `glass` → `block/glass`; `minecraft:block/glass` → `block/glass`.
+fn normalize_tile_name(name: &str) -> String {
+    let name = name.strip_prefix("minecraft:").unwrap_or(name);
+    let name = name.strip_prefix("textures/").unwrap_or(name);
+    let name = name.trim_end_matches(".png");
+    if name.contains('/') {
+        name.to_string()
+    } else {
+        format!("block/{}", name)
+    }
+}
+
+fn parse_faces(list: &str) -> [bool; 6] {
+    let mut faces = [false; 6];
+    let mut set = |dirs: &[Direction]| {
+        for d in dirs {
+            faces[face_index(*d)] = true;
+        }
+    };
+    for token in list.split_whitespace() {
+        match token {
+            "bottom" | "down" => set(&[Direction::Down]),
+            "top" | "up" => set(&[Direction::Up]),
+            "north" => set(&[Direction::North]),
+            "south" => set(&[Direction::South]),
+            "east" => set(&[Direction::East]),
+            "west" => set(&[Direction::West]),
+            "sides" => set(&[Direction::North, Direction::South, Direction::East, Direction::West]),
+            "all" => set(&Direction::ALL),
+            _ => {}
+        }
+    }
+    faces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_glass_rule() {
+        let rule = parse_ctm_properties(
+            "minecraft",
+            "optifine/ctm/glass/glass.properties",
+            "# connected glass\nmatchBlocks=glass minecraft:white_stained_glass\nmethod=ctm\ntiles=0-46\n",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(rule.method, CtmMethod::Ctm);
+        assert_eq!(rule.tiles.len(), 47);
+        assert_eq!(rule.tiles[5], CtmTile::Texture("optifine/ctm/glass/5".into()));
+        assert_eq!(rule.connect, CtmConnect::Block);
+        assert!(rule.applies_to(&InputBlock::new("minecraft:glass"), "block/glass", Direction::Up));
+        assert!(!rule.applies_to(&InputBlock::new("minecraft:stone"), "block/stone", Direction::Up));
+    }
+
+    #[test]
+    fn file_name_and_face_defaults() {
+        let rule = parse_ctm_properties(
+            "minecraft",
+            "optifine/ctm/bookshelf/bookshelf.properties",
+            "method=horizontal\ntiles=<default> 1 2 3\nfaces=sides\n",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(rule.match_tiles, vec!["block/bookshelf".to_string()]);
+        assert_eq!(rule.tiles[0], CtmTile::Default);
+        let shelf = InputBlock::new("minecraft:bookshelf");
+        assert!(rule.applies_to(&shelf, "minecraft:block/bookshelf", Direction::North));
+        assert!(!rule.applies_to(&shelf, "block/bookshelf", Direction::Up));
+
+        let logs = parse_block_matches("oak_log:axis=x,z mymod:pillar");
+        assert_eq!(logs[0].properties, vec![("axis".to_string(), vec!["x".to_string(), "z".to_string()])]);
+        assert_eq!(logs[1].name, "mymod:pillar");
+
+        assert!(parse_ctm_properties("minecraft", "optifine/ctm/x.properties", "method=ctm\ntiles=0-3").is_err());
+        assert_eq!(
+            parse_ctm_properties("minecraft", "optifine/ctm/x.properties", "method=fixed\ntiles=0"),
+            Ok(None)
+        );
+    }
+}
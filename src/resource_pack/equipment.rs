@@ -0,0 +1,135 @@
+//! Equipment assets (`equipment/*.json`, 1.21.4+).
+//!
+//! An equipment asset lists, per layer type (`humanoid`,
+//! `humanoid_leggings`, `horse_body`, ...), the textures drawn over the
+//! wearer's model, bottom to top. A layer's texture lives at
+//! `textures/entity/equipment/<layer type>/<texture>.png`. Dyeable layers
+//! are tinted by the item's `dyed_color`, falling back to
+//! `color_when_undyed`; without either they are not drawn. Layers with
+//! `use_player_texture` (elytra wings) draw the wearer's cape instead when
+//! it has one.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// A parsed equipment asset.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EquipmentAsset {
+    /// Layers by layer type.
+    #[serde(default)]
+    pub layers: HashMap<String, Vec<EquipmentLayer>>,
+}
+
+/// One texture layer of an equipment asset.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EquipmentLayer {
+    /// Texture id, relative to `entity/equipment/<layer type>/`.
+    pub texture: String,
+    #[serde(default)]
+    pub dyeable: Option<Dyeable>,
+    /// Draw the wearing player's cape texture instead, if it has one.
+    #[serde(default)]
+    pub use_player_texture: bool,
+}
+
+/// Dye settings of a layer.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Dyeable {
+    /// Packed RGB used when the item has no `dyed_color` (ARGB ints are
+    /// accepted; the alpha byte is ignored).
+    #[serde(default)]
+    pub color_when_undyed: Option<i64>,
+}
+
+impl EquipmentAsset {
+    /// The layers of one layer type, empty if the asset has none.
+    pub fn layers(&self, layer_type: &str) -> &[EquipmentLayer] {
+        self.layers.get(layer_type).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Built-in asset for the vanilla armor materials (`leather`,
+    /// `chainmail`, `iron`, `gold`, `diamond`, `netherite`,
+    /// `turtle_scute`) and `elytra`, used when the pack doesn't ship
+    /// `equipment/*.json`.
+    pub fn vanilla(asset_id: &str) -> Option<Self> {
+        let id = asset_id.strip_prefix("minecraft:").unwrap_or(asset_id);
+        let layer = |texture: &str, dyeable: Option<Dyeable>| EquipmentLayer {
+            texture: texture.to_string(),
+            dyeable,
+            use_player_texture: false,
+        };
+        let humanoid_layers = match id {
+            "leather" => vec![
+                layer("leather", Some(Dyeable { color_when_undyed: Some(0xA06540) })),
+                layer("leather_overlay", None),
+            ],
+            "chainmail" | "iron" | "gold" | "diamond" | "netherite" => vec![layer(id, None)],
+            "turtle_scute" => {
+                let mut layers = HashMap::new();
+                layers.insert("humanoid".to_string(), vec![layer(id, None)]);
+                return Some(Self { layers });
+            }
+            "elytra" => {
+                let mut layers = HashMap::new();
+                let wings = EquipmentLayer { use_player_texture: true, ..layer(id, None) };
+                layers.insert("wings".to_string(), vec![wings]);
+                return Some(Self { layers });
+            }
+            _ => return None,
+        };
+        let mut layers = HashMap::new();
+        layers.insert("humanoid".to_string(), humanoid_layers.clone());
+        layers.insert("humanoid_leggings".to_string(), humanoid_layers);
+        Some(Self { layers })
+    }
+}
+
+impl EquipmentLayer {
+    /// Texture path of this layer for a layer type:
+    /// `entity/equipment/<layer type>/<texture>`, namespaced unless vanilla.
+    pub fn texture_path(&self, layer_type: &str) -> String {
+        match self.texture.split_once(':') {
+            None | Some(("minecraft", _)) => {
+                let path = self.texture.strip_prefix("minecraft:").unwrap_or(&self.texture);
+                format!("entity/equipment/{}/{}", layer_type, path)
+            }
+            Some((namespace, path)) => format!("{}:entity/equipment/{}/{}", namespace, layer_type, path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_equipment_asset() {
+        let asset: EquipmentAsset = serde_json::from_str(r#"{
+            "layers": {
+                "humanoid": [
+                    {"texture": "minecraft:leather", "dyeable": {"color_when_undyed": -6265536}},
+                    {"texture": "minecraft:leather_overlay"}
+                ],
+                "humanoid_leggings": [{"texture": "mypack:cloth"}],
+                "wings": [{"texture": "mypack:wings", "use_player_texture": true}]
+            }
+        }"#).unwrap();
+
+        let humanoid = asset.layers("humanoid");
+        assert_eq!(humanoid.len(), 2);
+        assert_eq!(humanoid[0].dyeable.as_ref().unwrap().color_when_undyed, Some(-6265536));
+        assert_eq!(humanoid[1].texture_path("humanoid"), "entity/equipment/humanoid/leather_overlay");
+        assert_eq!(
+            asset.layers("humanoid_leggings")[0].texture_path("humanoid_leggings"),
+            "mypack:entity/equipment/humanoid_leggings/cloth"
+        );
+        assert!(asset.layers("wolf_body").is_empty());
+        assert!(asset.layers("wings")[0].use_player_texture && !humanoid[0].use_player_texture);
+
+        let turtle = EquipmentAsset::vanilla("minecraft:turtle_scute").unwrap();
+        assert_eq!(turtle.layers("humanoid").len(), 1);
+        assert!(turtle.layers("humanoid_leggings").is_empty());
+        assert_eq!(EquipmentAsset::vanilla("elytra").unwrap().layers("wings")[0].texture_path("wings"), "entity/equipment/wings/elytra");
+        assert!(EquipmentAsset::vanilla("copper").is_none());
+    }
+}
@@ -0,0 +1,664 @@
+//! Item model definition parsing (`items/*.json`).
+//!
+//! Since 1.21.4 an item's appearance is chosen by an item definition rather
+//! than by `models/item/<id>.json` directly. The definition is a tree of
+//! nodes: `model` leaves reference a model (with optional tint sources),
+//! `composite` stacks several models, and `condition`, `select` and
+//! `range_dispatch` branch on properties of the item stack's data components.
+
+use super::{ResourcePack, TextureData};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// A parsed item definition from items/*.json.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItemDefinition {
+    /// Root node of the model tree.
+    pub model: ItemModel,
+}
+
+/// A node of an item definition's model tree.
+#[derive(Debug, Clone)]
+pub enum ItemModel {
+    /// A plain model reference.
+    Model {
+        model: String,
+        tints: Vec<TintSource>,
+    },
+    /// Several models rendered on top of each other.
+    Composite(Vec<ItemModel>),
+    /// Boolean property branch.
+    Condition {
+        property: ItemProperty,
+        on_true: Box<ItemModel>,
+        on_false: Box<ItemModel>,
+    },
+    /// Switch on a property value; each case lists the values it matches.
+    Select {
+        property: ItemProperty,
+        cases: Vec<(Vec<Value>, ItemModel)>,
+        fallback: Option<Box<ItemModel>>,
+    },
+    /// Numeric property thresholds, sorted ascending.
+    RangeDispatch {
+        property: ItemProperty,
+        scale: f32,
+        entries: Vec<(f32, ItemModel)>,
+        fallback: Option<Box<ItemModel>>,
+    },
+    /// Hardcoded special renderer (chest, shield, head, ...). Only its `base`
+    /// model (particle texture and display transforms) is available here.
+    Special { base: String },
+    /// Renders nothing (`empty`, and node types this crate doesn't know).
+    Empty,
+}
+
+/// A property or tint source: its type (without the `minecraft:` prefix)
+/// plus the remaining fields of its JSON object as arguments.
+#[derive(Debug, Clone, Default)]
+pub struct ItemProperty {
+    pub kind: String,
+    pub args: Map<String, Value>,
+}
+
+/// A tint source of a `model` node (one per `tintindex`).
+pub type TintSource = ItemProperty;
+
+/// The context an item is displayed in; selects the model's `display`
+/// transform and feeds the `display_context` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemDisplayContext {
+    Fixed,
+    Ground,
+    Gui,
+    ThirdPersonRightHand,
+    ThirdPersonLeftHand,
+    FirstPersonRightHand,
+    FirstPersonLeftHand,
+    Head,
+}
+
+impl ItemDisplayContext {
+    /// Name used in model `display` blocks and `display_context` selects.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Fixed => "fixed",
+            Self::Ground => "ground",
+            Self::Gui => "gui",
+            Self::ThirdPersonRightHand => "thirdperson_righthand",
+            Self::ThirdPersonLeftHand => "thirdperson_lefthand",
+            Self::FirstPersonRightHand => "firstperson_righthand",
+            Self::FirstPersonLeftHand => "firstperson_lefthand",
+            Self::Head => "head",
+        }
+    }
+
+    /// Parse a context name as written by [`as_str`](Self::as_str).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "fixed" => Self::Fixed,
+            "ground" => Self::Ground,
+            "gui" => Self::Gui,
+            "thirdperson_righthand" => Self::ThirdPersonRightHand,
+            "thirdperson_lefthand" => Self::ThirdPersonLeftHand,
+            "firstperson_righthand" => Self::FirstPersonRightHand,
+            "firstperson_lefthand" => Self::FirstPersonLeftHand,
+            "head" => Self::Head,
+            _ => return None,
+        })
+    }
+
+    /// Whether items in this context are held in the left hand, which
+    /// mirrors the right-hand display transform.
+    pub fn is_left_hand(self) -> bool {
+        matches!(self, Self::ThirdPersonLeftHand | Self::FirstPersonLeftHand)
+    }
+
+    /// The right-hand counterpart of a left-hand context.
+    pub(crate) fn right_hand(self) -> Self {
+        match self {
+            Self::ThirdPersonLeftHand => Self::ThirdPersonRightHand,
+            Self::FirstPersonLeftHand => Self::FirstPersonRightHand,
+            other => other,
+        }
+    }
+}
+
+/// An item stack to evaluate definitions against.
+///
+/// Parsed from the command-style syntax `id[component=value,...]`, with an
+/// optional `:count` suffix (`minecraft:potion[potion_contents={"custom_color":255}]:3`).
+/// Component values are JSON; anything that doesn't parse as JSON is kept as
+/// a string. A bare number for `custom_model_data` is shorthand for
+/// `{"floats":[n]}`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ItemStack {
+    /// Item id, always namespaced (`minecraft:diamond_sword`).
+    pub id: String,
+    pub count: u32,
+    /// Data components, keyed without the `minecraft:` prefix.
+    pub components: HashMap<String, Value>,
+}
+
+impl ItemStack {
+    /// Parse an item stack string. Returns `None` for an empty string.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (head, components, tail) = match (s.find('['), s.rfind(']')) {
+            (Some(open), Some(close)) if close > open => {
+                (&s[..open], Some(&s[open + 1..close]), &s[close + 1..])
+            }
+            _ => (s, None, ""),
+        };
+
+        let (mut id, mut count) = (head, 1);
+        if let Some(n) = tail.strip_prefix(':').and_then(|n| n.trim().parse().ok()) {
+            count = n;
+        } else if let Some((rest, n)) = head.rsplit_once(':') {
+            if let Ok(n) = n.parse() {
+                id = rest;
+                count = n;
+            }
+        }
+        let id = id.trim();
+        if id.is_empty() {
+            return None;
+        }
+
+        let mut stack = Self {
+            id: if id.contains(':') { id.to_string() } else { format!("minecraft:{}", id) },
+            count,
+            components: HashMap::new(),
+        };
+        for entry in components.map(split_item_list).unwrap_or_default() {
+            if let Some((key, raw)) = entry.split_once('=') {
+                let raw = raw.trim();
+                let value = serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()));
+                stack.components.insert(bare(key.trim()).to_string(), value);
+            }
+        }
+        Some(stack)
+    }
+
+    /// Get a data component by name (with or without the `minecraft:` prefix).
+    pub fn component(&self, name: &str) -> Option<&Value> {
+        self.components.get(bare(name))
+    }
+
+    /// Entry `index` of one of the `custom_model_data` lists
+    /// (`floats`, `flags`, `strings` or `colors`).
+    fn custom_model_data(&self, list: &str, index: usize) -> Option<Value> {
+        match self.component("custom_model_data")? {
+            Value::Number(n) if list == "floats" && index == 0 => Some(Value::Number(n.clone())),
+            data => data.get(list)?.get(index).cloned(),
+        }
+    }
+
+    fn damage(&self) -> f32 {
+        self.component("damage").and_then(Value::as_f64).unwrap_or(0.0) as f32
+    }
+}
+
+/// Split a comma-separated list of item stacks (or stack components) at
+/// top-level commas, leaving commas inside brackets, braces and quotes alone.
+pub fn split_item_list(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut in_quotes, mut start) = (0i32, false, 0);
+    for (i, c) in s.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '[' | '{' if !in_quotes => depth += 1,
+            ']' | '}' if !in_quotes => depth -= 1,
+            ',' if !in_quotes && depth <= 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// A model picked by evaluating an item definition.
+#[derive(Debug, Clone)]
+pub struct ResolvedItemModel {
+    /// Model resource location (`minecraft:item/compass_16`).
+    pub model: String,
+    /// Tint sources, indexed by face `tintindex` (layer index for generated items).
+    pub tints: Vec<TintSource>,
+}
+
+impl ItemModel {
+    /// Evaluate the tree for a stack, returning the models to draw in order.
+    pub fn resolve(&self, stack: &ItemStack, context: ItemDisplayContext) -> Vec<ResolvedItemModel> {
+        let mut out = Vec::new();
+        self.resolve_into(stack, context, &mut out);
+        out
+    }
+
+    fn resolve_into(&self, stack: &ItemStack, context: ItemDisplayContext, out: &mut Vec<ResolvedItemModel>) {
+        match self {
+            ItemModel::Model { model, tints } => out.push(ResolvedItemModel {
+                model: namespaced(model),
+                tints: tints.clone(),
+            }),
+            ItemModel::Composite(models) => {
+                for model in models {
+                    model.resolve_into(stack, context, out);
+                }
+            }
+            ItemModel::Condition { property, on_true, on_false } => {
+                let branch = if property.condition(stack) { on_true } else { on_false };
+                branch.resolve_into(stack, context, out);
+            }
+            ItemModel::Select { property, cases, fallback } => {
+                let value = property.select_value(stack, context);
+                let case = value.as_ref().and_then(|value| {
+                    cases
+                        .iter()
+                        .find(|(when, _)| when.iter().any(|w| values_match(w, value)))
+                        .map(|(_, model)| model)
+                });
+                if let Some(model) = case.or(fallback.as_deref()) {
+                    model.resolve_into(stack, context, out);
+                }
+            }
+            ItemModel::RangeDispatch { property, scale, entries, fallback } => {
+                let value = property.range_value(stack) * scale;
+                let entry = entries
+                    .iter()
+                    .rev()
+                    .find(|(threshold, _)| *threshold <= value)
+                    .map(|(_, model)| model);
+                if let Some(model) = entry.or(fallback.as_deref()) {
+                    model.resolve_into(stack, context, out);
+                }
+            }
+            ItemModel::Special { base } => out.push(ResolvedItemModel {
+                model: namespaced(base),
+                tints: Vec::new(),
+            }),
+            ItemModel::Empty => {}
+        }
+    }
+
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let obj = value.as_object().ok_or("item model must be an object")?;
+        let kind = obj.get("type").and_then(Value::as_str).ok_or("item model has no type")?;
+        let child = |key: &str| -> Result<Box<ItemModel>, String> {
+            Ok(Box::new(Self::from_json(obj.get(key).ok_or(format!("missing `{}`", key))?)?))
+        };
+        let fallback = || obj.get("fallback").map(Self::from_json).transpose().map(|f| f.map(Box::new));
+
+        Ok(match bare(kind) {
+            "model" => ItemModel::Model {
+                model: obj.get("model").and_then(Value::as_str).ok_or("model node has no `model`")?.to_string(),
+                tints: obj
+                    .get("tints")
+                    .and_then(Value::as_array)
+                    .map(|tints| tints.iter().map(ItemProperty::from_json).collect())
+                    .unwrap_or_default(),
+            },
+            "composite" => ItemModel::Composite(
+                obj.get("models")
+                    .and_then(Value::as_array)
+                    .map(|models| models.iter().map(Self::from_json).collect::<Result<_, _>>())
+                    .transpose()?
+                    .unwrap_or_default(),
+            ),
+            "condition" => ItemModel::Condition {
+                property: ItemProperty::from_json(value),
+                on_true: child("on_true")?,
+                on_false: child("on_false")?,
+            },
+            "select" => ItemModel::Select {
+                property: ItemProperty::from_json(value),
+                cases: obj
+                    .get("cases")
+                    .and_then(Value::as_array)
+                    .map(|cases| {
+                        cases
+                            .iter()
+                            .map(|case| {
+                                let when = match case.get("when") {
+                                    Some(Value::Array(values)) => values.clone(),
+                                    Some(value) => vec![value.clone()],
+                                    None => Vec::new(),
+                                };
+                                Ok((when, Self::from_json(case.get("model").ok_or("select case has no `model`")?)?))
+                            })
+                            .collect::<Result<_, String>>()
+                    })
+                    .transpose()?
+                    .unwrap_or_default(),
+                fallback: fallback()?,
+            },
+            "range_dispatch" => {
+                let mut entries = obj
+                    .get("entries")
+                    .and_then(Value::as_array)
+                    .map(|entries| {
+                        entries
+                            .iter()
+                            .map(|entry| {
+                                let threshold = entry.get("threshold").and_then(Value::as_f64).unwrap_or(0.0) as f32;
+                                Ok((threshold, Self::from_json(entry.get("model").ok_or("range entry has no `model`")?)?))
+                            })
+                            .collect::<Result<Vec<_>, String>>()
+                    })
+                    .transpose()?
+                    .unwrap_or_default();
+                entries.sort_by(|a, b| a.0.total_cmp(&b.0));
+                ItemModel::RangeDispatch {
+                    property: ItemProperty::from_json(value),
+                    scale: obj.get("scale").and_then(Value::as_f64).unwrap_or(1.0) as f32,
+                    entries,
+                    fallback: fallback()?,
+                }
+            }
+            "special" => ItemModel::Special {
+                base: obj.get("base").and_then(Value::as_str).ok_or("special node has no `base`")?.to_string(),
+            },
+            _ => ItemModel::Empty,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemModel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        Self::from_json(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl ItemProperty {
+    /// Build from a node or tint object; the property type is `property` for
+    /// branch nodes and `type` for tint sources.
+    fn from_json(value: &Value) -> Self {
+        let mut args = value.as_object().cloned().unwrap_or_default();
+        let kind = match args.remove("property") {
+            Some(Value::String(kind)) => kind,
+            _ => args.remove("type").and_then(|t| t.as_str().map(str::to_string)).unwrap_or_default(),
+        };
+        Self { kind: bare(&kind).to_string(), args }
+    }
+
+    fn arg_str(&self, key: &str) -> Option<&str> {
+        self.args.get(key).and_then(Value::as_str)
+    }
+
+    fn arg_index(&self) -> usize {
+        self.args.get("index").and_then(Value::as_u64).unwrap_or(0) as usize
+    }
+
+    /// Value of a `condition` property. Properties that depend on the holder
+    /// (`using_item`, `selected`, ...) are false for a static item.
+    fn condition(&self, stack: &ItemStack) -> bool {
+        match self.kind.as_str() {
+            "has_component" => self.arg_str("component").is_some_and(|c| stack.component(c).is_some()),
+            "damaged" => stack.damage() > 0.0,
+            "broken" => stack
+                .component("max_damage")
+                .and_then(Value::as_f64)
+                .is_some_and(|max| stack.damage() as f64 >= max - 1.0),
+            "custom_model_data" => stack
+                .custom_model_data("flags", self.arg_index())
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            "component" => match (self.arg_str("predicate"), self.args.get("value")) {
+                (Some(predicate), Some(expected)) => {
+                    stack.component(predicate).is_some_and(|actual| values_match(expected, actual))
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Value of a `select` property, or `None` when it has none for this stack.
+    fn select_value(&self, stack: &ItemStack, context: ItemDisplayContext) -> Option<Value> {
+        match self.kind.as_str() {
+            "display_context" => Some(Value::String(context.as_str().to_string())),
+            "main_hand" => Some(Value::String("right".to_string())),
+            "charge_type" => Some(Value::String("none".to_string())),
+            "custom_model_data" => stack.custom_model_data("strings", self.arg_index()),
+            "component" => stack.component(self.arg_str("component")?).cloned(),
+            "block_state" => {
+                let state = stack.component("block_state")?.get(self.arg_str("block_state_property")?)?;
+                Some(Value::String(value_to_string(state)))
+            }
+            "trim_material" => stack.component("trim")?.get("material").cloned(),
+            _ => None,
+        }
+    }
+
+    /// Value of a `range_dispatch` property (before `scale`).
+    fn range_value(&self, stack: &ItemStack) -> f32 {
+        let normalize = self.args.get("normalize").and_then(Value::as_bool).unwrap_or(true);
+        let ratio = |value: f32, max: Option<f64>| match max {
+            Some(max) if normalize && max > 0.0 => (value / max as f32).clamp(0.0, 1.0),
+            _ => value,
+        };
+        match self.kind.as_str() {
+            "custom_model_data" => stack
+                .custom_model_data("floats", self.arg_index())
+                .and_then(|v| v.as_f64())
+                .unwrap_or(0.0) as f32,
+            "damage" => ratio(stack.damage(), stack.component("max_damage").and_then(Value::as_f64)),
+            "count" => ratio(
+                stack.count as f32,
+                Some(stack.component("max_stack_size").and_then(Value::as_f64).unwrap_or(64.0)),
+            ),
+            _ => 0.0,
+        }
+    }
+
+    /// RGB color of a tint source for a stack. Sources that depend on world
+    /// or player state use their `default`; `grass` samples the pack's
+    /// `colormap/grass` texture.
+    pub fn tint_color(&self, stack: &ItemStack, resource_pack: &ResourcePack) -> [f32; 3] {
+        let default = || self.args.get("default").and_then(parse_color).unwrap_or([1.0; 3]);
+        match self.kind.as_str() {
+            "constant" => self.args.get("value").and_then(parse_color).unwrap_or([1.0; 3]),
+            "dye" => stack
+                .component("dyed_color")
+                .and_then(|c| parse_color(c.get("rgb").unwrap_or(c)))
+                .unwrap_or_else(default),
+            "custom_model_data" => stack
+                .custom_model_data("colors", self.arg_index())
+                .and_then(|c| parse_color(&c))
+                .unwrap_or_else(default),
+            "potion" => stack
+                .component("potion_contents")
+                .and_then(|p| p.get("custom_color"))
+                .and_then(parse_color)
+                .unwrap_or_else(default),
+            "map_color" => stack.component("map_color").and_then(parse_color).unwrap_or_else(default),
+            "grass" => {
+                let temperature = self.args.get("temperature").and_then(Value::as_f64).unwrap_or(0.5) as f32;
+                let downfall = self.args.get("downfall").and_then(Value::as_f64).unwrap_or(1.0) as f32;
+                resource_pack
+                    .get_texture("minecraft:colormap/grass")
+                    .and_then(|map| sample_colormap(map, temperature, downfall))
+                    .unwrap_or([1.0; 3])
+            }
+            _ => default(),
+        }
+    }
+}
+
+/// Look up a biome color the way the game's `GrassColor` does.
+fn sample_colormap(map: &TextureData, temperature: f32, downfall: f32) -> Option<[f32; 3]> {
+    let temperature = temperature.clamp(0.0, 1.0);
+    let downfall = downfall.clamp(0.0, 1.0) * temperature;
+    let x = ((1.0 - temperature) * (map.width - 1) as f32) as u32;
+    let y = ((1.0 - downfall) * (map.height - 1) as f32) as u32;
+    let i = ((y * map.width + x) * 4) as usize;
+    let px = map.pixels.get(i..i + 3)?;
+    Some([px[0] as f32 / 255.0, px[1] as f32 / 255.0, px[2] as f32 / 255.0])
+}
+
+/// Parse a color given as a packed `0xRRGGBB` integer or an `[r, g, b]` float array.
+pub(crate) fn parse_color(value: &Value) -> Option<[f32; 3]> {
+    match value {
+        Value::Number(n) => {
+            let rgb = n.as_i64()? as u32;
+            Some([
+                ((rgb >> 16) & 0xFF) as f32 / 255.0,
+                ((rgb >> 8) & 0xFF) as f32 / 255.0,
+                (rgb & 0xFF) as f32 / 255.0,
+            ])
+        }
+        Value::Array(c) if c.len() >= 3 => Some([
+            c[0].as_f64()? as f32,
+            c[1].as_f64()? as f32,
+            c[2].as_f64()? as f32,
+        ]),
+        _ => None,
+    }
+}
+
+/// Compare a `when`/`value` from a definition with a component value.
+/// Strings compare without their `minecraft:` prefix; other values as JSON
+/// or by their string form (so `"5"` matches `5`).
+fn values_match(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::String(a), Value::String(b)) => bare(a) == bare(b),
+        _ => expected == actual || bare(&value_to_string(expected)) == bare(&value_to_string(actual)),
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn bare(id: &str) -> &str {
+    id.strip_prefix("minecraft:").unwrap_or(id)
+}
+
+fn namespaced(location: &str) -> String {
+    if location.contains(':') {
+        location.to_string()
+    } else {
+        format!("minecraft:{}", location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(json: &str) -> ItemDefinition {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn models(def: &ItemDefinition, stack: &str, context: ItemDisplayContext) -> Vec<String> {
+        let stack = ItemStack::parse(stack).unwrap();
+        def.model.resolve(&stack, context).into_iter().map(|m| m.model).collect()
+    }
+
+    #[test]
+    fn test_parse_item_stack() {
+        let stack = ItemStack::parse("diamond_sword[damage=5,minecraft:custom_name=\"a, b\"]:2").unwrap();
+        assert_eq!(stack.id, "minecraft:diamond_sword");
+        assert_eq!(stack.count, 2);
+        assert_eq!(stack.component("damage"), Some(&Value::from(5)));
+        assert_eq!(stack.component("minecraft:custom_name"), Some(&Value::from("a, b")));
+
+        let stack = ItemStack::parse("mymod:gem:16").unwrap();
+        assert_eq!((stack.id.as_str(), stack.count), ("mymod:gem", 16));
+        assert!(ItemStack::parse(" ").is_none());
+        assert_eq!(split_item_list("a[x={p:1,q:2}],,b:3"), vec!["a[x={p:1,q:2}]", "", "b:3"]);
+    }
+
+    #[test]
+    fn test_select_and_condition() {
+        let def = definition(r#"{"model": {
+            "type": "minecraft:select",
+            "property": "minecraft:display_context",
+            "cases": [{"when": ["gui", "fixed"], "model": {"type": "model", "model": "item/trident"}}],
+            "fallback": {
+                "type": "condition",
+                "property": "has_component",
+                "component": "minecraft:enchantments",
+                "on_true": {"type": "model", "model": "item/trident_in_hand_glint"},
+                "on_false": {"type": "model", "model": "item/trident_in_hand"}
+            }
+        }}"#);
+
+        assert_eq!(models(&def, "trident", ItemDisplayContext::Fixed), vec!["minecraft:item/trident"]);
+        assert_eq!(
+            models(&def, "trident", ItemDisplayContext::ThirdPersonRightHand),
+            vec!["minecraft:item/trident_in_hand"]
+        );
+        assert_eq!(
+            models(&def, "trident[enchantments={\"riptide\":3}]", ItemDisplayContext::Ground),
+            vec!["minecraft:item/trident_in_hand_glint"]
+        );
+    }
+
+    #[test]
+    fn test_range_dispatch_and_composite() {
+        let def = definition(r#"{"model": {
+            "type": "composite",
+            "models": [
+                {"type": "model", "model": "item/base"},
+                {
+                    "type": "range_dispatch",
+                    "property": "damage",
+                    "entries": [
+                        {"threshold": 0.5, "model": {"type": "model", "model": "item/worn"}},
+                        {"threshold": 0.9, "model": {"type": "model", "model": "item/broken"}}
+                    ]
+                },
+                {"type": "range_dispatch", "property": "custom_model_data", "scale": 2.0,
+                 "entries": [{"threshold": 2, "model": {"type": "model", "model": "item/custom"}}],
+                 "fallback": {"type": "empty"}}
+            ]
+        }}"#);
+
+        let ctx = ItemDisplayContext::Fixed;
+        assert_eq!(models(&def, "tool[damage=10,max_damage=100]", ctx), vec!["minecraft:item/base"]);
+        assert_eq!(
+            models(&def, "tool[damage=60,max_damage=100]", ctx),
+            vec!["minecraft:item/base", "minecraft:item/worn"]
+        );
+        assert_eq!(
+            models(&def, "tool[damage=95,max_damage=100,custom_model_data={\"floats\":[1]}]", ctx),
+            vec!["minecraft:item/base", "minecraft:item/broken", "minecraft:item/custom"]
+        );
+        // Legacy shorthand: a bare number is floats[0].
+        assert_eq!(models(&def, "tool[custom_model_data=1]", ctx), vec!["minecraft:item/base", "minecraft:item/custom"]);
+    }
+
+    #[test]
+    fn test_tint_sources() {
+        let def = definition(r#"{"model": {"type": "model", "model": "item/leather_helmet",
+            "tints": [{"type": "minecraft:dye", "default": -6265536}, {"type": "constant", "value": [0.0, 1.0, 0.0]}]}}"#);
+        let pack = ResourcePack::new();
+
+        let plain = ItemStack::parse("leather_helmet").unwrap();
+        let resolved = def.model.resolve(&plain, ItemDisplayContext::Fixed);
+        let tints: Vec<_> = resolved[0].tints.iter().map(|t| t.tint_color(&plain, &pack)).collect();
+        assert_eq!(tints[0], [160.0 / 255.0, 101.0 / 255.0, 64.0 / 255.0]);
+        assert_eq!(tints[1], [0.0, 1.0, 0.0]);
+
+        let dyed = ItemStack::parse("leather_helmet[dyed_color=16711680]").unwrap();
+        assert_eq!(resolved[0].tints[0].tint_color(&dyed, &pack), [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_unknown_node_is_empty() {
+        let def = definition(r#"{"model": {"type": "minecraft:bundle/selected_item"}}"#);
+        assert!(models(&def, "bundle", ItemDisplayContext::Gui).is_empty());
+        assert!(serde_json::from_str::<ItemDefinition>(r#"{"model": {"type": "model"}}"#).is_err());
+    }
+}
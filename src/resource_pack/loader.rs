@@ -1,8 +1,9 @@
 //! Resource pack loading from ZIP files and directories.
 
-use super::{BlockModel, BlockstateDefinition, ResourcePack, TextureData};
+use super::{AtlasDefinition, BlockModel, BlockstateDefinition, EquipmentAsset, ItemDefinition, ResourcePack, TextureData};
 use crate::error::{MesherError, Result};
-use crate::resource_pack::texture::{load_texture_from_bytes, parse_mcmeta};
+use crate::mesher::entity::{bedrock, EntityModelDef};
+use crate::resource_pack::texture::{load_texture_from_bytes, mcmeta_error, parse_mcmeta};
 use std::io::Read;
 use std::path::Path;
 
@@ -60,6 +61,7 @@ pub fn load_from_bytes(data: &[u8]) -> Result<ResourcePack> {
                                     "Warning: Failed to parse blockstate {}/{}: {}",
                                     namespace, block_id, e
                                 );
+                                pack.add_load_error(&file_path, e.to_string());
                             }
                         }
                     }
@@ -79,16 +81,87 @@ pub fn load_from_bytes(data: &[u8]) -> Result<ResourcePack> {
                                     "Warning: Failed to parse model {}/{}: {}",
                                     namespace, model_path, e
                                 );
+                                pack.add_load_error(&file_path, e.to_string());
                             }
                         }
                     }
                 }
+                "items" if asset_path.ends_with(".json") => {
+                    let mut contents = String::new();
+                    file.read_to_string(&mut contents)?;
+
+                    let item_id = asset_path.trim_end_matches(".json");
+                    match serde_json::from_str::<ItemDefinition>(&contents) {
+                        Ok(def) => {
+                            pack.add_item_definition(namespace, item_id, def);
+                        }
+                        Err(e) => {
+                            eprintln!(
+                                "Warning: Failed to parse item definition {}/{}: {}",
+                                namespace, item_id, e
+                            );
+                            pack.add_load_error(&file_path, e.to_string());
+                        }
+                    }
+                }
+                "equipment" if asset_path.ends_with(".json") => {
+                    let mut contents = String::new();
+                    file.read_to_string(&mut contents)?;
+
+                    let asset_id = asset_path.trim_end_matches(".json");
+                    match serde_json::from_str::<EquipmentAsset>(&contents) {
+                        Ok(asset) => {
+                            pack.add_equipment_asset(namespace, asset_id, asset);
+                        }
+                        Err(e) => {
+                            eprintln!(
+                                "Warning: Failed to parse equipment asset {}/{}: {}",
+                                namespace, asset_id, e
+                            );
+                            pack.add_load_error(&file_path, e.to_string());
+                        }
+                    }
+                }
+                "entity_models" if asset_path.ends_with(".json") => {
+                    let mut contents = String::new();
+                    file.read_to_string(&mut contents)?;
+
+                    let model_id = asset_path.trim_end_matches(".json");
+                    if let Err(e) = load_entity_model(&mut pack, namespace, model_id, &contents) {
+                        eprintln!(
+                            "Warning: Failed to parse entity model {}/{}: {}",
+                            namespace, model_id, e
+                        );
+                        pack.add_load_error(&file_path, e.to_string());
+                    }
+                }
+                "atlases" if asset_path.ends_with(".json") => {
+                    let mut contents = String::new();
+                    file.read_to_string(&mut contents)?;
+
+                    let atlas = asset_path.trim_end_matches(".json");
+                    match serde_json::from_str::<AtlasDefinition>(&contents) {
+                        Ok(def) => {
+                            pack.add_atlas_sources(namespace, atlas, def.sources);
+                        }
+                        Err(e) => {
+                            eprintln!(
+                                "Warning: Failed to parse atlas {}/{}: {}",
+                                namespace, atlas, e
+                            );
+                            pack.add_load_error(&file_path, e.to_string());
+                        }
+                    }
+                }
                 "textures" => {
                     if asset_path.ends_with(".png.mcmeta") {
                         // Parse .mcmeta and defer application until all textures are loaded
                         let mut contents = String::new();
                         file.read_to_string(&mut contents)?;
                         let texture_path = asset_path.trim_end_matches(".png.mcmeta");
+                        if let Some(e) = mcmeta_error(&contents) {
+                            pack.add_load_error(&file_path, e);
+                        }
                         if let Some(meta) = parse_mcmeta(&contents) {
                             pending_mcmeta.push((namespace.to_string(), texture_path.to_string(), meta));
                         }
@@ -106,10 +179,32 @@ pub fn load_from_bytes(data: &[u8]) -> Result<ResourcePack> {
                                     "Warning: Failed to load texture {}/{}: {}",
                                     namespace, texture_path, e
                                 );
+                                pack.add_load_error(&file_path, e.to_string());
                             }
                         }
                     }
                 }
+                "optifine" if asset_path == "emissive.properties" => {
+                    let mut contents = String::new();
+                    file.read_to_string(&mut contents)?;
+                    pack.emissive_suffix = parse_emissive_suffix(&contents);
+                }
+                "optifine" if asset_path.starts_with("ctm/") => {
+                    if asset_path.ends_with(".properties") {
+                        let mut contents = String::new();
+                        file.read_to_string(&mut contents)?;
+                        let path = format!("optifine/{}", asset_path);
+                        load_ctm_rule(&mut pack, namespace, &path, &contents, &file_path);
+                    } else if asset_path.ends_with(".png") {
+                        let mut data = Vec::new();
+                        file.read_to_end(&mut data)?;
+                        let texture_path = format!("optifine/{}", asset_path.trim_end_matches(".png"));
+                        match load_texture_from_bytes(&data) {
+                            Ok(texture) => pack.add_texture(namespace, &texture_path, texture),
+                            Err(e) => pack.add_load_error(&file_path, e.to_string()),
+                        }
+                    }
+                }
                 _ => {}
             }
         }
@@ -124,6 +219,9 @@ pub fn load_from_bytes(data: &[u8]) -> Result<ResourcePack> {
         }
     }
 
+    // ZIP entry order is arbitrary; keep rule order stable.
+    pack.ctm_rules.sort_by(|a, b| a.source.cmp(&b.source));
+    pack.apply_atlases();
     Ok(pack)
 }
 
@@ -156,8 +254,12 @@ fn load_from_directory(path: &Path) -> Result<ResourcePack> {
         let blockstates_path = namespace_path.join("blockstates");
         if blockstates_path.exists() {
             load_json_files(&blockstates_path, &namespace, |block_id, contents| {
-                if let Ok(def) = serde_json::from_str::<BlockstateDefinition>(contents) {
-                    pack.add_blockstate(&namespace, block_id, def);
+                match serde_json::from_str::<BlockstateDefinition>(contents) {
+                    Ok(def) => pack.add_blockstate(&namespace, block_id, def),
+                    Err(e) => pack.add_load_error(
+                        format!("assets/{}/blockstates/{}.json", namespace, block_id),
+                        e.to_string(),
+                    ),
                 }
             })?;
         }
@@ -166,8 +268,67 @@ fn load_from_directory(path: &Path) -> Result<ResourcePack> {
         let models_path = namespace_path.join("models");
         if models_path.exists() {
             load_json_files_recursive(&models_path, &models_path, &namespace, &mut |model_path, contents| {
-                if let Ok(model) = serde_json::from_str::<BlockModel>(contents) {
-                    pack.add_model(&namespace, model_path, model);
+                match serde_json::from_str::<BlockModel>(contents) {
+                    Ok(model) => pack.add_model(&namespace, model_path, model),
+                    Err(e) => pack.add_load_error(
+                        format!("assets/{}/models/{}.json", namespace, model_path),
+                        e.to_string(),
+                    ),
+                }
+            })?;
+        }
+
+        // Load item definitions
+        let items_path = namespace_path.join("items");
+        if items_path.exists() {
+            load_json_files_recursive(&items_path, &items_path, &namespace, &mut |item_id, contents| {
+                match serde_json::from_str::<ItemDefinition>(contents) {
+                    Ok(def) => pack.add_item_definition(&namespace, item_id, def),
+                    Err(e) => pack.add_load_error(
+                        format!("assets/{}/items/{}.json", namespace, item_id),
+                        e.to_string(),
+                    ),
+                }
+            })?;
+        }
+
+        // Load equipment assets
+        let equipment_path = namespace_path.join("equipment");
+        if equipment_path.exists() {
+            load_json_files_recursive(&equipment_path, &equipment_path, &namespace, &mut |asset_id, contents| {
+                match serde_json::from_str::<EquipmentAsset>(contents) {
+                    Ok(asset) => pack.add_equipment_asset(&namespace, asset_id, asset),
+                    Err(e) => pack.add_load_error(
+                        format!("assets/{}/equipment/{}.json", namespace, asset_id),
+                        e.to_string(),
+                    ),
+                }
+            })?;
+        }
+
+        // Load entity model overrides
+        let entity_models_path = namespace_path.join("entity_models");
+        if entity_models_path.exists() {
+            load_json_files_recursive(&entity_models_path, &entity_models_path, &namespace, &mut |model_id, contents| {
+                if let Err(e) = load_entity_model(&mut pack, &namespace, model_id, contents) {
+                    pack.add_load_error(
+                        format!("assets/{}/entity_models/{}.json", namespace, model_id),
+                        e.to_string(),
+                    );
+                }
+            })?;
+        }
+
+        // Load atlas definitions
+        let atlases_path = namespace_path.join("atlases");
+        if atlases_path.exists() {
+            load_json_files(&atlases_path, &namespace, |atlas, contents| {
+                match serde_json::from_str::<AtlasDefinition>(contents) {
+                    Ok(def) => pack.add_atlas_sources(&namespace, atlas, def.sources),
+                    Err(e) => pack.add_load_error(
+                        format!("assets/{}/atlases/{}.json", namespace, atlas),
+                        e.to_string(),
+                    ),
                 }
             })?;
         }
@@ -176,18 +337,32 @@ fn load_from_directory(path: &Path) -> Result<ResourcePack> {
         let textures_path = namespace_path.join("textures");
         if textures_path.exists() {
             load_texture_files_recursive(&textures_path, &textures_path, &namespace, &mut |texture_path, data| {
-                if let Ok(texture) = load_texture_from_bytes(data) {
-                    pack.add_texture(&namespace, texture_path, texture);
+                match load_texture_from_bytes(data) {
+                    Ok(texture) => pack.add_texture(&namespace, texture_path, texture),
+                    Err(e) => pack.add_load_error(
+                        format!("assets/{}/textures/{}.png", namespace, texture_path),
+                        e.to_string(),
+                    ),
                 }
             })?;
 
             // Load .png.mcmeta files and apply to textures
             let mut pending_mcmeta = Vec::new();
+            let mut malformed_mcmeta = Vec::new();
             load_mcmeta_files_recursive(&textures_path, &textures_path, &mut |texture_path, contents| {
+                if let Some(e) = mcmeta_error(contents) {
+                    malformed_mcmeta.push((texture_path.to_string(), e));
+                }
                 if let Some(meta) = parse_mcmeta(contents) {
                     pending_mcmeta.push((texture_path.to_string(), meta));
                 }
             })?;
+            for (texture_path, e) in malformed_mcmeta {
+                pack.add_load_error(
+                    format!("assets/{}/textures/{}.png.mcmeta", namespace, texture_path),
+                    e,
+                );
+            }
 
             for (texture_path, meta) in pending_mcmeta {
                 if let Some(ns_textures) = pack.textures.get_mut(&namespace) {
@@ -197,11 +372,59 @@ fn load_from_directory(path: &Path) -> Result<ResourcePack> {
                 }
             }
         }
+
+        let emissive_path = namespace_path.join("optifine").join("emissive.properties");
+        if let Ok(contents) = std::fs::read_to_string(&emissive_path) {
+            pack.emissive_suffix = parse_emissive_suffix(&contents);
+        }
+
+        // Load connected-texture rules and their tile textures
+        let ctm_path = namespace_path.join("optifine").join("ctm");
+        if ctm_path.exists() {
+            load_texture_files_recursive(&ctm_path, &ctm_path, &namespace, &mut |tile_path, data| {
+                let texture_path = format!("optifine/ctm/{}", tile_path);
+                match load_texture_from_bytes(data) {
+                    Ok(texture) => pack.add_texture(&namespace, &texture_path, texture),
+                    Err(e) => pack.add_load_error(
+                        format!("assets/{}/{}.png", namespace, texture_path),
+                        e.to_string(),
+                    ),
+                }
+            })?;
+            load_properties_files_recursive(&ctm_path, &ctm_path, &mut |rule_path, contents| {
+                let path = format!("optifine/ctm/{}.properties", rule_path);
+                let file_path = format!("assets/{}/{}", namespace, path);
+                load_ctm_rule(&mut pack, &namespace, &path, contents, &file_path);
+            })?;
+        }
     }
 
+    pack.ctm_rules.sort_by(|a, b| a.source.cmp(&b.source));
+    pack.apply_atlases();
     Ok(pack)
 }
 
+/// Read `suffix.emissive` from an OptiFine `emissive.properties` file.
+fn parse_emissive_suffix(contents: &str) -> Option<String> {
+    contents
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .find(|(key, _)| key.trim() == "suffix.emissive")
+        .map(|(_, value)| value.trim().to_string())
+        .filter(|suffix| !suffix.is_empty())
+}
+
+/// Parse one CTM properties file into `pack`, recording unusable files as
+/// load errors. Valid files with unsupported methods are skipped silently.
+fn load_ctm_rule(pack: &mut ResourcePack, namespace: &str, path: &str, contents: &str, file_path: &str) {
+    match super::ctm::parse_ctm_properties(namespace, path, contents) {
+        Ok(Some(rule)) => pack.add_ctm_rule(rule),
+        Ok(None) => {}
+        Err(e) => pack.add_load_error(file_path, e),
+    }
+}
+
 /// Parse an asset path from a ZIP file.
 /// Returns (namespace, asset_type, asset_path) if valid.
 fn parse_asset_path(file_path: &str) -> Option<(&str, &str, &str)> {
@@ -269,6 +492,22 @@ where
     Ok(())
 }
 
+/// Add an `entity_models/` file to the pack. `<id>.geo.json` files are
+/// Bedrock geometry, added under each geometry's name (in the same
+/// directory); anything else is an [`EntityModelDef`] added as `<id>`.
+fn load_entity_model(pack: &mut ResourcePack, namespace: &str, model_id: &str, contents: &str) -> Result<()> {
+    match model_id.strip_suffix(".geo") {
+        Some(file_id) => {
+            let dir = file_id.rsplit_once('/').map(|(dir, _)| format!("{}/", dir)).unwrap_or_default();
+            for (name, model) in bedrock::parse_geometry(contents)? {
+                pack.add_entity_model(namespace, &format!("{}{}", dir, name), model);
+            }
+        }
+        None => pack.add_entity_model(namespace, model_id, EntityModelDef::from_json(contents)?),
+    }
+    Ok(())
+}
+
 /// Load texture files recursively from a directory.
 fn load_texture_files_recursive<F>(
     base: &Path,
@@ -333,6 +572,36 @@ where
     Ok(())
 }
 
+/// Load `.properties` files recursively from a directory.
+fn load_properties_files_recursive<F>(
+    base: &Path,
+    dir: &Path,
+    handler: &mut F,
+) -> Result<()>
+where
+    F: FnMut(&str, &str),
+{
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            load_properties_files_recursive(base, &path, handler)?;
+        } else if path.extension().map(|e| e == "properties").unwrap_or(false) {
+            let relative = path
+                .strip_prefix(base)
+                .unwrap()
+                .with_extension("")
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            let contents = std::fs::read_to_string(&path)?;
+            handler(&relative, &contents);
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -354,4 +623,11 @@ mod tests {
         assert_eq!(parse_asset_path("pack.mcmeta"), None);
         assert_eq!(parse_asset_path("data/minecraft/recipes/test.json"), None);
     }
+
+    #[test]
+    fn test_parse_emissive_suffix() {
+        assert_eq!(parse_emissive_suffix("# glow\nsuffix.emissive = _glow\n"), Some("_glow".to_string()));
+        assert_eq!(parse_emissive_suffix("suffix.emissive="), None);
+        assert_eq!(parse_emissive_suffix("#suffix.emissive=_x"), None);
+    }
 }
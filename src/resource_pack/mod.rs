@@ -5,14 +5,31 @@
 
 pub mod loader;
 pub mod blockstate;
+pub mod ctm;
+pub mod equipment;
+pub mod item_definition;
 pub mod model;
+pub mod painting;
+pub mod registry;
+pub mod skins;
+pub mod sprite_source;
 pub mod texture;
+pub mod validate;
 
 pub use blockstate::{BlockstateDefinition, ModelVariant, MultipartCase, MultipartCondition};
+pub use ctm::{CtmMethod, CtmRule, CtmTile};
+pub use equipment::{EquipmentAsset, EquipmentLayer};
+pub use item_definition::{ItemDefinition, ItemDisplayContext, ItemModel, ItemStack};
 pub use model::{BlockModel, ModelElement, ModelFace};
+pub use painting::{PaintingRegistry, PaintingVariant};
+pub use registry::{BlockInfo, BlockRegistry, InvalidProperty};
+pub use skins::SkinLibrary;
+pub use sprite_source::{AtlasDefinition, SpriteSource};
 pub use texture::{TextureData, AnimationMeta, AnimFrame};
+pub use validate::{validate_pack, IssueKind, Severity, ValidationIssue, ValidationReport};
 
-use std::collections::HashMap;
+use crate::mesher::entity::EntityModelDef;
+use std::collections::{HashMap, HashSet};
 
 /// A loaded Minecraft resource pack.
 #[derive(Debug, Default, Clone)]
@@ -28,6 +45,60 @@ pub struct ResourcePack {
     /// Texture data by namespace and texture path.
     /// Key: namespace, Value: map of texture_path to data.
     pub textures: HashMap<String, HashMap<String, TextureData>>,
+
+    /// Item model definitions (`items/*.json`) by namespace and item ID.
+    pub item_definitions: HashMap<String, HashMap<String, ItemDefinition>>,
+
+    /// Entity model overrides (`entity_models/*.json`) by namespace and model
+    /// id (e.g. "cow", "chest"). They replace the built-in models of the same id.
+    pub entity_models: HashMap<String, HashMap<String, EntityModelDef>>,
+
+    /// Equipment assets (`equipment/*.json`) by namespace and asset id
+    /// (e.g. "diamond", "leather"): the texture layers of worn armor.
+    pub equipment_assets: HashMap<String, HashMap<String, EquipmentAsset>>,
+
+    /// Files the loader found but could not parse (bad JSON, undecodable PNG,
+    /// malformed `.mcmeta`). They are skipped during loading and surfaced by
+    /// [`validate_pack`].
+    pub load_errors: Vec<LoadError>,
+
+    /// Optional block registry (vanilla `blocks.json` report). When present,
+    /// partially specified block states are completed with the game's defaults
+    /// before variant lookup.
+    pub block_registry: Option<std::sync::Arc<BlockRegistry>>,
+
+    /// Optional painting variants from a registry JSON or data pack. They add
+    /// to and override the built-in vanilla variants.
+    pub painting_registry: Option<std::sync::Arc<PaintingRegistry>>,
+
+    /// Optional player skins and capes by texture hash, for profile
+    /// `textures` properties.
+    pub skin_library: Option<std::sync::Arc<SkinLibrary>>,
+
+    /// OptiFine/Continuity connected-texture rules (`optifine/ctm/**/*.properties`).
+    pub ctm_rules: Vec<CtmRule>,
+
+    /// Sprite sources from `atlases/*.json`, by atlas id (e.g. "minecraft:blocks").
+    /// Overlaid packs append their sources, as the game does.
+    pub atlas_sources: HashMap<String, Vec<SpriteSource>>,
+
+    /// Sprite ids declared by the `minecraft:blocks` atlas, set by
+    /// [`ResourcePack::apply_atlases`]. `None` when no pack defines that
+    /// atlas, in which case every texture can be used by block models.
+    pub block_sprites: Option<HashSet<String>>,
+
+    /// Suffix of emissive overlay textures, from `optifine/emissive.properties`
+    /// (`suffix.emissive`). `None` means the conventional `_e`.
+    pub emissive_suffix: Option<String>,
+}
+
+/// A pack file that was skipped because it failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadError {
+    /// Path of the file inside the pack (e.g. "assets/minecraft/models/block/stone.json").
+    pub path: String,
+    /// Parser error message.
+    pub message: String,
 }
 
 impl ResourcePack {
@@ -55,6 +126,50 @@ impl ResourcePack {
         self.textures.get(namespace).and_then(|ns| ns.get(path))
     }
 
+    /// Get an item definition by item ID (e.g., "minecraft:diamond_sword").
+    pub fn get_item_definition(&self, item_id: &str) -> Option<&ItemDefinition> {
+        let (namespace, path) = parse_resource_location(item_id);
+        self.item_definitions.get(namespace).and_then(|ns| ns.get(path))
+    }
+
+    /// Get an entity model override by model ID (e.g., "minecraft:cow").
+    pub fn get_entity_model(&self, model_id: &str) -> Option<&EntityModelDef> {
+        let (namespace, path) = parse_resource_location(model_id);
+        self.entity_models.get(namespace).and_then(|ns| ns.get(path))
+    }
+
+    /// Get an equipment asset by id (e.g. "minecraft:leather"): the pack's
+    /// `equipment/<id>.json`, else the built-in vanilla armor asset.
+    pub fn get_equipment_asset(&self, asset_id: &str) -> Option<EquipmentAsset> {
+        let (namespace, path) = parse_resource_location(asset_id);
+        self.equipment_assets
+            .get(namespace)
+            .and_then(|ns| ns.get(path).cloned())
+            .or_else(|| EquipmentAsset::vanilla(asset_id))
+    }
+
+    /// Get the LabPBR normal map (`<texture>_n`) for a texture, if the pack has one.
+    pub fn get_normal_map(&self, resource_location: &str) -> Option<&TextureData> {
+        self.get_texture(&format!("{}_n", resource_location))
+    }
+
+    /// Get the LabPBR specular map (`<texture>_s`) for a texture, if the pack has one.
+    pub fn get_specular_map(&self, resource_location: &str) -> Option<&TextureData> {
+        self.get_texture(&format!("{}_s", resource_location))
+    }
+
+    /// Get the emissive overlay (`<texture>_e`, or the pack's configured
+    /// suffix) for a texture, if the pack has one.
+    pub fn get_emissive_map(&self, resource_location: &str) -> Option<&TextureData> {
+        self.get_texture(&self.emissive_map_path(resource_location))
+    }
+
+    /// Texture path of the emissive overlay for a texture (whether or not the
+    /// pack has one).
+    pub fn emissive_map_path(&self, resource_location: &str) -> String {
+        format!("{}{}", resource_location, self.emissive_suffix.as_deref().unwrap_or("_e"))
+    }
+
     /// Add a blockstate definition.
     pub fn add_blockstate(
         &mut self,
@@ -84,6 +199,30 @@ impl ResourcePack {
             .insert(texture_path.to_string(), texture);
     }
 
+    /// Add an item definition.
+    pub fn add_item_definition(&mut self, namespace: &str, item_id: &str, definition: ItemDefinition) {
+        self.item_definitions
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(item_id.to_string(), definition);
+    }
+
+    /// Add an entity model override.
+    pub fn add_entity_model(&mut self, namespace: &str, model_id: &str, model: EntityModelDef) {
+        self.entity_models
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(model_id.to_string(), model);
+    }
+
+    /// Add an equipment asset.
+    pub fn add_equipment_asset(&mut self, namespace: &str, asset_id: &str, asset: EquipmentAsset) {
+        self.equipment_assets
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(asset_id.to_string(), asset);
+    }
+
     /// Get the total number of blockstate definitions.
     pub fn blockstate_count(&self) -> usize {
         self.blockstates.values().map(|m| m.len()).sum()
@@ -113,11 +252,11 @@ impl ResourcePack {
 
     /// Overlay `higher` on top of this pack. Entries in `higher` replace
     /// entries in `self` on per-key collision (blockstate id, model path,
-    /// texture path). Namespaces present only in one side are preserved
+    /// texture path, item id, entity model id, equipment asset id). Namespaces present only in one side are preserved
     /// as-is. Mirrors Minecraft's resource-pack priority model where packs
     /// loaded later override packs loaded earlier.
     pub fn overlay(&mut self, higher: ResourcePack) {
-        let ResourcePack { blockstates, models, textures } = higher;
+        let ResourcePack { blockstates, models, textures, item_definitions, entity_models, equipment_assets, load_errors, block_registry, painting_registry, skin_library, ctm_rules, atlas_sources, block_sprites: _, emissive_suffix } = higher;
 
         for (ns, entries) in blockstates {
             self.blockstates.entry(ns).or_default().extend(entries);
@@ -128,6 +267,93 @@ impl ResourcePack {
         for (ns, entries) in textures {
             self.textures.entry(ns).or_default().extend(entries);
         }
+        for (ns, entries) in item_definitions {
+            self.item_definitions.entry(ns).or_default().extend(entries);
+        }
+        for (ns, entries) in entity_models {
+            self.entity_models.entry(ns).or_default().extend(entries);
+        }
+        for (ns, entries) in equipment_assets {
+            self.equipment_assets.entry(ns).or_default().extend(entries);
+        }
+        self.load_errors.extend(load_errors);
+        if block_registry.is_some() {
+            self.block_registry = block_registry;
+        }
+        if painting_registry.is_some() {
+            self.painting_registry = painting_registry;
+        }
+        if skin_library.is_some() {
+            self.skin_library = skin_library;
+        }
+        // A higher pack's properties file replaces the same file below it.
+        self.ctm_rules
+            .retain(|rule| !ctm_rules.iter().any(|r| r.source == rule.source));
+        self.ctm_rules.extend(ctm_rules);
+        if emissive_suffix.is_some() {
+            self.emissive_suffix = emissive_suffix;
+        }
+        // Sources can reference textures from either pack (e.g. new trim
+        // palettes over vanilla trim textures), so re-evaluate on the merge.
+        for (atlas, sources) in atlas_sources {
+            self.atlas_sources.entry(atlas).or_default().extend(sources);
+        }
+        self.apply_atlases();
+    }
+
+    /// Append an atlas definition's sprite sources.
+    pub fn add_atlas_sources(&mut self, namespace: &str, atlas: &str, sources: Vec<SpriteSource>) {
+        self.atlas_sources
+            .entry(format!("{}:{}", namespace, atlas))
+            .or_default()
+            .extend(sources);
+    }
+
+    /// Add a connected-texture rule.
+    pub fn add_ctm_rule(&mut self, rule: CtmRule) {
+        self.ctm_rules.push(rule);
+    }
+
+    /// Attach a block registry used to complete partial block states.
+    pub fn set_block_registry(&mut self, registry: BlockRegistry) {
+        self.block_registry = Some(std::sync::Arc::new(registry));
+    }
+
+    /// Attach painting variants (1.21 data-driven `painting_variant` entries).
+    pub fn set_painting_registry(&mut self, registry: PaintingRegistry) {
+        self.painting_registry = Some(std::sync::Arc::new(registry));
+    }
+
+    /// Look up a painting variant: the attached registry first, then the
+    /// built-in vanilla variants.
+    pub fn get_painting_variant(&self, id: &str) -> Option<PaintingVariant> {
+        self.painting_registry
+            .as_ref()
+            .and_then(|registry| registry.get(id).cloned())
+            .or_else(|| painting::vanilla_variant(id))
+    }
+
+    /// Attach player skins and capes keyed by texture hash.
+    pub fn set_skin_library(&mut self, library: SkinLibrary) {
+        self.skin_library = Some(std::sync::Arc::new(library));
+    }
+
+    /// Add a skin or cape to the attached library, creating it if needed.
+    pub fn add_skin(&mut self, key: &str, texture: TextureData) {
+        std::sync::Arc::make_mut(self.skin_library.get_or_insert_with(Default::default)).insert(key, texture);
+    }
+
+    /// Look up a skin or cape by texture hash or URL.
+    pub fn get_skin(&self, key: &str) -> Option<&TextureData> {
+        self.skin_library.as_ref().and_then(|library| library.get(key))
+    }
+
+    /// Record a file that failed to parse during loading.
+    pub fn add_load_error(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.load_errors.push(LoadError {
+            path: path.into(),
+            message: message.into(),
+        });
     }
 }
 
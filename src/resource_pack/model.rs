@@ -82,6 +82,10 @@ pub struct ModelElement {
     /// Whether this element receives shade.
     #[serde(default = "default_shade")]
     pub shade: bool,
+    /// Minimum block light of the element's faces (0-15, 1.21+). Nonzero
+    /// elements are meshed fullbright and flagged emissive.
+    #[serde(default)]
+    pub light_emission: u8,
     /// Face definitions.
     #[serde(default)]
     pub faces: HashMap<Direction, ModelFace>,
@@ -293,6 +297,7 @@ mod tests {
             to: [16.0, 16.0, 16.0],
             rotation: None,
             shade: true,
+            light_emission: 0,
             faces: HashMap::new(),
         };
 
@@ -0,0 +1,201 @@
+//! Painting variants: size and texture of each `painting_variant`.
+//!
+//! Since 1.21 painting variants are data-driven: a data pack's
+//! `data/<namespace>/painting_variant/<id>.json` gives the variant's size in
+//! blocks and its `asset_id`, the texture under `textures/painting/`. The
+//! vanilla variants are built in; a [`PaintingRegistry`] attached to the
+//! [`ResourcePack`](super::ResourcePack) adds new variants and overrides
+//! built-in ones.
+
+use crate::error::{MesherError, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// One painting variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaintingVariant {
+    /// Texture id without the `painting/` prefix (e.g. `minecraft:kebab`).
+    pub asset_id: String,
+    /// Width in blocks (1–16).
+    pub width: u32,
+    /// Height in blocks (1–16).
+    pub height: u32,
+}
+
+impl PaintingVariant {
+    /// Texture path of the painting's front (e.g. `painting/kebab`,
+    /// `mypack:painting/sunrise`).
+    pub fn texture(&self) -> String {
+        match self.asset_id.split_once(':') {
+            Some(("minecraft", path)) => format!("painting/{}", path),
+            Some((namespace, path)) => format!("{}:painting/{}", namespace, path),
+            None => format!("painting/{}", self.asset_id),
+        }
+    }
+}
+
+/// Vanilla variants as of 1.21: (id, width, height).
+const VANILLA_VARIANTS: &[(&str, u32, u32)] = &[
+    ("alban", 1, 1), ("aztec", 1, 1), ("aztec2", 1, 1), ("backyard", 3, 4),
+    ("baroque", 2, 2), ("bomb", 1, 1), ("bouquet", 3, 3), ("burning_skull", 4, 4),
+    ("bust", 2, 2), ("cavebird", 3, 3), ("changing", 4, 2), ("cotan", 3, 3),
+    ("courbet", 2, 1), ("creebet", 2, 1), ("donkey_kong", 4, 3), ("earth", 2, 2),
+    ("endboss", 3, 3), ("fern", 3, 3), ("fighters", 4, 2), ("finding", 4, 2),
+    ("fire", 2, 2), ("graham", 1, 2), ("humble", 2, 2), ("kebab", 1, 1),
+    ("lowmist", 4, 2), ("match", 2, 2), ("meditative", 1, 1), ("orb", 4, 4),
+    ("owlemons", 3, 3), ("passage", 4, 2), ("pigscene", 4, 4), ("plant", 1, 1),
+    ("pointer", 4, 4), ("pond", 3, 4), ("pool", 2, 1), ("prairie_ride", 1, 2),
+    ("sea", 2, 1), ("skeleton", 4, 3), ("skull_and_roses", 2, 2), ("stage", 2, 2),
+    ("sunflowers", 3, 3), ("sunset", 2, 1), ("tides", 3, 3), ("unpacked", 4, 4),
+    ("void", 2, 2), ("wanderer", 1, 2), ("wasteland", 1, 1), ("water", 2, 2),
+    ("wind", 2, 2), ("wither", 2, 2),
+];
+
+/// Look up a built-in variant. Unprefixed ids are treated as `minecraft:`.
+pub fn vanilla_variant(id: &str) -> Option<PaintingVariant> {
+    let name = id.strip_prefix("minecraft:").unwrap_or(id);
+    VANILLA_VARIANTS
+        .iter()
+        .find(|(variant, _, _)| *variant == name)
+        .map(|&(variant, width, height)| PaintingVariant {
+            asset_id: format!("minecraft:{}", variant),
+            width,
+            height,
+        })
+}
+
+/// Painting variants keyed by full id (e.g. `mypack:sunrise`).
+#[derive(Debug, Clone, Default)]
+pub struct PaintingRegistry {
+    variants: HashMap<String, PaintingVariant>,
+}
+
+impl PaintingRegistry {
+    /// Parse a registry JSON: an object mapping variant ids to
+    /// `painting_variant` entries (`{"asset_id", "width", "height"}`).
+    pub fn from_json(json: &str) -> Result<Self> {
+        let root: serde_json::Value = serde_json::from_str(json)?;
+        let entries = root.as_object().ok_or_else(|| {
+            MesherError::InvalidResourcePack("painting registry: expected an object of variants".to_string())
+        })?;
+        let mut registry = Self::default();
+        for (id, entry) in entries {
+            registry.insert(id, parse_variant(id, entry)?);
+        }
+        Ok(registry)
+    }
+
+    /// Collect `data/<namespace>/painting_variant/*.json` from an unpacked
+    /// data pack (or a world's `datapacks/<pack>` folder).
+    pub fn from_datapack<P: AsRef<Path>>(root: P) -> Result<Self> {
+        let mut registry = Self::default();
+        let data = root.as_ref().join("data");
+        for namespace in std::fs::read_dir(&data)? {
+            let namespace = namespace?;
+            let dir = namespace.path().join("painting_variant");
+            if !dir.is_dir() {
+                continue;
+            }
+            let namespace = namespace.file_name().to_string_lossy().into_owned();
+            for file in std::fs::read_dir(&dir)? {
+                let path = file?.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                    continue;
+                }
+                let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                    continue;
+                };
+                let id = format!("{}:{}", namespace, name);
+                let entry: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+                registry.insert(&id, parse_variant(&id, &entry)?);
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Load a registry JSON file, or a data pack directory.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if path.is_dir() {
+            Self::from_datapack(path)
+        } else {
+            Self::from_json(&std::fs::read_to_string(path)?)
+        }
+    }
+
+    /// Add or replace a variant. Unprefixed ids are treated as `minecraft:`.
+    pub fn insert(&mut self, id: &str, variant: PaintingVariant) {
+        self.variants.insert(full_id(id), variant);
+    }
+
+    /// Look up a variant registered here. Unprefixed ids are treated as
+    /// `minecraft:`.
+    pub fn get(&self, id: &str) -> Option<&PaintingVariant> {
+        self.variants.get(&full_id(id))
+    }
+
+    /// Number of variants in the registry.
+    pub fn len(&self) -> usize {
+        self.variants.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+}
+
+fn full_id(id: &str) -> String {
+    if id.contains(':') {
+        id.to_string()
+    } else {
+        format!("minecraft:{}", id)
+    }
+}
+
+fn parse_variant(id: &str, entry: &serde_json::Value) -> Result<PaintingVariant> {
+    let size = |key: &str| {
+        entry
+            .get(key)
+            .and_then(|v| v.as_u64())
+            .filter(|n| (1..=16).contains(n))
+            .map(|n| n as u32)
+            .ok_or_else(|| {
+                MesherError::InvalidResourcePack(format!("painting variant {}: missing or invalid {}", id, key))
+            })
+    };
+    let asset_id = entry
+        .get("asset_id")
+        .and_then(|v| v.as_str())
+        .map(full_id)
+        .unwrap_or_else(|| full_id(id));
+    Ok(PaintingVariant {
+        asset_id,
+        width: size("width")?,
+        height: size("height")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_registry_and_falls_back_to_vanilla() {
+        let registry = PaintingRegistry::from_json(r#"{
+            "mypack:sunrise": {"asset_id": "mypack:sunrise", "width": 3, "height": 2},
+            "kebab": {"asset_id": "mypack:big_kebab", "width": 2, "height": 2, "title": "Kebab"}
+        }"#).unwrap();
+        assert_eq!(registry.len(), 2);
+
+        let sunrise = registry.get("mypack:sunrise").unwrap();
+        assert_eq!((sunrise.width, sunrise.height), (3, 2));
+        assert_eq!(sunrise.texture(), "mypack:painting/sunrise");
+        assert_eq!(registry.get("minecraft:kebab").unwrap().asset_id, "mypack:big_kebab");
+
+        assert_eq!(vanilla_variant("pigscene").map(|v| (v.width, v.height)), Some((4, 4)));
+        assert_eq!(vanilla_variant("minecraft:wanderer").unwrap().texture(), "painting/wanderer");
+        assert!(vanilla_variant("sunrise").is_none());
+
+        assert!(PaintingRegistry::from_json(r#"{"bad": {"width": 0, "height": 1}}"#).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+//! Block registry loaded from the vanilla `blocks.json` report.
+//!
+//! The data generator (`java -DbundlerMainClass=net.minecraft.data.Main -jar
+//! server.jar --reports`) writes `reports/blocks.json`, listing every block's
+//! property domains and its default state. With a registry attached to the
+//! [`ResourcePack`](super::ResourcePack), partially specified blocks are
+//! completed with the game's own defaults before variant lookup instead of the
+//! resolver's "default-looking value" heuristics.
+
+use crate::error::{MesherError, Result};
+use crate::types::InputBlock;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Property domains and default state of one block.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlockInfo {
+    /// Allowed values per property, in report order.
+    pub properties: HashMap<String, Vec<String>>,
+    /// The state the game places when no properties are given.
+    pub default_state: HashMap<String, String>,
+}
+
+/// A property value outside the block's domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidProperty {
+    pub property: String,
+    pub value: String,
+}
+
+/// Every known block, keyed by full id (e.g. `minecraft:oak_stairs`).
+#[derive(Debug, Clone, Default)]
+pub struct BlockRegistry {
+    blocks: HashMap<String, BlockInfo>,
+}
+
+impl BlockRegistry {
+    /// Parse a `blocks.json` report.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let root: serde_json::Value = serde_json::from_str(json)?;
+        let entries = root.as_object().ok_or_else(|| {
+            MesherError::InvalidResourcePack("blocks.json: expected an object of blocks".to_string())
+        })?;
+
+        let mut blocks = HashMap::with_capacity(entries.len());
+        for (name, entry) in entries {
+            let mut info = BlockInfo::default();
+            if let Some(props) = entry.get("properties").and_then(|p| p.as_object()) {
+                for (prop, values) in props {
+                    let values = values
+                        .as_array()
+                        .map(|a| a.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+                        .unwrap_or_default();
+                    info.properties.insert(prop.clone(), values);
+                }
+            }
+            let default_state = entry
+                .get("states")
+                .and_then(|s| s.as_array())
+                .and_then(|states| {
+                    states
+                        .iter()
+                        .find(|s| s.get("default").and_then(|d| d.as_bool()).unwrap_or(false))
+                });
+            if let Some(props) = default_state
+                .and_then(|s| s.get("properties"))
+                .and_then(|p| p.as_object())
+            {
+                for (prop, value) in props {
+                    if let Some(value) = value.as_str() {
+                        info.default_state.insert(prop.clone(), value.to_string());
+                    }
+                }
+            }
+            blocks.insert(name.clone(), info);
+        }
+        Ok(Self { blocks })
+    }
+
+    /// Load a `blocks.json` report from disk.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Look up a block. Unprefixed names are treated as `minecraft:`.
+    pub fn get(&self, name: &str) -> Option<&BlockInfo> {
+        if name.contains(':') {
+            self.blocks.get(name)
+        } else {
+            self.blocks.get(&format!("minecraft:{}", name))
+        }
+    }
+
+    /// Number of blocks in the registry.
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Complete `block` to a full state the way the game would: unspecified
+    /// and invalid properties take the default value. Properties the block
+    /// doesn't have (including the mesher's own annotations like `inventory`)
+    /// are dropped. Returns `None` for blocks not in the registry.
+    pub fn complete_state(&self, block: &InputBlock) -> Option<InputBlock> {
+        let info = self.get(&block.name)?;
+        let mut properties = info.default_state.clone();
+        for (prop, value) in &block.properties {
+            if info.properties.get(prop).is_some_and(|domain| domain.contains(value)) {
+                properties.insert(prop.clone(), value.clone());
+            }
+        }
+        Some(InputBlock {
+            name: block.name.clone(),
+            properties,
+        })
+    }
+
+    /// Properties of `block` whose values are outside the block's domain,
+    /// sorted by property name. Unknown blocks and properties report nothing.
+    pub fn invalid_properties(&self, block: &InputBlock) -> Vec<InvalidProperty> {
+        let Some(info) = self.get(&block.name) else {
+            return Vec::new();
+        };
+        let mut invalid: Vec<_> = block
+            .properties
+            .iter()
+            .filter(|(prop, value)| {
+                info.properties.get(*prop).is_some_and(|domain| !domain.contains(value))
+            })
+            .map(|(prop, value)| InvalidProperty {
+                property: prop.clone(),
+                value: value.clone(),
+            })
+            .collect();
+        invalid.sort_by(|a, b| a.property.cmp(&b.property));
+        invalid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = r#"{
+        "minecraft:oak_stairs": {
+            "properties": {
+                "facing": ["north", "south", "west", "east"],
+                "half": ["top", "bottom"],
+                "shape": ["straight", "inner_left", "inner_right", "outer_left", "outer_right"],
+                "waterlogged": ["true", "false"]
+            },
+            "states": [
+                {"id": 1, "properties": {"facing": "north", "half": "top", "shape": "straight", "waterlogged": "true"}},
+                {"id": 2, "default": true, "properties": {"facing": "north", "half": "bottom", "shape": "straight", "waterlogged": "false"}}
+            ]
+        },
+        "minecraft:stone": {"states": [{"id": 3, "default": true}]}
+    }"#;
+
+    #[test]
+    fn completes_with_game_defaults() {
+        let registry = BlockRegistry::from_json(REPORT).unwrap();
+        assert_eq!(registry.len(), 2);
+
+        let block = InputBlock::new("minecraft:oak_stairs")
+            .with_property("facing", "east")
+            .with_property("half", "sideways")
+            .with_property("inventory", "[]");
+        let completed = registry.complete_state(&block).unwrap();
+        assert_eq!(completed.properties.len(), 4);
+        assert_eq!(completed.properties["facing"], "east");
+        assert_eq!(completed.properties["half"], "bottom");
+        assert_eq!(completed.properties["waterlogged"], "false");
+
+        assert_eq!(
+            registry.invalid_properties(&block),
+            vec![InvalidProperty { property: "half".into(), value: "sideways".into() }]
+        );
+        assert!(registry.complete_state(&InputBlock::new("stone")).unwrap().properties.is_empty());
+        assert!(registry.complete_state(&InputBlock::new("mymod:widget")).is_none());
+    }
+}
@@ -0,0 +1,87 @@
+//! Player skins and capes supplied by the caller.
+//!
+//! Player heads and NPCs name their skin through a game profile `textures`
+//! property whose `SKIN` and `CAPE` entries point at
+//! `http://textures.minecraft.net/texture/<hash>`. The mesher never fetches
+//! those URLs: a [`SkinLibrary`] attached to the
+//! [`ResourcePack`](super::ResourcePack) maps texture hashes to PNGs the
+//! caller already has.
+
+use super::texture::load_texture_from_bytes;
+use super::TextureData;
+use crate::error::Result;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Skin and cape textures keyed by texture hash.
+#[derive(Debug, Clone, Default)]
+pub struct SkinLibrary {
+    textures: HashMap<String, TextureData>,
+}
+
+impl SkinLibrary {
+    /// Load every `<hash>.png` in a directory.
+    pub fn load<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let mut library = Self::default();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("png") {
+                continue;
+            }
+            let Some(hash) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            library.insert_png(hash, &std::fs::read(&path)?)?;
+        }
+        Ok(library)
+    }
+
+    /// Add a texture under a hash or a full texture URL.
+    pub fn insert(&mut self, key: &str, texture: TextureData) {
+        self.textures.insert(texture_hash(key).to_string(), texture);
+    }
+
+    /// Decode and add a PNG under a hash or a full texture URL.
+    pub fn insert_png(&mut self, key: &str, png: &[u8]) -> Result<()> {
+        self.insert(key, load_texture_from_bytes(png)?);
+        Ok(())
+    }
+
+    /// Look up a texture by hash or full texture URL.
+    pub fn get(&self, key: &str) -> Option<&TextureData> {
+        self.textures.get(texture_hash(key))
+    }
+
+    /// Number of textures in the library.
+    pub fn len(&self) -> usize {
+        self.textures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.textures.is_empty()
+    }
+}
+
+/// The hash of a texture URL (its last path segment); a bare hash is
+/// returned as is.
+pub fn texture_hash(url: &str) -> &str {
+    url.trim().trim_end_matches('/').rsplit('/').next().unwrap_or(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_up_by_hash_or_url() {
+        let mut library = SkinLibrary::default();
+        assert!(library.is_empty());
+        library.insert("1a2b3c", TextureData::new(64, 32, vec![0; 64 * 32 * 4]));
+
+        assert_eq!(library.len(), 1);
+        assert_eq!(library.get("http://textures.minecraft.net/texture/1a2b3c").map(|t| t.height), Some(32));
+        assert!(library.get("1a2b3c").is_some());
+        assert!(library.get("4d5e6f").is_none());
+        assert!(library.insert_png("bad", b"not a png").is_err());
+    }
+}
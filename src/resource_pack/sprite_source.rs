@@ -0,0 +1,331 @@
+//! Atlas sprite source parsing (`atlases/*.json`).
+//!
+//! Since 1.19.3 each texture atlas lists the sprites it stitches through a
+//! sequence of sources: `directory` (every texture under a folder),
+//! `single` (one texture, optionally renamed), `filter` (drop sprites
+//! matching a regex) and `paletted_permutations` (recolor textures through
+//! color palettes, used by armor trims). [`ResourcePack::apply_atlases`]
+//! evaluates them, materializes renamed and generated sprites into the
+//! pack's textures and records which sprite ids the blocks atlas declares.
+
+use super::{ResourcePack, TextureData};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Atlas whose sprites block models may use.
+const BLOCKS_ATLAS: &str = "minecraft:blocks";
+
+/// A parsed atlas definition from atlases/*.json.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AtlasDefinition {
+    #[serde(default)]
+    pub sources: Vec<SpriteSource>,
+}
+
+/// One entry of an atlas definition's `sources` list.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
+pub enum SpriteSource {
+    /// Every texture under `textures/<source>/`, with the directory replaced by `prefix`.
+    #[serde(rename = "minecraft:directory", alias = "directory")]
+    Directory { source: String, prefix: String },
+    /// A single texture, registered as `sprite` (defaults to `resource`).
+    #[serde(rename = "minecraft:single", alias = "single")]
+    Single {
+        resource: String,
+        #[serde(default)]
+        sprite: Option<String>,
+    },
+    /// Remove already-added sprites whose namespace and path both match.
+    #[serde(rename = "minecraft:filter", alias = "filter")]
+    Filter { pattern: SpritePattern },
+    /// For every texture and palette, a copy with the colors of `palette_key`
+    /// replaced by the palette's, registered as `<texture><separator><name>`.
+    #[serde(rename = "minecraft:paletted_permutations", alias = "paletted_permutations")]
+    PalettedPermutations {
+        textures: Vec<String>,
+        palette_key: String,
+        permutations: BTreeMap<String, String>,
+        #[serde(default = "default_separator")]
+        separator: String,
+    },
+    /// Source types this crate doesn't evaluate (`unstitch`, mod-specific ones).
+    #[serde(other)]
+    Unsupported,
+}
+
+fn default_separator() -> String {
+    "_".to_string()
+}
+
+/// Regex pair of a `filter` source; a missing part matches anything.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SpritePattern {
+    #[serde(default)]
+    pub namespace: Option<String>,
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+/// Where a declared sprite's pixels come from.
+enum Sprite {
+    Texture(String),
+    Generated(TextureData),
+}
+
+impl ResourcePack {
+    /// Evaluate every atlas definition: add renamed (`single` with `sprite`,
+    /// `directory` with a different `prefix`) and palette-permuted sprites to
+    /// `textures` under their sprite id, and record the sprite ids the
+    /// `minecraft:blocks` atlas declares in `block_sprites`. Does nothing for
+    /// packs without atlas definitions.
+    pub fn apply_atlases(&mut self) {
+        if self.atlas_sources.is_empty() {
+            return;
+        }
+
+        let mut atlas_ids: Vec<&String> = self.atlas_sources.keys().collect();
+        atlas_ids.sort();
+        let mut declared: Vec<(String, Sprite)> = Vec::new();
+        let mut block_sprites = None;
+        for atlas_id in atlas_ids {
+            let mut sprites: HashMap<String, Sprite> = HashMap::new();
+            for source in &self.atlas_sources[atlas_id] {
+                self.apply_sprite_source(source, &mut sprites);
+            }
+            if atlas_id == BLOCKS_ATLAS {
+                block_sprites = Some(sprites.keys().cloned().collect::<HashSet<_>>());
+            }
+            let mut sprites: Vec<_> = sprites.into_iter().collect();
+            sprites.sort_by(|a, b| a.0.cmp(&b.0));
+            declared.extend(sprites);
+        }
+
+        for (id, sprite) in declared {
+            let texture = match sprite {
+                Sprite::Texture(location) if location == id => None,
+                Sprite::Texture(location) => self.get_texture(&location).cloned(),
+                Sprite::Generated(texture) => Some(texture),
+            };
+            if let Some(texture) = texture {
+                let (namespace, path) = id.split_once(':').unwrap_or(("minecraft", &id));
+                self.add_texture(namespace, path, texture);
+            }
+        }
+        self.block_sprites = block_sprites;
+    }
+
+    fn apply_sprite_source(&self, source: &SpriteSource, sprites: &mut HashMap<String, Sprite>) {
+        match source {
+            SpriteSource::Directory { source, prefix } => {
+                let dir = format!("{}/", source.trim_end_matches('/'));
+                for (namespace, textures) in &self.textures {
+                    for path in textures.keys() {
+                        if let Some(rest) = path.strip_prefix(&dir) {
+                            sprites.insert(
+                                format!("{}:{}{}", namespace, prefix, rest),
+                                Sprite::Texture(format!("{}:{}", namespace, path)),
+                            );
+                        }
+                    }
+                }
+            }
+            SpriteSource::Single { resource, sprite } => {
+                let resource = namespaced(resource);
+                if self.get_texture(&resource).is_some() {
+                    let id = sprite.as_deref().map(namespaced).unwrap_or_else(|| resource.clone());
+                    sprites.insert(id, Sprite::Texture(resource));
+                }
+            }
+            SpriteSource::Filter { pattern } => {
+                let namespace = pattern.namespace.as_deref().map(full_match_regex);
+                let path = pattern.path.as_deref().map(full_match_regex);
+                let matches = |re: &Option<Option<Regex>>, text: &str| match re {
+                    None => true,
+                    Some(Some(re)) => re.is_match(text),
+                    Some(None) => false,
+                };
+                sprites.retain(|id, _| {
+                    let (ns, p) = id.split_once(':').unwrap_or(("minecraft", id));
+                    !(matches(&namespace, ns) && matches(&path, p))
+                });
+            }
+            SpriteSource::PalettedPermutations { textures, palette_key, permutations, separator } => {
+                let Some(key) = self.get_texture(&namespaced(palette_key)) else {
+                    return;
+                };
+                let key = key.first_frame();
+                for (suffix, palette) in permutations {
+                    let Some(palette) = self.get_texture(&namespaced(palette)) else {
+                        continue;
+                    };
+                    let mapping = palette_mapping(&key, &palette.first_frame());
+                    for texture in textures {
+                        let texture = namespaced(texture);
+                        if let Some(base) = self.get_texture(&texture) {
+                            sprites.insert(
+                                format!("{}{}{}", texture, separator, suffix),
+                                Sprite::Generated(apply_palette(base, &mapping)),
+                            );
+                        }
+                    }
+                }
+            }
+            SpriteSource::Unsupported => {}
+        }
+    }
+
+    /// Recolor one texture through a palette the way a
+    /// `paletted_permutations` source would, for sprites a pack uses without
+    /// declaring them in an atlas (e.g. armor trims in packs without
+    /// `atlases/armor_trims.json`).
+    pub fn paletted_texture(&self, texture: &str, palette_key: &str, palette: &str) -> Option<TextureData> {
+        let base = self.get_texture(&namespaced(texture))?;
+        let key = self.get_texture(&namespaced(palette_key))?.first_frame();
+        let palette = self.get_texture(&namespaced(palette))?.first_frame();
+        Some(apply_palette(base, &palette_mapping(&key, &palette)))
+    }
+
+    /// Whether a texture may be used by a block model: it is declared by the
+    /// `minecraft:blocks` atlas, or the pack doesn't define that atlas.
+    pub fn is_block_sprite(&self, resource_location: &str) -> bool {
+        match &self.block_sprites {
+            Some(sprites) => sprites.contains(&namespaced(resource_location)),
+            None => true,
+        }
+    }
+}
+
+/// Map each palette key color (RGB) to the palette color at the same pixel index.
+fn palette_mapping(key: &TextureData, palette: &TextureData) -> HashMap<[u8; 3], [u8; 4]> {
+    key.pixels
+        .chunks_exact(4)
+        .zip(palette.pixels.chunks_exact(4))
+        .map(|(k, p)| ([k[0], k[1], k[2]], [p[0], p[1], p[2], p[3]]))
+        .collect()
+}
+
+/// Recolor every (non-transparent) pixel whose RGB is a palette key. The
+/// result keeps the pixel's alpha, scaled by the palette color's.
+fn apply_palette(base: &TextureData, mapping: &HashMap<[u8; 3], [u8; 4]>) -> TextureData {
+    let mut texture = base.clone();
+    for px in texture.pixels.chunks_exact_mut(4) {
+        if px[3] == 0 {
+            continue;
+        }
+        if let Some(&[r, g, b, a]) = mapping.get(&[px[0], px[1], px[2]]) {
+            px[0] = r;
+            px[1] = g;
+            px[2] = b;
+            px[3] = ((px[3] as u32 * a as u32) / 255) as u8;
+        }
+    }
+    texture
+}
+
+fn namespaced(location: &str) -> String {
+    if location.contains(':') {
+        location.to_string()
+    } else {
+        format!("minecraft:{}", location)
+    }
+}
+
+/// Compile a `filter` pattern with Java `matches()` (whole-string)
+/// semantics; `None` if it is malformed (the filter then matches nothing).
+/// The `regex` crate matches in linear time, so hostile patterns such as
+/// `(a+)+b` can't stall pack loading.
+fn full_match_regex(pattern: &str) -> Option<Regex> {
+    Regex::new(&format!("^(?:{})$", pattern)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(rgba: [u8; 4], n: usize) -> TextureData {
+        TextureData::new(n as u32, 1, rgba.repeat(n))
+    }
+
+    #[test]
+    fn test_regex_full_match() {
+        let re = |p: &str| full_match_regex(p).unwrap();
+        assert!(re("block/.*").is_match("block/stone"));
+        assert!(!re("block/.*").is_match("item/block/stone"));
+        assert!(re("block/(?:oak|birch)_[a-z]+\\d?").is_match("block/birch_log2"));
+        assert!(!re("block/(oak|birch)_log").is_match("block/spruce_log"));
+        assert!(re("[^/]+/x{2,3}").is_match("a/xxx"));
+        assert!(!re("[^/]+/x{2,3}").is_match("a/b/xx"));
+        assert!(full_match_regex("(unclosed").is_none());
+    }
+
+    #[test]
+    fn test_regex_pathological_pattern() {
+        // Exponential for a backtracking matcher; linear here.
+        let re = full_match_regex("(a+)+b").unwrap();
+        assert!(!re.is_match(&"a".repeat(64)));
+        assert!(re.is_match(&format!("{}b", "a".repeat(64))));
+    }
+
+    #[test]
+    fn test_sources_declare_and_rename_sprites() {
+        let mut pack = ResourcePack::new();
+        pack.add_texture("minecraft", "block/stone", solid([1, 1, 1, 255], 1));
+        pack.add_texture("minecraft", "block/dirt", solid([2, 2, 2, 255], 1));
+        pack.add_texture("minecraft", "entity/bell/bell_body", solid([3, 3, 3, 255], 1));
+        pack.add_texture("minecraft", "misc/unlisted", solid([4, 4, 4, 255], 1));
+        let atlas: AtlasDefinition = serde_json::from_str(r#"{"sources": [
+            {"type": "directory", "source": "block", "prefix": "block/"},
+            {"type": "minecraft:single", "resource": "entity/bell/bell_body", "sprite": "block/bell"},
+            {"type": "filter", "pattern": {"path": "block/d.*"}},
+            {"type": "unstitch", "resource": "x", "regions": []}
+        ]}"#).unwrap();
+        pack.add_atlas_sources("minecraft", "blocks", atlas.sources);
+        let signs: AtlasDefinition =
+            serde_json::from_str(r#"{"sources": [{"type": "single", "resource": "misc/unlisted"}]}"#).unwrap();
+        pack.add_atlas_sources("minecraft", "signs", signs.sources);
+        pack.apply_atlases();
+
+        assert!(pack.is_block_sprite("block/stone"));
+        assert!(pack.is_block_sprite("minecraft:block/bell"));
+        assert!(!pack.is_block_sprite("block/dirt"));
+        // Declared, but by another atlas.
+        assert!(!pack.is_block_sprite("misc/unlisted"));
+        assert_eq!(pack.get_texture("block/bell").unwrap().pixels, vec![3, 3, 3, 255]);
+    }
+
+    #[test]
+    fn test_paletted_permutations() {
+        let mut pack = ResourcePack::new();
+        // Two-color key, a trim texture using both colors plus an unmapped one.
+        pack.add_texture("minecraft", "trims/color_palettes/trim_palette", TextureData::new(2, 1, vec![10, 10, 10, 255, 20, 20, 20, 255]));
+        pack.add_texture("minecraft", "trims/color_palettes/gold", TextureData::new(2, 1, vec![200, 150, 0, 255, 250, 200, 0, 255]));
+        pack.add_texture(
+            "minecraft",
+            "trims/items/helmet_trim",
+            TextureData::new(4, 1, vec![10, 10, 10, 255, 20, 20, 20, 128, 99, 99, 99, 255, 10, 10, 10, 0]),
+        );
+        let atlas: AtlasDefinition = serde_json::from_str(r#"{"sources": [{
+            "type": "paletted_permutations",
+            "textures": ["trims/items/helmet_trim"],
+            "palette_key": "trims/color_palettes/trim_palette",
+            "permutations": {"gold": "trims/color_palettes/gold", "missing": "trims/color_palettes/nope"}
+        }]}"#).unwrap();
+        pack.add_atlas_sources("minecraft", "blocks", atlas.sources);
+        pack.apply_atlases();
+
+        let gold = pack.get_texture("trims/items/helmet_trim_gold").unwrap();
+        assert_eq!(
+            gold.pixels,
+            vec![200, 150, 0, 255, 250, 200, 0, 128, 99, 99, 99, 255, 10, 10, 10, 0]
+        );
+        let on_demand = pack
+            .paletted_texture("trims/items/helmet_trim", "trims/color_palettes/trim_palette", "trims/color_palettes/gold")
+            .unwrap();
+        assert_eq!(on_demand.pixels, gold.pixels);
+        assert!(pack.is_block_sprite("trims/items/helmet_trim_gold"));
+        assert!(pack.get_texture("trims/items/helmet_trim_missing").is_none());
+        assert!(!pack.is_block_sprite("trims/items/helmet_trim"));
+    }
+}
@@ -57,6 +57,60 @@ pub fn parse_mcmeta(json: &str) -> Option<AnimationMeta> {
     Some(AnimationMeta { frametime, interpolate, frames, frame_width, frame_height })
 }
 
+/// Describe what is wrong with a `.png.mcmeta` file: a JSON syntax error, or
+/// an `animation` section the game would reject (mistyped fields, zero frame
+/// times or sizes, frames without an index). [`parse_mcmeta`] falls back to
+/// defaults for such fields, so this is what reports the broken animation.
+pub(crate) fn mcmeta_error(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = match serde_json::from_str(json) {
+        Ok(parsed) => parsed,
+        Err(e) => return Some(e.to_string()),
+    };
+    let anim = parsed.get("animation")?;
+    let Some(anim) = anim.as_object() else {
+        return Some(format!("animation: expected an object, got {}", anim));
+    };
+
+    let is_positive = |v: &serde_json::Value| v.as_u64().is_some_and(|n| n > 0 && n <= u32::MAX as u64);
+    for key in ["frametime", "width", "height"] {
+        match anim.get(key) {
+            Some(v) if !is_positive(v) => {
+                return Some(format!("animation.{}: expected a positive integer, got {}", key, v));
+            }
+            _ => {}
+        }
+    }
+    match anim.get("interpolate") {
+        Some(v) if !v.is_boolean() => {
+            return Some(format!("animation.interpolate: expected a boolean, got {}", v));
+        }
+        _ => {}
+    }
+
+    let frames = anim.get("frames")?;
+    let Some(frames) = frames.as_array() else {
+        return Some(format!("animation.frames: expected an array, got {}", frames));
+    };
+    for (i, frame) in frames.iter().enumerate() {
+        let valid = match frame {
+            serde_json::Value::Number(n) => n.as_u64().is_some(),
+            serde_json::Value::Object(obj) => {
+                let index = obj.get("index").is_some_and(|v| v.as_u64().is_some());
+                let time = match obj.get("time") {
+                    Some(time) => is_positive(time),
+                    None => true,
+                };
+                index && time
+            }
+            _ => false,
+        };
+        if !valid {
+            return Some(format!("animation.frames[{}]: expected an index or {{\"index\", \"time\"}}, got {}", i, frame));
+        }
+    }
+    None
+}
+
 /// Raw texture data loaded from PNG.
 #[derive(Debug, Clone)]
 pub struct TextureData {
@@ -224,6 +278,99 @@ impl TextureData {
             animation: None,
         }
     }
+
+    /// The frame of a companion map (LabPBR `_n`/`_s`) that matches this
+    /// texture's [`first_frame`](Self::first_frame). Companions of animated
+    /// textures are frame strips without a `.mcmeta` of their own, so they
+    /// are sliced with this texture's frame count and order, at the
+    /// companion's resolution.
+    pub fn companion_frame(&self, companion: &TextureData) -> TextureData {
+        if companion.is_animated
+            || !self.is_animated
+            || self.frame_count <= 1
+            || companion.height % self.frame_count != 0
+        {
+            return companion.first_frame();
+        }
+        let frame_height = companion.height / self.frame_count;
+        TextureData {
+            is_animated: true,
+            frame_count: self.frame_count,
+            animation: self.animation.clone().map(|meta| AnimationMeta {
+                frame_width: None,
+                frame_height: Some(frame_height),
+                ..meta
+            }),
+            ..companion.clone()
+        }
+        .first_frame()
+    }
+
+    /// Bake an interpolating animation into plain frames, one every
+    /// `step_ticks` ticks, blended the way the game blends them live: each
+    /// frame fades towards the next in sequence over its duration, keeping
+    /// its own alpha. The result plays the frames in order with per-frame
+    /// times and no interpolation.
+    ///
+    /// Returns None if the texture doesn't interpolate or `step_ticks` is 0.
+    /// Long animations get large: a 300-tick frame is 300 frames at step 1.
+    pub fn bake_interpolation(&self, step_ticks: u32) -> Option<TextureData> {
+        let meta = self.animation.as_ref().filter(|m| m.interpolate)?;
+        if step_ticks == 0 || !self.is_animated || self.frame_count <= 1 {
+            return None;
+        }
+        let frame_height = self.height / self.frame_count;
+        let frame_size = (self.width * frame_height * 4) as usize;
+        if self.pixels.len() < frame_size * self.frame_count as usize {
+            return None;
+        }
+
+        let last = self.frame_count - 1;
+        let sequence: Vec<(u32, u32)> = match &meta.frames {
+            Some(frames) => frames
+                .iter()
+                .map(|f| (f.index.min(last), f.time.unwrap_or(meta.frametime)))
+                .collect(),
+            None => (0..self.frame_count).map(|i| (i, meta.frametime)).collect(),
+        };
+        let frame = |i: u32| &self.pixels[i as usize * frame_size..(i as usize + 1) * frame_size];
+
+        let mut pixels = Vec::new();
+        let mut frames = Vec::new();
+        for (n, &(index, time)) in sequence.iter().enumerate() {
+            let next = sequence[(n + 1) % sequence.len()].0;
+            let time = time.max(1);
+            let mut tick = 0;
+            while tick < time {
+                let t = tick as f32 / time as f32;
+                for (a, b) in frame(index).chunks_exact(4).zip(frame(next).chunks_exact(4)) {
+                    for c in 0..3 {
+                        pixels.push((a[c] as f32 * (1.0 - t) + b[c] as f32 * t).round() as u8);
+                    }
+                    pixels.push(a[3]);
+                }
+                let duration = step_ticks.min(time - tick);
+                frames.push(AnimFrame { index: frames.len() as u32, time: Some(duration) });
+                tick += duration;
+            }
+        }
+
+        let frame_count = frames.len() as u32;
+        Some(TextureData {
+            width: self.width,
+            height: frame_height * frame_count,
+            pixels,
+            is_animated: frame_count > 1,
+            frame_count,
+            animation: Some(AnimationMeta {
+                frametime: step_ticks,
+                interpolate: false,
+                frames: Some(frames),
+                frame_width: meta.frame_width,
+                frame_height: Some(frame_height),
+            }),
+        })
+    }
 }
 
 /// Load a texture from PNG bytes.
@@ -278,6 +425,22 @@ mod tests {
         assert!(transparent.has_transparency());
     }
 
+    #[test]
+    fn test_companion_frame_follows_color_frames() {
+        // Two 1x1 frames, played second-first.
+        let mut color = TextureData::new(1, 2, vec![10, 10, 10, 255, 20, 20, 20, 255]);
+        color.apply_mcmeta(parse_mcmeta(r#"{"animation": {"frames": [1, 0]}}"#).unwrap());
+        // A 2x-resolution normal strip without mcmeta.
+        let mut pixels = [1, 1, 1, 255].repeat(4);
+        pixels.extend([2, 2, 2, 255].repeat(4));
+        let normal = TextureData::new(2, 4, pixels);
+
+        let frame = color.companion_frame(&normal);
+        assert_eq!((frame.width, frame.height), (2, 2));
+        assert_eq!(frame.pixels, [2, 2, 2, 255].repeat(4));
+        assert_eq!(color.first_frame().pixels, vec![20, 20, 20, 255]);
+    }
+
     #[test]
     fn test_parse_mcmeta_basic() {
         let json = r#"{"animation":{"frametime":2,"interpolate":true}}"#;
@@ -316,6 +479,29 @@ mod tests {
         assert!(meta.frames.is_none());
     }
 
+    #[test]
+    fn test_mcmeta_error() {
+        assert!(mcmeta_error(r#"{"animation": {"frametime": 2, "interpolate": true, "frames": [0, {"index": 1, "time": 4}]}}"#).is_none());
+        assert!(mcmeta_error(r#"{"villager": {"hat": "full"}}"#).is_none());
+        assert!(mcmeta_error(r#"{"animation": {"#).is_some());
+
+        // Well-formed JSON that parse_mcmeta would silently default.
+        for bad in [
+            r#"{"animation": {"frametime": "2"}}"#,
+            r#"{"animation": {"frametime": 0}}"#,
+            r#"{"animation": {"interpolate": 1}}"#,
+            r#"{"animation": {"width": -16}}"#,
+            r#"{"animation": {"frames": {"0": 1}}}"#,
+            r#"{"animation": {"frames": [0, "1"]}}"#,
+            r#"{"animation": {"frames": [{"time": 2}]}}"#,
+            r#"{"animation": []}"#,
+        ] {
+            assert!(mcmeta_error(bad).is_some(), "{}", bad);
+            assert!(parse_mcmeta(bad).is_some());
+        }
+        assert!(mcmeta_error(r#"{"animation": {"frametime": "2"}}"#).unwrap().contains("animation.frametime"));
+    }
+
     #[test]
     fn test_apply_mcmeta() {
         // 16x64 texture = 4 frames of 16x16
@@ -366,4 +552,39 @@ mod tests {
         assert_eq!(frame.height, 16);
         assert_eq!(frame.pixels[0], 42); // Should be frame index 2
     }
+
+    #[test]
+    fn test_bake_interpolation() {
+        // Two 1x1 frames, black then white, 4 ticks each.
+        let tex = TextureData {
+            width: 1,
+            height: 2,
+            pixels: vec![0, 0, 0, 255, 255, 255, 255, 128],
+            is_animated: true,
+            frame_count: 2,
+            animation: Some(AnimationMeta {
+                frametime: 4,
+                interpolate: true,
+                frames: None,
+                frame_width: None,
+                frame_height: None,
+            }),
+        };
+
+        let baked = tex.bake_interpolation(2).unwrap();
+        assert_eq!(baked.frame_count, 4);
+        assert_eq!(baked.height, 4);
+        let reds: Vec<u8> = baked.pixels.chunks(4).map(|px| px[0]).collect();
+        assert_eq!(reds, vec![0, 128, 255, 128]);
+        // Alpha comes from the frame being faded out, not the blend.
+        assert_eq!(baked.pixels[7], 255);
+        assert_eq!(baked.pixels[11], 128);
+        let meta = baked.animation.unwrap();
+        assert!(!meta.interpolate);
+        let times: Vec<_> = meta.frames.unwrap().iter().map(|f| f.time).collect();
+        assert_eq!(times, vec![Some(2); 4]);
+
+        assert!(tex.bake_interpolation(0).is_none());
+        assert!(tex.first_frame().bake_interpolation(1).is_none());
+    }
 }
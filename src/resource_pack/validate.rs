@@ -0,0 +1,539 @@
+//! Resource pack lint/validation.
+//!
+//! [`validate_pack`] walks every blockstate → model → parent chain → texture
+//! reference and collects everything that would otherwise only show up as a
+//! magenta placeholder or a one-off resolution error at mesh time.
+
+use super::{BlockstateDefinition, ResourcePack};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// How serious a validation issue is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// The block will not render as authored (missing geometry or texture).
+    Error,
+    /// The block renders, but the asset is unusual and may look wrong.
+    Warning,
+}
+
+/// The category of a validation issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// A pack file could not be parsed and was skipped by the loader.
+    MalformedFile,
+    /// A blockstate or child model references a model that doesn't exist.
+    MissingModel,
+    /// A model's parent chain loops back on itself.
+    CircularParent,
+    /// A model's parent chain is longer than the resolver will follow.
+    ParentChainTooDeep,
+    /// A face texture variable (`#name`) is never bound in the model chain.
+    UnresolvedTextureRef,
+    /// A face resolves to a texture path that isn't in the pack.
+    MissingTexture,
+    /// A block texture (or animation frame) is not square.
+    NonSquareTexture,
+    /// A block texture (or animation frame) side is not a power of two.
+    NonPowerOfTwoTexture,
+    /// `.mcmeta` animation data is inconsistent with its texture.
+    MalformedMcmeta,
+}
+
+impl IssueKind {
+    /// The default severity for this kind of issue.
+    pub fn severity(self) -> Severity {
+        match self {
+            IssueKind::NonSquareTexture | IssueKind::NonPowerOfTwoTexture => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+/// A single problem found in a resource pack.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidationIssue {
+    /// What kind of problem this is.
+    pub kind: IssueKind,
+    /// Error or warning.
+    pub severity: Severity,
+    /// The offending resource (model location, texture path, file path, ...).
+    pub resource: String,
+    /// The first resource found referencing it (blockstate or model), if any.
+    pub referenced_by: Option<String>,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Structured result of [`validate_pack`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ValidationReport {
+    /// Number of blockstate definitions walked.
+    pub blockstates_checked: usize,
+    /// Number of distinct models reached from blockstates (including parents).
+    pub models_checked: usize,
+    /// Number of distinct textures reached from models.
+    pub textures_checked: usize,
+    /// All issues, sorted by kind then resource.
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// True when no errors were found (warnings are allowed).
+    pub fn is_ok(&self) -> bool {
+        self.error_count() == 0
+    }
+
+    /// Number of error-severity issues.
+    pub fn error_count(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == Severity::Error).count()
+    }
+
+    /// Number of warning-severity issues.
+    pub fn warning_count(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == Severity::Warning).count()
+    }
+
+    /// All issues of the given kind.
+    pub fn issues_of(&self, kind: IssueKind) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(move |i| i.kind == kind)
+    }
+}
+
+/// Maximum parent chain length before the walk gives up. Matches the
+/// resolver's limit so anything reported here also fails at mesh time.
+const MAX_PARENT_DEPTH: usize = 10;
+
+/// Validate a loaded resource pack.
+///
+/// Validate the merged result of [`load_resource_packs`](crate::load_resource_packs)
+/// when checking an overlay pack, otherwise every vanilla parent model will be
+/// reported missing.
+pub fn validate_pack(pack: &ResourcePack) -> ValidationReport {
+    let mut v = Validator {
+        pack,
+        issues: BTreeMap::new(),
+        models_seen: HashSet::new(),
+        textures_seen: HashSet::new(),
+    };
+
+    for err in &pack.load_errors {
+        v.report(IssueKind::MalformedFile, &err.path, None, err.message.clone());
+    }
+
+    // Sorted so `referenced_by` is stable between runs.
+    let mut blockstates: Vec<(String, &BlockstateDefinition)> = pack
+        .blockstates
+        .iter()
+        .flat_map(|(ns, defs)| defs.iter().map(move |(id, def)| (format!("{}:{}", ns, id), def)))
+        .collect();
+    blockstates.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (block_id, def) in &blockstates {
+        let locations: Vec<String> = match def {
+            BlockstateDefinition::Variants(variants) => variants
+                .values()
+                .flatten()
+                .map(|variant| variant.model_location())
+                .collect(),
+            BlockstateDefinition::Multipart(cases) => cases
+                .iter()
+                .flat_map(|case| case.apply.variants())
+                .map(|variant| variant.model_location())
+                .collect(),
+        };
+        let mut unique: Vec<String> = locations;
+        unique.sort();
+        unique.dedup();
+        for location in unique {
+            v.check_block_model(&location, block_id);
+        }
+    }
+
+    // Animation metadata is checked for every texture, not just block ones:
+    // a bad mcmeta on an entity texture is just as broken.
+    let mut texture_paths: Vec<String> = pack
+        .textures
+        .iter()
+        .flat_map(|(ns, texs)| texs.keys().map(move |path| format!("{}:{}", ns, path)))
+        .collect();
+    texture_paths.sort();
+    for path in &texture_paths {
+        v.check_mcmeta(path);
+    }
+
+    ValidationReport {
+        blockstates_checked: blockstates.len(),
+        models_checked: v.models_seen.len(),
+        textures_checked: v.textures_seen.len(),
+        issues: v.issues.into_values().collect(),
+    }
+}
+
+struct Validator<'a> {
+    pack: &'a ResourcePack,
+    /// Keyed by (kind, resource) so each problem is reported once, with the
+    /// first referencer encountered.
+    issues: BTreeMap<(IssueKind, String), ValidationIssue>,
+    models_seen: HashSet<String>,
+    textures_seen: HashSet<String>,
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, kind: IssueKind, resource: &str, referenced_by: Option<&str>, message: String) {
+        self.issues
+            .entry((kind, resource.to_string()))
+            .or_insert_with(|| ValidationIssue {
+                kind,
+                severity: kind.severity(),
+                resource: resource.to_string(),
+                referenced_by: referenced_by.map(str::to_string),
+                message,
+            });
+    }
+
+    /// Walk a model referenced by a blockstate: parent chain, then every face
+    /// texture of the effective (inherited) element list.
+    fn check_block_model(&mut self, location: &str, block_id: &str) {
+        let mut chain: Vec<String> = Vec::new();
+        let mut current = normalize(location);
+        let mut referrer = block_id.to_string();
+
+        loop {
+            if current.starts_with("minecraft:builtin/") {
+                break;
+            }
+            if chain.contains(&current) {
+                chain.push(current.clone());
+                let message = format!("parent chain loops: {}", chain.join(" -> "));
+                self.report(IssueKind::CircularParent, &current, Some(&referrer), message);
+                return;
+            }
+            if chain.len() >= MAX_PARENT_DEPTH {
+                let message = format!("parent chain deeper than {} models", MAX_PARENT_DEPTH);
+                self.report(IssueKind::ParentChainTooDeep, &chain[0], Some(block_id), message);
+                return;
+            }
+            self.models_seen.insert(current.clone());
+            let Some(model) = self.pack.get_model(&current) else {
+                let message = format!("model {} not found", current);
+                self.report(IssueKind::MissingModel, &current, Some(&referrer), message);
+                return;
+            };
+            chain.push(current.clone());
+            match model.parent_location() {
+                Some(parent) => {
+                    referrer = current;
+                    current = parent;
+                }
+                None => break,
+            }
+        }
+
+        // Merge texture variables root-first so children override parents, and
+        // take the elements of the nearest model that defines any.
+        let mut textures: HashMap<String, String> = HashMap::new();
+        for loc in chain.iter().rev() {
+            if let Some(model) = self.pack.get_model(loc) {
+                textures.extend(model.textures.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+        }
+        let elements = chain
+            .iter()
+            .filter_map(|loc| self.pack.get_model(loc))
+            .find(|m| m.has_elements())
+            .map(|m| &m.elements);
+        let Some(elements) = elements else {
+            return;
+        };
+
+        let model_id = normalize(location);
+        let mut refs: Vec<&str> = elements
+            .iter()
+            .flat_map(|e| e.faces.values())
+            .map(|f| f.texture.as_str())
+            .collect();
+        refs.sort_unstable();
+        refs.dedup();
+
+        for reference in refs {
+            match resolve_texture_ref(reference, &textures) {
+                Ok(path) => self.check_block_texture(&normalize(&path), &model_id),
+                Err(var) => {
+                    let message = format!("texture variable {} is never assigned", var);
+                    let resource = format!("{}{}", model_id, var);
+                    self.report(IssueKind::UnresolvedTextureRef, &resource, Some(&model_id), message);
+                }
+            }
+        }
+    }
+
+    fn check_block_texture(&mut self, path: &str, model_id: &str) {
+        if !self.textures_seen.insert(path.to_string()) {
+            return;
+        }
+        let Some(texture) = self.pack.get_texture(path) else {
+            let message = format!("texture {} not found", path);
+            self.report(IssueKind::MissingTexture, path, Some(model_id), message);
+            return;
+        };
+
+        // Judge animated textures by their frame, not the whole strip.
+        let (w, h) = frame_size(texture);
+        if w != h {
+            let message = format!("block texture is {}x{}, expected square", w, h);
+            self.report(IssueKind::NonSquareTexture, path, Some(model_id), message);
+        }
+        if !w.is_power_of_two() || !h.is_power_of_two() {
+            let message = format!("block texture is {}x{}, expected power-of-two sides", w, h);
+            self.report(IssueKind::NonPowerOfTwoTexture, path, Some(model_id), message);
+        }
+    }
+
+    fn check_mcmeta(&mut self, path: &str) {
+        let Some(texture) = self.pack.get_texture(path) else {
+            return;
+        };
+        let Some(meta) = &texture.animation else {
+            return;
+        };
+
+        let (fw, fh) = frame_size(texture);
+        let problem = if meta.frametime == 0 {
+            Some("frametime must be at least 1".to_string())
+        } else if fw == 0 || fh == 0 || fw > texture.width || fh > texture.height {
+            Some(format!(
+                "frame size {}x{} does not fit the {}x{} texture",
+                fw, fh, texture.width, texture.height
+            ))
+        } else if texture.width % fw != 0 || texture.height % fh != 0 {
+            Some(format!(
+                "texture {}x{} is not a whole number of {}x{} frames",
+                texture.width, texture.height, fw, fh
+            ))
+        } else {
+            let available = (texture.width / fw) * (texture.height / fh);
+            meta.frames.as_ref().and_then(|frames| {
+                frames.iter().find(|f| f.index >= available).map(|f| {
+                    format!("frame index {} out of range ({} frames)", f.index, available)
+                })
+            })
+        };
+
+        if let Some(message) = problem {
+            let resource = format!("{}.png.mcmeta", path);
+            self.report(IssueKind::MalformedMcmeta, &resource, None, message);
+        }
+    }
+}
+
+/// Frame dimensions of a texture: the mcmeta override, or square frames for
+/// animated strips, or the whole image.
+fn frame_size(texture: &super::TextureData) -> (u32, u32) {
+    match &texture.animation {
+        Some(meta) => {
+            let fw = meta.frame_width.unwrap_or(texture.width);
+            (fw, meta.frame_height.unwrap_or(fw))
+        }
+        None if texture.is_animated => (texture.width, texture.width),
+        None => (texture.width, texture.height),
+    }
+}
+
+/// Follow `#var` references to a texture path. On failure returns the
+/// variable that was never bound.
+fn resolve_texture_ref(reference: &str, textures: &HashMap<String, String>) -> Result<String, String> {
+    let mut current = reference;
+    for _ in 0..MAX_PARENT_DEPTH {
+        let Some(var) = current.strip_prefix('#') else {
+            return Ok(current.to_string());
+        };
+        match textures.get(var) {
+            Some(next) => current = next,
+            None => return Err(current.to_string()),
+        }
+    }
+    Err(current.to_string())
+}
+
+fn normalize(location: &str) -> String {
+    if location.contains(':') {
+        location.to_string()
+    } else {
+        format!("minecraft:{}", location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource_pack::{AnimFrame, AnimationMeta, BlockModel, ModelElement, ModelFace, ModelVariant, TextureData};
+    use crate::types::Direction;
+
+    fn cube(texture_ref: &str) -> BlockModel {
+        BlockModel {
+            elements: vec![ModelElement {
+                from: [0.0, 0.0, 0.0],
+                to: [16.0, 16.0, 16.0],
+                rotation: None,
+                shade: true,
+                light_emission: 0,
+                faces: Direction::ALL
+                    .iter()
+                    .map(|d| {
+                        (*d, ModelFace {
+                            uv: None,
+                            texture: texture_ref.to_string(),
+                            cullface: Some(*d),
+                            rotation: 0,
+                            tintindex: -1,
+                        })
+                    })
+                    .collect(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn child(parent: &str, textures: &[(&str, &str)]) -> BlockModel {
+        BlockModel {
+            parent: Some(parent.to_string()),
+            textures: textures.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn blockstate(model: &str) -> BlockstateDefinition {
+        let variant = ModelVariant { model: model.to_string(), x: 0, y: 0, uvlock: false, weight: 1 };
+        BlockstateDefinition::Variants([(String::new(), vec![variant])].into_iter().collect())
+    }
+
+    fn texture(w: u32, h: u32) -> TextureData {
+        TextureData::new(w, h, vec![255; (w * h * 4) as usize])
+    }
+
+    fn base_pack() -> ResourcePack {
+        let mut pack = ResourcePack::new();
+        pack.add_model("minecraft", "block/cube_all", cube("#all"));
+        pack.add_model("minecraft", "block/stone", child("block/cube_all", &[("all", "block/stone")]));
+        pack.add_blockstate("minecraft", "stone", blockstate("block/stone"));
+        pack.add_texture("minecraft", "block/stone", texture(16, 16));
+        pack
+    }
+
+    #[test]
+    fn clean_pack_has_no_issues() {
+        let report = validate_pack(&base_pack());
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+        assert!(report.is_ok());
+        assert_eq!(report.blockstates_checked, 1);
+        assert_eq!(report.models_checked, 2);
+        assert_eq!(report.textures_checked, 1);
+    }
+
+    #[test]
+    fn reports_missing_model_and_parent() {
+        let mut pack = base_pack();
+        pack.add_blockstate("minecraft", "dirt", blockstate("block/dirt"));
+        pack.add_model("minecraft", "block/gravel", child("block/nope", &[]));
+        pack.add_blockstate("minecraft", "gravel", blockstate("block/gravel"));
+
+        let report = validate_pack(&pack);
+        let missing: Vec<_> = report.issues_of(IssueKind::MissingModel).collect();
+        assert_eq!(missing.len(), 2);
+        assert_eq!(missing[0].resource, "minecraft:block/dirt");
+        assert_eq!(missing[0].referenced_by.as_deref(), Some("minecraft:dirt"));
+        assert_eq!(missing[1].resource, "minecraft:block/nope");
+        assert_eq!(missing[1].referenced_by.as_deref(), Some("minecraft:block/gravel"));
+        assert!(!report.is_ok());
+    }
+
+    #[test]
+    fn reports_circular_parent() {
+        let mut pack = base_pack();
+        pack.add_model("minecraft", "block/a", child("block/b", &[]));
+        pack.add_model("minecraft", "block/b", child("block/a", &[]));
+        pack.add_blockstate("minecraft", "loop", blockstate("block/a"));
+
+        let report = validate_pack(&pack);
+        assert_eq!(report.issues_of(IssueKind::CircularParent).count(), 1);
+    }
+
+    #[test]
+    fn reports_parent_chain_too_deep() {
+        let mut pack = base_pack();
+        for i in 0..MAX_PARENT_DEPTH {
+            pack.add_model("minecraft", &format!("block/deep{}", i), child(&format!("block/deep{}", i + 1), &[]));
+        }
+        pack.add_model("minecraft", &format!("block/deep{}", MAX_PARENT_DEPTH), child("block/cube_all", &[]));
+        pack.add_blockstate("minecraft", "deep", blockstate("block/deep0"));
+
+        let report = validate_pack(&pack);
+        let deep: Vec<_> = report.issues_of(IssueKind::ParentChainTooDeep).collect();
+        assert_eq!(deep.len(), 1);
+        assert_eq!(deep[0].resource, "minecraft:block/deep0");
+        assert_eq!(report.issues_of(IssueKind::CircularParent).count(), 0);
+    }
+
+    #[test]
+    fn reports_unresolved_and_missing_textures() {
+        let mut pack = base_pack();
+        pack.add_model("minecraft", "block/unbound", child("block/cube_all", &[]));
+        pack.add_blockstate("minecraft", "unbound", blockstate("block/unbound"));
+        pack.add_model("minecraft", "block/gone", child("block/cube_all", &[("all", "block/gone")]));
+        pack.add_blockstate("minecraft", "gone", blockstate("block/gone"));
+
+        let report = validate_pack(&pack);
+        let unresolved: Vec<_> = report.issues_of(IssueKind::UnresolvedTextureRef).collect();
+        assert_eq!(unresolved.len(), 1);
+        assert_eq!(unresolved[0].resource, "minecraft:block/unbound#all");
+        let missing: Vec<_> = report.issues_of(IssueKind::MissingTexture).collect();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].resource, "minecraft:block/gone");
+    }
+
+    #[test]
+    fn warns_on_odd_texture_sizes_but_not_animation_strips() {
+        let mut pack = base_pack();
+        pack.add_texture("minecraft", "block/stone", texture(24, 16));
+        let mut strip = texture(16, 64);
+        strip.apply_mcmeta(AnimationMeta {
+            frametime: 2,
+            interpolate: false,
+            frames: None,
+            frame_width: None,
+            frame_height: None,
+        });
+        pack.add_texture("minecraft", "block/lava", strip);
+        pack.add_model("minecraft", "block/lava", child("block/cube_all", &[("all", "block/lava")]));
+        pack.add_blockstate("minecraft", "lava", blockstate("block/lava"));
+
+        let report = validate_pack(&pack);
+        assert!(report.is_ok());
+        assert_eq!(report.warning_count(), 2);
+        assert!(report.issues.iter().all(|i| i.resource == "minecraft:block/stone"));
+    }
+
+    #[test]
+    fn reports_bad_mcmeta_and_load_errors() {
+        let mut pack = base_pack();
+        let mut strip = texture(16, 32);
+        strip.apply_mcmeta(AnimationMeta {
+            frametime: 1,
+            interpolate: false,
+            frames: Some(vec![AnimFrame { index: 0, time: None }, AnimFrame { index: 5, time: None }]),
+            frame_width: None,
+            frame_height: None,
+        });
+        pack.add_texture("minecraft", "entity/thing", strip);
+        pack.add_load_error("assets/minecraft/models/block/broken.json", "expected value");
+
+        let report = validate_pack(&pack);
+        let mcmeta: Vec<_> = report.issues_of(IssueKind::MalformedMcmeta).collect();
+        assert_eq!(mcmeta.len(), 1);
+        assert_eq!(mcmeta[0].resource, "minecraft:entity/thing.png.mcmeta");
+        assert_eq!(report.issues_of(IssueKind::MalformedFile).count(), 1);
+    }
+}
@@ -9,7 +9,7 @@ pub use transform::{BlockTransform, ElementRotation};
 use std::collections::HashMap;
 
 /// A block position in 3D space.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
 pub struct BlockPosition {
     pub x: i32,
     pub y: i32,
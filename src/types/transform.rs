@@ -26,15 +26,31 @@ impl BlockTransform {
 }
 
 /// Element-level rotation from model element.
+///
+/// Vanilla models rotate around a single `axis` by `angle` (±45° in 22.5°
+/// steps). Blockbench exports and newer model versions may instead give
+/// per-axis angles as `x`, `y` and `z`, of any size. These apply in that
+/// order, after the single-axis rotation when both are present.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ElementRotation {
     /// Origin point for rotation (in 0-16 Minecraft coordinates).
     #[serde(default = "default_origin")]
     pub origin: [f32; 3],
     /// Axis to rotate around.
+    #[serde(default = "default_axis")]
     pub axis: Axis,
-    /// Rotation angle in degrees (-45 to 45, in 22.5 increments).
+    /// Rotation angle in degrees around `axis`.
+    #[serde(default)]
     pub angle: f32,
+    /// Rotation around X in degrees.
+    #[serde(default)]
+    pub x: f32,
+    /// Rotation around Y in degrees.
+    #[serde(default)]
+    pub y: f32,
+    /// Rotation around Z in degrees.
+    #[serde(default)]
+    pub z: f32,
     /// Whether to rescale the element after rotation.
     #[serde(default)]
     pub rescale: bool,
@@ -44,6 +60,10 @@ fn default_origin() -> [f32; 3] {
     [8.0, 8.0, 8.0]
 }
 
+fn default_axis() -> Axis {
+    Axis::Y
+}
+
 impl ElementRotation {
     /// Convert origin from Minecraft coordinates (0-16) to normalized (-0.5 to 0.5).
     pub fn normalized_origin(&self) -> [f32; 3] {
@@ -59,13 +79,59 @@ impl ElementRotation {
         self.angle.to_radians()
     }
 
-    /// Get the rescale factor for this rotation.
-    /// When rescale is true, the element is scaled to maintain its original size.
-    pub fn rescale_factor(&self) -> f32 {
+    /// The single-axis rotations to apply, in order, as (axis, degrees).
+    /// Zero angles are skipped.
+    pub fn steps(&self) -> impl Iterator<Item = (Axis, f32)> {
+        [
+            (self.axis, self.angle),
+            (Axis::X, self.x),
+            (Axis::Y, self.y),
+            (Axis::Z, self.z),
+        ]
+        .into_iter()
+        .filter(|&(_, angle)| angle != 0.0)
+    }
+
+    /// Get the rescale factor for one rotation step of `angle_degrees`.
+    /// When rescale is true, the element is scaled on the two axes
+    /// perpendicular to the rotation so it spans its original extent again:
+    /// `1 / cos` up to 45° (as in vanilla), `1 / sin` beyond.
+    pub fn rescale_factor(&self, angle_degrees: f32) -> f32 {
         if self.rescale {
-            1.0 / self.angle_radians().cos()
+            let angle = angle_degrees.to_radians();
+            1.0 / angle.cos().abs().max(angle.sin().abs())
         } else {
             1.0
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_axis_rotation_steps() {
+        let rotation: ElementRotation =
+            serde_json::from_str(r#"{"origin": [8, 0, 8], "x": 30, "z": -90}"#).unwrap();
+        let steps: Vec<_> = rotation.steps().collect();
+        assert_eq!(steps, vec![(Axis::X, 30.0), (Axis::Z, -90.0)]);
+    }
+
+    #[test]
+    fn test_rescale_factor_matches_vanilla_and_stays_finite() {
+        let rotation = ElementRotation {
+            origin: [8.0, 8.0, 8.0],
+            axis: Axis::Y,
+            angle: 22.5,
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            rescale: true,
+        };
+        assert!((rotation.rescale_factor(22.5) - 1.0 / 22.5f32.to_radians().cos()).abs() < 1e-6);
+        assert!((rotation.rescale_factor(45.0) - std::f32::consts::SQRT_2).abs() < 1e-5);
+        assert!((rotation.rescale_factor(90.0) - 1.0).abs() < 1e-5);
+        assert!((rotation.rescale_factor(60.0) - rotation.rescale_factor(30.0)).abs() < 1e-5);
+    }
+}
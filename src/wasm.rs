@@ -3,7 +3,8 @@
 //! This module exposes the mesher to JavaScript via `wasm-bindgen`. It provides:
 //!
 //! - **[`ResourcePackHandle`]** — Load and inspect a resource pack from a ZIP `Uint8Array`.
-//! - **[`MesherOptions`]** — Configure face culling, AO, atlas size, and biome tinting.
+//! - **[`MesherOptions`]** — Configure face culling, AO, atlas size, biome tinting, and
+//!   the DataVersion to upgrade blocks from.
 //! - **[`mesh_block()`]** / **[`mesh_blocks_json()`]** — One-shot meshing returning GLB bytes.
 //! - **[`MeshOutputWrapper`]** — Zero-copy per-layer typed array access (`Float32Array` /
 //!   `Uint32Array`) for direct WebGL/WebGPU upload.
@@ -62,6 +63,15 @@ impl ResourcePackHandle {
     pub fn texture_count(&self) -> usize {
         self.inner.texture_count()
     }
+
+    /// Add a player skin or cape PNG under its texture hash (or full texture
+    /// URL), for entities and heads whose `textures` property names it.
+    pub fn add_skin(&mut self, key: &str, png: &[u8]) -> Result<(), JsError> {
+        let texture = crate::resource_pack::texture::load_texture_from_bytes(png)
+            .map_err(|e| JsError::new(&e.to_string()))?;
+        self.inner.add_skin(key, texture);
+        Ok(())
+    }
 }
 
 /// Mesher configuration options.
@@ -73,6 +83,7 @@ pub struct MesherOptions {
     ao_intensity: f32,
     atlas_max_size: u32,
     biome: Option<String>,
+    data_version: Option<u32>,
 }
 
 #[wasm_bindgen]
@@ -85,6 +96,7 @@ impl MesherOptions {
             ao_intensity: 0.4,
             atlas_max_size: 4096,
             biome: None,
+            data_version: None,
         }
     }
 
@@ -112,6 +124,12 @@ impl MesherOptions {
     pub fn set_biome(&mut self, value: String) {
         self.biome = Some(value);
     }
+
+    /// DataVersion the blocks were saved with; older states are upgraded.
+    #[wasm_bindgen(setter)]
+    pub fn set_data_version(&mut self, value: u32) {
+        self.data_version = Some(value);
+    }
 }
 
 /// A block to be meshed.
@@ -150,6 +168,7 @@ pub struct MeshResult {
     vertex_count: usize,
     triangle_count: usize,
     has_transparency: bool,
+    diagnostics: crate::MeshDiagnostics,
 }
 
 #[wasm_bindgen]
@@ -177,6 +196,17 @@ impl MeshResult {
     pub fn has_transparency(&self) -> bool {
         self.has_transparency
     }
+
+    /// Skipped blocks, missing textures and fallbacks as a JSON string.
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics_json(&self) -> Result<String, JsError> {
+        diagnostics_to_json(&self.diagnostics)
+    }
+}
+
+/// Helper: serialize mesh diagnostics for JavaScript.
+fn diagnostics_to_json(diagnostics: &crate::MeshDiagnostics) -> Result<String, JsError> {
+    serde_json::to_string(diagnostics).map_err(|e| JsError::new(&e.to_string()))
 }
 
 /// Helper: build a MesherConfig from MesherOptions.
@@ -186,6 +216,7 @@ fn build_config(options: &MesherOptions) -> crate::MesherConfig {
     config.ambient_occlusion = options.ambient_occlusion;
     config.ao_intensity = options.ao_intensity;
     config.atlas_max_size = options.atlas_max_size;
+    config.data_version = options.data_version;
     if let Some(biome) = &options.biome {
         config = config.with_biome(biome);
     }
@@ -230,6 +261,7 @@ pub fn mesh_block(
         vertex_count: output.total_vertices(),
         triangle_count: output.total_triangles(),
         has_transparency: output.has_transparency(),
+        diagnostics: output.diagnostics,
     })
 }
 
@@ -267,6 +299,7 @@ pub fn mesh_blocks_json(
         vertex_count: output.total_vertices(),
         triangle_count: output.total_triangles(),
         has_transparency: output.has_transparency(),
+        diagnostics: output.diagnostics,
     })
 }
 
@@ -363,6 +396,10 @@ fn parse_blocks_json(json: &str) -> Result<(Vec<(crate::BlockPosition, crate::In
 ///
 /// - `to_glb()` → `Uint8Array` (GLB binary)
 /// - `to_usdz()` → `Uint8Array` (USDZ archive)
+///
+/// ## Diagnostics
+///
+/// - `diagnostics_json()` → `string` (skipped blocks, missing textures, fallbacks)
 #[wasm_bindgen]
 pub struct MeshOutputWrapper {
     inner: crate::mesh_output::MeshOutput,
@@ -537,6 +574,20 @@ impl MeshOutputWrapper {
         self.inner.atlas.height
     }
 
+    /// Number of atlas pages. Page `n` geometry has U offset by `n`.
+    pub fn atlas_page_count(&self) -> u32 {
+        self.inner.atlas.page_count() as u32
+    }
+
+    /// RGBA pixel data of atlas page `page` as `Uint8Array` (empty if out of range).
+    pub fn atlas_page_rgba(&self, page: u32) -> js_sys::Uint8Array {
+        let page = page as usize;
+        if page >= self.inner.atlas.page_count() {
+            return js_sys::Uint8Array::new_with_length(0);
+        }
+        unsafe { js_sys::Uint8Array::view(self.inner.atlas.page_pixels(page)) }
+    }
+
     // --- Spatial info ---
 
     /// Bounding box minimum as `[x, y, z]`.
@@ -602,6 +653,11 @@ impl MeshOutputWrapper {
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
+
+    /// Skipped blocks, missing textures and fallbacks as a JSON string.
+    pub fn diagnostics_json(&self) -> Result<String, JsError> {
+        diagnostics_to_json(&self.inner.diagnostics)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -655,8 +711,10 @@ impl ChunkMeshIteratorWrapper {
             cutout: crate::mesh_output::MeshLayer::new(),
             transparent: crate::mesh_output::MeshLayer::new(),
             atlas: crate::atlas::TextureAtlas::empty(),
+            texture_array: None,
             greedy_materials: Vec::new(),
             animated_textures: Vec::new(),
+            diagnostics: Default::default(),
             bounds: crate::BoundingBox::new([0.0; 3], [0.0; 3]),
             chunk_coord: None,
             lod_level: 0,